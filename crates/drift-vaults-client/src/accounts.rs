@@ -0,0 +1,79 @@
+use anchor_lang::error::ErrorCode;
+use anchor_lang::{Discriminator, Result, ZeroCopy};
+use drift_vaults::state::{
+    FeeUpdate, ManagerStats, NavHistory, NavPolicy, TokenizedVaultDepositor, Vault, VaultDepositor,
+    VaultProtocol,
+};
+
+/// Decodes a zero copy account of the vaults program from its data, discriminator included. The data
+/// doesn't need to be aligned.
+pub fn decode_account<T: ZeroCopy + Discriminator>(data: &[u8]) -> Result<T> {
+    let discriminator = data
+        .get(..8)
+        .ok_or(ErrorCode::AccountDiscriminatorNotFound)?;
+    if discriminator != T::discriminator() {
+        return Err(ErrorCode::AccountDiscriminatorMismatch.into());
+    }
+
+    let bytes = data
+        .get(8..8 + std::mem::size_of::<T>())
+        .ok_or(ErrorCode::AccountDidNotDeserialize)?;
+    bytemuck::try_pod_read_unaligned(bytes).map_err(|_| ErrorCode::AccountDidNotDeserialize.into())
+}
+
+pub fn decode_vault(data: &[u8]) -> Result<Vault> {
+    decode_account(data)
+}
+
+pub fn decode_vault_depositor(data: &[u8]) -> Result<VaultDepositor> {
+    decode_account(data)
+}
+
+pub fn decode_tokenized_vault_depositor(data: &[u8]) -> Result<TokenizedVaultDepositor> {
+    decode_account(data)
+}
+
+pub fn decode_vault_protocol(data: &[u8]) -> Result<VaultProtocol> {
+    decode_account(data)
+}
+
+pub fn decode_fee_update(data: &[u8]) -> Result<FeeUpdate> {
+    decode_account(data)
+}
+
+pub fn decode_nav_policy(data: &[u8]) -> Result<NavPolicy> {
+    decode_account(data)
+}
+
+pub fn decode_nav_history(data: &[u8]) -> Result<NavHistory> {
+    decode_account(data)
+}
+
+pub fn decode_manager_stats(data: &[u8]) -> Result<ManagerStats> {
+    decode_account(data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::prelude::Pubkey;
+
+    #[test]
+    fn test_decode_vault_depositor() {
+        let vault_depositor = VaultDepositor {
+            vault: Pubkey::new_unique(),
+            authority: Pubkey::new_unique(),
+            vault_shares: 1_000_000,
+            ..VaultDepositor::default()
+        };
+
+        // offset by one so the account bytes are unaligned
+        let mut data = vec![0_u8];
+        data.extend_from_slice(&VaultDepositor::discriminator());
+        data.extend_from_slice(bytemuck::bytes_of(&vault_depositor));
+
+        assert_eq!(decode_vault_depositor(&data[1..]).unwrap(), vault_depositor);
+        assert!(decode_vault(&data[1..]).is_err());
+        assert!(decode_vault_depositor(&data[1..data.len() - 1]).is_err());
+    }
+}
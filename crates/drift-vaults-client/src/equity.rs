@@ -0,0 +1,478 @@
+//! Off-chain vault equity and depositor balances from raw account data.
+//!
+//! Runs the program's own equity, fee, rebase and profit share math on copies of the accounts, so the
+//! numbers match what the vault would charge or pay out at `now` without sending a transaction.
+
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+use drift::instructions::optional_accounts::{load_maps, AccountMaps};
+use drift::state::perp_market::PerpMarket;
+use drift::state::spot_market::SpotMarket;
+use drift::state::user::User;
+use drift_vaults::state::{
+    DepositorEquityPreview, FeeUpdate, FeeUpdateStatus, FeesPreview, NavPolicy, NavSide, Vault,
+    VaultPreview, VaultProtocol,
+};
+use std::collections::BTreeSet;
+
+use crate::accounts::{decode_account, decode_vault, decode_vault_depositor};
+use crate::pda::{get_fee_update_address, get_nav_policy_address, get_vault_protocol_address};
+
+/// An account as returned by rpc
+#[derive(Clone, Debug, Default)]
+pub struct RawAccount {
+    pub pubkey: Pubkey,
+    pub owner: Pubkey,
+    pub data: Vec<u8>,
+}
+
+/// The account data a vault's equity is calculated from
+#[derive(Clone, Debug, Default)]
+pub struct EquityAccounts {
+    pub vault: Vec<u8>,
+    pub drift_user: Vec<u8>,
+    /// The oracles, spot markets and perp markets of the drift user's positions, in any order
+    pub markets: Vec<RawAccount>,
+    /// Required if the vault has a [`VaultProtocol`]
+    pub vault_protocol: Option<Vec<u8>>,
+    /// Required while the vault has a pending fee update, ignored otherwise
+    pub fee_update: Option<Vec<u8>>,
+    /// Required if the vault has a [`NavPolicy`]
+    pub nav_policy: Option<Vec<u8>>,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct VaultBreakdown {
+    pub ts: i64,
+    /// The drift user's equity in the vault's deposit asset
+    pub vault_equity: u64,
+    /// The equity deposits are priced at, after the vault's [`NavPolicy`] if it has one
+    pub deposit_equity: u64,
+    /// The equity withdrawals are priced at, after the vault's [`NavPolicy`] if it has one
+    pub withdraw_equity: u64,
+    /// Fees owed since the vault's last fee update
+    pub fees: FeesPreview,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct DepositorBreakdown {
+    /// The depositor's shares and their value at the withdraw equity, after fees and profit share
+    pub equity: DepositorEquityPreview,
+    /// The value of the depositor's pending withdraw request, 0 if there is none
+    pub withdraw_request_value: u64,
+    /// What the pending withdraw request pays out at the withdraw equity
+    pub withdraw_request_payout: u64,
+    /// The ts the pending withdraw request can be completed at
+    pub withdrawable_ts: i64,
+    /// The withdraw request payout once the redeem period is over, 0 before
+    pub withdrawable_amount: u64,
+}
+
+pub fn calculate_vault_breakdown(
+    accounts: &EquityAccounts,
+    slot: u64,
+    now: i64,
+) -> Result<VaultBreakdown> {
+    Ok(calculate_breakdown(accounts, slot, now, |_| Ok(()))?.0)
+}
+
+pub fn calculate_depositor_breakdown(
+    accounts: &EquityAccounts,
+    vault_depositor: &[u8],
+    slot: u64,
+    now: i64,
+) -> Result<(VaultBreakdown, DepositorBreakdown)> {
+    let vault_depositor = decode_vault_depositor(vault_depositor)?;
+
+    calculate_breakdown(accounts, slot, now, |preview| {
+        require_keys_eq!(vault_depositor.vault, preview.vault.pubkey);
+
+        let equity = VaultPreview::new(
+            preview.vault,
+            preview.vault_protocol,
+            preview.fee_update,
+            preview.withdraw_equity,
+            now,
+        )?
+        .preview_depositor_equity(vault_depositor)?;
+        let withdraw_request_payout = VaultPreview::new(
+            preview.vault,
+            preview.vault_protocol,
+            preview.fee_update,
+            preview.withdraw_equity,
+            now,
+        )?
+        .preview_withdraw_payout(vault_depositor)?;
+
+        let withdraw_request = vault_depositor.last_withdraw_request;
+        let withdrawable_ts = if withdraw_request.pending() {
+            withdraw_request
+                .ts
+                .saturating_add(preview.vault.redeem_period)
+        } else {
+            0
+        };
+        let withdrawable_amount = if withdraw_request.pending() && now >= withdrawable_ts {
+            withdraw_request_payout
+        } else {
+            0
+        };
+
+        Ok(DepositorBreakdown {
+            equity,
+            withdraw_request_value: withdraw_request.value,
+            withdraw_request_payout,
+            withdrawable_ts,
+            withdrawable_amount,
+        })
+    })
+}
+
+/// The vault state depositor breakdowns are previewed from
+struct BreakdownPreview<'a, 'info> {
+    vault: &'a Vault,
+    vault_protocol: &'a Option<AccountLoader<'info, VaultProtocol>>,
+    fee_update: &'a Option<AccountLoader<'info, FeeUpdate>>,
+    withdraw_equity: u64,
+}
+
+/// An owned copy of an account that [`AccountInfo`]s can borrow from
+struct OwnedAccount {
+    key: Pubkey,
+    owner: Pubkey,
+    lamports: u64,
+    data: Vec<u8>,
+}
+
+impl OwnedAccount {
+    fn new(key: Pubkey, owner: Pubkey, data: &[u8]) -> Self {
+        OwnedAccount {
+            key,
+            owner,
+            lamports: 0,
+            data: data.to_vec(),
+        }
+    }
+
+    fn account_info(&mut self) -> AccountInfo<'_> {
+        AccountInfo::new(
+            &self.key,
+            false,
+            false,
+            &mut self.lamports,
+            &mut self.data,
+            &self.owner,
+            false,
+            0,
+        )
+    }
+}
+
+/// The maps stop loading at the first account that isn't an oracle, then the first that isn't a spot market
+fn market_account_order(acct: &OwnedAccount) -> u8 {
+    let discriminator = acct.data.get(..8);
+    if acct.owner == drift::ID && discriminator == Some(&SpotMarket::discriminator()[..]) {
+        1
+    } else if acct.owner == drift::ID && discriminator == Some(&PerpMarket::discriminator()[..]) {
+        2
+    } else {
+        0
+    }
+}
+
+/// Calculates the vault's breakdown and runs `preview` on the same state
+fn calculate_breakdown<T>(
+    accounts: &EquityAccounts,
+    slot: u64,
+    now: i64,
+    preview: impl FnOnce(BreakdownPreview) -> Result<T>,
+) -> Result<(VaultBreakdown, T)> {
+    let vault = decode_vault(&accounts.vault)?;
+    let user: User = decode_account(&accounts.drift_user)?;
+    require_keys_eq!(user.authority, vault.pubkey);
+
+    // the vault's optional accounts go through the same loaders and checks as on-chain
+    let mut vault_protocol = accounts.vault_protocol.as_ref().map(|data| {
+        OwnedAccount::new(
+            get_vault_protocol_address(&vault.pubkey),
+            drift_vaults::ID,
+            data,
+        )
+    });
+    let mut fee_update = accounts
+        .fee_update
+        .as_ref()
+        .filter(|_| FeeUpdateStatus::has_pending_fee_update(vault.fee_update_status))
+        .map(|data| {
+            OwnedAccount::new(
+                get_fee_update_address(&vault.pubkey),
+                drift_vaults::ID,
+                data,
+            )
+        });
+    let mut nav_policy = accounts.nav_policy.as_ref().map(|data| {
+        OwnedAccount::new(
+            get_nav_policy_address(&vault.pubkey),
+            drift_vaults::ID,
+            data,
+        )
+    });
+
+    let vault_protocol_info = vault_protocol.as_mut().map(OwnedAccount::account_info);
+    let fee_update_info = fee_update.as_mut().map(OwnedAccount::account_info);
+    let nav_policy_info = nav_policy.as_mut().map(OwnedAccount::account_info);
+
+    let vp: Option<AccountLoader<VaultProtocol>> = vault_protocol_info
+        .as_ref()
+        .map(AccountLoader::try_from)
+        .transpose()?;
+    vault.validate_vault_protocol(&vp)?;
+
+    let fee_update: Option<AccountLoader<FeeUpdate>> = fee_update_info
+        .as_ref()
+        .map(AccountLoader::try_from)
+        .transpose()?;
+    vault.validate_fee_update(&fee_update)?;
+
+    let nav_policy: Option<AccountLoader<NavPolicy>> = nav_policy_info
+        .as_ref()
+        .map(AccountLoader::try_from)
+        .transpose()?;
+    vault.validate_nav_policy(&nav_policy)?;
+
+    let mut markets: Vec<OwnedAccount> = accounts
+        .markets
+        .iter()
+        .map(|acct| OwnedAccount::new(acct.pubkey, acct.owner, &acct.data))
+        .collect();
+    markets.sort_by_key(market_account_order);
+    let market_infos: Vec<AccountInfo> =
+        markets.iter_mut().map(OwnedAccount::account_info).collect();
+
+    let AccountMaps {
+        perp_market_map,
+        spot_market_map,
+        mut oracle_map,
+    } = load_maps(
+        &mut market_infos.iter().peekable(),
+        &BTreeSet::new(),
+        &BTreeSet::new(),
+        slot,
+        None,
+    )?;
+
+    let vault_equity =
+        vault.calculate_equity(&user, &perp_market_map, &spot_market_map, &mut oracle_map)?;
+    let deposit_equity = vault.calculate_swing_equity(
+        &nav_policy,
+        NavSide::Deposit,
+        &user,
+        &perp_market_map,
+        &spot_market_map,
+        &mut oracle_map,
+    )?;
+    let withdraw_equity = vault.calculate_swing_equity(
+        &nav_policy,
+        NavSide::Withdraw,
+        &user,
+        &perp_market_map,
+        &spot_market_map,
+        &mut oracle_map,
+    )?;
+
+    let vault_breakdown = VaultBreakdown {
+        ts: now,
+        vault_equity,
+        deposit_equity,
+        withdraw_equity,
+        fees: VaultPreview::new(&vault, &vp, &fee_update, vault_equity, now)?.preview_fees()?,
+    };
+
+    let preview = preview(BreakdownPreview {
+        vault: &vault,
+        vault_protocol: &vp,
+        fee_update: &fee_update,
+        withdraw_equity,
+    })?;
+
+    Ok((vault_breakdown, preview))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use drift::math::constants::{
+        PRICE_PRECISION_I64, QUOTE_PRECISION_U64, SPOT_BALANCE_PRECISION,
+        SPOT_BALANCE_PRECISION_U64, SPOT_CUMULATIVE_INTEREST_PRECISION,
+    };
+    use drift::math::spot_balance::get_token_amount;
+    use drift::state::oracle::{HistoricalOracleData, OracleSource};
+    use drift::state::perp_market::MarketStatus;
+    use drift::state::spot_market::SpotBalanceType;
+    use drift::state::user::{SpotPosition, UserStats};
+    use drift_vaults::state::VaultDepositor;
+
+    use crate::pda::get_spot_market_address;
+
+    fn account_data<T: bytemuck::Pod + Discriminator>(account: &T) -> Vec<u8> {
+        let mut data = T::discriminator().to_vec();
+        data.extend_from_slice(bytemuck::bytes_of(account));
+        data
+    }
+
+    #[test]
+    fn test_calculate_depositor_breakdown_without_positions() {
+        let vault_pubkey = Pubkey::new_unique();
+        let vault = Vault {
+            pubkey: vault_pubkey,
+            vault_protocol: true,
+            redeem_period: 3600,
+            ..Vault::default()
+        };
+        let user = User {
+            authority: vault_pubkey,
+            ..User::default()
+        };
+        let vault_depositor = VaultDepositor {
+            vault: vault_pubkey,
+            ..VaultDepositor::default()
+        };
+
+        let mut accounts = EquityAccounts {
+            vault: account_data(&vault),
+            drift_user: account_data(&user),
+            ..EquityAccounts::default()
+        };
+
+        // the vault has a VaultProtocol, so it must be passed like on-chain
+        assert!(calculate_vault_breakdown(&accounts, 0, 100).is_err());
+
+        accounts.vault_protocol = Some(account_data(&VaultProtocol::default()));
+        let (vault_breakdown, depositor_breakdown) =
+            calculate_depositor_breakdown(&accounts, &account_data(&vault_depositor), 0, 100)
+                .unwrap();
+
+        assert_eq!(vault_breakdown.ts, 100);
+        assert_eq!(vault_breakdown.vault_equity, 0);
+        assert_eq!(vault_breakdown.withdraw_equity, 0);
+        assert_eq!(depositor_breakdown, DepositorBreakdown::default());
+
+        // a depositor of another vault
+        let other_vault_depositor = VaultDepositor {
+            vault: Pubkey::new_unique(),
+            ..VaultDepositor::default()
+        };
+        assert!(calculate_depositor_breakdown(
+            &accounts,
+            &account_data(&other_vault_depositor),
+            0,
+            100
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_calculate_depositor_breakdown_matches_program_preview() {
+        let now = 1_700_000_000;
+        let vault_pubkey = Pubkey::new_unique();
+        let mut vault = Vault {
+            pubkey: vault_pubkey,
+            spot_market_index: 0,
+            management_fee: 20_000, // 2%
+            profit_share: 200_000,  // 20%
+            redeem_period: 3600,
+            last_fee_update_ts: now,
+            ..Vault::default()
+        };
+
+        let mut vault_depositor = VaultDepositor::new(
+            Pubkey::new_unique(),
+            vault_pubkey,
+            Pubkey::new_unique(),
+            now,
+        );
+        vault_depositor
+            .deposit(
+                1_000 * QUOTE_PRECISION_U64,
+                0,
+                &mut vault,
+                &mut None,
+                &mut None,
+                now,
+                &UserStats::default(),
+                &None,
+                PRICE_PRECISION_I64,
+            )
+            .unwrap();
+
+        // usdc, the quote spot market, after 5% of deposit interest accrued
+        let spot_market = SpotMarket {
+            market_index: 0,
+            oracle_source: OracleSource::QuoteAsset,
+            status: MarketStatus::Active,
+            decimals: 6,
+            cumulative_deposit_interest: SPOT_CUMULATIVE_INTEREST_PRECISION * 105 / 100,
+            cumulative_borrow_interest: SPOT_CUMULATIVE_INTEREST_PRECISION,
+            deposit_balance: 1_000 * SPOT_BALANCE_PRECISION,
+            historical_oracle_data: HistoricalOracleData {
+                last_oracle_price: PRICE_PRECISION_I64,
+                last_oracle_price_twap: PRICE_PRECISION_I64,
+                last_oracle_price_twap_5min: PRICE_PRECISION_I64,
+                ..HistoricalOracleData::default()
+            },
+            ..SpotMarket::default()
+        };
+        let mut user = User {
+            authority: vault_pubkey,
+            ..User::default()
+        };
+        user.spot_positions[0] = SpotPosition {
+            market_index: 0,
+            balance_type: SpotBalanceType::Deposit,
+            scaled_balance: 1_000 * SPOT_BALANCE_PRECISION_U64,
+            ..SpotPosition::default()
+        };
+
+        let accounts = EquityAccounts {
+            vault: account_data(&vault),
+            drift_user: account_data(&user),
+            markets: vec![RawAccount {
+                pubkey: get_spot_market_address(0),
+                owner: drift::ID,
+                data: account_data(&spot_market),
+            }],
+            ..EquityAccounts::default()
+        };
+
+        let now = now + 30 * 24 * 60 * 60;
+        let (vault_breakdown, depositor_breakdown) =
+            calculate_depositor_breakdown(&accounts, &account_data(&vault_depositor), 0, now)
+                .unwrap();
+
+        let vault_equity = get_token_amount(
+            user.spot_positions[0].scaled_balance as u128,
+            &spot_market,
+            &SpotBalanceType::Deposit,
+        )
+        .unwrap() as u64;
+        assert_eq!(vault_equity, 1_050 * QUOTE_PRECISION_U64);
+        assert_eq!(vault_breakdown.vault_equity, vault_equity);
+        assert_eq!(vault_breakdown.deposit_equity, vault_equity);
+        assert_eq!(vault_breakdown.withdraw_equity, vault_equity);
+
+        let fees = VaultPreview::new(&vault, &None, &None, vault_equity, now)
+            .unwrap()
+            .preview_fees()
+            .unwrap();
+        assert!(fees.management_fee_payment > 0);
+        assert_eq!(vault_breakdown.fees, fees);
+
+        let equity = VaultPreview::new(&vault, &None, &None, vault_equity, now)
+            .unwrap()
+            .preview_depositor_equity(vault_depositor)
+            .unwrap();
+        assert!(equity.manager_profit_share > 0);
+        assert_eq!(depositor_breakdown.equity, equity);
+        assert_eq!(depositor_breakdown.withdraw_request_payout, 0);
+    }
+}
@@ -0,0 +1,246 @@
+//! Decodes the vault program's events from transaction logs.
+//!
+//! Anchor's `emit!` logs an event as `Program data: <base64>`, the event's discriminator followed by its borsh
+//! encoding. Drift logs its own events the same way during cpis, so only data logged while the vault program
+//! is the innermost invoked program is decoded.
+
+use anchor_lang::error::ErrorCode;
+use anchor_lang::{AnchorDeserialize, Discriminator, Result};
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use drift_vaults::state::events::{
+    DrawdownProtectionRecord, FeeUpdateRecord, FuelDistributionUpdateRecord, FuelSeasonRecord,
+    ManagerBorrowRecord, ManagerRepayRecord, ManagerUpdateBorrowRecord, NavCircuitBreakerRecord,
+    ShareTransferRecord, VaultConfigRecord, VaultDepositorFuelRecord, VaultDepositorRecord,
+    VaultDepositorV1Record, VaultDepositorV2Record, VaultRecord,
+};
+
+const PROGRAM_DATA: &str = "Program data: ";
+
+/// Every event the vault program emits, including records older versions of the program emitted
+pub enum VaultEvent {
+    VaultRecord(VaultRecord),
+    VaultDepositorRecord(VaultDepositorRecord),
+    VaultDepositorV1Record(VaultDepositorV1Record),
+    VaultDepositorV2Record(VaultDepositorV2Record),
+    ShareTransferRecord(ShareTransferRecord),
+    FuelSeasonRecord(FuelSeasonRecord),
+    VaultDepositorFuelRecord(VaultDepositorFuelRecord),
+    FeeUpdateRecord(FeeUpdateRecord),
+    FuelDistributionUpdateRecord(FuelDistributionUpdateRecord),
+    ManagerBorrowRecord(ManagerBorrowRecord),
+    ManagerRepayRecord(ManagerRepayRecord),
+    ManagerUpdateBorrowRecord(ManagerUpdateBorrowRecord),
+    NavCircuitBreakerRecord(NavCircuitBreakerRecord),
+    DrawdownProtectionRecord(DrawdownProtectionRecord),
+    VaultConfigRecord(VaultConfigRecord),
+}
+
+macro_rules! decode_events {
+    ($discriminator:expr, $data:expr, $($event:ident),+ $(,)?) => {
+        $(
+            if $discriminator == $event::discriminator() {
+                let event = $event::deserialize($data)
+                    .map_err(|_| ErrorCode::AccountDidNotDeserialize)?;
+                return Ok(Some(VaultEvent::$event(event)));
+            }
+        )+
+    };
+}
+
+impl VaultEvent {
+    /// Decodes an event from its discriminator and borsh encoded fields. Returns None if the discriminator
+    /// isn't one of the vault program's events.
+    pub fn decode(data: &[u8]) -> Result<Option<VaultEvent>> {
+        let (discriminator, mut data) = match data.len() {
+            len if len >= 8 => data.split_at(8),
+            _ => return Ok(None),
+        };
+
+        decode_events!(
+            discriminator,
+            &mut data,
+            VaultRecord,
+            VaultDepositorRecord,
+            VaultDepositorV1Record,
+            VaultDepositorV2Record,
+            ShareTransferRecord,
+            FuelSeasonRecord,
+            VaultDepositorFuelRecord,
+            FeeUpdateRecord,
+            FuelDistributionUpdateRecord,
+            ManagerBorrowRecord,
+            ManagerRepayRecord,
+            ManagerUpdateBorrowRecord,
+            NavCircuitBreakerRecord,
+            DrawdownProtectionRecord,
+            VaultConfigRecord,
+        );
+
+        Ok(None)
+    }
+
+    pub fn ts(&self) -> i64 {
+        match self {
+            VaultEvent::VaultRecord(event) => event.ts,
+            VaultEvent::VaultDepositorRecord(event) => event.ts,
+            VaultEvent::VaultDepositorV1Record(event) => event.ts,
+            VaultEvent::VaultDepositorV2Record(event) => event.ts,
+            VaultEvent::ShareTransferRecord(event) => event.ts,
+            VaultEvent::FuelSeasonRecord(event) => event.ts,
+            VaultEvent::VaultDepositorFuelRecord(event) => event.ts,
+            VaultEvent::FeeUpdateRecord(event) => event.ts,
+            VaultEvent::FuelDistributionUpdateRecord(event) => event.ts,
+            VaultEvent::ManagerBorrowRecord(event) => event.ts,
+            VaultEvent::ManagerRepayRecord(event) => event.ts,
+            VaultEvent::ManagerUpdateBorrowRecord(event) => event.ts,
+            VaultEvent::NavCircuitBreakerRecord(event) => event.ts,
+            VaultEvent::DrawdownProtectionRecord(event) => event.ts,
+            VaultEvent::VaultConfigRecord(event) => event.ts,
+        }
+    }
+}
+
+/// Decodes the vault program's events from a transaction's log messages, in the order they were emitted.
+/// Logs truncated by the runtime's log limit only lose the events past the truncation.
+pub fn parse_logs<S: AsRef<str>>(logs: &[S]) -> Result<Vec<VaultEvent>> {
+    let program_id = drift_vaults::ID.to_string();
+    let mut invoked_programs: Vec<&str> = vec![];
+    let mut events = vec![];
+
+    for log in logs.iter().map(AsRef::as_ref) {
+        if let Some(data) = log.strip_prefix(PROGRAM_DATA) {
+            if invoked_programs.last() != Some(&program_id.as_str()) {
+                continue;
+            }
+
+            let data = match STANDARD.decode(data) {
+                Ok(data) => data,
+                Err(_) => continue,
+            };
+            if let Some(event) = VaultEvent::decode(&data)? {
+                events.push(event);
+            }
+        } else if let Some(program) = invoked_program(log) {
+            invoked_programs.push(program);
+        } else if is_program_exit(log) {
+            invoked_programs.pop();
+        }
+    }
+
+    Ok(events)
+}
+
+/// `Program <id> invoke [<depth>]`
+fn invoked_program(log: &str) -> Option<&str> {
+    let mut words = log.split(' ');
+    match (words.next(), words.next(), words.next()) {
+        (Some("Program"), Some(program), Some("invoke")) => Some(program),
+        _ => None,
+    }
+}
+
+/// `Program <id> success` or `Program <id> failed: <error>`
+fn is_program_exit(log: &str) -> bool {
+    let mut words = log.split(' ');
+    matches!(
+        (words.next(), words.next(), words.next()),
+        (Some("Program"), Some(_), Some("success" | "failed:"))
+    )
+}
+
+/// The log line `emit!` writes for an event
+#[cfg(test)]
+pub(crate) fn program_data_log<E: anchor_lang::Event>(event: &E) -> String {
+    format!("{}{}", PROGRAM_DATA, STANDARD.encode(event.data()))
+}
+
+/// Transactions' log messages, separated by a blank line, with drift's and the token program's cpis and a
+/// deposit logged before the program emitted v2 records
+#[cfg(test)]
+pub(crate) const VAULT_DEPOSITOR_LOGS: &str =
+    include_str!("../../../tests/fixtures/vault_depositor_logs.txt");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::prelude::Pubkey;
+    use drift_vaults::state::events::VaultDepositorAction;
+
+    #[test]
+    fn test_parse_logs_skips_cpi_events() {
+        let vault = Pubkey::new_unique();
+        let deposit = VaultDepositorRecord {
+            ts: 100,
+            vault,
+            action: VaultDepositorAction::Deposit,
+            amount: 1_000_000,
+            ..VaultDepositorRecord::default()
+        };
+        let vault_record = VaultRecord {
+            ts: 100,
+            vault,
+            ..VaultRecord::default()
+        };
+
+        let logs = vec![
+            format!("Program {} invoke [1]", drift_vaults::ID),
+            "Program log: Instruction: Deposit".to_string(),
+            format!("Program {} invoke [2]", drift::ID),
+            "Program log: Instruction: Deposit".to_string(),
+            // drift's own records are logged the same way, but aren't vault events
+            format!("{}{}", PROGRAM_DATA, STANDARD.encode([1_u8; 48])),
+            program_data_log(&vault_record),
+            format!("Program {} success", drift::ID),
+            program_data_log(&vault_record),
+            program_data_log(&deposit),
+            format!("Program {} success", drift_vaults::ID),
+        ];
+
+        let events = parse_logs(&logs).unwrap();
+        assert_eq!(events.len(), 2);
+        assert!(matches!(&events[0], VaultEvent::VaultRecord(event) if event.vault == vault));
+        assert!(matches!(
+            &events[1],
+            VaultEvent::VaultDepositorRecord(event) if event.amount == 1_000_000 && event.ts == 100
+        ));
+    }
+
+    #[test]
+    fn test_parse_fixture_logs() {
+        let events: Vec<VaultEvent> = VAULT_DEPOSITOR_LOGS
+            .split("\n\n")
+            .map(|transaction| parse_logs(&transaction.lines().collect::<Vec<_>>()).unwrap())
+            .collect::<Vec<_>>()
+            .concat();
+
+        // drift's deposit and withdraw records logged in its cpis are skipped
+        assert_eq!(events.len(), 4);
+        assert!(matches!(
+            &events[0],
+            VaultEvent::VaultDepositorRecord(event)
+                if event.action == VaultDepositorAction::Deposit && event.amount == 1_000_000_000
+        ));
+        assert!(matches!(
+            &events[1],
+            VaultEvent::VaultDepositorV2Record(event)
+                if event.action == VaultDepositorAction::Deposit
+                    && event.vault_equity_before == 1_250_000_000
+                    && event.cost_basis_after == Some(1_500_000_000)
+        ));
+        assert!(matches!(
+            &events[2],
+            VaultEvent::VaultDepositorV2Record(event)
+                if event.action == VaultDepositorAction::WithdrawRequest
+                    && event.vault_shares_after == 1_400_000_000
+        ));
+        assert!(matches!(
+            &events[3],
+            VaultEvent::VaultDepositorV2Record(event)
+                if event.action == VaultDepositorAction::Withdraw
+                    && event.vault_shares_after == 700_000_000
+                    && event.realized_pnl == Some(125_000_000)
+                    && event.protocol_profit_share.is_none()
+        ));
+    }
+}
@@ -0,0 +1,486 @@
+//! Rebuilds each vault depositor's history from the vault program's events.
+//!
+//! Realized pnl uses average cost, like the program: a withdraw or share transfer realizes the value of the
+//! shares leaving the depositor less their cost basis, in proportion to the depositor's shares before it, and the
+//! receiver of a transfer buys the shares at that value. Profit share is charged by burning the depositor's
+//! shares, so it lowers pnl without touching the cost basis. Management and protocol fees dilute every
+//! depositor through newly minted shares and show up in the value of the shares rather than as a charge.
+//!
+//! Records that carry the program's cost basis and realized pnl override the reducer's own, which also covers
+//! depositors whose earlier records are missing.
+
+use std::collections::BTreeMap;
+
+use anchor_lang::prelude::Pubkey;
+use drift_vaults::state::events::{
+    ShareTransferRecord, VaultDepositorAction, VaultDepositorRecord, VaultDepositorV1Record,
+    VaultDepositorV2Record,
+};
+
+use crate::events::VaultEvent;
+use crate::pda::get_vault_depositor_address;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DepositorHistoryAction {
+    Deposit,
+    WithdrawRequest,
+    CancelWithdrawRequest,
+    Withdraw,
+    FeePayment,
+    TransferIn,
+    TransferOut,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DepositorHistoryEntry {
+    pub ts: i64,
+    pub action: DepositorHistoryAction,
+    /// The tokens deposited or withdrawn, or the value of the shares transferred
+    pub amount: u64,
+    /// Profit share paid to the manager and protocol
+    pub profit_share: u64,
+    pub vault_shares_after: u128,
+    /// The pnl the entry realized
+    pub realized_pnl: i64,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DepositorHistory {
+    pub vault: Pubkey,
+    pub vault_depositor: Pubkey,
+    /// Default for depositors only seen through share transfers
+    pub authority: Pubkey,
+    pub total_deposits: u64,
+    pub total_withdraws: u64,
+    pub manager_profit_share_paid: u64,
+    pub protocol_profit_share_paid: u64,
+    /// The deposit asset paid for the depositor's shares
+    pub cost_basis: u64,
+    pub realized_pnl: i64,
+    /// In the shares_base of the last entry
+    pub vault_shares: u128,
+    pub entries: Vec<DepositorHistoryEntry>,
+}
+
+/// The fields every version of the vault depositor record has
+struct DepositorRecord {
+    ts: i64,
+    vault: Pubkey,
+    authority: Pubkey,
+    action: VaultDepositorAction,
+    amount: u64,
+    vault_shares_before: u128,
+    vault_shares_after: u128,
+    manager_profit_share: u64,
+    protocol_profit_share: u64,
+    cost_basis_after: Option<u64>,
+    realized_pnl: Option<i64>,
+}
+
+impl From<&VaultDepositorRecord> for DepositorRecord {
+    fn from(record: &VaultDepositorRecord) -> Self {
+        DepositorRecord {
+            ts: record.ts,
+            vault: record.vault,
+            authority: record.depositor_authority,
+            action: record.action,
+            amount: record.amount,
+            vault_shares_before: record.vault_shares_before,
+            vault_shares_after: record.vault_shares_after,
+            manager_profit_share: record.profit_share,
+            protocol_profit_share: 0,
+            cost_basis_after: None,
+            realized_pnl: None,
+        }
+    }
+}
+
+impl From<&VaultDepositorV1Record> for DepositorRecord {
+    fn from(record: &VaultDepositorV1Record) -> Self {
+        DepositorRecord {
+            ts: record.ts,
+            vault: record.vault,
+            authority: record.depositor_authority,
+            action: record.action,
+            amount: record.amount,
+            vault_shares_before: record.vault_shares_before,
+            vault_shares_after: record.vault_shares_after,
+            manager_profit_share: record.manager_profit_share,
+            protocol_profit_share: record.protocol_profit_share,
+            cost_basis_after: None,
+            realized_pnl: None,
+        }
+    }
+}
+
+impl From<&VaultDepositorV2Record> for DepositorRecord {
+    fn from(record: &VaultDepositorV2Record) -> Self {
+        DepositorRecord {
+            ts: record.ts,
+            vault: record.vault,
+            authority: record.depositor_authority,
+            action: record.action,
+            amount: record.amount,
+            vault_shares_before: record.vault_shares_before,
+            vault_shares_after: record.vault_shares_after,
+            manager_profit_share: record.manager_profit_share,
+            protocol_profit_share: record.protocol_profit_share.unwrap_or(0),
+            cost_basis_after: record.cost_basis_after,
+            realized_pnl: record.realized_pnl,
+        }
+    }
+}
+
+impl DepositorHistory {
+    /// Removes the cost basis of the shares leaving the depositor and returns it
+    fn remove_cost_basis(&mut self, shares_before: u128, shares_after: u128) -> u64 {
+        if shares_before == 0 || shares_after >= shares_before {
+            return 0;
+        }
+
+        let removed = (self.cost_basis as u128)
+            .saturating_mul(shares_before - shares_after)
+            .checked_div(shares_before)
+            .unwrap_or(0) as u64;
+        self.cost_basis = self.cost_basis.saturating_sub(removed);
+        removed
+    }
+
+    fn push(
+        &mut self,
+        ts: i64,
+        action: DepositorHistoryAction,
+        amount: u64,
+        profit_share: u64,
+        vault_shares_after: u128,
+        realized_pnl: i64,
+    ) {
+        self.vault_shares = vault_shares_after;
+        self.realized_pnl = self.realized_pnl.saturating_add(realized_pnl);
+        self.entries.push(DepositorHistoryEntry {
+            ts,
+            action,
+            amount,
+            profit_share,
+            vault_shares_after,
+            realized_pnl,
+        });
+    }
+}
+
+/// Folds events, in the order they were emitted, into a [`DepositorHistory`] per vault depositor
+#[derive(Clone, Debug, Default)]
+pub struct DepositorHistoryReducer {
+    histories: BTreeMap<Pubkey, DepositorHistory>,
+}
+
+impl DepositorHistoryReducer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn get(&self, vault_depositor: &Pubkey) -> Option<&DepositorHistory> {
+        self.histories.get(vault_depositor)
+    }
+
+    /// The histories keyed by vault depositor address
+    pub fn histories(&self) -> &BTreeMap<Pubkey, DepositorHistory> {
+        &self.histories
+    }
+
+    pub fn apply_events<'a>(&mut self, events: impl IntoIterator<Item = &'a VaultEvent>) {
+        for event in events {
+            self.apply(event);
+        }
+    }
+
+    /// Events that don't change a depositor's shares or cost basis are ignored
+    pub fn apply(&mut self, event: &VaultEvent) {
+        match event {
+            VaultEvent::VaultDepositorRecord(record) => self.apply_depositor_record(record.into()),
+            VaultEvent::VaultDepositorV1Record(record) => {
+                self.apply_depositor_record(record.into())
+            }
+            VaultEvent::VaultDepositorV2Record(record) => {
+                self.apply_depositor_record(record.into())
+            }
+            VaultEvent::ShareTransferRecord(record) => self.apply_share_transfer(record),
+            _ => {}
+        }
+    }
+
+    fn history(&mut self, vault: Pubkey, vault_depositor: Pubkey) -> &mut DepositorHistory {
+        self.histories
+            .entry(vault_depositor)
+            .or_insert_with(|| DepositorHistory {
+                vault,
+                vault_depositor,
+                ..DepositorHistory::default()
+            })
+    }
+
+    fn apply_depositor_record(&mut self, record: DepositorRecord) {
+        // tokenized vault depositors log the vault as their authority, their holders are tracked by the share mint
+        if record.authority == record.vault {
+            return;
+        }
+
+        let vault_depositor = get_vault_depositor_address(&record.vault, &record.authority);
+        let history = self.history(record.vault, vault_depositor);
+        history.authority = record.authority;
+        history.manager_profit_share_paid = history
+            .manager_profit_share_paid
+            .saturating_add(record.manager_profit_share);
+        history.protocol_profit_share_paid = history
+            .protocol_profit_share_paid
+            .saturating_add(record.protocol_profit_share);
+        let profit_share = record
+            .manager_profit_share
+            .saturating_add(record.protocol_profit_share);
+
+        let (action, realized_pnl) = match record.action {
+            VaultDepositorAction::Deposit => {
+                history.total_deposits = history.total_deposits.saturating_add(record.amount);
+                history.cost_basis = history.cost_basis.saturating_add(record.amount);
+                (DepositorHistoryAction::Deposit, 0)
+            }
+            VaultDepositorAction::Withdraw => {
+                history.total_withdraws = history.total_withdraws.saturating_add(record.amount);
+                let cost_basis = history
+                    .remove_cost_basis(record.vault_shares_before, record.vault_shares_after);
+                let realized_pnl = (record.amount as i128 - cost_basis as i128) as i64;
+                (DepositorHistoryAction::Withdraw, realized_pnl)
+            }
+            VaultDepositorAction::WithdrawRequest => (DepositorHistoryAction::WithdrawRequest, 0),
+            VaultDepositorAction::CancelWithdrawRequest => {
+                (DepositorHistoryAction::CancelWithdrawRequest, 0)
+            }
+            VaultDepositorAction::FeePayment => (DepositorHistoryAction::FeePayment, 0),
+            // only logged for tokenized vault depositors
+            VaultDepositorAction::TokenizeShares | VaultDepositorAction::RedeemTokens => return,
+        };
+        let realized_pnl = record.realized_pnl.unwrap_or(realized_pnl);
+        if let Some(cost_basis) = record.cost_basis_after {
+            history.cost_basis = cost_basis;
+        }
+
+        history.push(
+            record.ts,
+            action,
+            record.amount,
+            profit_share,
+            record.vault_shares_after,
+            realized_pnl,
+        );
+    }
+
+    /// The shares are sold to the receiver at their value
+    fn apply_share_transfer(&mut self, record: &ShareTransferRecord) {
+        let from = self.history(record.vault, record.from_vault_depositor);
+        let cost_basis = from.remove_cost_basis(
+            record.from_depositor_shares_before,
+            record.from_depositor_shares_after,
+        );
+        let realized_pnl = (record.value as i128 - cost_basis as i128) as i64;
+        from.push(
+            record.ts,
+            DepositorHistoryAction::TransferOut,
+            record.value,
+            0,
+            record.from_depositor_shares_after,
+            realized_pnl,
+        );
+
+        let to = self.history(record.vault, record.to_vault_depositor);
+        to.cost_basis = to.cost_basis.saturating_add(record.value);
+        to.push(
+            record.ts,
+            DepositorHistoryAction::TransferIn,
+            record.value,
+            0,
+            record.to_depositor_shares_after,
+            0,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::{parse_logs, program_data_log, VAULT_DEPOSITOR_LOGS};
+    use drift::math::constants::QUOTE_PRECISION_U64;
+    use std::str::FromStr;
+
+    fn instruction_logs(name: &str, data: Vec<String>) -> Vec<String> {
+        let mut logs = vec![
+            format!("Program {} invoke [1]", drift_vaults::ID),
+            format!("Program log: Instruction: {}", name),
+        ];
+        logs.extend(data);
+        logs.push(format!("Program {} success", drift_vaults::ID));
+        logs
+    }
+
+    #[test]
+    fn test_depositor_history_from_logs() {
+        let vault = Pubkey::new_unique();
+        let authority = Pubkey::new_unique();
+        let receiver = Pubkey::new_unique();
+        let vault_depositor = get_vault_depositor_address(&vault, &authority);
+
+        // deposits 1000, the vault doubles, requests half its shares and pays 20% profit share on 1000 of
+        // gains, then withdraws half of the remaining 1800 and transfers the rest
+        let deposit = VaultDepositorRecord {
+            ts: 1,
+            vault,
+            depositor_authority: authority,
+            action: VaultDepositorAction::Deposit,
+            amount: 1_000 * QUOTE_PRECISION_U64,
+            vault_shares_before: 0,
+            vault_shares_after: 1_000 * QUOTE_PRECISION_U64 as u128,
+            ..VaultDepositorRecord::default()
+        };
+        let withdraw_request = VaultDepositorV1Record {
+            ts: 2,
+            vault,
+            depositor_authority: authority,
+            action: VaultDepositorAction::WithdrawRequest,
+            amount: 900 * QUOTE_PRECISION_U64,
+            vault_shares_before: 1_000 * QUOTE_PRECISION_U64 as u128,
+            vault_shares_after: 900 * QUOTE_PRECISION_U64 as u128,
+            manager_profit_share: 150 * QUOTE_PRECISION_U64,
+            protocol_profit_share: 50 * QUOTE_PRECISION_U64,
+            ..VaultDepositorV1Record::default()
+        };
+        let withdraw = VaultDepositorV2Record {
+            ts: 3,
+            vault,
+            depositor_authority: authority,
+            action: VaultDepositorAction::Withdraw,
+            amount: 900 * QUOTE_PRECISION_U64,
+            vault_shares_before: 900 * QUOTE_PRECISION_U64 as u128,
+            vault_shares_after: 450 * QUOTE_PRECISION_U64 as u128,
+            protocol_profit_share: Some(0),
+            ..VaultDepositorV2Record::default()
+        };
+        let transfer = ShareTransferRecord {
+            ts: 4,
+            vault,
+            from_vault_depositor: vault_depositor,
+            to_vault_depositor: get_vault_depositor_address(&vault, &receiver),
+            shares: 450 * QUOTE_PRECISION_U64 as u128,
+            value: 900 * QUOTE_PRECISION_U64,
+            from_depositor_shares_before: 450 * QUOTE_PRECISION_U64 as u128,
+            from_depositor_shares_after: 0,
+            to_depositor_shares_before: 0,
+            to_depositor_shares_after: 450 * QUOTE_PRECISION_U64 as u128,
+        };
+
+        let mut logs = instruction_logs("Deposit", vec![program_data_log(&deposit)]);
+        logs.extend(instruction_logs(
+            "RequestWithdraw",
+            vec![program_data_log(&withdraw_request)],
+        ));
+        logs.extend(instruction_logs(
+            "Withdraw",
+            vec![program_data_log(&withdraw)],
+        ));
+        logs.extend(instruction_logs(
+            "TransferVaultDepositorShares",
+            vec![program_data_log(&transfer)],
+        ));
+
+        let mut reducer = DepositorHistoryReducer::new();
+        reducer.apply_events(&parse_logs(&logs).unwrap());
+
+        let history = reducer.get(&vault_depositor).unwrap();
+        assert_eq!(history.authority, authority);
+        assert_eq!(history.total_deposits, 1_000 * QUOTE_PRECISION_U64);
+        assert_eq!(history.total_withdraws, 900 * QUOTE_PRECISION_U64);
+        assert_eq!(history.manager_profit_share_paid, 150 * QUOTE_PRECISION_U64);
+        assert_eq!(history.protocol_profit_share_paid, 50 * QUOTE_PRECISION_U64);
+        // the withdraw and the transfer each sold half the shares for 900, against half the 1000 cost basis
+        assert_eq!(history.realized_pnl, 800 * QUOTE_PRECISION_U64 as i64);
+        assert_eq!(history.cost_basis, 0);
+        assert_eq!(history.vault_shares, 0);
+        assert_eq!(
+            history
+                .entries
+                .iter()
+                .map(|entry| entry.action)
+                .collect::<Vec<_>>(),
+            vec![
+                DepositorHistoryAction::Deposit,
+                DepositorHistoryAction::WithdrawRequest,
+                DepositorHistoryAction::Withdraw,
+                DepositorHistoryAction::TransferOut,
+            ]
+        );
+
+        let receiver_history = reducer
+            .get(&get_vault_depositor_address(&vault, &receiver))
+            .unwrap();
+        assert_eq!(receiver_history.cost_basis, 900 * QUOTE_PRECISION_U64);
+        assert_eq!(
+            receiver_history.vault_shares,
+            450 * QUOTE_PRECISION_U64 as u128
+        );
+        assert_eq!(receiver_history.realized_pnl, 0);
+    }
+
+    #[test]
+    fn test_depositor_history_from_fixture_logs() {
+        let vault = Pubkey::from_str("6anbDQNCcVh2f6okexjaX1VGj6tEnizJ1kV5UTBS8Zhi").unwrap();
+        let authority = Pubkey::from_str("Borqy3dEjw9az7Uj9nW69A9ZDansFGHWEggUx7tkv44f").unwrap();
+
+        let mut reducer = DepositorHistoryReducer::new();
+        for transaction in VAULT_DEPOSITOR_LOGS.split("\n\n") {
+            let logs: Vec<&str> = transaction.lines().collect();
+            reducer.apply_events(&parse_logs(&logs).unwrap());
+        }
+
+        // deposits 1000 at 1 per share and 500 at 1.25, then requests and withdraws half its shares at 1.25
+        assert_eq!(reducer.histories().len(), 1);
+        let history = reducer
+            .get(&get_vault_depositor_address(&vault, &authority))
+            .unwrap();
+        assert_eq!(history.vault, vault);
+        assert_eq!(history.authority, authority);
+        assert_eq!(history.total_deposits, 1_500 * QUOTE_PRECISION_U64);
+        assert_eq!(history.total_withdraws, 875 * QUOTE_PRECISION_U64);
+        assert_eq!(history.manager_profit_share_paid, 0);
+        assert_eq!(history.protocol_profit_share_paid, 0);
+        assert_eq!(history.realized_pnl, 125 * QUOTE_PRECISION_U64 as i64);
+        assert_eq!(history.cost_basis, 750 * QUOTE_PRECISION_U64);
+        assert_eq!(history.vault_shares, 700 * QUOTE_PRECISION_U64 as u128);
+        assert_eq!(
+            history
+                .entries
+                .iter()
+                .map(|entry| (entry.ts, entry.action, entry.amount))
+                .collect::<Vec<_>>(),
+            vec![
+                (
+                    1_719_360_000,
+                    DepositorHistoryAction::Deposit,
+                    1_000 * QUOTE_PRECISION_U64
+                ),
+                (
+                    1_719_446_400,
+                    DepositorHistoryAction::Deposit,
+                    500 * QUOTE_PRECISION_U64
+                ),
+                (
+                    1_719_532_800,
+                    DepositorHistoryAction::WithdrawRequest,
+                    875 * QUOTE_PRECISION_U64
+                ),
+                (
+                    1_719_619_200,
+                    DepositorHistoryAction::Withdraw,
+                    875 * QUOTE_PRECISION_U64
+                ),
+            ]
+        );
+    }
+}
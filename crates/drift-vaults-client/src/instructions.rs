@@ -0,0 +1,267 @@
+use anchor_lang::prelude::{AccountMeta, Pubkey};
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::{system_program, InstructionData, Result, ToAccountMetas};
+use drift::state::user::{User, UserStats};
+use drift_vaults::state::{Vault, WithdrawUnit};
+
+use crate::pda::{
+    get_drift_signer_address, get_drift_state_address, get_manager_stats_address,
+    get_nav_history_address, get_nav_policy_address, get_spot_market_vault_address,
+    get_vault_depositor_address,
+};
+use crate::remaining_accounts::{MarketOracles, RemainingAccountsBuilder};
+
+/// The vault and its drift accounts, which the instruction builders read the instruction's accounts and
+/// remaining accounts from
+#[derive(Clone, Copy)]
+pub struct VaultState<'a> {
+    pub vault: &'a Vault,
+    pub user: &'a User,
+    pub user_stats: &'a UserStats,
+    pub oracles: &'a MarketOracles,
+    /// Whether the vault has a [`drift_vaults::state::NavHistory`]. The vault account doesn't record it, and
+    /// deposits and withdrawals only snapshot it if it is passed.
+    pub nav_history: bool,
+    /// Whether the vault has a [`drift_vaults::state::ManagerStats`]. Like nav_history, deposits and
+    /// withdrawals only update it if it is passed.
+    pub manager_stats: bool,
+}
+
+impl VaultState<'_> {
+    /// The drift markets and oracles needed to value the vault and its optional accounts. The deposit spot
+    /// market is writable if the instruction moves tokens in or out of drift.
+    pub fn remaining_accounts(&self, writable_spot_market: bool) -> Result<Vec<AccountMeta>> {
+        let mut builder = RemainingAccountsBuilder::new()
+            .vault_user(self.vault, self.user, self.oracles, writable_spot_market)?
+            .vault_accounts(self.vault, self.user_stats);
+        if self.nav_history {
+            builder = builder.nav_history(&self.vault.pubkey);
+        }
+        if self.manager_stats {
+            builder = builder.manager_stats(&self.vault.pubkey);
+        }
+        Ok(builder.build())
+    }
+
+    /// The drift markets and oracles needed to value the vault, for instructions that don't take its
+    /// optional accounts
+    fn market_remaining_accounts(&self) -> Result<Vec<AccountMeta>> {
+        Ok(RemainingAccountsBuilder::new()
+            .vault_user(self.vault, self.user, self.oracles, false)?
+            .build())
+    }
+}
+
+fn instruction(
+    accounts: impl ToAccountMetas,
+    data: impl InstructionData,
+    remaining_accounts: Vec<AccountMeta>,
+) -> Instruction {
+    let mut account_metas = accounts.to_account_metas(None);
+    account_metas.extend(remaining_accounts);
+    Instruction {
+        program_id: drift_vaults::id(),
+        accounts: account_metas,
+        data: data.data(),
+    }
+}
+
+pub fn initialize_vault_depositor(
+    vault: &Pubkey,
+    authority: &Pubkey,
+    payer: &Pubkey,
+) -> Instruction {
+    let accounts = drift_vaults::accounts::InitializeVaultDepositor {
+        vault: *vault,
+        vault_depositor: get_vault_depositor_address(vault, authority),
+        authority: *authority,
+        payer: *payer,
+        rent: anchor_lang::solana_program::sysvar::rent::ID,
+        system_program: system_program::ID,
+    };
+    instruction(
+        accounts,
+        drift_vaults::instruction::InitializeVaultDepositor {},
+        vec![],
+    )
+}
+
+pub fn deposit(
+    state: VaultState,
+    authority: &Pubkey,
+    user_token_account: &Pubkey,
+    amount: u64,
+) -> Result<Instruction> {
+    let vault = state.vault;
+    let accounts = drift_vaults::accounts::Deposit {
+        vault: vault.pubkey,
+        vault_depositor: get_vault_depositor_address(&vault.pubkey, authority),
+        authority: *authority,
+        vault_token_account: vault.token_account,
+        drift_user_stats: vault.user_stats,
+        drift_user: vault.user,
+        drift_state: get_drift_state_address(),
+        drift_spot_market_vault: get_spot_market_vault_address(vault.spot_market_index),
+        user_token_account: *user_token_account,
+        drift_program: drift::id(),
+        token_program: anchor_spl::token::ID,
+    };
+    Ok(instruction(
+        accounts,
+        drift_vaults::instruction::Deposit { amount },
+        state.remaining_accounts(true)?,
+    ))
+}
+
+pub fn request_withdraw(
+    state: VaultState,
+    authority: &Pubkey,
+    withdraw_amount: u64,
+    withdraw_unit: WithdrawUnit,
+) -> Result<Instruction> {
+    let vault = state.vault;
+    let accounts = drift_vaults::accounts::RequestWithdraw {
+        vault: vault.pubkey,
+        vault_depositor: get_vault_depositor_address(&vault.pubkey, authority),
+        authority: *authority,
+        drift_user_stats: vault.user_stats,
+        drift_user: vault.user,
+    };
+    Ok(instruction(
+        accounts,
+        drift_vaults::instruction::RequestWithdraw {
+            withdraw_amount,
+            withdraw_unit,
+        },
+        state.remaining_accounts(false)?,
+    ))
+}
+
+pub fn cancel_request_withdraw(state: VaultState, authority: &Pubkey) -> Result<Instruction> {
+    let vault = state.vault;
+    let accounts = drift_vaults::accounts::CancelWithdrawRequest {
+        vault: vault.pubkey,
+        vault_depositor: get_vault_depositor_address(&vault.pubkey, authority),
+        authority: *authority,
+        drift_user_stats: vault.user_stats,
+        drift_user: vault.user,
+    };
+    Ok(instruction(
+        accounts,
+        drift_vaults::instruction::CancelRequestWithdraw {},
+        state.remaining_accounts(false)?,
+    ))
+}
+
+pub fn withdraw(
+    state: VaultState,
+    authority: &Pubkey,
+    user_token_account: &Pubkey,
+) -> Result<Instruction> {
+    let vault = state.vault;
+    let accounts = drift_vaults::accounts::Withdraw {
+        vault: vault.pubkey,
+        vault_depositor: get_vault_depositor_address(&vault.pubkey, authority),
+        authority: *authority,
+        vault_token_account: vault.token_account,
+        drift_user_stats: vault.user_stats,
+        drift_user: vault.user,
+        drift_state: get_drift_state_address(),
+        drift_spot_market_vault: get_spot_market_vault_address(vault.spot_market_index),
+        drift_signer: get_drift_signer_address(),
+        user_token_account: *user_token_account,
+        drift_program: drift::id(),
+        token_program: anchor_spl::token::ID,
+    };
+    Ok(instruction(
+        accounts,
+        drift_vaults::instruction::Withdraw {},
+        state.remaining_accounts(true)?,
+    ))
+}
+
+/// Signed by the vault's manager, delegate or the admin
+pub fn apply_profit_share(
+    state: VaultState,
+    vault_depositor: &Pubkey,
+    manager: &Pubkey,
+) -> Result<Instruction> {
+    let vault = state.vault;
+    let accounts = drift_vaults::accounts::ApplyProfitShare {
+        vault: vault.pubkey,
+        vault_depositor: *vault_depositor,
+        manager: *manager,
+        drift_user_stats: vault.user_stats,
+        drift_user: vault.user,
+        drift_state: get_drift_state_address(),
+        drift_signer: get_drift_signer_address(),
+        drift_program: drift::id(),
+    };
+    Ok(instruction(
+        accounts,
+        drift_vaults::instruction::ApplyProfitShare {},
+        state.remaining_accounts(true)?,
+    ))
+}
+
+/// Permissionless, requires the vault's [`drift_vaults::state::NavHistory`]
+pub fn snapshot_vault(state: VaultState) -> Result<Instruction> {
+    let vault = state.vault;
+    let accounts = drift_vaults::accounts::SnapshotVault {
+        vault: vault.pubkey,
+        nav_history: get_nav_history_address(&vault.pubkey),
+        drift_user: vault.user,
+    };
+    Ok(instruction(
+        accounts,
+        drift_vaults::instruction::SnapshotVault {},
+        state.market_remaining_accounts()?,
+    ))
+}
+
+/// Permissionless, requires the vault's [`drift_vaults::state::ManagerStats`]
+pub fn update_manager_stats(state: VaultState) -> Result<Instruction> {
+    let vault = state.vault;
+    let accounts = drift_vaults::accounts::UpdateManagerStats {
+        vault: vault.pubkey,
+        manager_stats: get_manager_stats_address(&vault.pubkey),
+        drift_user: vault.user,
+    };
+    Ok(instruction(
+        accounts,
+        drift_vaults::instruction::UpdateManagerStats {},
+        state.market_remaining_accounts()?,
+    ))
+}
+
+/// Permissionless, requires the vault's [`drift_vaults::state::NavPolicy`]
+pub fn check_max_drawdown(state: VaultState) -> Result<Instruction> {
+    let vault = state.vault;
+    let accounts = drift_vaults::accounts::CheckMaxDrawdown {
+        vault: vault.pubkey,
+        nav_policy: get_nav_policy_address(&vault.pubkey),
+        drift_user: vault.user,
+        drift_program: drift::id(),
+    };
+    Ok(instruction(
+        accounts,
+        drift_vaults::instruction::CheckMaxDrawdown {},
+        state.market_remaining_accounts()?,
+    ))
+}
+
+/// Permissionless, requires the vault's [`drift_vaults::state::NavPolicy`] with the circuit breaker enabled.
+/// Fails unless the breaker trips.
+pub fn trip_nav_breaker(state: VaultState) -> Result<Instruction> {
+    let vault = state.vault;
+    let accounts = drift_vaults::accounts::TripNavBreaker {
+        vault: vault.pubkey,
+        nav_policy: get_nav_policy_address(&vault.pubkey),
+        drift_user: vault.user,
+    };
+    Ok(instruction(
+        accounts,
+        drift_vaults::instruction::TripNavBreaker {},
+        state.market_remaining_accounts()?,
+    ))
+}
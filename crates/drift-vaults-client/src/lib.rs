@@ -0,0 +1,14 @@
+//! Client side helpers for the drift vaults program: pda derivations, instruction builders that assemble
+//! the drift market and oracle remaining accounts, decoders for the program's accounts and events, an
+//! off-chain calculator for vault equity and depositor balances, and a reducer rebuilding depositor history
+//! from events.
+
+pub mod accounts;
+pub mod equity;
+pub mod events;
+pub mod history;
+pub mod instructions;
+pub mod pda;
+pub mod remaining_accounts;
+
+pub use drift_vaults::ID as PROGRAM_ID;
@@ -0,0 +1,137 @@
+use anchor_lang::prelude::Pubkey;
+
+pub const MAX_NAME_LENGTH: usize = 32;
+
+/// Pads name with spaces to the vault's 32 byte name, like the ts sdk's encodeName
+pub fn encode_name(name: &str) -> [u8; MAX_NAME_LENGTH] {
+    assert!(
+        name.len() <= MAX_NAME_LENGTH,
+        "name ({}) longer than 32 bytes",
+        name
+    );
+    let mut encoded = [b' '; MAX_NAME_LENGTH];
+    encoded[..name.len()].copy_from_slice(name.as_bytes());
+    encoded
+}
+
+pub fn get_vault_address(name: &[u8; MAX_NAME_LENGTH]) -> Pubkey {
+    Pubkey::find_program_address(&[b"vault", name.as_ref()], &drift_vaults::id()).0
+}
+
+pub fn get_vault_depositor_address(vault: &Pubkey, authority: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"vault_depositor", vault.as_ref(), authority.as_ref()],
+        &drift_vaults::id(),
+    )
+    .0
+}
+
+pub fn get_vault_protocol_address(vault: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"vault_protocol", vault.as_ref()], &drift_vaults::id()).0
+}
+
+pub fn get_vault_token_account_address(vault: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"vault_token_account", vault.as_ref()],
+        &drift_vaults::id(),
+    )
+    .0
+}
+
+/// The mint of the vault's tokenized shares at shares_base
+pub fn get_mint_address(vault: &Pubkey, shares_base: u32) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"mint", vault.as_ref(), shares_base.to_string().as_bytes()],
+        &drift_vaults::id(),
+    )
+    .0
+}
+
+pub fn get_tokenized_vault_depositor_address(vault: &Pubkey, shares_base: u32) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            b"tokenized_vault_depositor",
+            vault.as_ref(),
+            shares_base.to_string().as_bytes(),
+        ],
+        &drift_vaults::id(),
+    )
+    .0
+}
+
+pub fn get_fee_update_address(vault: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"fee_update", vault.as_ref()], &drift_vaults::id()).0
+}
+
+pub fn get_nav_policy_address(vault: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"nav_policy", vault.as_ref()], &drift_vaults::id()).0
+}
+
+pub fn get_nav_history_address(vault: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"nav_history", vault.as_ref()], &drift_vaults::id()).0
+}
+
+pub fn get_manager_stats_address(vault: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"manager_stats", vault.as_ref()], &drift_vaults::id()).0
+}
+
+/// The vault's drift user, the vault is its authority with sub account 0
+pub fn get_drift_user_address(vault: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"user", vault.as_ref(), 0_u16.to_le_bytes().as_ref()],
+        &drift::id(),
+    )
+    .0
+}
+
+pub fn get_drift_user_stats_address(vault: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"user_stats", vault.as_ref()], &drift::id()).0
+}
+
+pub fn get_drift_fuel_overflow_address(vault: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"fuel_overflow", vault.as_ref()], &drift::id()).0
+}
+
+pub fn get_drift_state_address() -> Pubkey {
+    Pubkey::find_program_address(&[b"drift_state"], &drift::id()).0
+}
+
+pub fn get_drift_signer_address() -> Pubkey {
+    Pubkey::find_program_address(&[b"drift_signer"], &drift::id()).0
+}
+
+pub fn get_spot_market_address(market_index: u16) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"spot_market", market_index.to_le_bytes().as_ref()],
+        &drift::id(),
+    )
+    .0
+}
+
+pub fn get_perp_market_address(market_index: u16) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"perp_market", market_index.to_le_bytes().as_ref()],
+        &drift::id(),
+    )
+    .0
+}
+
+pub fn get_spot_market_vault_address(market_index: u16) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"spot_market_vault", market_index.to_le_bytes().as_ref()],
+        &drift::id(),
+    )
+    .0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_name() {
+        let name = encode_name("my vault");
+        assert_eq!(&name[..8], b"my vault");
+        assert!(name[8..].iter().all(|b| *b == b' '));
+    }
+}
@@ -0,0 +1,260 @@
+use std::collections::BTreeMap;
+
+use anchor_lang::prelude::{AccountMeta, Pubkey};
+use anchor_lang::Result;
+use drift::error::ErrorCode as DriftErrorCode;
+use drift::math::constants::QUOTE_SPOT_MARKET_INDEX;
+use drift::state::perp_market::PerpMarket;
+use drift::state::spot_market::SpotMarket;
+use drift::state::user::{FuelOverflowStatus, User, UserStats};
+use drift_vaults::state::{FeeUpdateStatus, Vault};
+
+use crate::pda::{
+    get_drift_fuel_overflow_address, get_fee_update_address, get_manager_stats_address,
+    get_nav_history_address, get_nav_policy_address, get_perp_market_address,
+    get_spot_market_address, get_vault_protocol_address,
+};
+
+/// The oracles of the drift markets a vault can hold positions in
+#[derive(Default, Clone, Debug)]
+pub struct MarketOracles {
+    pub spot: BTreeMap<u16, Pubkey>,
+    pub perp: BTreeMap<u16, Pubkey>,
+}
+
+impl MarketOracles {
+    pub fn add_spot_market(&mut self, spot_market: &SpotMarket) {
+        self.spot
+            .insert(spot_market.market_index, spot_market.oracle);
+    }
+
+    pub fn add_perp_market(&mut self, perp_market: &PerpMarket) {
+        self.perp
+            .insert(perp_market.market_index, perp_market.amm.oracle);
+    }
+}
+
+/// Builds an instruction's remaining accounts. Drift loads the oracles, then the spot markets, then the
+/// perp markets, so they are always emitted in that order. The vault's optional accounts go after them.
+#[derive(Default, Clone, Debug)]
+pub struct RemainingAccountsBuilder {
+    oracles: Vec<Pubkey>,
+    spot_markets: BTreeMap<u16, bool>,
+    perp_markets: BTreeMap<u16, bool>,
+    vault_accounts: Vec<AccountMeta>,
+}
+
+impl RemainingAccountsBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn add_oracle(&mut self, oracle: Pubkey) {
+        if oracle != Pubkey::default() && !self.oracles.contains(&oracle) {
+            self.oracles.push(oracle);
+        }
+    }
+
+    fn add_vault_account(&mut self, pubkey: Pubkey, is_writable: bool) {
+        if !self.vault_accounts.iter().any(|meta| meta.pubkey == pubkey) {
+            self.vault_accounts.push(AccountMeta {
+                pubkey,
+                is_signer: false,
+                is_writable,
+            });
+        }
+    }
+
+    pub fn spot_market(mut self, market_index: u16, oracle: Pubkey, is_writable: bool) -> Self {
+        self.add_oracle(oracle);
+        let writable = self.spot_markets.entry(market_index).or_insert(false);
+        *writable |= is_writable;
+        self
+    }
+
+    pub fn perp_market(mut self, market_index: u16, oracle: Pubkey, is_writable: bool) -> Self {
+        self.add_oracle(oracle);
+        let writable = self.perp_markets.entry(market_index).or_insert(false);
+        *writable |= is_writable;
+        self
+    }
+
+    /// Adds the markets of the drift user's positions, and the vault's deposit spot market, marked
+    /// writable if writable_spot_market is set. Fails if a market is missing from oracles, the program
+    /// can't value the vault without it.
+    pub fn vault_user(
+        mut self,
+        vault: &Vault,
+        user: &User,
+        oracles: &MarketOracles,
+        writable_spot_market: bool,
+    ) -> Result<Self> {
+        let mut spot_market_indexes: Vec<u16> = user
+            .spot_positions
+            .iter()
+            .filter(|position| !position.is_available())
+            .map(|position| position.market_index)
+            .collect();
+
+        for position in user.perp_positions.iter() {
+            if position.is_available() {
+                continue;
+            }
+            let oracle = oracles
+                .perp
+                .get(&position.market_index)
+                .ok_or(DriftErrorCode::PerpMarketNotFound)?;
+            self = self.perp_market(position.market_index, *oracle, false);
+            spot_market_indexes.push(QUOTE_SPOT_MARKET_INDEX);
+        }
+
+        spot_market_indexes.push(vault.spot_market_index);
+
+        for market_index in spot_market_indexes {
+            let oracle = oracles
+                .spot
+                .get(&market_index)
+                .ok_or(DriftErrorCode::SpotMarketNotFound)?;
+            let is_writable = writable_spot_market && market_index == vault.spot_market_index;
+            self = self.spot_market(market_index, *oracle, is_writable);
+        }
+
+        Ok(self)
+    }
+
+    /// Adds the vault's optional accounts the program requires once the vault has them. The vault doesn't
+    /// record whether it has a [`drift_vaults::state::NavHistory`] or
+    /// [`drift_vaults::state::ManagerStats`], add them with [`Self::nav_history`] and
+    /// [`Self::manager_stats`].
+    pub fn vault_accounts(mut self, vault: &Vault, user_stats: &UserStats) -> Self {
+        if vault.vault_protocol {
+            self.add_vault_account(get_vault_protocol_address(&vault.pubkey), true);
+        }
+        if FeeUpdateStatus::has_pending_fee_update(vault.fee_update_status) {
+            self.add_vault_account(get_fee_update_address(&vault.pubkey), true);
+        }
+        if vault.nav_policy {
+            self.add_vault_account(get_nav_policy_address(&vault.pubkey), true);
+        }
+        if user_stats.fuel_overflow_status & FuelOverflowStatus::Exists as u8 != 0 {
+            self.add_vault_account(get_drift_fuel_overflow_address(&vault.pubkey), false);
+        }
+        self
+    }
+
+    /// Adds the vault's [`drift_vaults::state::NavHistory`], so deposits and withdrawals snapshot it
+    pub fn nav_history(mut self, vault: &Pubkey) -> Self {
+        self.add_vault_account(get_nav_history_address(vault), true);
+        self
+    }
+
+    /// Adds the vault's [`drift_vaults::state::ManagerStats`], so deposits, withdrawals and snapshot_vault
+    /// update it
+    pub fn manager_stats(mut self, vault: &Pubkey) -> Self {
+        self.add_vault_account(get_manager_stats_address(vault), true);
+        self
+    }
+
+    pub fn build(self) -> Vec<AccountMeta> {
+        let oracles = self.oracles.into_iter().map(|pubkey| AccountMeta {
+            pubkey,
+            is_signer: false,
+            is_writable: false,
+        });
+        let spot_markets = self
+            .spot_markets
+            .into_iter()
+            .map(|(market_index, is_writable)| AccountMeta {
+                pubkey: get_spot_market_address(market_index),
+                is_signer: false,
+                is_writable,
+            });
+        let perp_markets = self
+            .perp_markets
+            .into_iter()
+            .map(|(market_index, is_writable)| AccountMeta {
+                pubkey: get_perp_market_address(market_index),
+                is_signer: false,
+                is_writable,
+            });
+
+        oracles
+            .chain(spot_markets)
+            .chain(perp_markets)
+            .chain(self.vault_accounts)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use drift::state::user::{PerpPosition, SpotPosition};
+
+    #[test]
+    fn test_vault_user_remaining_accounts() {
+        let vault = Vault {
+            pubkey: Pubkey::new_unique(),
+            spot_market_index: 1,
+            nav_policy: true,
+            ..Vault::default()
+        };
+
+        let mut user = User::default();
+        user.spot_positions[0] = SpotPosition {
+            market_index: 1,
+            scaled_balance: 1,
+            ..SpotPosition::default()
+        };
+        user.perp_positions[0] = PerpPosition {
+            market_index: 2,
+            base_asset_amount: 1,
+            ..PerpPosition::default()
+        };
+
+        let mut oracles = MarketOracles::default();
+        let (quote_oracle, sol_oracle, perp_oracle) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        oracles.spot.insert(0, quote_oracle);
+        oracles.spot.insert(1, sol_oracle);
+        oracles.perp.insert(2, perp_oracle);
+
+        let remaining_accounts = RemainingAccountsBuilder::new()
+            .vault_user(&vault, &user, &oracles, true)
+            .unwrap()
+            .vault_accounts(&vault, &UserStats::default())
+            .build();
+
+        let pubkeys: Vec<Pubkey> = remaining_accounts.iter().map(|meta| meta.pubkey).collect();
+        assert_eq!(
+            pubkeys,
+            vec![
+                perp_oracle,
+                sol_oracle,
+                quote_oracle,
+                get_spot_market_address(0),
+                get_spot_market_address(1),
+                get_perp_market_address(2),
+                get_nav_policy_address(&vault.pubkey),
+            ]
+        );
+
+        let writable: Vec<bool> = remaining_accounts
+            .iter()
+            .map(|meta| meta.is_writable)
+            .collect();
+        assert_eq!(
+            writable,
+            vec![false, false, false, false, true, false, true]
+        );
+
+        // the perp market's oracle is missing
+        oracles.perp.clear();
+        assert!(RemainingAccountsBuilder::new()
+            .vault_user(&vault, &user, &oracles, true)
+            .is_err());
+    }
+}
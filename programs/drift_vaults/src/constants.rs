@@ -15,4 +15,12 @@ pub mod admin {
 }
 
 pub const FUEL_SHARE_PRECISION: u128 = 1_000_000_000_000_000_000; // expo -18
+pub const FUEL_DISTRIBUTION_PRECISION: u16 = 10_000; // bps
+pub const FUEL_LOCKUP_MULTIPLIER_PRECISION: u32 = 10_000; // bps
+pub const REWARD_SHARE_PRECISION: u128 = 1_000_000_000_000_000_000; // expo -18
+pub const NAV_POLICY_BPS_PRECISION: u16 = 10_000; // bps
+pub const MAX_SWING_PRICING_BPS: u16 = 500; // 5%
+pub const NAV_PER_SHARE_PRECISION: u128 = 1_000_000_000_000; // expo -12
+pub const NAV_HISTORY_MIN_SNAPSHOT_INTERVAL: i64 = ONE_HOUR;
+pub const MANAGER_STATS_RETURN_PERIOD: i64 = ONE_DAY;
 pub const MAGIC_FUEL_START_TS: u32 = 123; // some arbitrary timestamp to identify VaultDepositors created after fuel distribution started.
@@ -0,0 +1,523 @@
+//! Cpi helpers for programs that hold a [`crate::state::VaultDepositor`] through one of their pdas.
+//!
+//! The calling program's pda is the depositor's `authority`: it signs every cpi through the seeds passed
+//! in, and must own the token account deposits are made from and withdrawals are paid to.
+//!
+//! `remaining_accounts` are passed to the vault program as is. They must hold the drift oracles, spot
+//! markets and perp markets of the vault's positions, in that order, plus any of the vault's optional
+//! accounts ([`crate::state::VaultProtocol`], [`crate::state::FeeUpdate`], [`crate::state::NavPolicy`],
+//! [`crate::state::NavHistory`], [`crate::state::ManagerStats`], the drift user stats' fuel overflow and the
+//! depositor's reward stream accounts) in any order before or after them. [`get_remaining_accounts`]
+//! builds them from the accounts the caller was passed, and the `get_*_address` helpers derive the
+//! drift accounts the cpis take.
+//!
+//! The accounts and arguments of these helpers only change with [`DEPOSITOR_CPI_VERSION`]. Callers can pin
+//! the version they were built against with [`assert_depositor_cpi_version`].
+
+use std::collections::BTreeSet;
+
+use anchor_lang::prelude::*;
+use drift::error::ErrorCode as DriftErrorCode;
+use drift::math::constants::QUOTE_SPOT_MARKET_INDEX;
+use drift::state::perp_market::PerpMarket;
+use drift::state::spot_market::SpotMarket;
+use drift::state::user::User;
+
+use crate::cpi::accounts::{
+    Deposit, InitializeVaultDepositor, PreviewDepositorEquity, RequestWithdraw, Withdraw,
+};
+use crate::state::{DepositorEquityPreview, Vault};
+use crate::WithdrawUnit;
+
+pub const DEPOSITOR_CPI_VERSION: u8 = 1;
+
+/// Fails to compile if the helpers changed since `version`:
+/// `const _: () = drift_vaults::depositor_cpi::assert_depositor_cpi_version(1);`
+pub const fn assert_depositor_cpi_version(version: u8) {
+    assert!(
+        version == DEPOSITOR_CPI_VERSION,
+        "drift_vaults depositor cpi version changed"
+    );
+}
+
+pub fn get_vault_depositor_address(vault: &Pubkey, authority: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"vault_depositor", vault.as_ref(), authority.as_ref()],
+        &crate::id(),
+    )
+    .0
+}
+
+pub fn get_vault_token_account_address(vault: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"vault_token_account", vault.as_ref()], &crate::id()).0
+}
+
+/// The vault's drift user, the vault is its authority with sub account 0
+pub fn get_drift_user_address(vault: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"user", vault.as_ref(), 0_u16.to_le_bytes().as_ref()],
+        &drift::id(),
+    )
+    .0
+}
+
+pub fn get_drift_user_stats_address(vault: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"user_stats", vault.as_ref()], &drift::id()).0
+}
+
+pub fn get_drift_state_address() -> Pubkey {
+    Pubkey::find_program_address(&[b"drift_state"], &drift::id()).0
+}
+
+pub fn get_drift_signer_address() -> Pubkey {
+    Pubkey::find_program_address(&[b"drift_signer"], &drift::id()).0
+}
+
+/// The drift spot market vault of the vault's deposit spot market
+pub fn get_drift_spot_market_vault_address(market_index: u16) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"spot_market_vault", market_index.to_le_bytes().as_ref()],
+        &drift::id(),
+    )
+    .0
+}
+
+/// The spot and perp markets a vault instruction loads: the markets of the drift user's positions, the
+/// quote spot market if it has perp positions, and the vault's deposit spot market
+pub fn get_vault_market_indexes(vault: &Vault, user: &User) -> (BTreeSet<u16>, BTreeSet<u16>) {
+    let mut spot_market_indexes: BTreeSet<u16> = user
+        .spot_positions
+        .iter()
+        .filter(|position| !position.is_available())
+        .map(|position| position.market_index)
+        .collect();
+    let perp_market_indexes: BTreeSet<u16> = user
+        .perp_positions
+        .iter()
+        .filter(|position| !position.is_available())
+        .map(|position| position.market_index)
+        .collect();
+
+    if !perp_market_indexes.is_empty() {
+        spot_market_indexes.insert(QUOTE_SPOT_MARKET_INDEX);
+    }
+    spot_market_indexes.insert(vault.spot_market_index);
+
+    (spot_market_indexes, perp_market_indexes)
+}
+
+/// Picks the oracles, spot markets and perp markets of [`get_vault_market_indexes`] out of
+/// `market_accounts`, in the order drift loads them, followed by `vault_accounts`. `market_accounts` can
+/// hold other markets and oracles too, fails if one the vault needs is missing.
+pub fn get_remaining_accounts<'info>(
+    vault: &AccountInfo<'info>,
+    drift_user: &AccountInfo<'info>,
+    market_accounts: &[AccountInfo<'info>],
+    vault_accounts: &[AccountInfo<'info>],
+) -> Result<Vec<AccountInfo<'info>>> {
+    let (spot_market_indexes, perp_market_indexes) = {
+        let vault = AccountLoader::<Vault>::try_from(vault)?;
+        let user = AccountLoader::<User>::try_from(drift_user)?;
+        let (vault, user) = (vault.load()?, user.load()?);
+        get_vault_market_indexes(&vault, &user)
+    };
+
+    let mut oracle_keys: Vec<Pubkey> = vec![];
+    let mut spot_markets: Vec<AccountInfo<'info>> = vec![];
+    let mut perp_markets: Vec<AccountInfo<'info>> = vec![];
+
+    for market_index in spot_market_indexes {
+        let (spot_market, oracle) = market_accounts
+            .iter()
+            .find_map(|acct| {
+                let loader = AccountLoader::<SpotMarket>::try_from(acct).ok()?;
+                let spot_market = loader.load().ok()?;
+                (spot_market.market_index == market_index).then_some((acct, spot_market.oracle))
+            })
+            .ok_or_else(|| {
+                msg!("spot market {} missing from market accounts", market_index);
+                DriftErrorCode::SpotMarketNotFound
+            })?;
+        spot_markets.push(spot_market.clone());
+        if oracle != Pubkey::default() && !oracle_keys.contains(&oracle) {
+            oracle_keys.push(oracle);
+        }
+    }
+
+    for market_index in perp_market_indexes {
+        let (perp_market, oracle) = market_accounts
+            .iter()
+            .find_map(|acct| {
+                let loader = AccountLoader::<PerpMarket>::try_from(acct).ok()?;
+                let perp_market = loader.load().ok()?;
+                (perp_market.market_index == market_index).then_some((acct, perp_market.amm.oracle))
+            })
+            .ok_or_else(|| {
+                msg!("perp market {} missing from market accounts", market_index);
+                DriftErrorCode::PerpMarketNotFound
+            })?;
+        perp_markets.push(perp_market.clone());
+        if oracle != Pubkey::default() && !oracle_keys.contains(&oracle) {
+            oracle_keys.push(oracle);
+        }
+    }
+
+    let mut remaining_accounts = Vec::with_capacity(
+        oracle_keys.len() + spot_markets.len() + perp_markets.len() + vault_accounts.len(),
+    );
+    for oracle in oracle_keys {
+        let oracle = market_accounts
+            .iter()
+            .find(|acct| acct.key() == oracle)
+            .ok_or_else(|| {
+                msg!("oracle {} missing from market accounts", oracle);
+                DriftErrorCode::OracleNotFound
+            })?;
+        remaining_accounts.push(oracle.clone());
+    }
+    remaining_accounts.extend(spot_markets);
+    remaining_accounts.extend(perp_markets);
+    remaining_accounts.extend_from_slice(vault_accounts);
+
+    Ok(remaining_accounts)
+}
+
+/// The accounts every depositor cpi takes
+#[derive(Clone)]
+pub struct DepositorCpiAccounts<'info> {
+    pub vaults_program: AccountInfo<'info>,
+    pub vault: AccountInfo<'info>,
+    /// The pda of vault and authority, see [`get_vault_depositor_address`]
+    pub vault_depositor: AccountInfo<'info>,
+    /// The calling program's pda that owns the vault depositor
+    pub authority: AccountInfo<'info>,
+    /// See [`get_drift_user_stats_address`]
+    pub drift_user_stats: AccountInfo<'info>,
+    /// See [`get_drift_user_address`]
+    pub drift_user: AccountInfo<'info>,
+    /// See [`get_remaining_accounts`]
+    pub remaining_accounts: Vec<AccountInfo<'info>>,
+}
+
+/// The accounts deposits and withdrawals move tokens through
+#[derive(Clone)]
+pub struct DepositorTokenCpiAccounts<'info> {
+    /// See [`get_vault_token_account_address`]
+    pub vault_token_account: AccountInfo<'info>,
+    /// See [`get_drift_state_address`]
+    pub drift_state: AccountInfo<'info>,
+    /// See [`get_drift_spot_market_vault_address`]
+    pub drift_spot_market_vault: AccountInfo<'info>,
+    /// Only used by withdrawals, see [`get_drift_signer_address`]
+    pub drift_signer: AccountInfo<'info>,
+    /// The authority's token account of the vault's deposit mint
+    pub user_token_account: AccountInfo<'info>,
+    pub drift_program: AccountInfo<'info>,
+    pub token_program: AccountInfo<'info>,
+}
+
+pub fn initialize_vault_depositor<'info>(
+    accounts: &DepositorCpiAccounts<'info>,
+    payer: AccountInfo<'info>,
+    rent: AccountInfo<'info>,
+    system_program: AccountInfo<'info>,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let cpi_accounts = InitializeVaultDepositor {
+        vault: accounts.vault.clone(),
+        vault_depositor: accounts.vault_depositor.clone(),
+        authority: accounts.authority.clone(),
+        payer,
+        rent,
+        system_program,
+    };
+    let cpi_context =
+        CpiContext::new_with_signer(accounts.vaults_program.clone(), cpi_accounts, signer_seeds);
+    crate::cpi::initialize_vault_depositor(cpi_context)
+}
+
+pub fn deposit<'info>(
+    accounts: &DepositorCpiAccounts<'info>,
+    token_accounts: &DepositorTokenCpiAccounts<'info>,
+    amount: u64,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let cpi_accounts = Deposit {
+        vault: accounts.vault.clone(),
+        vault_depositor: accounts.vault_depositor.clone(),
+        authority: accounts.authority.clone(),
+        vault_token_account: token_accounts.vault_token_account.clone(),
+        drift_user_stats: accounts.drift_user_stats.clone(),
+        drift_user: accounts.drift_user.clone(),
+        drift_state: token_accounts.drift_state.clone(),
+        drift_spot_market_vault: token_accounts.drift_spot_market_vault.clone(),
+        user_token_account: token_accounts.user_token_account.clone(),
+        drift_program: token_accounts.drift_program.clone(),
+        token_program: token_accounts.token_program.clone(),
+    };
+    let cpi_context =
+        CpiContext::new_with_signer(accounts.vaults_program.clone(), cpi_accounts, signer_seeds)
+            .with_remaining_accounts(accounts.remaining_accounts.clone());
+    crate::cpi::deposit(cpi_context, amount)
+}
+
+pub fn request_withdraw<'info>(
+    accounts: &DepositorCpiAccounts<'info>,
+    withdraw_amount: u64,
+    withdraw_unit: WithdrawUnit,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let cpi_accounts = RequestWithdraw {
+        vault: accounts.vault.clone(),
+        vault_depositor: accounts.vault_depositor.clone(),
+        authority: accounts.authority.clone(),
+        drift_user_stats: accounts.drift_user_stats.clone(),
+        drift_user: accounts.drift_user.clone(),
+    };
+    let cpi_context =
+        CpiContext::new_with_signer(accounts.vaults_program.clone(), cpi_accounts, signer_seeds)
+            .with_remaining_accounts(accounts.remaining_accounts.clone());
+    crate::cpi::request_withdraw(cpi_context, withdraw_amount, withdraw_unit)
+}
+
+/// Completes the depositor's withdraw request once the vault's redeem period is over
+pub fn withdraw<'info>(
+    accounts: &DepositorCpiAccounts<'info>,
+    token_accounts: &DepositorTokenCpiAccounts<'info>,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let cpi_accounts = Withdraw {
+        vault: accounts.vault.clone(),
+        vault_depositor: accounts.vault_depositor.clone(),
+        authority: accounts.authority.clone(),
+        vault_token_account: token_accounts.vault_token_account.clone(),
+        drift_user_stats: accounts.drift_user_stats.clone(),
+        drift_user: accounts.drift_user.clone(),
+        drift_state: token_accounts.drift_state.clone(),
+        drift_spot_market_vault: token_accounts.drift_spot_market_vault.clone(),
+        drift_signer: token_accounts.drift_signer.clone(),
+        user_token_account: token_accounts.user_token_account.clone(),
+        drift_program: token_accounts.drift_program.clone(),
+        token_program: token_accounts.token_program.clone(),
+    };
+    let cpi_context =
+        CpiContext::new_with_signer(accounts.vaults_program.clone(), cpi_accounts, signer_seeds)
+            .with_remaining_accounts(accounts.remaining_accounts.clone());
+    crate::cpi::withdraw(cpi_context)
+}
+
+/// The depositor's shares and their value after fees and profit share, read through preview_depositor_equity
+pub fn depositor_equity<'info>(
+    accounts: &DepositorCpiAccounts<'info>,
+) -> Result<DepositorEquityPreview> {
+    let cpi_accounts = PreviewDepositorEquity {
+        vault: accounts.vault.clone(),
+        vault_depositor: accounts.vault_depositor.clone(),
+        drift_user: accounts.drift_user.clone(),
+    };
+    let cpi_context = CpiContext::new(accounts.vaults_program.clone(), cpi_accounts)
+        .with_remaining_accounts(accounts.remaining_accounts.clone());
+    Ok(crate::cpi::preview_depositor_equity(cpi_context)?.get())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::{Discriminator, ZeroCopy};
+    use drift::state::user::{PerpPosition, SpotPosition};
+
+    const _: () = assert_depositor_cpi_version(1);
+
+    fn account_data<T: ZeroCopy + Discriminator>(account: &T) -> Vec<u8> {
+        let mut data = T::discriminator().to_vec();
+        data.extend_from_slice(bytemuck::bytes_of(account));
+        data
+    }
+
+    #[test]
+    fn test_depositor_cpi_signatures() {
+        // the cpi surface only changes with DEPOSITOR_CPI_VERSION
+        fn assert_signatures<'info>() {
+            let _: fn(
+                &DepositorCpiAccounts<'info>,
+                AccountInfo<'info>,
+                AccountInfo<'info>,
+                AccountInfo<'info>,
+                &[&[&[u8]]],
+            ) -> Result<()> = initialize_vault_depositor;
+            let _: fn(
+                &DepositorCpiAccounts<'info>,
+                &DepositorTokenCpiAccounts<'info>,
+                u64,
+                &[&[&[u8]]],
+            ) -> Result<()> = deposit;
+            let _: fn(&DepositorCpiAccounts<'info>, u64, WithdrawUnit, &[&[&[u8]]]) -> Result<()> =
+                request_withdraw;
+            let _: fn(
+                &DepositorCpiAccounts<'info>,
+                &DepositorTokenCpiAccounts<'info>,
+                &[&[&[u8]]],
+            ) -> Result<()> = withdraw;
+            let _: fn(&DepositorCpiAccounts<'info>) -> Result<DepositorEquityPreview> =
+                depositor_equity;
+        }
+        assert_signatures();
+        assert_eq!(DEPOSITOR_CPI_VERSION, 1);
+    }
+
+    #[test]
+    fn test_get_vault_market_indexes() {
+        let vault = Vault {
+            spot_market_index: 1,
+            ..Vault::default()
+        };
+
+        let mut user = User::default();
+        let (spot_market_indexes, perp_market_indexes) = get_vault_market_indexes(&vault, &user);
+        assert_eq!(spot_market_indexes, BTreeSet::from([1]));
+        assert!(perp_market_indexes.is_empty());
+
+        user.spot_positions[0] = SpotPosition {
+            market_index: 3,
+            scaled_balance: 1,
+            ..SpotPosition::default()
+        };
+        user.perp_positions[0] = PerpPosition {
+            market_index: 2,
+            base_asset_amount: 1,
+            ..PerpPosition::default()
+        };
+        let (spot_market_indexes, perp_market_indexes) = get_vault_market_indexes(&vault, &user);
+        assert_eq!(spot_market_indexes, BTreeSet::from([0, 1, 3]));
+        assert_eq!(perp_market_indexes, BTreeSet::from([2]));
+    }
+
+    #[test]
+    fn test_get_remaining_accounts() {
+        let vault_key = Pubkey::new_unique();
+        let mut vault_data = account_data(&Vault {
+            pubkey: vault_key,
+            spot_market_index: 1,
+            ..Vault::default()
+        });
+        let mut user = User::default();
+        user.perp_positions[0] = PerpPosition {
+            market_index: 2,
+            base_asset_amount: 1,
+            ..PerpPosition::default()
+        };
+        let mut user_data = account_data(&user);
+
+        let (quote_oracle, sol_oracle, perp_oracle, other_oracle) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        let mut spot_market_data: Vec<Vec<u8>> =
+            [(0, quote_oracle), (1, sol_oracle), (4, other_oracle)]
+                .iter()
+                .map(|(market_index, oracle)| {
+                    account_data(&SpotMarket {
+                        market_index: *market_index,
+                        oracle: *oracle,
+                        ..SpotMarket::default()
+                    })
+                })
+                .collect();
+        let mut perp_market = PerpMarket {
+            market_index: 2,
+            ..PerpMarket::default()
+        };
+        perp_market.amm.oracle = perp_oracle;
+        let mut perp_market_data = account_data(&perp_market);
+
+        let keys: Vec<Pubkey> = (0..10).map(|_| Pubkey::new_unique()).collect();
+        let mut lamports = [0_u64; 10];
+        let mut oracle_data: Vec<Vec<u8>> = vec![vec![]; 4];
+        let vaults_program = crate::id();
+        let drift_program = drift::id();
+        let oracle_program = Pubkey::new_unique();
+
+        let mut lamports_iter = lamports.iter_mut();
+        let mut spot_market_iter = spot_market_data.iter_mut();
+        let mut oracle_iter = oracle_data.iter_mut();
+        let oracle_keys = [quote_oracle, sol_oracle, perp_oracle, other_oracle];
+
+        let vault = AccountInfo::new(
+            &vault_key,
+            false,
+            false,
+            lamports_iter.next().unwrap(),
+            &mut vault_data,
+            &vaults_program,
+            false,
+            0,
+        );
+        let drift_user = AccountInfo::new(
+            &keys[0],
+            false,
+            false,
+            lamports_iter.next().unwrap(),
+            &mut user_data,
+            &drift_program,
+            false,
+            0,
+        );
+        let mut market_accounts: Vec<AccountInfo> = vec![AccountInfo::new(
+            &keys[1],
+            false,
+            false,
+            lamports_iter.next().unwrap(),
+            &mut perp_market_data,
+            &drift_program,
+            false,
+            0,
+        )];
+        for key in keys[2..5].iter() {
+            market_accounts.push(AccountInfo::new(
+                key,
+                false,
+                true,
+                lamports_iter.next().unwrap(),
+                spot_market_iter.next().unwrap(),
+                &drift_program,
+                false,
+                0,
+            ));
+        }
+        for key in oracle_keys.iter() {
+            market_accounts.push(AccountInfo::new(
+                key,
+                false,
+                false,
+                lamports_iter.next().unwrap(),
+                oracle_iter.next().unwrap(),
+                &oracle_program,
+                false,
+                0,
+            ));
+        }
+
+        let remaining_accounts =
+            get_remaining_accounts(&vault, &drift_user, &market_accounts, &[drift_user.clone()])
+                .unwrap();
+        let pubkeys: Vec<Pubkey> = remaining_accounts.iter().map(|acct| acct.key()).collect();
+        assert_eq!(
+            pubkeys,
+            vec![
+                quote_oracle,
+                sol_oracle,
+                perp_oracle,
+                keys[2],
+                keys[3],
+                keys[1],
+                keys[0],
+            ]
+        );
+
+        // the perp market's oracle is missing
+        market_accounts.retain(|acct| acct.key() != perp_oracle);
+        assert!(get_remaining_accounts(&vault, &drift_user, &market_accounts, &[]).is_err());
+    }
+}
@@ -67,6 +67,28 @@ pub enum ErrorCode {
     InvalidBorrowAmount,
     #[msg("InvalidRepayAmount")]
     InvalidRepayAmount,
+    #[msg("VaultDepositorLocked")]
+    VaultDepositorLocked,
+    #[msg("InvalidRewardStream")]
+    InvalidRewardStream,
+    #[msg("InvalidNavPolicy")]
+    InvalidNavPolicy,
+    #[msg("NavPolicyMissing")]
+    NavPolicyMissing,
+    #[msg("NavCircuitBreakerTripped")]
+    NavCircuitBreakerTripped,
+    #[msg("NavSnapshotTooSoon")]
+    NavSnapshotTooSoon,
+    #[msg("FuelDistributionMissing")]
+    FuelDistributionMissing,
+    #[msg("FuelBoostNotExpired")]
+    FuelBoostNotExpired,
+    #[msg("RewardStreamDepositorMissing")]
+    RewardStreamDepositorMissing,
+    #[msg("InvalidFuelSeason")]
+    InvalidFuelSeason,
+    #[msg("NavCircuitBreakerNotTripped")]
+    NavCircuitBreakerNotTripped,
 }
 
 impl From<DriftErrorCode> for ErrorCode {
@@ -1,16 +1,24 @@
 use anchor_lang::prelude::*;
 
 use crate::constraints::is_admin;
-use crate::state::events::{FeeUpdateAction, FeeUpdateRecord};
+use crate::state::events::{
+    FeeUpdateAction, FeeUpdateRecord, VaultConfig, VaultConfigAction, VaultConfigRecord,
+};
 use crate::state::{FeeUpdate, FeeUpdateStatus, Vault};
 
 pub fn admin_delete_fee_update<'info>(
     ctx: Context<'_, '_, '_, 'info, AdminDeleteFeeUpdate<'info>>,
 ) -> Result<()> {
     let mut vault = ctx.accounts.vault.load_mut()?;
+    let fee_update = ctx.accounts.fee_update.load()?;
+
+    let now = Clock::get()?.unix_timestamp;
+    let old_config = VaultConfig {
+        fee_update: Some(fee_update.get_config()),
+        ..vault.get_config(None)
+    };
 
     if vault.fee_update_status == FeeUpdateStatus::PendingFeeUpdate as u8 {
-        let now = Clock::get()?.unix_timestamp;
         emit!(FeeUpdateRecord {
             ts: now,
             action: FeeUpdateAction::Cancelled,
@@ -27,6 +35,15 @@ pub fn admin_delete_fee_update<'info>(
 
     vault.fee_update_status = FeeUpdateStatus::None as u8;
 
+    emit!(VaultConfigRecord {
+        ts: now,
+        action: VaultConfigAction::DeleteFeeUpdate,
+        vault: vault.pubkey,
+        authority: ctx.accounts.admin.key(),
+        old_config,
+        new_config: vault.get_config(None),
+    });
+
     Ok(())
 }
 
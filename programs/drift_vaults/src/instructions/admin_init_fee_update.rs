@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
 
 use crate::constraints::is_admin;
+use crate::state::events::{VaultConfig, VaultConfigAction, VaultConfigRecord};
 use crate::state::traits::Size;
 use crate::state::{FeeUpdate, FeeUpdateStatus, Vault};
 use crate::{error::ErrorCode, validate};
@@ -19,6 +20,19 @@ pub fn admin_init_fee_update<'info>(
     )?;
 
     fee_update.reset();
+    fee_update.bump = ctx.bumps.fee_update;
+
+    emit!(VaultConfigRecord {
+        ts: Clock::get()?.unix_timestamp,
+        action: VaultConfigAction::InitializeFeeUpdate,
+        vault: vault.pubkey,
+        authority: ctx.accounts.admin.key(),
+        old_config: vault.get_config(None),
+        new_config: VaultConfig {
+            fee_update: Some(fee_update.get_config()),
+            ..vault.get_config(None)
+        },
+    });
 
     Ok(())
 }
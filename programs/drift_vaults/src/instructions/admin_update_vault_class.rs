@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
 
 use crate::constraints::is_admin;
+use crate::state::events::{VaultConfigAction, VaultConfigRecord};
 use crate::state::Vault;
 use crate::{error::ErrorCode, validate};
 
@@ -16,6 +17,8 @@ pub fn admin_update_vault_class<'info>(
         "New vault class must be different from current vault class"
     )?;
 
+    let old_config = vault.get_config(None);
+
     msg!(
         "Updating vault class from {:?} to {:?}",
         vault.vault_class,
@@ -23,6 +26,15 @@ pub fn admin_update_vault_class<'info>(
     );
     vault.vault_class = new_vault_class;
 
+    emit!(VaultConfigRecord {
+        ts: Clock::get()?.unix_timestamp,
+        action: VaultConfigAction::UpdateVaultClass,
+        vault: vault.pubkey,
+        authority: ctx.accounts.admin.key(),
+        old_config,
+        new_config: vault.get_config(None),
+    });
+
     Ok(())
 }
 
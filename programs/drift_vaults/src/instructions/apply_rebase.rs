@@ -3,7 +3,8 @@ use drift::instructions::optional_accounts::AccountMaps;
 use drift::state::user::User;
 
 use crate::constraints::{is_user_for_vault, is_vault_for_vault_depositor};
-use crate::{AccountMapProvider, Vault, VaultDepositor, VaultProtocolProvider};
+use crate::state::settle_reward_streams;
+use crate::{AccountMapProvider, Vault, VaultDepositor, VaultRemainingAccountsProvider};
 
 pub fn apply_rebase<'c: 'info, 'info>(
     ctx: Context<'_, '_, 'c, 'info, ApplyRebase<'info>>,
@@ -13,8 +14,9 @@ pub fn apply_rebase<'c: 'info, 'info>(
     let mut vault = ctx.accounts.vault.load_mut()?;
     let mut vault_depositor = ctx.accounts.vault_depositor.load_mut()?;
 
-    // backwards compatible: if last rem acct does not deserialize into [`VaultProtocol`] then it's a legacy vault.
-    let mut vp = ctx.vault_protocol();
+    let remaining_accounts = ctx.vault_remaining_accounts(&vault)?;
+    // backwards compatible: vaults without a [`VaultProtocol`] are legacy vaults.
+    let mut vp = remaining_accounts.vault_protocol;
     vault.validate_vault_protocol(&vp)?;
     let mut vp = vp.as_mut().map(|vp| vp.load_mut()).transpose()?;
 
@@ -25,19 +27,21 @@ pub fn apply_rebase<'c: 'info, 'info>(
         perp_market_map,
         spot_market_map,
         mut oracle_map,
-    } = ctx.load_maps(
-        clock.slot,
-        Some(spot_market_index),
-        vp.is_some(),
-        false,
-        false,
-    )?;
+    } = ctx.load_maps(clock.slot, Some(spot_market_index))?;
 
     let vault_equity =
         vault.calculate_equity(&user, &perp_market_map, &spot_market_map, &mut oracle_map)?;
 
     vault_depositor.apply_rebase(&mut vault, &mut vp, vault_equity)?;
 
+    settle_reward_streams(
+        &remaining_accounts.reward_streams,
+        &remaining_accounts.reward_stream_depositors,
+        &vault_depositor,
+        &vault,
+        clock.unix_timestamp,
+    )?;
+
     Ok(())
 }
 
@@ -4,7 +4,7 @@ use drift::state::user::User;
 
 use crate::constraints::{is_tokenized_depositor_for_vault, is_user_for_vault};
 use crate::state::traits::VaultDepositorBase;
-use crate::{AccountMapProvider, TokenizedVaultDepositor, Vault, VaultProtocolProvider};
+use crate::{AccountMapProvider, TokenizedVaultDepositor, Vault, VaultRemainingAccountsProvider};
 
 pub fn apply_rebase_tokenized_depositor<'c: 'info, 'info>(
     ctx: Context<'_, '_, 'c, 'info, ApplyRebaseTokenizedDepositor<'info>>,
@@ -13,8 +13,9 @@ pub fn apply_rebase_tokenized_depositor<'c: 'info, 'info>(
 
     let mut vault = ctx.accounts.vault.load_mut()?;
 
-    // backwards compatible: if last rem acct does not deserialize into [`VaultProtocol`] then it's a legacy vault.
-    let mut vp = ctx.vault_protocol();
+    let remaining_accounts = ctx.vault_remaining_accounts(&vault)?;
+    // backwards compatible: vaults without a [`VaultProtocol`] are legacy vaults.
+    let mut vp = remaining_accounts.vault_protocol;
     vault.validate_vault_protocol(&vp)?;
     let mut vp = vp.as_mut().map(|vp| vp.load_mut()).transpose()?;
 
@@ -25,13 +26,7 @@ pub fn apply_rebase_tokenized_depositor<'c: 'info, 'info>(
         perp_market_map,
         spot_market_map,
         mut oracle_map,
-    } = ctx.load_maps(
-        clock.slot,
-        Some(spot_market_index),
-        vp.is_some(),
-        false,
-        false,
-    )?;
+    } = ctx.load_maps(clock.slot, Some(spot_market_index))?;
 
     let vault_equity =
         vault.calculate_equity(&user, &perp_market_map, &spot_market_map, &mut oracle_map)?;
@@ -0,0 +1,175 @@
+use crate::constraints::{
+    is_mint_for_tokenized_depositor, is_tokenized_depositor_for_vault, is_user_for_vault,
+    is_user_stats_for_vault,
+};
+use crate::error::ErrorCode;
+use crate::token_cpi::MintTokensCPI;
+use crate::{validate, AccountMapProvider};
+use crate::{
+    TokenizedRedeemRequest, TokenizedVaultDepositor, Vault, VaultRemainingAccountsProvider,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{mint_to, Mint, MintTo, TokenAccount, TokenInterface};
+use drift::instructions::optional_accounts::AccountMaps;
+use drift::math::casting::Cast;
+use drift::math::safe_math::SafeMath;
+use drift::state::user::{User, UserStats};
+
+pub fn cancel_redeem_request<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, CancelRedeemRequest<'info>>,
+    _request_id: u16,
+) -> Result<()> {
+    let clock = &Clock::get()?;
+
+    let mut vault = ctx.accounts.vault.load_mut()?;
+    let mut tokenized_vault_depositor = ctx.accounts.tokenized_vault_depositor.load_mut()?;
+    let mut redeem_request = ctx.accounts.redeem_request.load_mut()?;
+
+    let remaining_accounts = ctx.vault_remaining_accounts(&vault)?;
+    // backwards compatible: vaults without a [`VaultProtocol`] are legacy vaults.
+    let mut vp = remaining_accounts.vault_protocol;
+    vault.validate_vault_protocol(&vp)?;
+    let mut vp = vp.as_mut().map(|vp| vp.load_mut()).transpose()?;
+
+    let user = ctx.accounts.drift_user.load()?;
+    let user_stats = ctx.accounts.drift_user_stats.load()?;
+    let fuel_overflow = remaining_accounts.fuel_overflow;
+    user_stats.validate_fuel_overflow(&fuel_overflow)?;
+    let spot_market_index = vault.spot_market_index;
+
+    let mut fee_update = remaining_accounts.fee_update;
+    vault.validate_fee_update(&fee_update)?;
+
+    let AccountMaps {
+        perp_market_map,
+        spot_market_map,
+        mut oracle_map,
+    } = ctx.load_maps(clock.slot, Some(spot_market_index))?;
+
+    let vault_equity =
+        vault.calculate_equity(&user, &perp_market_map, &spot_market_map, &mut oracle_map)?;
+
+    let total_supply_before = ctx.accounts.mint.supply;
+    // accrue fuel to the tokenized shares before they change
+    tokenized_vault_depositor.update_cumulative_fuel_amount(
+        clock.unix_timestamp,
+        &mut vault,
+        &user_stats,
+        &fuel_overflow,
+    )?;
+    let spot_market = spot_market_map.get_ref(&spot_market_index)?;
+    let oracle = oracle_map.get_price_data(&spot_market.oracle_id())?;
+
+    let tokens_to_mint = tokenized_vault_depositor.cancel_redeem_request(
+        &mut redeem_request,
+        &mut vault,
+        &mut vp,
+        &mut fee_update,
+        total_supply_before,
+        vault_equity.cast()?,
+        clock.unix_timestamp,
+        oracle.price,
+    )?;
+
+    let vault_name = vault.name;
+    let vault_bump = vault.bump;
+
+    drop(spot_market);
+    drop(vault);
+    drop(user);
+    drop(user_stats);
+    drop(vp);
+    drop(tokenized_vault_depositor);
+    drop(redeem_request);
+
+    if tokens_to_mint > 0 {
+        ctx.mint(vault_name, vault_bump, tokens_to_mint)?;
+
+        msg!(
+            "Minted {} tokens to {}",
+            tokens_to_mint,
+            ctx.accounts.user_token_account.key()
+        );
+    }
+
+    ctx.accounts.mint.reload()?;
+    let total_supply_after = ctx.accounts.mint.supply;
+
+    let supply_delta = total_supply_after.safe_sub(total_supply_before)?;
+    validate!(
+        supply_delta.eq(&tokens_to_mint),
+        ErrorCode::InvalidTokenization,
+        "Tokens minted ({}) != supply delta ({})",
+        tokens_to_mint,
+        supply_delta
+    )?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(request_id: u16)]
+pub struct CancelRedeemRequest<'info> {
+    #[account(mut)]
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        mut,
+        constraint = is_tokenized_depositor_for_vault(&tokenized_vault_depositor, &vault)?,
+    )]
+    pub tokenized_vault_depositor: AccountLoader<'info, TokenizedVaultDepositor>,
+    #[account(
+        mut,
+        seeds = [b"tokenized_redeem_request", tokenized_vault_depositor.key().as_ref(), authority.key().as_ref(), request_id.to_le_bytes().as_ref()],
+        bump,
+        has_one = authority,
+        close = authority
+    )]
+    pub redeem_request: AccountLoader<'info, TokenizedRedeemRequest>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        mint::authority = vault.key(),
+        constraint = is_mint_for_tokenized_depositor(&mint.key(), &tokenized_vault_depositor)?,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        token::authority = authority,
+        token::mint = mint.key()
+    )]
+    pub user_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        constraint = is_user_for_vault(&vault, &drift_user.key())?
+    )]
+    pub drift_user: AccountLoader<'info, User>,
+    #[account(
+        constraint = is_user_stats_for_vault(&vault, &drift_user_stats.key())?
+    )]
+    /// CHECK: checked in drift cpi
+    pub drift_user_stats: AccountLoader<'info, UserStats>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> MintTokensCPI for Context<'_, '_, '_, 'info, CancelRedeemRequest<'info>> {
+    fn mint(&self, vault_name: [u8; 32], vault_bump: u8, amount: u64) -> Result<()> {
+        let signature_seeds = Vault::get_vault_signer_seeds(&vault_name, &vault_bump);
+        let signers = &[&signature_seeds[..]];
+
+        let cpi_accounts = MintTo {
+            mint: self.accounts.mint.to_account_info(),
+            to: self.accounts.user_token_account.to_account_info(),
+            authority: self.accounts.vault.to_account_info(),
+        };
+
+        let cpi_context = CpiContext::new_with_signer(
+            self.accounts.token_program.to_account_info(),
+            cpi_accounts,
+            signers,
+        );
+
+        mint_to(cpi_context, amount)?;
+
+        Ok(())
+    }
+}
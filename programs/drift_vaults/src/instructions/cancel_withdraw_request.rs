@@ -1,14 +1,14 @@
 use anchor_lang::prelude::*;
 use drift::instructions::optional_accounts::AccountMaps;
 use drift::math::casting::Cast;
-use drift::state::user::{FuelOverflowStatus, User, UserStats};
+use drift::state::user::{User, UserStats};
 
 use crate::constraints::{
     is_authority_for_vault_depositor, is_user_for_vault, is_user_stats_for_vault,
 };
-use crate::state::FuelOverflowProvider;
+use crate::state::settle_reward_streams;
 use crate::AccountMapProvider;
-use crate::{FeeUpdateProvider, FeeUpdateStatus, Vault, VaultDepositor, VaultProtocolProvider};
+use crate::{Vault, VaultDepositor, VaultRemainingAccountsProvider};
 
 pub fn cancel_withdraw_request<'c: 'info, 'info>(
     ctx: Context<'_, '_, 'c, 'info, CancelWithdrawRequest<'info>>,
@@ -17,33 +17,26 @@ pub fn cancel_withdraw_request<'c: 'info, 'info>(
     let mut vault = ctx.accounts.vault.load_mut()?;
     let mut vault_depositor = ctx.accounts.vault_depositor.load_mut()?;
 
-    // backwards compatible: if last rem acct does not deserialize into [`VaultProtocol`] then it's a legacy vault.
-    let mut vp = ctx.vault_protocol();
+    let remaining_accounts = ctx.vault_remaining_accounts(&vault)?;
+    // backwards compatible: vaults without a [`VaultProtocol`] are legacy vaults.
+    let mut vp = remaining_accounts.vault_protocol;
     vault.validate_vault_protocol(&vp)?;
     let mut vp = vp.as_mut().map(|vp| vp.load_mut()).transpose()?;
 
     let user = ctx.accounts.drift_user.load()?;
 
     let user_stats = ctx.accounts.drift_user_stats.load()?;
-    let has_fuel_overflow = FuelOverflowStatus::exists(user_stats.fuel_overflow_status);
-    let fuel_overflow = ctx.fuel_overflow(vp.is_some(), has_fuel_overflow);
+    let fuel_overflow = remaining_accounts.fuel_overflow;
     user_stats.validate_fuel_overflow(&fuel_overflow)?;
 
-    let has_fee_update = FeeUpdateStatus::has_pending_fee_update(vault.fee_update_status);
-    let mut fee_update = ctx.fee_update(vp.is_some(), has_fuel_overflow, has_fee_update);
+    let mut fee_update = remaining_accounts.fee_update;
     vault.validate_fee_update(&fee_update)?;
 
     let AccountMaps {
         perp_market_map,
         spot_market_map,
         mut oracle_map,
-    } = ctx.load_maps(
-        clock.slot,
-        None,
-        vp.is_some(),
-        has_fuel_overflow,
-        has_fee_update,
-    )?;
+    } = ctx.load_maps(clock.slot, None)?;
 
     let vault_equity =
         vault.calculate_equity(&user, &perp_market_map, &spot_market_map, &mut oracle_map)?;
@@ -62,6 +55,14 @@ pub fn cancel_withdraw_request<'c: 'info, 'info>(
         oracle.price,
     )?;
 
+    settle_reward_streams(
+        &remaining_accounts.reward_streams,
+        &remaining_accounts.reward_stream_depositors,
+        &vault_depositor,
+        &vault,
+        clock.unix_timestamp,
+    )?;
+
     Ok(())
 }
 
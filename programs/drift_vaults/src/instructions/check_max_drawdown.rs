@@ -0,0 +1,80 @@
+use anchor_lang::prelude::*;
+use drift::cpi::accounts::UpdateUser;
+use drift::instructions::optional_accounts::AccountMaps;
+use drift::program::Drift;
+use drift::state::user::User;
+
+use crate::constraints::is_user_for_vault;
+use crate::drift_cpi::UpdateUserReduceOnlyCPI;
+use crate::state::{NavPolicy, Vault, VaultRemainingAccountsProvider};
+use crate::AccountMapProvider;
+use crate::{declare_vault_seeds, implement_update_user_reduce_only_cpi};
+
+/// Permissionless crank that updates the vault's share price high-water mark and checks it against the
+/// [`NavPolicy`] max drawdown. On a breach the vault's drift user is set reduce-only and the redeem period
+/// is waived until the manager re-enables trading. The vault's [`crate::state::ManagerStats`] can be passed
+/// in remaining_accounts to start the high-water mark from its one.
+pub fn check_max_drawdown<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, CheckMaxDrawdown<'info>>,
+) -> Result<()> {
+    let clock = &Clock::get()?;
+    let mut vault = ctx.accounts.vault.load_mut()?;
+    let mut nav_policy = ctx.accounts.nav_policy.load_mut()?;
+    let user = ctx.accounts.drift_user.load()?;
+
+    let AccountMaps {
+        perp_market_map,
+        spot_market_map,
+        mut oracle_map,
+    } = ctx.load_maps(clock.slot, None)?;
+
+    let vault_equity =
+        vault.calculate_equity(&user, &perp_market_map, &spot_market_map, &mut oracle_map)?;
+
+    let manager_stats = ctx.vault_remaining_accounts(&vault)?.manager_stats;
+    let manager_stats = manager_stats.as_ref().map(|ms| ms.load()).transpose()?;
+
+    let triggered = nav_policy.check_max_drawdown(
+        &mut vault,
+        manager_stats.as_deref(),
+        vault_equity,
+        clock.unix_timestamp,
+    )?;
+
+    drop(user);
+    drop(manager_stats);
+    drop(nav_policy);
+    drop(vault);
+
+    if triggered {
+        ctx.drift_update_user_reduce_only(true)?;
+    }
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CheckMaxDrawdown<'info> {
+    #[account(mut)]
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        mut,
+        seeds = [b"nav_policy".as_ref(), vault.key().as_ref()],
+        bump,
+    )]
+    pub nav_policy: AccountLoader<'info, NavPolicy>,
+    #[account(
+        mut,
+        constraint = is_user_for_vault(&vault, &drift_user.key())?
+    )]
+    /// CHECK: checked in drift cpi
+    pub drift_user: AccountLoader<'info, User>,
+    pub drift_program: Program<'info, Drift>,
+}
+
+impl<'info> UpdateUserReduceOnlyCPI for Context<'_, '_, '_, 'info, CheckMaxDrawdown<'info>> {
+    fn drift_update_user_reduce_only(&self, reduce_only: bool) -> Result<()> {
+        implement_update_user_reduce_only_cpi!(self, reduce_only);
+        Ok(())
+    }
+}
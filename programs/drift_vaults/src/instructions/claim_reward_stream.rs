@@ -0,0 +1,109 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+use crate::constraints::{
+    is_authority_for_vault_depositor, is_vault_for_reward_stream, is_vault_for_vault_depositor,
+};
+use crate::{RewardStream, RewardStreamDepositor, Vault, VaultDepositor};
+
+pub fn claim_reward_stream<'info>(
+    ctx: Context<'_, '_, '_, 'info, ClaimRewardStream<'info>>,
+) -> Result<()> {
+    let clock = &Clock::get()?;
+
+    let vault = ctx.accounts.vault.load()?;
+    let vault_depositor = ctx.accounts.vault_depositor.load()?;
+    let mut reward_stream = ctx.accounts.reward_stream.load_mut()?;
+    let mut reward_stream_depositor = ctx.accounts.reward_stream_depositor.load_mut()?;
+
+    reward_stream.update(clock.unix_timestamp)?;
+
+    let vault_shares = reward_stream.to_stream_shares(
+        vault_depositor.checked_vault_shares(&vault)?,
+        vault.shares_base,
+    )?;
+    reward_stream_depositor.update_rewards(
+        &mut reward_stream,
+        vault_shares,
+        clock.unix_timestamp,
+    )?;
+    let amount = reward_stream_depositor.claim(&mut reward_stream)?;
+
+    msg!(
+        "Claiming {} rewards from reward stream {}",
+        amount,
+        ctx.accounts.reward_stream.key()
+    );
+
+    let vault_name = vault.name;
+    let vault_bump = vault.bump;
+
+    drop(reward_stream_depositor);
+    drop(reward_stream);
+    drop(vault_depositor);
+    drop(vault);
+
+    if amount > 0 {
+        let signature_seeds = Vault::get_vault_signer_seeds(&vault_name, &vault_bump);
+        let signers = &[&signature_seeds[..]];
+
+        transfer_checked(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.token_account.to_account_info(),
+                    mint: ctx.accounts.mint.to_account_info(),
+                    to: ctx.accounts.user_token_account.to_account_info(),
+                    authority: ctx.accounts.vault.to_account_info(),
+                },
+                signers,
+            ),
+            amount,
+            ctx.accounts.mint.decimals,
+        )?;
+    }
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ClaimRewardStream<'info> {
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        mut,
+        constraint = is_vault_for_reward_stream(&reward_stream, &vault)?,
+    )]
+    pub reward_stream: AccountLoader<'info, RewardStream>,
+    #[account(
+        constraint = is_vault_for_vault_depositor(&vault_depositor, &vault)?,
+        constraint = is_authority_for_vault_depositor(&vault_depositor, &authority)?,
+    )]
+    pub vault_depositor: AccountLoader<'info, VaultDepositor>,
+    #[account(
+        mut,
+        seeds = [b"reward_stream_depositor", reward_stream.key().as_ref(), vault_depositor.key().as_ref()],
+        bump,
+    )]
+    pub reward_stream_depositor: AccountLoader<'info, RewardStreamDepositor>,
+    pub authority: Signer<'info>,
+    #[account(
+        address = reward_stream.load()?.mint,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        seeds = [b"reward_stream_token_account".as_ref(), reward_stream.key().as_ref()],
+        bump,
+    )]
+    pub token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = authority,
+        associated_token::token_program = token_program,
+    )]
+    pub user_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
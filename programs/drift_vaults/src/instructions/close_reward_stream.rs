@@ -0,0 +1,118 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    close_account, transfer_checked, CloseAccount, Mint, TokenAccount, TokenInterface,
+    TransferChecked,
+};
+
+use crate::constraints::{is_manager_for_vault, is_vault_for_reward_stream};
+use crate::{RewardStream, Vault};
+
+/// Ends the stream and returns its undistributed rewards to the manager. Once the stream has no
+/// depositors left, which anyone can close after the stream ended, the stream and its token account are
+/// closed as well and whatever remains in the token account is returned.
+pub fn close_reward_stream<'info>(
+    ctx: Context<'_, '_, '_, 'info, CloseRewardStream<'info>>,
+) -> Result<()> {
+    let clock = &Clock::get()?;
+
+    let vault = ctx.accounts.vault.load()?;
+    let mut reward_stream = ctx.accounts.reward_stream.load_mut()?;
+
+    reward_stream.update(clock.unix_timestamp)?;
+    let remainder = reward_stream.close(clock.unix_timestamp)?;
+
+    // every depositor has been paid, the rounding dust goes back to the manager with the remainder
+    let close_accounts = reward_stream.depositor_count == 0;
+    let amount = if close_accounts {
+        ctx.accounts.token_account.amount
+    } else {
+        remainder
+    };
+
+    msg!(
+        "Closed reward stream {}, returning {} rewards{}",
+        ctx.accounts.reward_stream.key(),
+        amount,
+        if close_accounts {
+            " and closing its accounts"
+        } else {
+            ""
+        }
+    );
+
+    let vault_name = vault.name;
+    let vault_bump = vault.bump;
+
+    drop(reward_stream);
+    drop(vault);
+
+    let signature_seeds = Vault::get_vault_signer_seeds(&vault_name, &vault_bump);
+    let signers = &[&signature_seeds[..]];
+
+    if amount > 0 {
+        transfer_checked(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.token_account.to_account_info(),
+                    mint: ctx.accounts.mint.to_account_info(),
+                    to: ctx.accounts.manager_token_account.to_account_info(),
+                    authority: ctx.accounts.vault.to_account_info(),
+                },
+                signers,
+            ),
+            amount,
+            ctx.accounts.mint.decimals,
+        )?;
+    }
+
+    if close_accounts {
+        close_account(CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            CloseAccount {
+                account: ctx.accounts.token_account.to_account_info(),
+                destination: ctx.accounts.manager.to_account_info(),
+                authority: ctx.accounts.vault.to_account_info(),
+            },
+            signers,
+        ))?;
+
+        ctx.accounts
+            .reward_stream
+            .close(ctx.accounts.manager.to_account_info())?;
+    }
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CloseRewardStream<'info> {
+    #[account(
+        constraint = is_manager_for_vault(&vault, &manager)?,
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(mut)]
+    pub manager: Signer<'info>,
+    #[account(
+        mut,
+        constraint = is_vault_for_reward_stream(&reward_stream, &vault)?,
+    )]
+    pub reward_stream: AccountLoader<'info, RewardStream>,
+    #[account(
+        address = reward_stream.load()?.mint,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        seeds = [b"reward_stream_token_account".as_ref(), reward_stream.key().as_ref()],
+        bump,
+    )]
+    pub token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        token::authority = manager,
+        token::mint = mint,
+    )]
+    pub manager_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
@@ -0,0 +1,132 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+use drift::math::safe_math::SafeMath;
+
+use crate::constraints::{
+    is_authority_key_for_vault_depositor, is_vault_for_reward_stream, is_vault_for_vault_depositor,
+};
+use crate::error::ErrorCode;
+use crate::{validate, RewardStream, RewardStreamDepositor, Vault, VaultDepositor};
+
+/// Pays out the depositor's unclaimed rewards, unregisters its shares and closes its
+/// [`RewardStreamDepositor`]. The depositor's authority can close it at any time, anyone can once the stream
+/// has ended so the stream itself can be closed.
+pub fn close_reward_stream_depositor<'info>(
+    ctx: Context<'_, '_, '_, 'info, CloseRewardStreamDepositor<'info>>,
+) -> Result<()> {
+    let clock = &Clock::get()?;
+
+    let vault = ctx.accounts.vault.load()?;
+    let mut vault_depositor = ctx.accounts.vault_depositor.load_mut()?;
+    let mut reward_stream = ctx.accounts.reward_stream.load_mut()?;
+    let mut reward_stream_depositor = ctx.accounts.reward_stream_depositor.load_mut()?;
+
+    validate!(
+        ctx.accounts.signer.key() == ctx.accounts.authority.key()
+            || reward_stream.is_ended(clock.unix_timestamp),
+        ErrorCode::InvalidRewardStream,
+        "only the depositor's authority can close its reward stream depositor before the stream ends at {}",
+        reward_stream.end_ts
+    )?;
+
+    reward_stream.update(clock.unix_timestamp)?;
+
+    let vault_shares = reward_stream.to_stream_shares(
+        vault_depositor.checked_vault_shares(&vault)?,
+        vault.shares_base,
+    )?;
+    reward_stream_depositor.update_rewards(
+        &mut reward_stream,
+        vault_shares,
+        clock.unix_timestamp,
+    )?;
+    let amount = reward_stream_depositor.claim(&mut reward_stream)?;
+    // unregister the depositor's shares
+    reward_stream_depositor.update_rewards(&mut reward_stream, 0, clock.unix_timestamp)?;
+
+    reward_stream.depositor_count = reward_stream.depositor_count.safe_sub(1)?;
+    vault_depositor.reward_stream_count = vault_depositor.reward_stream_count.safe_sub(1)?;
+
+    msg!(
+        "Closing reward stream depositor {}, claiming {} rewards",
+        ctx.accounts.reward_stream_depositor.key(),
+        amount
+    );
+
+    let vault_name = vault.name;
+    let vault_bump = vault.bump;
+
+    drop(reward_stream_depositor);
+    drop(reward_stream);
+    drop(vault_depositor);
+    drop(vault);
+
+    if amount > 0 {
+        let signature_seeds = Vault::get_vault_signer_seeds(&vault_name, &vault_bump);
+        let signers = &[&signature_seeds[..]];
+
+        transfer_checked(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.token_account.to_account_info(),
+                    mint: ctx.accounts.mint.to_account_info(),
+                    to: ctx.accounts.user_token_account.to_account_info(),
+                    authority: ctx.accounts.vault.to_account_info(),
+                },
+                signers,
+            ),
+            amount,
+            ctx.accounts.mint.decimals,
+        )?;
+    }
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CloseRewardStreamDepositor<'info> {
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        mut,
+        constraint = is_vault_for_reward_stream(&reward_stream, &vault)?,
+    )]
+    pub reward_stream: AccountLoader<'info, RewardStream>,
+    #[account(
+        mut,
+        constraint = is_vault_for_vault_depositor(&vault_depositor, &vault)?,
+        constraint = is_authority_key_for_vault_depositor(&vault_depositor, &authority.key())?,
+    )]
+    pub vault_depositor: AccountLoader<'info, VaultDepositor>,
+    #[account(
+        mut,
+        seeds = [b"reward_stream_depositor", reward_stream.key().as_ref(), vault_depositor.key().as_ref()],
+        bump,
+        close = authority,
+    )]
+    pub reward_stream_depositor: AccountLoader<'info, RewardStreamDepositor>,
+    pub signer: Signer<'info>,
+    /// CHECK: the vault depositor's authority, receives the rewards and the rent
+    #[account(mut)]
+    pub authority: AccountInfo<'info>,
+    #[account(
+        address = reward_stream.load()?.mint,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        seeds = [b"reward_stream_token_account".as_ref(), reward_stream.key().as_ref()],
+        bump,
+    )]
+    pub token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = authority,
+        associated_token::token_program = token_program,
+    )]
+    pub user_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
@@ -3,7 +3,7 @@ use anchor_spl::associated_token::get_associated_token_address;
 use drift::state::insurance_fund_stake::InsuranceFundStake;
 
 use crate::constants::admin;
-use crate::state::VaultProtocol;
+use crate::state::{RewardStream, VaultProtocol};
 use crate::{TokenizedVaultDepositor, Vault, VaultDepositor};
 
 pub fn is_vault_for_vault_depositor(
@@ -110,6 +110,13 @@ pub fn is_vault_shares_base_for_tokenized_depositor(
         .eq(vault_shares_base))
 }
 
+pub fn is_vault_for_reward_stream(
+    reward_stream: &AccountLoader<RewardStream>,
+    vault: &AccountLoader<Vault>,
+) -> Result<bool> {
+    Ok(reward_stream.load()?.vault.eq(&vault.key()))
+}
+
 pub fn is_ata(token_account: &Pubkey, owner: &Pubkey, mint: &Pubkey) -> anchor_lang::Result<bool> {
     Ok(get_associated_token_address(owner, mint).eq(token_account))
 }
@@ -3,7 +3,7 @@ use anchor_spl::token::{self, Token, TokenAccount, Transfer};
 use drift::cpi::accounts::Deposit as DriftDeposit;
 use drift::instructions::optional_accounts::AccountMaps;
 use drift::program::Drift;
-use drift::state::user::{FuelOverflowStatus, User, UserStats};
+use drift::state::user::{User, UserStats};
 
 use crate::constraints::{
     is_authority_for_vault_depositor, is_user_for_vault, is_user_stats_for_vault,
@@ -11,8 +11,7 @@ use crate::constraints::{
 use crate::drift_cpi::DepositCPI;
 use crate::error::ErrorCode;
 use crate::state::{
-    FeeUpdateProvider, FeeUpdateStatus, FuelOverflowProvider, Vault, VaultDepositor,
-    VaultProtocolProvider,
+    settle_reward_streams, NavSide, Vault, VaultDepositor, VaultRemainingAccountsProvider,
 };
 use crate::token_cpi::TokenTransferCPI;
 use crate::{declare_vault_seeds, implement_deposit, validate, AccountMapProvider};
@@ -28,8 +27,9 @@ pub fn deposit<'c: 'info, 'info>(
 
     let mut vault_depositor = ctx.accounts.vault_depositor.load_mut()?;
 
-    // backwards compatible: if last rem acct does not deserialize into [`VaultProtocol`] then it's a legacy vault.
-    let mut vp = ctx.vault_protocol();
+    let remaining_accounts = ctx.vault_remaining_accounts(&vault)?;
+    // backwards compatible: vaults without a [`VaultProtocol`] are legacy vaults.
+    let mut vp = remaining_accounts.vault_protocol;
     vault.validate_vault_protocol(&vp)?;
     let mut vp = vp.as_mut().map(|vp| vp.load_mut()).transpose()?;
 
@@ -37,28 +37,48 @@ pub fn deposit<'c: 'info, 'info>(
     let spot_market_index = vault.spot_market_index;
 
     let user_stats = ctx.accounts.drift_user_stats.load()?;
-    let has_fuel_overflow = FuelOverflowStatus::exists(user_stats.fuel_overflow_status);
-    let fuel_overflow = ctx.fuel_overflow(vp.is_some(), has_fuel_overflow);
+    let fuel_overflow = remaining_accounts.fuel_overflow;
     user_stats.validate_fuel_overflow(&fuel_overflow)?;
 
-    let has_fee_update = FeeUpdateStatus::has_pending_fee_update(vault.fee_update_status);
-    let mut fee_update = ctx.fee_update(vp.is_some(), has_fuel_overflow, has_fee_update);
+    let mut fee_update = remaining_accounts.fee_update;
     vault.validate_fee_update(&fee_update)?;
 
+    let nav_policy = remaining_accounts.nav_policy;
+    vault.validate_nav_policy(&nav_policy)?;
+
+    let nav_history = remaining_accounts.nav_history;
+    let manager_stats = remaining_accounts.manager_stats;
+
     let AccountMaps {
         perp_market_map,
         spot_market_map,
         mut oracle_map,
-    } = ctx.load_maps(
-        clock.slot,
-        Some(spot_market_index),
-        vp.is_some(),
-        has_fuel_overflow,
-        has_fee_update,
+    } = ctx.load_maps(clock.slot, Some(spot_market_index))?;
+
+    let (vault_equity, all_oracles_valid) = vault.calculate_nav_policy_equity(
+        &nav_policy,
+        NavSide::Deposit,
+        &user,
+        &perp_market_map,
+        &spot_market_map,
+        &mut oracle_map,
     )?;
 
-    let vault_equity =
-        vault.calculate_equity(&user, &perp_market_map, &spot_market_map, &mut oracle_map)?;
+    vault.check_nav_breaker(&nav_policy, vault_equity, clock.unix_timestamp)?;
+
+    let valid_vault_equity = all_oracles_valid.then_some(vault_equity);
+    vault.snapshot_nav_history(&nav_history, valid_vault_equity, clock.unix_timestamp)?;
+    vault.update_manager_stats(&manager_stats, valid_vault_equity, clock.unix_timestamp)?;
+
+    let vault_equity = vault.apply_swing_pricing(
+        &nav_policy,
+        NavSide::Deposit,
+        vault_equity,
+        &user,
+        &perp_market_map,
+        &spot_market_map,
+        &mut oracle_map,
+    )?;
 
     let deposit_room_remaining = vault.max_tokens.saturating_sub(vault_equity);
     let mut deposit_amount = amount;
@@ -87,6 +107,14 @@ pub fn deposit<'c: 'info, 'info>(
         oracle.price,
     )?;
 
+    settle_reward_streams(
+        &remaining_accounts.reward_streams,
+        &remaining_accounts.reward_stream_depositors,
+        &vault_depositor,
+        &vault,
+        clock.unix_timestamp,
+    )?;
+
     drop(spot_market);
     drop(vault);
     drop(user);
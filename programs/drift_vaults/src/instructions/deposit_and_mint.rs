@@ -0,0 +1,261 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use anchor_spl::token_interface::{
+    mint_to, Mint, MintTo, TokenAccount as InterfaceTokenAccount, TokenInterface,
+};
+use drift::cpi::accounts::Deposit as DriftDeposit;
+use drift::instructions::optional_accounts::AccountMaps;
+use drift::math::safe_math::SafeMath;
+use drift::program::Drift;
+use drift::state::user::{User, UserStats};
+
+use crate::constraints::{
+    is_mint_for_tokenized_depositor, is_tokenized_depositor_for_vault, is_user_for_vault,
+    is_user_stats_for_vault, is_vault_shares_base_for_tokenized_depositor,
+};
+use crate::drift_cpi::DepositCPI;
+use crate::error::ErrorCode;
+use crate::state::{NavSide, TokenizedVaultDepositor, Vault, VaultRemainingAccountsProvider};
+use crate::token_cpi::{MintTokensCPI, TokenTransferCPI};
+use crate::{declare_vault_seeds, implement_deposit, validate, AccountMapProvider};
+
+pub fn deposit_and_mint<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, DepositAndMint<'info>>,
+    amount: u64,
+) -> Result<()> {
+    let clock = &Clock::get()?;
+
+    let mut vault = ctx.accounts.vault.load_mut()?;
+    validate!(!vault.in_liquidation(), ErrorCode::OngoingLiquidation)?;
+    validate!(
+        !vault.permissioned,
+        ErrorCode::PermissionedVault,
+        "Cannot deposit and mint into a permissioned vault"
+    )?;
+
+    let mut tokenized_vault_depositor = ctx.accounts.tokenized_vault_depositor.load_mut()?;
+
+    let remaining_accounts = ctx.vault_remaining_accounts(&vault)?;
+    // backwards compatible: vaults without a [`VaultProtocol`] are legacy vaults.
+    let mut vp = remaining_accounts.vault_protocol;
+    vault.validate_vault_protocol(&vp)?;
+    let mut vp = vp.as_mut().map(|vp| vp.load_mut()).transpose()?;
+
+    let user = ctx.accounts.drift_user.load()?;
+    let user_stats = ctx.accounts.drift_user_stats.load()?;
+    let fuel_overflow = remaining_accounts.fuel_overflow;
+    user_stats.validate_fuel_overflow(&fuel_overflow)?;
+    let spot_market_index = vault.spot_market_index;
+
+    let mut fee_update = remaining_accounts.fee_update;
+    vault.validate_fee_update(&fee_update)?;
+
+    let nav_policy = remaining_accounts.nav_policy;
+    vault.validate_nav_policy(&nav_policy)?;
+
+    let AccountMaps {
+        perp_market_map,
+        spot_market_map,
+        mut oracle_map,
+    } = ctx.load_maps(clock.slot, Some(spot_market_index))?;
+
+    let (vault_equity, _) = vault.calculate_nav_policy_equity(
+        &nav_policy,
+        NavSide::Deposit,
+        &user,
+        &perp_market_map,
+        &spot_market_map,
+        &mut oracle_map,
+    )?;
+
+    vault.check_nav_breaker(&nav_policy, vault_equity, clock.unix_timestamp)?;
+
+    let vault_equity = vault.apply_swing_pricing(
+        &nav_policy,
+        NavSide::Deposit,
+        vault_equity,
+        &user,
+        &perp_market_map,
+        &spot_market_map,
+        &mut oracle_map,
+    )?;
+
+    let deposit_room_remaining = vault.max_tokens.saturating_sub(vault_equity);
+    let mut deposit_amount = amount;
+    if vault.max_tokens > 0 && deposit_room_remaining < amount {
+        msg!(
+            "Deposting {}/{} to stay within vault max tokens {}",
+            deposit_room_remaining,
+            amount,
+            vault.max_tokens
+        );
+        deposit_amount = deposit_room_remaining;
+    }
+
+    let total_supply_before = ctx.accounts.mint.supply;
+    // accrue fuel to the tokenized shares before they change
+    tokenized_vault_depositor.update_cumulative_fuel_amount(
+        clock.unix_timestamp,
+        &mut vault,
+        &user_stats,
+        &fuel_overflow,
+    )?;
+
+    let spot_market = spot_market_map.get_ref(&spot_market_index)?;
+    let oracle = oracle_map.get_price_data(&spot_market.oracle_id())?;
+
+    let tokens_to_mint = tokenized_vault_depositor.deposit(
+        &mut vault,
+        &mut vp,
+        &mut fee_update,
+        total_supply_before,
+        deposit_amount,
+        vault_equity,
+        ctx.accounts.authority.key(),
+        clock.unix_timestamp,
+        oracle.price,
+    )?;
+
+    let vault_name = vault.name;
+    let vault_bump = vault.bump;
+
+    drop(spot_market);
+    drop(vault);
+    drop(user);
+    drop(user_stats);
+    drop(vp);
+    drop(tokenized_vault_depositor);
+
+    ctx.token_transfer(deposit_amount)?;
+
+    ctx.drift_deposit(deposit_amount)?;
+
+    ctx.mint(vault_name, vault_bump, tokens_to_mint)?;
+
+    msg!(
+        "Minted {} tokens to {}",
+        tokens_to_mint,
+        ctx.accounts.user_share_token_account.key()
+    );
+
+    ctx.accounts.mint.reload()?;
+    let total_supply_after = ctx.accounts.mint.supply;
+
+    let supply_delta = total_supply_after.safe_sub(total_supply_before)?;
+    validate!(
+        supply_delta.eq(&tokens_to_mint),
+        ErrorCode::InvalidTokenization,
+        "Tokens minted ({}) != supply delta ({})",
+        tokens_to_mint,
+        supply_delta
+    )?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct DepositAndMint<'info> {
+    #[account(mut)]
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        mut,
+        constraint = is_tokenized_depositor_for_vault(&tokenized_vault_depositor, &vault)?,
+        constraint = is_vault_shares_base_for_tokenized_depositor(&vault.load()?.shares_base, &tokenized_vault_depositor)?,
+    )]
+    pub tokenized_vault_depositor: AccountLoader<'info, TokenizedVaultDepositor>,
+    #[account(
+        mut,
+        seeds = [b"mint", vault.key().as_ref(), vault.load()?.shares_base.to_string().as_bytes()],
+        bump,
+        mint::authority = vault.key(),
+        constraint = is_mint_for_tokenized_depositor(&mint.key(), &tokenized_vault_depositor)?,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"vault_token_account".as_ref(), vault.key().as_ref()],
+        bump
+    )]
+    pub vault_token_account: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        constraint = is_user_stats_for_vault(&vault, &drift_user_stats.key())?
+    )]
+    /// CHECK: checked in drift cpi
+    pub drift_user_stats: AccountLoader<'info, UserStats>,
+    #[account(
+        mut,
+        constraint = is_user_for_vault(&vault, &drift_user.key())?
+    )]
+    /// CHECK: checked in drift cpi
+    pub drift_user: AccountLoader<'info, User>,
+    /// CHECK: checked in drift cpi
+    pub drift_state: AccountInfo<'info>,
+    #[account(
+        mut,
+        token::mint = vault_token_account.mint
+    )]
+    pub drift_spot_market_vault: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        token::authority = authority,
+        token::mint = vault_token_account.mint
+    )]
+    pub user_token_account: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        token::mint = mint.key()
+    )]
+    pub user_share_token_account: Box<InterfaceAccount<'info, InterfaceTokenAccount>>,
+    pub drift_program: Program<'info, Drift>,
+    pub token_program: Program<'info, Token>,
+    /// The token program that owns the share token mint, either SPL Token or Token-2022
+    pub share_token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> TokenTransferCPI for Context<'_, '_, '_, 'info, DepositAndMint<'info>> {
+    fn token_transfer(&self, amount: u64) -> Result<()> {
+        let cpi_accounts = Transfer {
+            from: self.accounts.user_token_account.to_account_info().clone(),
+            to: self.accounts.vault_token_account.to_account_info().clone(),
+            authority: self.accounts.authority.to_account_info().clone(),
+        };
+        let token_program = self.accounts.token_program.to_account_info().clone();
+        let cpi_context = CpiContext::new(token_program, cpi_accounts);
+
+        token::transfer(cpi_context, amount)?;
+
+        Ok(())
+    }
+}
+
+impl<'info> DepositCPI for Context<'_, '_, '_, 'info, DepositAndMint<'info>> {
+    fn drift_deposit(&self, amount: u64) -> Result<()> {
+        implement_deposit!(self, amount);
+        Ok(())
+    }
+}
+
+impl<'info> MintTokensCPI for Context<'_, '_, '_, 'info, DepositAndMint<'info>> {
+    fn mint(&self, vault_name: [u8; 32], vault_bump: u8, amount: u64) -> Result<()> {
+        let signature_seeds = Vault::get_vault_signer_seeds(&vault_name, &vault_bump);
+        let signers = &[&signature_seeds[..]];
+
+        let cpi_accounts = MintTo {
+            mint: self.accounts.mint.to_account_info(),
+            to: self.accounts.user_share_token_account.to_account_info(),
+            authority: self.accounts.vault.to_account_info(),
+        };
+
+        let cpi_context = CpiContext::new_with_signer(
+            self.accounts.share_token_program.to_account_info(),
+            cpi_accounts,
+            signers,
+        );
+
+        mint_to(cpi_context, amount)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,111 @@
+use anchor_lang::prelude::*;
+use drift::cpi::accounts::UpdateUser;
+use drift::instructions::optional_accounts::AccountMaps;
+use drift::program::Drift;
+use drift::state::user::User;
+
+use crate::constraints::{is_authority_for_vault_depositor, is_user_for_vault};
+use crate::drift_cpi::UpdateUserReduceOnlyCPI;
+use crate::error::ErrorCode;
+use crate::state::events::{VaultConfig, VaultConfigAction, VaultConfigRecord};
+use crate::state::traits::VaultDepositorBase;
+use crate::state::{NavPolicy, Vault};
+use crate::{
+    declare_vault_seeds, implement_update_user_reduce_only_cpi, validate, AccountMapProvider,
+    VaultDepositor,
+};
+
+/// The depositor side of re-enabling trading after the [`NavPolicy`] max drawdown was triggered, so
+/// depositors aren't left waiting on the manager. Any depositor with shares can start or finish the same
+/// timelock as `manager_reenable_trading`.
+pub fn depositor_reenable_trading<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, DepositorReenableTrading<'info>>,
+) -> Result<()> {
+    let clock = &Clock::get()?;
+    let mut vault = ctx.accounts.vault.load_mut()?;
+    let mut nav_policy = ctx.accounts.nav_policy.load_mut()?;
+    let vault_depositor = ctx.accounts.vault_depositor.load()?;
+    let user = ctx.accounts.drift_user.load()?;
+
+    validate!(
+        vault_depositor.checked_vault_shares(&vault)? > 0,
+        ErrorCode::InvalidNavPolicy,
+        "only depositors with shares can re-enable trading"
+    )?;
+
+    let AccountMaps {
+        perp_market_map,
+        spot_market_map,
+        mut oracle_map,
+    } = ctx.load_maps(clock.slot, None)?;
+
+    let vault_equity =
+        vault.calculate_equity(&user, &perp_market_map, &spot_market_map, &mut oracle_map)?;
+
+    let old_config = VaultConfig {
+        nav_policy: Some(nav_policy.get_config()),
+        ..vault.get_config(None)
+    };
+
+    let reenabled = nav_policy.reenable_trading(&mut vault, vault_equity, clock.unix_timestamp)?;
+
+    emit!(VaultConfigRecord {
+        ts: clock.unix_timestamp,
+        action: VaultConfigAction::ReenableTrading,
+        vault: vault.pubkey,
+        authority: ctx.accounts.authority.key(),
+        old_config,
+        new_config: VaultConfig {
+            nav_policy: Some(nav_policy.get_config()),
+            ..vault.get_config(None)
+        },
+    });
+    // a liquidation keeps the user reduce-only until it ends
+    let in_liquidation = vault.in_liquidation();
+
+    drop(user);
+    drop(vault_depositor);
+    drop(nav_policy);
+    drop(vault);
+
+    if reenabled && !in_liquidation {
+        ctx.drift_update_user_reduce_only(false)?;
+    }
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct DepositorReenableTrading<'info> {
+    #[account(mut)]
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        seeds = [b"vault_depositor", vault.key().as_ref(), authority.key().as_ref()],
+        bump,
+        constraint = is_authority_for_vault_depositor(&vault_depositor, &authority)?,
+    )]
+    pub vault_depositor: AccountLoader<'info, VaultDepositor>,
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"nav_policy".as_ref(), vault.key().as_ref()],
+        bump,
+    )]
+    pub nav_policy: AccountLoader<'info, NavPolicy>,
+    #[account(
+        mut,
+        constraint = is_user_for_vault(&vault, &drift_user.key())?
+    )]
+    /// CHECK: checked in drift cpi
+    pub drift_user: AccountLoader<'info, User>,
+    pub drift_program: Program<'info, Drift>,
+}
+
+impl<'info> UpdateUserReduceOnlyCPI
+    for Context<'_, '_, '_, 'info, DepositorReenableTrading<'info>>
+{
+    fn drift_update_user_reduce_only(&self, reduce_only: bool) -> Result<()> {
+        implement_update_user_reduce_only_cpi!(self, reduce_only);
+        Ok(())
+    }
+}
@@ -0,0 +1,69 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+use crate::constraints::{is_manager_for_vault, is_vault_for_reward_stream};
+use crate::{RewardStream, Vault};
+
+pub fn fund_reward_stream<'info>(
+    ctx: Context<'_, '_, '_, 'info, FundRewardStream<'info>>,
+    amount: u64,
+) -> Result<()> {
+    let clock = &Clock::get()?;
+
+    let mut reward_stream = ctx.accounts.reward_stream.load_mut()?;
+
+    // emit for the time before the new funds arrived first
+    reward_stream.update(clock.unix_timestamp)?;
+    reward_stream.fund(amount, clock.unix_timestamp)?;
+
+    drop(reward_stream);
+
+    transfer_checked(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.manager_token_account.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.token_account.to_account_info(),
+                authority: ctx.accounts.manager.to_account_info(),
+            },
+        ),
+        amount,
+        ctx.accounts.mint.decimals,
+    )?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct FundRewardStream<'info> {
+    #[account(
+        constraint = is_manager_for_vault(&vault, &manager)?,
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+    pub manager: Signer<'info>,
+    #[account(
+        mut,
+        constraint = is_vault_for_reward_stream(&reward_stream, &vault)?,
+    )]
+    pub reward_stream: AccountLoader<'info, RewardStream>,
+    #[account(
+        address = reward_stream.load()?.mint,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        seeds = [b"reward_stream_token_account".as_ref(), reward_stream.key().as_ref()],
+        bump,
+    )]
+    pub token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        token::authority = manager,
+        token::mint = mint,
+    )]
+    pub manager_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+use crate::constraints::is_manager_for_vault;
+use crate::state::traits::Size;
+use crate::state::{FuelDistribution, Vault};
+
+pub fn initialize_fuel_distribution<'info>(
+    ctx: Context<'_, '_, '_, 'info, InitializeFuelDistribution<'info>>,
+) -> Result<()> {
+    let vault = ctx.accounts.vault.load()?;
+    let mut fuel_distribution = ctx.accounts.fuel_distribution.load_init()?;
+
+    fuel_distribution.vault = ctx.accounts.vault.key();
+    fuel_distribution.bump = ctx.bumps.fuel_distribution;
+    fuel_distribution.last_cumulative_fuel = vault.cumulative_fuel;
+    fuel_distribution.last_settle_ts = Clock::get()?.unix_timestamp as u32;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeFuelDistribution<'info> {
+    #[account(
+        constraint = is_manager_for_vault(&vault, &manager)?,
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(mut)]
+    pub manager: Signer<'info>,
+    #[account(
+        init,
+        seeds = [b"fuel_distribution".as_ref(), vault.key().as_ref()],
+        bump,
+        payer = manager,
+        space = FuelDistribution::SIZE,
+    )]
+    pub fuel_distribution: AccountLoader<'info, FuelDistribution>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+
+use crate::state::traits::Size;
+use crate::state::{ManagerStats, Vault};
+
+pub fn initialize_manager_stats<'info>(
+    ctx: Context<'_, '_, '_, 'info, InitializeManagerStats<'info>>,
+) -> Result<()> {
+    let vault = ctx.accounts.vault.load()?;
+    let mut manager_stats = ctx.accounts.manager_stats.load_init()?;
+
+    manager_stats.vault = vault.pubkey;
+    manager_stats.inception_ts = vault.init_ts;
+    manager_stats.shares_base = vault.shares_base;
+    manager_stats.bump = ctx.bumps.manager_stats;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeManagerStats<'info> {
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        init,
+        seeds = [b"manager_stats".as_ref(), vault.key().as_ref()],
+        bump,
+        payer = payer,
+        space = ManagerStats::SIZE,
+    )]
+    pub manager_stats: AccountLoader<'info, ManagerStats>,
+    pub system_program: Program<'info, System>,
+}
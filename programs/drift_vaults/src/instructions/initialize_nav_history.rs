@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+use crate::state::traits::Size;
+use crate::state::{NavHistory, Vault};
+
+pub fn initialize_nav_history<'info>(
+    ctx: Context<'_, '_, '_, 'info, InitializeNavHistory<'info>>,
+) -> Result<()> {
+    let mut nav_history = ctx.accounts.nav_history.load_init()?;
+
+    nav_history.vault = ctx.accounts.vault.key();
+    nav_history.bump = ctx.bumps.nav_history;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeNavHistory<'info> {
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        init,
+        seeds = [b"nav_history".as_ref(), vault.key().as_ref()],
+        bump,
+        payer = payer,
+        space = NavHistory::SIZE,
+    )]
+    pub nav_history: AccountLoader<'info, NavHistory>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+
+use crate::constraints::is_manager_for_vault;
+use crate::state::events::{VaultConfig, VaultConfigAction, VaultConfigRecord};
+use crate::state::traits::Size;
+use crate::state::{NavPolicy, NavPolicyParams, Vault};
+use crate::{error::ErrorCode, validate};
+
+pub fn initialize_nav_policy<'info>(
+    ctx: Context<'_, '_, '_, 'info, InitializeNavPolicy<'info>>,
+    params: NavPolicyParams,
+) -> Result<()> {
+    let mut vault = ctx.accounts.vault.load_mut()?;
+    let mut nav_policy = ctx.accounts.nav_policy.load_init()?;
+
+    validate!(!vault.in_liquidation(), ErrorCode::OngoingLiquidation)?;
+
+    let old_config = vault.get_config(None);
+
+    let now = Clock::get()?.unix_timestamp;
+    nav_policy.vault = ctx.accounts.vault.key();
+    nav_policy.bump = ctx.bumps.nav_policy;
+    nav_policy.update(params, &vault, now)?;
+
+    vault.nav_policy = true;
+
+    emit!(VaultConfigRecord {
+        ts: now,
+        action: VaultConfigAction::InitializeNavPolicy,
+        vault: vault.pubkey,
+        authority: ctx.accounts.manager.key(),
+        old_config,
+        new_config: VaultConfig {
+            nav_policy: Some(nav_policy.get_config()),
+            ..vault.get_config(None)
+        },
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeNavPolicy<'info> {
+    #[account(
+        mut,
+        constraint = is_manager_for_vault(&vault, &manager)?,
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(mut)]
+    pub manager: Signer<'info>,
+    #[account(
+        init,
+        seeds = [b"nav_policy".as_ref(), vault.key().as_ref()],
+        bump,
+        payer = manager,
+        space = NavPolicy::SIZE,
+    )]
+    pub nav_policy: AccountLoader<'info, NavPolicy>,
+    pub system_program: Program<'info, System>,
+}
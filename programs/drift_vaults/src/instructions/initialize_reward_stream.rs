@@ -0,0 +1,89 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::constraints::is_manager_for_vault;
+use crate::error::ErrorCode;
+use crate::{validate, RewardStream, Size, Vault};
+
+pub fn initialize_reward_stream<'info>(
+    ctx: Context<'_, '_, '_, 'info, InitializeRewardStream<'info>>,
+    params: InitializeRewardStreamParams,
+) -> Result<()> {
+    let clock = &Clock::get()?;
+    let now = clock.unix_timestamp;
+
+    validate!(
+        params.reward_rate > 0,
+        ErrorCode::InvalidRewardStream,
+        "reward_rate must be > 0"
+    )?;
+    validate!(
+        params.end_ts > params.start_ts && params.end_ts > now,
+        ErrorCode::InvalidRewardStream,
+        "end_ts ({}) must be after start_ts ({}) and now ({})",
+        params.end_ts,
+        params.start_ts,
+        now
+    )?;
+
+    let vault = ctx.accounts.vault.load()?;
+    let mut reward_stream = ctx.accounts.reward_stream.load_init()?;
+
+    reward_stream.vault = ctx.accounts.vault.key();
+    reward_stream.mint = ctx.accounts.mint.key();
+    reward_stream.token_account = ctx.accounts.token_account.key();
+    reward_stream.reward_rate = params.reward_rate;
+    // a stream can't emit for time before it existed
+    reward_stream.start_ts = params.start_ts.max(now);
+    reward_stream.end_ts = params.end_ts;
+    reward_stream.last_update_ts = now;
+    reward_stream.shares_base = vault.shares_base;
+    reward_stream.reward_stream_id = params.reward_stream_id;
+    reward_stream.bump = ctx.bumps.reward_stream;
+
+    Ok(())
+}
+
+#[derive(Debug, Clone, Copy, AnchorSerialize, AnchorDeserialize, PartialEq, Eq)]
+pub struct InitializeRewardStreamParams {
+    pub reward_stream_id: u16,
+    pub reward_rate: u64,
+    pub start_ts: i64,
+    pub end_ts: i64,
+}
+
+#[derive(Accounts)]
+#[instruction(params: InitializeRewardStreamParams)]
+pub struct InitializeRewardStream<'info> {
+    #[account(
+        constraint = is_manager_for_vault(&vault, &manager)?,
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(mut)]
+    pub manager: Signer<'info>,
+    #[account(
+        init,
+        seeds = [b"reward_stream", vault.key().as_ref(), params.reward_stream_id.to_le_bytes().as_ref()],
+        space = RewardStream::SIZE,
+        bump,
+        payer = manager
+    )]
+    pub reward_stream: AccountLoader<'info, RewardStream>,
+    #[account(
+        mint::token_program = token_program,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        init,
+        seeds = [b"reward_stream_token_account".as_ref(), reward_stream.key().as_ref()],
+        bump,
+        payer = manager,
+        token::mint = mint,
+        token::authority = vault,
+        token::token_program = token_program,
+    )]
+    pub token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    /// The token program that owns the reward mint, either SPL Token or Token-2022
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,80 @@
+use anchor_lang::prelude::*;
+use drift::math::safe_math::SafeMath;
+
+use crate::constraints::{
+    is_authority_for_vault_depositor, is_vault_for_reward_stream, is_vault_for_vault_depositor,
+};
+use crate::error::ErrorCode;
+use crate::{validate, RewardStream, RewardStreamDepositor, Size, Vault, VaultDepositor};
+
+pub fn initialize_reward_stream_depositor<'info>(
+    ctx: Context<'_, '_, '_, 'info, InitializeRewardStreamDepositor<'info>>,
+) -> Result<()> {
+    let clock = &Clock::get()?;
+
+    let vault = ctx.accounts.vault.load()?;
+    let mut vault_depositor = ctx.accounts.vault_depositor.load_mut()?;
+    let mut reward_stream = ctx.accounts.reward_stream.load_mut()?;
+
+    validate!(
+        !reward_stream.is_ended(clock.unix_timestamp),
+        ErrorCode::InvalidRewardStream,
+        "reward stream ended at {}",
+        reward_stream.end_ts
+    )?;
+
+    reward_stream.update(clock.unix_timestamp)?;
+
+    let vault_shares = reward_stream.to_stream_shares(
+        vault_depositor.checked_vault_shares(&vault)?,
+        vault.shares_base,
+    )?;
+
+    let mut reward_stream_depositor = ctx.accounts.reward_stream_depositor.load_init()?;
+    *reward_stream_depositor = RewardStreamDepositor::new(
+        ctx.accounts.reward_stream.key(),
+        ctx.accounts.vault_depositor.key(),
+        vault_depositor.authority,
+        ctx.bumps.reward_stream_depositor,
+    );
+    // register the depositor's shares, rewards emitted before the account existed aren't earned
+    reward_stream_depositor.update_rewards(
+        &mut reward_stream,
+        vault_shares,
+        clock.unix_timestamp,
+    )?;
+
+    reward_stream.depositor_count = reward_stream.depositor_count.safe_add(1)?;
+    vault_depositor.reward_stream_count = vault_depositor.reward_stream_count.safe_add(1)?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeRewardStreamDepositor<'info> {
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        mut,
+        constraint = is_vault_for_reward_stream(&reward_stream, &vault)?,
+    )]
+    pub reward_stream: AccountLoader<'info, RewardStream>,
+    #[account(
+        mut,
+        constraint = is_vault_for_vault_depositor(&vault_depositor, &vault)?,
+        constraint = is_authority_for_vault_depositor(&vault_depositor, &authority)?,
+    )]
+    pub vault_depositor: AccountLoader<'info, VaultDepositor>,
+    /// The depositor has to pass the reward stream depositor whenever its shares change, so only it can opt in
+    pub authority: Signer<'info>,
+    #[account(
+        init,
+        seeds = [b"reward_stream_depositor", reward_stream.key().as_ref(), vault_depositor.key().as_ref()],
+        space = RewardStreamDepositor::SIZE,
+        bump,
+        payer = payer
+    )]
+    pub reward_stream_depositor: AccountLoader<'info, RewardStreamDepositor>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
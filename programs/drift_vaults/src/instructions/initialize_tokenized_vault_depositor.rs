@@ -50,9 +50,9 @@ pub fn initialize_tokenized_vault_depositor(
             collection: None,
             uses: None,
         },
-        false, // Is mutable
-        true,  // Update authority is signer
-        None,  // Collection details
+        true, // Is mutable
+        true, // Update authority is signer
+        None, // Collection details
     )?;
 
     Ok(())
@@ -0,0 +1,223 @@
+use crate::constraints::is_manager_for_vault;
+use crate::error::ErrorCode;
+use crate::instructions::{transfer_hook_extra_account_metas, EXTRA_ACCOUNT_METAS_SEED};
+use crate::{validate, Size, TokenizedVaultDepositor, Vault};
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::{invoke, invoke_signed};
+use anchor_lang::system_program::{create_account, CreateAccount};
+use anchor_spl::token_2022::spl_token_2022::extension::{
+    metadata_pointer, transfer_hook, ExtensionType,
+};
+use anchor_spl::token_2022::spl_token_2022::state::Mint as MintState;
+use anchor_spl::token_2022::{initialize_mint2, InitializeMint2, Token2022};
+use spl_tlv_account_resolution::state::ExtraAccountMetaList;
+use spl_token_metadata_interface::state::TokenMetadata;
+use spl_transfer_hook_interface::instruction::ExecuteInstruction;
+
+/// Creates a TokenizedVaultDepositor whose share mint is a Token-2022 mint. Token metadata is stored
+/// on the mint itself via the metadata pointer and token metadata extensions instead of Metaplex.
+/// If `enable_transfer_hook` is set, the mint is created with this program as its transfer hook, which
+/// enforces the vault's allowlist and depositor lockups on secondary transfers, and
+/// `extra_account_meta_list` must be passed to be initialized with the accounts the hook needs.
+pub fn initialize_tokenized_vault_depositor_token_2022(
+    ctx: Context<InitializeTokenizedVaultDepositorToken2022>,
+    params: InitializeTokenizedVaultDepositorToken2022Params,
+) -> Result<()> {
+    let vault = ctx.accounts.vault.load()?;
+    let vault_key = ctx.accounts.vault.key();
+    let mint_key = ctx.accounts.mint_account.key();
+    let token_program_id = ctx.accounts.token_program.key();
+
+    let mut tokenized_vault_depositor = ctx.accounts.vault_depositor.load_init()?;
+    *tokenized_vault_depositor = TokenizedVaultDepositor::new(
+        vault_key,
+        ctx.accounts.vault_depositor.key(),
+        mint_key,
+        vault.shares_base,
+        ctx.bumps.vault_depositor,
+        Clock::get()?.unix_timestamp,
+    );
+
+    let mut extensions = vec![ExtensionType::MetadataPointer];
+    if params.enable_transfer_hook {
+        extensions.push(ExtensionType::TransferHook);
+    }
+    let mint_space = ExtensionType::try_calculate_account_len::<MintState>(&extensions)?;
+
+    // token metadata is reallocated into the mint by the token program, so the mint must be funded for it upfront
+    let metadata_space = TokenMetadata {
+        name: params.token_name.clone(),
+        symbol: params.token_symbol.clone(),
+        uri: params.token_uri.clone(),
+        ..Default::default()
+    }
+    .tlv_size_of()?;
+    let lamports = Rent::get()?.minimum_balance(mint_space + metadata_space);
+
+    let shares_base = vault.shares_base.to_string();
+    let mint_seeds = [
+        b"mint".as_ref(),
+        vault_key.as_ref(),
+        shares_base.as_bytes(),
+        &[ctx.bumps.mint_account],
+    ];
+    create_account(
+        CpiContext::new_with_signer(
+            ctx.accounts.system_program.to_account_info(),
+            CreateAccount {
+                from: ctx.accounts.payer.to_account_info(),
+                to: ctx.accounts.mint_account.to_account_info(),
+            },
+            &[&mint_seeds[..]],
+        ),
+        lamports,
+        mint_space as u64,
+        &token_program_id,
+    )?;
+
+    invoke(
+        &metadata_pointer::instruction::initialize(
+            &token_program_id,
+            &mint_key,
+            Some(vault_key),
+            Some(mint_key),
+        )?,
+        &[ctx.accounts.mint_account.to_account_info()],
+    )?;
+
+    if params.enable_transfer_hook {
+        invoke(
+            &transfer_hook::instruction::initialize(
+                &token_program_id,
+                &mint_key,
+                Some(vault_key),
+                Some(crate::ID),
+            )?,
+            &[ctx.accounts.mint_account.to_account_info()],
+        )?;
+
+        initialize_extra_account_meta_list(&ctx, &mint_key, &vault_key)?;
+    }
+
+    initialize_mint2(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            InitializeMint2 {
+                mint: ctx.accounts.mint_account.to_account_info(),
+            },
+        ),
+        params.decimals,
+        &vault_key,
+        Some(&vault_key),
+    )?;
+
+    let signature_seeds = Vault::get_vault_signer_seeds(vault.name.as_ref(), &vault.bump);
+    let signers = &[&signature_seeds[..]];
+
+    invoke_signed(
+        &spl_token_metadata_interface::instruction::initialize(
+            &token_program_id,
+            &mint_key,
+            &vault_key,
+            &mint_key,
+            &vault_key,
+            params.token_name,
+            params.token_symbol,
+            params.token_uri,
+        ),
+        &[
+            ctx.accounts.mint_account.to_account_info(),
+            ctx.accounts.vault.to_account_info(),
+        ],
+        signers,
+    )?;
+
+    Ok(())
+}
+
+fn initialize_extra_account_meta_list(
+    ctx: &Context<InitializeTokenizedVaultDepositorToken2022>,
+    mint_key: &Pubkey,
+    vault_key: &Pubkey,
+) -> Result<()> {
+    let extra_account_meta_list = ctx
+        .accounts
+        .extra_account_meta_list
+        .as_ref()
+        .ok_or(ErrorCode::InvalidTokenization)?;
+
+    let (extra_account_meta_list_key, bump) =
+        Pubkey::find_program_address(&[EXTRA_ACCOUNT_METAS_SEED, mint_key.as_ref()], &crate::ID);
+    validate!(
+        extra_account_meta_list.key() == extra_account_meta_list_key,
+        ErrorCode::InvalidTokenization,
+        "extra_account_meta_list must be the mint's extra account metas pda {}",
+        extra_account_meta_list_key
+    )?;
+
+    let extra_account_metas = transfer_hook_extra_account_metas(vault_key)?;
+    let space = ExtraAccountMetaList::size_of(extra_account_metas.len())?;
+
+    create_account(
+        CpiContext::new_with_signer(
+            ctx.accounts.system_program.to_account_info(),
+            CreateAccount {
+                from: ctx.accounts.payer.to_account_info(),
+                to: extra_account_meta_list.to_account_info(),
+            },
+            &[&[EXTRA_ACCOUNT_METAS_SEED, mint_key.as_ref(), &[bump]]],
+        ),
+        Rent::get()?.minimum_balance(space),
+        space as u64,
+        &crate::ID,
+    )?;
+
+    ExtraAccountMetaList::init::<ExecuteInstruction>(
+        &mut extra_account_meta_list.try_borrow_mut_data()?,
+        &extra_account_metas,
+    )?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeTokenizedVaultDepositorToken2022<'info> {
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        init,
+        seeds = [b"tokenized_vault_depositor", vault.key().as_ref(), vault.load()?.shares_base.to_string().as_bytes()],
+        space = TokenizedVaultDepositor::SIZE,
+        bump,
+        payer = payer
+    )]
+    pub vault_depositor: AccountLoader<'info, TokenizedVaultDepositor>,
+    /// CHECK: created and initialized as a Token-2022 mint in this instruction
+    #[account(
+        mut,
+        seeds = [b"mint", vault.key().as_ref(), vault.load()?.shares_base.to_string().as_bytes()],
+        bump,
+    )]
+    pub mint_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        constraint = is_manager_for_vault(&vault, &payer)?,
+    )]
+    pub payer: Signer<'info>,
+    /// CHECK: the mint's extra account metas pda, created and initialized in this instruction when
+    /// enable_transfer_hook is set
+    #[account(mut)]
+    pub extra_account_meta_list: Option<UncheckedAccount<'info>>,
+    pub token_program: Program<'info, Token2022>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Debug, Clone, AnchorSerialize, AnchorDeserialize, PartialEq, Eq)]
+pub struct InitializeTokenizedVaultDepositorToken2022Params {
+    pub token_name: String,
+    pub token_symbol: String,
+    pub token_uri: String,
+    pub decimals: u8,
+    /// Create the mint with this program as its transfer hook, enforcing the vault allowlist and
+    /// depositor lockups on secondary transfers
+    pub enable_transfer_hook: bool,
+}
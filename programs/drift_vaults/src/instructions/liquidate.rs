@@ -11,7 +11,9 @@ use crate::constraints::{
 use crate::drift_cpi::{UpdateUserDelegateCPI, UpdateUserReduceOnlyCPI};
 use crate::state::{Vault, VaultDepositor};
 use crate::{declare_vault_seeds, implement_update_user_delegate_cpi};
-use crate::{implement_update_user_reduce_only_cpi, AccountMapProvider, VaultProtocolProvider};
+use crate::{
+    implement_update_user_reduce_only_cpi, AccountMapProvider, VaultRemainingAccountsProvider,
+};
 
 pub fn liquidate<'c: 'info, 'info>(
     ctx: Context<'_, '_, 'c, 'info, Liquidate<'info>>,
@@ -23,8 +25,9 @@ pub fn liquidate<'c: 'info, 'info>(
     let mut vault = ctx.accounts.vault.load_mut()?;
     let vault_depositor = ctx.accounts.vault_depositor.load()?;
 
-    // backwards compatible: if last rem acct does not deserialize into [`VaultProtocol`] then it's a legacy vault.
-    let mut vp = ctx.vault_protocol();
+    let remaining_accounts = ctx.vault_remaining_accounts(&vault)?;
+    // backwards compatible: vaults without a [`VaultProtocol`] are legacy vaults.
+    let mut vp = remaining_accounts.vault_protocol;
     vault.validate_vault_protocol(&vp)?;
     let vp = vp.as_mut().map(|vp| vp.load_mut()).transpose()?;
 
@@ -32,13 +35,7 @@ pub fn liquidate<'c: 'info, 'info>(
         perp_market_map,
         spot_market_map,
         mut oracle_map,
-    } = ctx.load_maps(
-        clock.slot,
-        Some(vault.spot_market_index),
-        vp.is_some(),
-        false,
-        false,
-    )?;
+    } = ctx.load_maps(clock.slot, Some(vault.spot_market_index))?;
 
     // 1. Check the vault depositor has waited the redeem period
     vault_depositor
@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+use drift::state::user::UserStats;
+
+use crate::constraints::{is_authority_for_vault_depositor, is_user_stats_for_vault};
+use crate::error::ErrorCode;
+use crate::state::{FuelLockupPeriod, Vault, VaultRemainingAccountsProvider};
+use crate::{validate, VaultDepositor};
+
+pub fn lock_vault_depositor<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, LockVaultDepositor<'info>>,
+    lockup_period: FuelLockupPeriod,
+) -> Result<()> {
+    let clock = &Clock::get()?;
+
+    let mut vault = ctx.accounts.vault.load_mut()?;
+    validate!(!vault.in_liquidation(), ErrorCode::OngoingLiquidation)?;
+
+    let mut vault_depositor = ctx.accounts.vault_depositor.load_mut()?;
+
+    let remaining_accounts = ctx.vault_remaining_accounts(&vault)?;
+    // backwards compatible: vaults without a [`VaultProtocol`] are legacy vaults.
+    let mut vp = remaining_accounts.vault_protocol;
+    vault.validate_vault_protocol(&vp)?;
+    let vp = vp.as_mut().map(|vp| vp.load_mut()).transpose()?;
+
+    let user_stats = ctx.accounts.drift_user_stats.load()?;
+    let fuel_overflow = remaining_accounts.fuel_overflow;
+    user_stats.validate_fuel_overflow(&fuel_overflow)?;
+
+    vault_depositor.fuel_lockup(
+        lockup_period,
+        clock.unix_timestamp,
+        &mut vault,
+        &user_stats,
+        &fuel_overflow,
+    )?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct LockVaultDepositor<'info> {
+    #[account(mut)]
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        mut,
+        seeds = [b"vault_depositor", vault.key().as_ref(), authority.key().as_ref()],
+        bump,
+        constraint = is_authority_for_vault_depositor(&vault_depositor, &authority)?,
+    )]
+    pub vault_depositor: AccountLoader<'info, VaultDepositor>,
+    pub authority: Signer<'info>,
+    #[account(
+        constraint = is_user_stats_for_vault(&vault, &drift_user_stats.key())?
+    )]
+    pub drift_user_stats: AccountLoader<'info, UserStats>,
+}
@@ -4,7 +4,7 @@ use drift::math::casting::Cast;
 use drift::state::user::User;
 
 use crate::constraints::{is_manager_for_vault, is_user_for_vault, is_user_stats_for_vault};
-use crate::state::{Vault, VaultProtocolProvider};
+use crate::state::{Vault, VaultRemainingAccountsProvider};
 use crate::AccountMapProvider;
 
 pub fn manager_cancel_withdraw_request<'c: 'info, 'info>(
@@ -13,8 +13,9 @@ pub fn manager_cancel_withdraw_request<'c: 'info, 'info>(
     let clock = &Clock::get()?;
     let vault = &mut ctx.accounts.vault.load_mut()?;
 
-    // backwards compatible: if last rem acct does not deserialize into [`VaultProtocol`] then it's a legacy vault.
-    let mut vp = ctx.vault_protocol();
+    let remaining_accounts = ctx.vault_remaining_accounts(&vault)?;
+    // backwards compatible: vaults without a [`VaultProtocol`] are legacy vaults.
+    let mut vp = remaining_accounts.vault_protocol;
     vault.validate_vault_protocol(&vp)?;
     let mut vp = vp.as_mut().map(|vp| vp.load_mut()).transpose()?;
 
@@ -24,7 +25,7 @@ pub fn manager_cancel_withdraw_request<'c: 'info, 'info>(
         perp_market_map,
         spot_market_map,
         mut oracle_map,
-    } = ctx.load_maps(clock.slot, None, vp.is_some(), false, false)?;
+    } = ctx.load_maps(clock.slot, None)?;
 
     let vault_equity =
         vault.calculate_equity(&user, &perp_market_map, &spot_market_map, &mut oracle_map)?;
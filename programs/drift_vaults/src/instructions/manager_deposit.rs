@@ -7,7 +7,7 @@ use drift::state::user::User;
 
 use crate::constraints::{is_manager_for_vault, is_user_for_vault, is_user_stats_for_vault};
 use crate::drift_cpi::DepositCPI;
-use crate::state::{Vault, VaultProtocolProvider};
+use crate::state::{NavSide, Vault, VaultRemainingAccountsProvider};
 use crate::token_cpi::TokenTransferCPI;
 use crate::{declare_vault_seeds, AccountMapProvider};
 
@@ -19,29 +19,33 @@ pub fn manager_deposit<'c: 'info, 'info>(
 
     let mut vault = ctx.accounts.vault.load_mut()?;
 
-    // backwards compatible: if last rem acct does not deserialize into [`VaultProtocol`] then it's a legacy vault.
-    let mut vp = ctx.vault_protocol();
+    let remaining_accounts = ctx.vault_remaining_accounts(&vault)?;
+    // backwards compatible: vaults without a [`VaultProtocol`] are legacy vaults.
+    let mut vp = remaining_accounts.vault_protocol;
     vault.validate_vault_protocol(&vp)?;
     let mut vp = vp.as_mut().map(|vp| vp.load_mut()).transpose()?;
 
     let user = ctx.accounts.drift_user.load()?;
     let spot_market_index = vault.spot_market_index;
 
+    let nav_policy = remaining_accounts.nav_policy;
+    vault.validate_nav_policy(&nav_policy)?;
+
     let AccountMaps {
         perp_market_map,
         spot_market_map,
         mut oracle_map,
-    } = ctx.load_maps(
-        clock.slot,
-        Some(spot_market_index),
-        vp.is_some(),
-        false,
-        false,
+    } = ctx.load_maps(clock.slot, Some(spot_market_index))?;
+
+    let vault_equity = vault.calculate_swing_equity(
+        &nav_policy,
+        NavSide::Deposit,
+        &user,
+        &perp_market_map,
+        &spot_market_map,
+        &mut oracle_map,
     )?;
 
-    let vault_equity =
-        vault.calculate_equity(&user, &perp_market_map, &spot_market_map, &mut oracle_map)?;
-
     let spot_market = spot_market_map.get_ref(&spot_market_index)?;
     let oracle = oracle_map.get_price_data(&spot_market.oracle_id())?;
 
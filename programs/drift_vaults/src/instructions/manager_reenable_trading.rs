@@ -0,0 +1,94 @@
+use anchor_lang::prelude::*;
+use drift::cpi::accounts::UpdateUser;
+use drift::instructions::optional_accounts::AccountMaps;
+use drift::program::Drift;
+use drift::state::user::User;
+
+use crate::constraints::{is_manager_for_vault, is_user_for_vault};
+use crate::drift_cpi::UpdateUserReduceOnlyCPI;
+use crate::state::events::{VaultConfig, VaultConfigAction, VaultConfigRecord};
+use crate::state::{NavPolicy, Vault};
+use crate::{declare_vault_seeds, implement_update_user_reduce_only_cpi, AccountMapProvider};
+
+/// Re-enables trading after the [`NavPolicy`] max drawdown was triggered. The first call starts the
+/// timelock, during which depositors can keep withdrawing without a redeem period. Calling it again once the
+/// timelock has passed lifts reduce-only and restores the redeem period, unless the share price fell past the
+/// max drawdown again since the first call. Depositors can do the same through `depositor_reenable_trading`.
+pub fn manager_reenable_trading<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, ManagerReenableTrading<'info>>,
+) -> Result<()> {
+    let clock = &Clock::get()?;
+    let mut vault = ctx.accounts.vault.load_mut()?;
+    let mut nav_policy = ctx.accounts.nav_policy.load_mut()?;
+    let user = ctx.accounts.drift_user.load()?;
+
+    let AccountMaps {
+        perp_market_map,
+        spot_market_map,
+        mut oracle_map,
+    } = ctx.load_maps(clock.slot, None)?;
+
+    let vault_equity =
+        vault.calculate_equity(&user, &perp_market_map, &spot_market_map, &mut oracle_map)?;
+
+    let old_config = VaultConfig {
+        nav_policy: Some(nav_policy.get_config()),
+        ..vault.get_config(None)
+    };
+
+    let reenabled = nav_policy.reenable_trading(&mut vault, vault_equity, clock.unix_timestamp)?;
+
+    emit!(VaultConfigRecord {
+        ts: clock.unix_timestamp,
+        action: VaultConfigAction::ReenableTrading,
+        vault: vault.pubkey,
+        authority: ctx.accounts.manager.key(),
+        old_config,
+        new_config: VaultConfig {
+            nav_policy: Some(nav_policy.get_config()),
+            ..vault.get_config(None)
+        },
+    });
+    // a liquidation keeps the user reduce-only until it ends
+    let in_liquidation = vault.in_liquidation();
+
+    drop(user);
+    drop(nav_policy);
+    drop(vault);
+
+    if reenabled && !in_liquidation {
+        ctx.drift_update_user_reduce_only(false)?;
+    }
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ManagerReenableTrading<'info> {
+    #[account(
+        mut,
+        constraint = is_manager_for_vault(&vault, &manager)?,
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+    pub manager: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"nav_policy".as_ref(), vault.key().as_ref()],
+        bump,
+    )]
+    pub nav_policy: AccountLoader<'info, NavPolicy>,
+    #[account(
+        mut,
+        constraint = is_user_for_vault(&vault, &drift_user.key())?
+    )]
+    /// CHECK: checked in drift cpi
+    pub drift_user: AccountLoader<'info, User>,
+    pub drift_program: Program<'info, Drift>,
+}
+
+impl<'info> UpdateUserReduceOnlyCPI for Context<'_, '_, '_, 'info, ManagerReenableTrading<'info>> {
+    fn drift_update_user_reduce_only(&self, reduce_only: bool) -> Result<()> {
+        implement_update_user_reduce_only_cpi!(self, reduce_only);
+        Ok(())
+    }
+}
@@ -1,9 +1,7 @@
 use crate::constraints::{is_manager_for_vault, is_user_for_vault, is_user_stats_for_vault};
 use crate::drift_cpi::ManagerRepayCPI;
 use crate::state::events::{ManagerRepayRecord, ManagerUpdateBorrowRecord};
-use crate::state::{
-    FeeUpdateProvider, FeeUpdateStatus, FuelOverflowProvider, VaultProtocolProvider,
-};
+use crate::state::VaultRemainingAccountsProvider;
 use crate::token_cpi::TokenTransferCPI;
 use crate::{declare_vault_seeds, AccountMapProvider};
 use crate::{error::ErrorCode, validate, Vault};
@@ -14,7 +12,7 @@ use drift::cpi::accounts::Deposit as DriftDeposit;
 use drift::instructions::optional_accounts::AccountMaps;
 use drift::math::safe_math::SafeMath;
 use drift::program::Drift;
-use drift::state::user::{FuelOverflowStatus, User, UserStats};
+use drift::state::user::{User, UserStats};
 
 pub fn manager_repay<'c: 'info, 'info>(
     ctx: Context<'_, '_, 'c, 'info, ManagerRepay<'info>>,
@@ -38,31 +36,24 @@ pub fn manager_repay<'c: 'info, 'info>(
     let clock = &Clock::get()?;
     let now = clock.unix_timestamp;
 
-    // backwards compatible: if last rem acct does not deserialize into [`VaultProtocol`] then it's a legacy vault.
-    let mut vp = ctx.vault_protocol();
+    let remaining_accounts = ctx.vault_remaining_accounts(&vault)?;
+    // backwards compatible: vaults without a [`VaultProtocol`] are legacy vaults.
+    let mut vp = remaining_accounts.vault_protocol;
     vault.validate_vault_protocol(&vp)?;
     let vp = vp.as_mut().map(|vp| vp.load_mut()).transpose()?;
 
     let user_stats = ctx.accounts.drift_user_stats.load()?;
-    let has_fuel_overflow = FuelOverflowStatus::exists(user_stats.fuel_overflow_status);
-    let fuel_overflow = ctx.fuel_overflow(vp.is_some(), has_fuel_overflow);
+    let fuel_overflow = remaining_accounts.fuel_overflow;
     user_stats.validate_fuel_overflow(&fuel_overflow)?;
 
-    let has_fee_update = FeeUpdateStatus::has_pending_fee_update(vault.fee_update_status);
-    let fee_update = ctx.fee_update(vp.is_some(), has_fuel_overflow, has_fee_update);
+    let fee_update = remaining_accounts.fee_update;
     vault.validate_fee_update(&fee_update)?;
 
     let AccountMaps {
         perp_market_map,
         spot_market_map,
         mut oracle_map,
-    } = ctx.load_maps(
-        clock.slot,
-        Some(repay_spot_market_index),
-        vp.is_some(),
-        has_fuel_overflow,
-        has_fee_update,
-    )?;
+    } = ctx.load_maps(clock.slot, Some(repay_spot_market_index))?;
 
     let user = ctx.accounts.drift_user.load()?;
 
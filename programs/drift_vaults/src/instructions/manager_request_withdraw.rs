@@ -3,9 +3,9 @@ use drift::instructions::optional_accounts::AccountMaps;
 use drift::state::user::User;
 
 use crate::constraints::{is_manager_for_vault, is_user_for_vault, is_user_stats_for_vault};
-use crate::state::VaultProtocolProvider;
+use crate::state::VaultRemainingAccountsProvider;
 use crate::AccountMapProvider;
-use crate::{Vault, WithdrawUnit};
+use crate::{NavSide, Vault, WithdrawUnit};
 
 pub fn manager_request_withdraw<'c: 'info, 'info>(
     ctx: Context<'_, '_, 'c, 'info, ManagerRequestWithdraw<'info>>,
@@ -16,28 +16,44 @@ pub fn manager_request_withdraw<'c: 'info, 'info>(
     let mut vault = ctx.accounts.vault.load_mut()?;
     let now = clock.unix_timestamp;
 
-    // backwards compatible: if last rem acct does not deserialize into [`VaultProtocol`] then it's a legacy vault.
-    let mut vp = ctx.vault_protocol();
+    let remaining_accounts = ctx.vault_remaining_accounts(&vault)?;
+    // backwards compatible: vaults without a [`VaultProtocol`] are legacy vaults.
+    let mut vp = remaining_accounts.vault_protocol;
     vault.validate_vault_protocol(&vp)?;
     let mut vp = vp.as_mut().map(|vp| vp.load_mut()).transpose()?;
 
     let user = ctx.accounts.drift_user.load()?;
     let spot_market_index = vault.spot_market_index;
 
+    let nav_policy = remaining_accounts.nav_policy;
+    vault.validate_nav_policy(&nav_policy)?;
+
     let AccountMaps {
         perp_market_map,
         spot_market_map,
         mut oracle_map,
-    } = ctx.load_maps(
-        clock.slot,
-        Some(spot_market_index),
-        vp.is_some(),
-        false,
-        false,
+    } = ctx.load_maps(clock.slot, Some(spot_market_index))?;
+
+    let (vault_equity, _) = vault.calculate_nav_policy_equity(
+        &nav_policy,
+        NavSide::Withdraw,
+        &user,
+        &perp_market_map,
+        &spot_market_map,
+        &mut oracle_map,
     )?;
 
-    let vault_equity =
-        vault.calculate_equity(&user, &perp_market_map, &spot_market_map, &mut oracle_map)?;
+    vault.check_nav_breaker(&nav_policy, vault_equity, clock.unix_timestamp)?;
+
+    let vault_equity = vault.apply_swing_pricing(
+        &nav_policy,
+        NavSide::Withdraw,
+        vault_equity,
+        &user,
+        &perp_market_map,
+        &spot_market_map,
+        &mut oracle_map,
+    )?;
 
     let spot_market = spot_market_map.get_ref(&spot_market_index)?;
     let oracle = oracle_map.get_price_data(&spot_market.oracle_id())?;
@@ -1,13 +1,11 @@
 use crate::constraints::{is_manager_for_vault, is_user_for_vault, is_user_stats_for_vault};
-use crate::state::events::ManagerUpdateBorrowRecord;
-use crate::state::{
-    FeeUpdateProvider, FeeUpdateStatus, FuelOverflowProvider, VaultProtocolProvider,
-};
+use crate::state::events::{ManagerUpdateBorrowRecord, VaultConfigAction, VaultConfigRecord};
+use crate::state::VaultRemainingAccountsProvider;
 use crate::AccountMapProvider;
 use crate::{error::ErrorCode, validate, Vault};
 use anchor_lang::prelude::*;
 use drift::instructions::optional_accounts::AccountMaps;
-use drift::state::user::{FuelOverflowStatus, User, UserStats};
+use drift::state::user::{User, UserStats};
 
 pub fn manager_update_borrow<'c: 'info, 'info>(
     ctx: Context<'_, '_, 'c, 'info, ManagerUpdateBorrow<'info>>,
@@ -23,37 +21,32 @@ pub fn manager_update_borrow<'c: 'info, 'info>(
     let clock = &Clock::get()?;
     let now = clock.unix_timestamp;
 
-    // backwards compatible: if last rem acct does not deserialize into [`VaultProtocol`] then it's a legacy vault.
-    let mut vp = ctx.vault_protocol();
+    let remaining_accounts = ctx.vault_remaining_accounts(&vault)?;
+    // backwards compatible: vaults without a [`VaultProtocol`] are legacy vaults.
+    let mut vp = remaining_accounts.vault_protocol;
     vault.validate_vault_protocol(&vp)?;
     let vp = vp.as_mut().map(|vp| vp.load_mut()).transpose()?;
 
     let user_stats = ctx.accounts.drift_user_stats.load()?;
-    let has_fuel_overflow = FuelOverflowStatus::exists(user_stats.fuel_overflow_status);
-    let fuel_overflow = ctx.fuel_overflow(vp.is_some(), has_fuel_overflow);
+    let fuel_overflow = remaining_accounts.fuel_overflow;
     user_stats.validate_fuel_overflow(&fuel_overflow)?;
 
-    let has_fee_update = FeeUpdateStatus::has_pending_fee_update(vault.fee_update_status);
-    let fee_update = ctx.fee_update(vp.is_some(), has_fuel_overflow, has_fee_update);
+    let fee_update = remaining_accounts.fee_update;
     vault.validate_fee_update(&fee_update)?;
 
     let AccountMaps {
         perp_market_map,
         spot_market_map,
         mut oracle_map,
-    } = ctx.load_maps(
-        clock.slot,
-        None,
-        vp.is_some(),
-        has_fuel_overflow,
-        has_fee_update,
-    )?;
+    } = ctx.load_maps(clock.slot, None)?;
 
     let user = ctx.accounts.drift_user.load()?;
 
     let vault_equity_before =
         vault.calculate_equity(&user, &perp_market_map, &spot_market_map, &mut oracle_map)?;
 
+    let old_config = vault.get_config(None);
+
     let previous_borrow_value = vault.manager_borrowed_value;
     vault.manager_borrowed_value = new_borrow_value;
 
@@ -78,6 +71,15 @@ pub fn manager_update_borrow<'c: 'info, 'info>(
         vault_equity_after,
     });
 
+    emit!(VaultConfigRecord {
+        ts: now,
+        action: VaultConfigAction::UpdateBorrow,
+        vault: vault.pubkey,
+        authority: ctx.accounts.manager.key(),
+        old_config,
+        new_config: vault.get_config(None),
+    });
+
     Ok(())
 }
 
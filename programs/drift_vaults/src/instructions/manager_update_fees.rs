@@ -1,6 +1,8 @@
 use crate::constants::ONE_WEEK;
 use crate::constraints::{is_admin, is_manager_for_vault};
-use crate::state::events::{FeeUpdateAction, FeeUpdateRecord};
+use crate::state::events::{
+    FeeUpdateAction, FeeUpdateRecord, VaultConfig, VaultConfigAction, VaultConfigRecord,
+};
 use crate::state::{FeeUpdate, FeeUpdateStatus};
 use crate::{error::ErrorCode, validate, Vault};
 use anchor_lang::prelude::*;
@@ -31,7 +33,7 @@ pub fn manager_update_fees<'info>(
             ErrorCode::InvalidFeeUpdateStatus,
             "Vault has pending fee status but FeeUpdate is not in a pending state"
         )?;
-        fee_update.try_update_vault_fees(now, &mut vault)?;
+        fee_update.try_update_vault_fees(now, &mut vault, ctx.accounts.manager.key())?;
     } else {
         validate!(
             params.timelock_duration > 0,
@@ -52,6 +54,11 @@ pub fn manager_update_fees<'info>(
         let old_profit_share = vault.profit_share;
         let old_hurdle_rate = vault.hurdle_rate;
 
+        let old_config = VaultConfig {
+            fee_update: Some(fee_update.get_config()),
+            ..vault.get_config(None)
+        };
+
         fee_update.incoming_update_ts = timelock_end_ts;
         fee_update.incoming_management_fee =
             params.new_management_fee.unwrap_or(old_management_fee);
@@ -72,6 +79,18 @@ pub fn manager_update_fees<'info>(
             new_profit_share: fee_update.incoming_profit_share,
             new_hurdle_rate: fee_update.incoming_hurdle_rate,
         });
+
+        emit!(VaultConfigRecord {
+            ts: now,
+            action: VaultConfigAction::UpdateFees,
+            vault: vault.pubkey,
+            authority: ctx.accounts.manager.key(),
+            old_config,
+            new_config: VaultConfig {
+                fee_update: Some(fee_update.get_config()),
+                ..vault.get_config(None)
+            },
+        });
     }
 
     Ok(())
@@ -0,0 +1,110 @@
+use anchor_lang::prelude::*;
+use drift::math::safe_math::SafeMath;
+
+use crate::constants::{FUEL_DISTRIBUTION_PRECISION, ONE_WEEK};
+use crate::constraints::is_manager_for_vault;
+use crate::state::events::{
+    FuelDistributionUpdateAction, FuelDistributionUpdateRecord, VaultConfig, VaultConfigAction,
+    VaultConfigRecord,
+};
+use crate::state::FuelDistribution;
+use crate::{error::ErrorCode, validate, Vault};
+
+/// Queues new fuel weights for the vault. The weights are applied by `update_fuel_distribution` once
+/// the timelock has passed, which also switches the vault to `FuelDistributionMode::Weighted`.
+pub fn manager_update_fuel_distribution<'info>(
+    ctx: Context<'_, '_, '_, 'info, ManagerUpdateFuelDistribution<'info>>,
+    params: ManagerUpdateFuelDistributionParams,
+) -> Result<()> {
+    let vault = ctx.accounts.vault.load()?;
+    let mut fuel_distribution = ctx.accounts.fuel_distribution.load_mut()?;
+
+    validate!(
+        !fuel_distribution.is_pending(),
+        ErrorCode::InvalidVaultUpdate,
+        "Fuel distribution update already pending"
+    )?;
+
+    // users always keep a share of the fuel
+    validate!(
+        params
+            .manager_fuel_bps
+            .safe_add(params.protocol_fuel_bps)?
+            .lt(&FUEL_DISTRIBUTION_PRECISION),
+        ErrorCode::InvalidFuelDistributionMode,
+        "manager_fuel_bps + protocol_fuel_bps must be < {}",
+        FUEL_DISTRIBUTION_PRECISION
+    )?;
+
+    validate!(
+        params.protocol_fuel_bps == 0 || vault.vault_protocol,
+        ErrorCode::VaultProtocolMissing,
+        "Vault must have a VaultProtocol to allocate fuel to the protocol"
+    )?;
+
+    let now = Clock::get()?.unix_timestamp;
+    let min_queue_period = vault.redeem_period.safe_mul(2)?.max(ONE_WEEK);
+    validate!(
+        params.timelock_duration >= min_queue_period,
+        ErrorCode::InvalidVaultUpdate,
+        "Fuel distribution updates must be queued for at least max(1 week, 2 redeem periods)"
+    )?;
+
+    let timelock_end_ts = now.safe_add(params.timelock_duration)?;
+
+    let old_config = VaultConfig {
+        fuel_distribution: Some(fuel_distribution.get_config()),
+        ..vault.get_config(None)
+    };
+
+    fuel_distribution.incoming_update_ts = timelock_end_ts;
+    fuel_distribution.incoming_manager_fuel_bps = params.manager_fuel_bps;
+    fuel_distribution.incoming_protocol_fuel_bps = params.protocol_fuel_bps;
+
+    emit!(FuelDistributionUpdateRecord {
+        ts: now,
+        action: FuelDistributionUpdateAction::Pending,
+        timelock_end_ts,
+        vault: vault.pubkey,
+        old_manager_fuel_bps: vault.manager_fuel_bps,
+        old_protocol_fuel_bps: vault.protocol_fuel_bps,
+        new_manager_fuel_bps: params.manager_fuel_bps,
+        new_protocol_fuel_bps: params.protocol_fuel_bps,
+    });
+
+    emit!(VaultConfigRecord {
+        ts: now,
+        action: VaultConfigAction::UpdateFuelDistribution,
+        vault: vault.pubkey,
+        authority: ctx.accounts.manager.key(),
+        old_config,
+        new_config: VaultConfig {
+            fuel_distribution: Some(fuel_distribution.get_config()),
+            ..vault.get_config(None)
+        },
+    });
+
+    Ok(())
+}
+
+#[derive(Debug, Clone, Copy, AnchorSerialize, AnchorDeserialize, PartialEq, Eq)]
+pub struct ManagerUpdateFuelDistributionParams {
+    pub timelock_duration: i64,
+    pub manager_fuel_bps: u16,
+    pub protocol_fuel_bps: u16,
+}
+
+#[derive(Accounts)]
+pub struct ManagerUpdateFuelDistribution<'info> {
+    #[account(
+        constraint = is_manager_for_vault(&vault, &manager)?,
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+    pub manager: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"fuel_distribution".as_ref(), vault.key().as_ref()],
+        bump,
+    )]
+    pub fuel_distribution: AccountLoader<'info, FuelDistribution>,
+}
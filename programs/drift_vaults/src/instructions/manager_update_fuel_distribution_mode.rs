@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
 
 use crate::constraints::is_manager_for_vault;
+use crate::state::events::{VaultConfigAction, VaultConfigRecord};
 use crate::Vault;
 
 pub fn manager_update_fuel_distribution_mode<'c: 'info, 'info>(
@@ -8,8 +9,18 @@ pub fn manager_update_fuel_distribution_mode<'c: 'info, 'info>(
     fuel_distribution_mode: u8,
 ) -> Result<()> {
     let mut vault = ctx.accounts.vault.load_mut()?;
+    let old_config = vault.get_config(None);
 
-    vault.update_fuel_distribution_mode(fuel_distribution_mode);
+    vault.update_fuel_distribution_mode(fuel_distribution_mode)?;
+
+    emit!(VaultConfigRecord {
+        ts: Clock::get()?.unix_timestamp,
+        action: VaultConfigAction::UpdateFuelDistributionMode,
+        vault: vault.pubkey,
+        authority: ctx.accounts.manager.key(),
+        old_config,
+        new_config: vault.get_config(None),
+    });
 
     Ok(())
 }
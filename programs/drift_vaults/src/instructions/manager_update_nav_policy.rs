@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+
+use crate::constraints::is_manager_for_vault;
+use crate::state::events::{VaultConfig, VaultConfigAction, VaultConfigRecord};
+use crate::state::{NavPolicy, NavPolicyParams, Vault};
+use crate::{error::ErrorCode, validate};
+
+pub fn manager_update_nav_policy<'info>(
+    ctx: Context<'_, '_, '_, 'info, ManagerUpdateNavPolicy<'info>>,
+    params: NavPolicyParams,
+) -> Result<()> {
+    let vault = ctx.accounts.vault.load()?;
+    let mut nav_policy = ctx.accounts.nav_policy.load_mut()?;
+
+    validate!(!vault.in_liquidation(), ErrorCode::OngoingLiquidation)?;
+
+    let old_config = VaultConfig {
+        nav_policy: Some(nav_policy.get_config()),
+        ..vault.get_config(None)
+    };
+
+    let now = Clock::get()?.unix_timestamp;
+    nav_policy.update(params, &vault, now)?;
+
+    emit!(VaultConfigRecord {
+        ts: now,
+        action: VaultConfigAction::UpdateNavPolicy,
+        vault: vault.pubkey,
+        authority: ctx.accounts.manager.key(),
+        old_config,
+        new_config: VaultConfig {
+            nav_policy: Some(nav_policy.get_config()),
+            ..vault.get_config(None)
+        },
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ManagerUpdateNavPolicy<'info> {
+    #[account(
+        constraint = is_manager_for_vault(&vault, &manager)?,
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+    pub manager: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"nav_policy".as_ref(), vault.key().as_ref()],
+        bump,
+    )]
+    pub nav_policy: AccountLoader<'info, NavPolicy>,
+}
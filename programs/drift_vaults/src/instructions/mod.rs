@@ -5,42 +5,77 @@ pub use admin_update_vault_class::*;
 pub use apply_profit_share::*;
 pub use apply_rebase::*;
 pub use apply_rebase_tokenized_depositor::*;
+pub use cancel_redeem_request::*;
 pub use cancel_request_remove_insurance_fund_stake::*;
 pub use cancel_withdraw_request::*;
+pub use check_max_drawdown::*;
+pub use claim_reward_stream::*;
+pub use close_reward_stream::*;
+pub use close_reward_stream_depositor::*;
 pub use deposit::*;
+pub use deposit_and_mint::*;
+pub use depositor_reenable_trading::*;
 pub use force_withdraw::*;
+pub use fund_reward_stream::*;
+pub use initialize_fuel_distribution::*;
 pub use initialize_insurance_fund_stake::*;
+pub use initialize_manager_stats::*;
+pub use initialize_nav_history::*;
+pub use initialize_nav_policy::*;
+pub use initialize_reward_stream::*;
+pub use initialize_reward_stream_depositor::*;
 pub use initialize_tokenized_vault_depositor::*;
+pub use initialize_tokenized_vault_depositor_token_2022::*;
 pub use initialize_vault::*;
 pub use initialize_vault_depositor::*;
 pub use initialize_vault_with_protocol::*;
 pub use liquidate::*;
+pub use lock_vault_depositor::*;
 pub use manager_borrow::*;
 pub use manager_cancel_fee_update::*;
 pub use manager_cancel_withdraw_request::*;
 pub use manager_deposit::*;
+pub use manager_reenable_trading::*;
 pub use manager_repay::*;
 pub use manager_request_withdraw::*;
 pub use manager_update_borrow::*;
 pub use manager_update_fees::*;
+pub use manager_update_fuel_distribution::*;
 pub use manager_update_fuel_distribution_mode::*;
+pub use manager_update_nav_policy::*;
 pub use manager_withdraw::*;
+pub use preview_deposit::*;
+pub use preview_depositor_equity::*;
+pub use preview_fees::*;
+pub use preview_withdraw::*;
 pub use protocol_cancel_withdraw_request::*;
 pub use protocol_request_withdraw::*;
 pub use protocol_withdraw::*;
 pub use redeem_tokens::*;
+pub use redeem_tokens_for_underlying::*;
+pub use redeem_tokens_v2::*;
+pub use remove_expired_fuel_boost::*;
 pub use remove_insurance_fund_stake::*;
+pub use request_redeem::*;
 pub use request_remove_insurance_fund_stake::*;
 pub use request_withdraw::*;
 pub use reset_delegate::*;
 pub use reset_fuel_season::*;
+pub use reset_nav_circuit_breaker::*;
 pub use reset_vault_fuel_season::*;
+pub use settle_fuel::*;
+pub use snapshot_vault::*;
 pub use tokenize_shares::*;
+pub use transfer_hook::*;
 pub use transfer_vault_depositor_shares::*;
+pub use trip_nav_breaker::*;
 pub use update_cumulative_fuel_amount::*;
 pub use update_delegate::*;
+pub use update_fuel_distribution::*;
+pub use update_manager_stats::*;
 pub use update_margin_trading_enabled::*;
 pub use update_pool_id::*;
+pub use update_tokenized_vault_depositor_metadata::*;
 pub use update_vault::*;
 pub use update_vault_manager::*;
 pub use update_vault_protocol::*;
@@ -53,43 +88,78 @@ mod admin_update_vault_class;
 mod apply_profit_share;
 mod apply_rebase;
 mod apply_rebase_tokenized_depositor;
+mod cancel_redeem_request;
 mod cancel_request_remove_insurance_fund_stake;
 mod cancel_withdraw_request;
+mod check_max_drawdown;
+mod claim_reward_stream;
+mod close_reward_stream;
+mod close_reward_stream_depositor;
 pub mod constraints;
 mod deposit;
+mod deposit_and_mint;
+mod depositor_reenable_trading;
 mod force_withdraw;
+mod fund_reward_stream;
+mod initialize_fuel_distribution;
 mod initialize_insurance_fund_stake;
+mod initialize_manager_stats;
+mod initialize_nav_history;
+mod initialize_nav_policy;
+mod initialize_reward_stream;
+mod initialize_reward_stream_depositor;
 mod initialize_tokenized_vault_depositor;
+mod initialize_tokenized_vault_depositor_token_2022;
 mod initialize_vault;
 mod initialize_vault_depositor;
 mod initialize_vault_with_protocol;
 mod liquidate;
+mod lock_vault_depositor;
 mod manager_borrow;
 mod manager_cancel_fee_update;
 mod manager_cancel_withdraw_request;
 mod manager_deposit;
+mod manager_reenable_trading;
 mod manager_repay;
 mod manager_request_withdraw;
 mod manager_update_borrow;
 mod manager_update_fees;
+mod manager_update_fuel_distribution;
 mod manager_update_fuel_distribution_mode;
+mod manager_update_nav_policy;
 mod manager_withdraw;
+mod preview_deposit;
+mod preview_depositor_equity;
+mod preview_fees;
+mod preview_withdraw;
 mod protocol_cancel_withdraw_request;
 mod protocol_request_withdraw;
 mod protocol_withdraw;
 mod redeem_tokens;
+mod redeem_tokens_for_underlying;
+mod redeem_tokens_v2;
+mod remove_expired_fuel_boost;
 mod remove_insurance_fund_stake;
+mod request_redeem;
 mod request_remove_insurance_fund_stake;
 mod request_withdraw;
 mod reset_delegate;
 mod reset_fuel_season;
+mod reset_nav_circuit_breaker;
 mod reset_vault_fuel_season;
+mod settle_fuel;
+mod snapshot_vault;
 mod tokenize_shares;
+mod transfer_hook;
 mod transfer_vault_depositor_shares;
+mod trip_nav_breaker;
 mod update_cumulative_fuel_amount;
 mod update_delegate;
+mod update_fuel_distribution;
+mod update_manager_stats;
 mod update_margin_trading_enabled;
 mod update_pool_id;
+mod update_tokenized_vault_depositor_metadata;
 mod update_vault;
 mod update_vault_manager;
 pub mod update_vault_protocol;
@@ -0,0 +1,77 @@
+use anchor_lang::prelude::*;
+use drift::instructions::optional_accounts::AccountMaps;
+use drift::state::user::User;
+
+use crate::constraints::{is_user_for_vault, is_vault_for_vault_depositor};
+use crate::state::account_maps::AccountMapProvider;
+use crate::state::{
+    DepositPreview, NavSide, Vault, VaultDepositor, VaultPreview, VaultRemainingAccountsProvider,
+};
+
+/// Read-only. Returns what a deposit of amount would mint through return data, running the deposit on
+/// copies of the vault state. vault_depositor can be omitted to preview a new depositor's first deposit.
+pub fn preview_deposit<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, PreviewDeposit<'info>>,
+    amount: u64,
+) -> Result<DepositPreview> {
+    let clock = &Clock::get()?;
+    let vault = ctx.accounts.vault.load()?;
+    let user = ctx.accounts.drift_user.load()?;
+
+    let vault_depositor = match &ctx.accounts.vault_depositor {
+        Some(vault_depositor) => *vault_depositor.load()?,
+        None => VaultDepositor::new(
+            vault.pubkey,
+            Pubkey::default(),
+            Pubkey::default(),
+            clock.unix_timestamp,
+        ),
+    };
+
+    let remaining_accounts = ctx.vault_remaining_accounts(&vault)?;
+    let vp = remaining_accounts.vault_protocol;
+    vault.validate_vault_protocol(&vp)?;
+
+    let fee_update = remaining_accounts.fee_update;
+    vault.validate_fee_update(&fee_update)?;
+
+    let nav_policy = remaining_accounts.nav_policy;
+    vault.validate_nav_policy(&nav_policy)?;
+
+    let AccountMaps {
+        perp_market_map,
+        spot_market_map,
+        mut oracle_map,
+    } = ctx.load_maps(clock.slot, None)?;
+
+    let vault_equity = vault.calculate_swing_equity(
+        &nav_policy,
+        NavSide::Deposit,
+        &user,
+        &perp_market_map,
+        &spot_market_map,
+        &mut oracle_map,
+    )?;
+
+    let deposit_amount = if vault.max_tokens > 0 {
+        amount.min(vault.max_tokens.saturating_sub(vault_equity))
+    } else {
+        amount
+    };
+
+    VaultPreview::new(&vault, &vp, &fee_update, vault_equity, clock.unix_timestamp)?
+        .preview_deposit(vault_depositor, deposit_amount)
+}
+
+#[derive(Accounts)]
+pub struct PreviewDeposit<'info> {
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        constraint = is_vault_for_vault_depositor(&vault_depositor, &vault)?,
+    )]
+    pub vault_depositor: Option<AccountLoader<'info, VaultDepositor>>,
+    #[account(
+        constraint = is_user_for_vault(&vault, &drift_user.key())?
+    )]
+    pub drift_user: AccountLoader<'info, User>,
+}
@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+use drift::instructions::optional_accounts::AccountMaps;
+use drift::state::user::User;
+
+use crate::constraints::{is_user_for_vault, is_vault_for_vault_depositor};
+use crate::state::account_maps::AccountMapProvider;
+use crate::state::{
+    DepositorEquityPreview, NavSide, Vault, VaultDepositor, VaultPreview,
+    VaultRemainingAccountsProvider,
+};
+
+/// Read-only. Returns the depositor's shares and their value after fees and profit share through return
+/// data. Shares are valued at the price withdrawals are.
+pub fn preview_depositor_equity<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, PreviewDepositorEquity<'info>>,
+) -> Result<DepositorEquityPreview> {
+    let clock = &Clock::get()?;
+    let vault = ctx.accounts.vault.load()?;
+    let vault_depositor = *ctx.accounts.vault_depositor.load()?;
+    let user = ctx.accounts.drift_user.load()?;
+
+    let remaining_accounts = ctx.vault_remaining_accounts(&vault)?;
+    let vp = remaining_accounts.vault_protocol;
+    vault.validate_vault_protocol(&vp)?;
+
+    let fee_update = remaining_accounts.fee_update;
+    vault.validate_fee_update(&fee_update)?;
+
+    let nav_policy = remaining_accounts.nav_policy;
+    vault.validate_nav_policy(&nav_policy)?;
+
+    let AccountMaps {
+        perp_market_map,
+        spot_market_map,
+        mut oracle_map,
+    } = ctx.load_maps(clock.slot, None)?;
+
+    let vault_equity = vault.calculate_swing_equity(
+        &nav_policy,
+        NavSide::Withdraw,
+        &user,
+        &perp_market_map,
+        &spot_market_map,
+        &mut oracle_map,
+    )?;
+
+    VaultPreview::new(&vault, &vp, &fee_update, vault_equity, clock.unix_timestamp)?
+        .preview_depositor_equity(vault_depositor)
+}
+
+#[derive(Accounts)]
+pub struct PreviewDepositorEquity<'info> {
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        constraint = is_vault_for_vault_depositor(&vault_depositor, &vault)?,
+    )]
+    pub vault_depositor: AccountLoader<'info, VaultDepositor>,
+    #[account(
+        constraint = is_user_for_vault(&vault, &drift_user.key())?
+    )]
+    pub drift_user: AccountLoader<'info, User>,
+}
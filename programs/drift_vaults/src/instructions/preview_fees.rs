@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+use drift::instructions::optional_accounts::AccountMaps;
+use drift::state::user::User;
+
+use crate::constraints::is_user_for_vault;
+use crate::state::account_maps::AccountMapProvider;
+use crate::state::{FeesPreview, Vault, VaultPreview, VaultRemainingAccountsProvider};
+
+/// Read-only. Returns the vault's current fees and the management and protocol fees accrued since they were
+/// last charged through return data.
+pub fn preview_fees<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, PreviewFees<'info>>,
+) -> Result<FeesPreview> {
+    let clock = &Clock::get()?;
+    let vault = ctx.accounts.vault.load()?;
+    let user = ctx.accounts.drift_user.load()?;
+
+    let remaining_accounts = ctx.vault_remaining_accounts(&vault)?;
+    let vp = remaining_accounts.vault_protocol;
+    vault.validate_vault_protocol(&vp)?;
+
+    let fee_update = remaining_accounts.fee_update;
+    vault.validate_fee_update(&fee_update)?;
+
+    let AccountMaps {
+        perp_market_map,
+        spot_market_map,
+        mut oracle_map,
+    } = ctx.load_maps(clock.slot, None)?;
+
+    let vault_equity =
+        vault.calculate_equity(&user, &perp_market_map, &spot_market_map, &mut oracle_map)?;
+
+    VaultPreview::new(&vault, &vp, &fee_update, vault_equity, clock.unix_timestamp)?.preview_fees()
+}
+
+#[derive(Accounts)]
+pub struct PreviewFees<'info> {
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        constraint = is_user_for_vault(&vault, &drift_user.key())?
+    )]
+    pub drift_user: AccountLoader<'info, User>,
+}
@@ -5,7 +5,9 @@ use drift::state::user::User;
 use crate::constraints::{
     is_protocol_for_vault, is_user_for_vault, is_user_stats_for_vault, is_vault_protocol_for_vault,
 };
-use crate::{AccountMapProvider, Vault, VaultProtocol, WithdrawUnit};
+use crate::{
+    AccountMapProvider, NavSide, Vault, VaultProtocol, VaultRemainingAccountsProvider, WithdrawUnit,
+};
 
 pub fn protocol_request_withdraw<'c: 'info, 'info>(
     ctx: Context<'_, '_, 'c, 'info, ProtocolRequestWithdraw<'info>>,
@@ -22,20 +24,35 @@ pub fn protocol_request_withdraw<'c: 'info, 'info>(
     let user = ctx.accounts.drift_user.load()?;
     let spot_market_index = vault.spot_market_index;
 
+    let nav_policy = ctx.vault_remaining_accounts(&vault)?.nav_policy;
+    vault.validate_nav_policy(&nav_policy)?;
+
     let AccountMaps {
         perp_market_map,
         spot_market_map,
         mut oracle_map,
-    } = ctx.load_maps(
-        clock.slot,
-        Some(spot_market_index),
-        vp.is_some(),
-        false,
-        false,
+    } = ctx.load_maps(clock.slot, Some(spot_market_index))?;
+
+    let (vault_equity, _) = vault.calculate_nav_policy_equity(
+        &nav_policy,
+        NavSide::Withdraw,
+        &user,
+        &perp_market_map,
+        &spot_market_map,
+        &mut oracle_map,
     )?;
 
-    let vault_equity =
-        vault.calculate_equity(&user, &perp_market_map, &spot_market_map, &mut oracle_map)?;
+    vault.check_nav_breaker(&nav_policy, vault_equity, clock.unix_timestamp)?;
+
+    let vault_equity = vault.apply_swing_pricing(
+        &nav_policy,
+        NavSide::Withdraw,
+        vault_equity,
+        &user,
+        &perp_market_map,
+        &spot_market_map,
+        &mut oracle_map,
+    )?;
 
     let spot_market = spot_market_map.get_ref(&spot_market_index)?;
     let oracle = oracle_map.get_price_data(&spot_market.oracle_id())?;
@@ -10,7 +10,7 @@ use crate::constraints::{
     is_protocol_for_vault, is_user_for_vault, is_user_stats_for_vault, is_vault_protocol_for_vault,
 };
 use crate::drift_cpi::WithdrawCPI;
-use crate::state::{Vault, VaultProtocol};
+use crate::state::{NavSide, Vault, VaultProtocol, VaultRemainingAccountsProvider};
 use crate::token_cpi::TokenTransferCPI;
 use crate::{declare_vault_seeds, AccountMapProvider};
 
@@ -26,21 +26,24 @@ pub fn protocol_withdraw<'c: 'info, 'info>(
 
     let mut vp = Some(ctx.accounts.vault_protocol.load_mut()?);
 
+    let nav_policy = ctx.vault_remaining_accounts(&vault)?.nav_policy;
+    vault.validate_nav_policy(&nav_policy)?;
+
     let AccountMaps {
         perp_market_map,
         spot_market_map,
         mut oracle_map,
-    } = ctx.load_maps(
-        clock.slot,
-        Some(spot_market_index),
-        vp.is_some(),
-        false,
-        false,
+    } = ctx.load_maps(clock.slot, Some(spot_market_index))?;
+
+    let vault_equity = vault.calculate_swing_equity(
+        &nav_policy,
+        NavSide::Withdraw,
+        &user,
+        &perp_market_map,
+        &spot_market_map,
+        &mut oracle_map,
     )?;
 
-    let vault_equity =
-        vault.calculate_equity(&user, &perp_market_map, &spot_market_map, &mut oracle_map)?;
-
     let spot_market = spot_market_map.get_ref(&spot_market_index)?;
     let oracle = oracle_map.get_price_data(&spot_market.oracle_id())?;
 
@@ -3,16 +3,23 @@ use crate::constraints::{
     is_tokenized_depositor_for_vault, is_user_for_vault,
 };
 use crate::error::ErrorCode;
+use crate::state::settle_reward_streams;
 use crate::state::traits::VaultDepositorBase;
-use crate::token_cpi::{BurnTokensCPI, TokenTransferCPI};
+use crate::token_cpi::{BurnVaultTokensCPI, TokenTransferCPI};
 use crate::{validate, AccountMapProvider};
-use crate::{TokenizedVaultDepositor, Vault, VaultDepositor, VaultProtocolProvider, WithdrawUnit};
+use crate::{
+    TokenizedVaultDepositor, Vault, VaultDepositor, VaultRemainingAccountsProvider, WithdrawUnit,
+};
 use anchor_lang::prelude::*;
 use anchor_spl::token::{burn, transfer, Burn, Mint, Token, TokenAccount, Transfer};
 use drift::instructions::optional_accounts::AccountMaps;
 use drift::math::safe_math::SafeMath;
 use drift::state::user::User;
 
+/// Moves the holder's share tokens to the vault's token account, burns them and moves their shares
+/// back to the holder's [`VaultDepositor`]. Kept with its original accounts for existing integrations,
+/// it only supports SPL Token share mints and, without the vault's user stats, doesn't accrue fuel
+/// before the shares move. Prefer `redeem_tokens_v2`.
 pub fn redeem_tokens<'info>(
     ctx: Context<'_, '_, 'info, 'info, RedeemTokens<'info>>,
     tokens_to_burn: u64,
@@ -26,11 +33,15 @@ pub fn redeem_tokens<'info>(
     let mut vault_depositor = ctx.accounts.vault_depositor.load_mut()?;
     let mut tokenized_vault_depositor = ctx.accounts.tokenized_vault_depositor.load_mut()?;
 
-    // backwards compatible: if last rem acct does not deserialize into [`VaultProtocol`] then it's a legacy vault.
-    let mut vp = ctx.vault_protocol();
+    let remaining_accounts = ctx.vault_remaining_accounts(&vault)?;
+    // backwards compatible: vaults without a [`VaultProtocol`] are legacy vaults.
+    let mut vp = remaining_accounts.vault_protocol;
     vault.validate_vault_protocol(&vp)?;
     let mut vp = vp.as_mut().map(|vp| vp.load_mut()).transpose()?;
 
+    let mut fee_update = remaining_accounts.fee_update;
+    vault.validate_fee_update(&fee_update)?;
+
     let manager_shares_before = vault.get_manager_shares(&mut vp)?;
     let total_shares_before = vault_depositor
         .get_vault_shares()
@@ -43,13 +54,7 @@ pub fn redeem_tokens<'info>(
         perp_market_map,
         spot_market_map,
         mut oracle_map,
-    } = ctx.load_maps(
-        clock.slot,
-        Some(spot_market_index),
-        vp.is_some(),
-        false,
-        false,
-    )?;
+    } = ctx.load_maps(clock.slot, Some(spot_market_index))?;
 
     let vault_equity =
         vault.calculate_equity(&user, &perp_market_map, &spot_market_map, &mut oracle_map)?;
@@ -66,7 +71,7 @@ pub fn redeem_tokens<'info>(
     let (shares_to_transfer, mut vp) = tokenized_vault_depositor.redeem_tokens(
         &mut vault,
         &mut vp,
-        &mut None,
+        &mut fee_update,
         total_supply_before,
         vault_equity,
         tokens_to_burn,
@@ -77,7 +82,7 @@ pub fn redeem_tokens<'info>(
         &mut *vault_depositor,
         &mut vault,
         &mut vp,
-        &mut None,
+        &mut fee_update,
         shares_to_transfer,
         WithdrawUnit::Shares,
         vault_equity,
@@ -85,6 +90,14 @@ pub fn redeem_tokens<'info>(
         oracle.price,
     )?;
 
+    settle_reward_streams(
+        &remaining_accounts.reward_streams,
+        &remaining_accounts.reward_stream_depositors,
+        &vault_depositor,
+        &vault,
+        clock.unix_timestamp,
+    )?;
+
     let manager_shares_after = vault.get_manager_shares(&mut vp)?;
     let total_shares_after = vault_depositor
         .get_vault_shares()
@@ -111,7 +124,7 @@ pub fn redeem_tokens<'info>(
     drop(tokenized_vault_depositor);
 
     ctx.token_transfer(tokens_to_burn)?;
-    ctx.burn(vault_name, vault_bump, tokens_to_burn)?;
+    ctx.burn_vault_tokens(vault_name, vault_bump, tokens_to_burn)?;
 
     msg!(
         "Burned {} tokens from {}",
@@ -200,8 +213,8 @@ impl<'info> TokenTransferCPI for Context<'_, '_, '_, 'info, RedeemTokens<'info>>
     }
 }
 
-impl<'info> BurnTokensCPI for Context<'_, '_, '_, 'info, RedeemTokens<'info>> {
-    fn burn(&self, vault_name: [u8; 32], vault_bump: u8, amount: u64) -> Result<()> {
+impl<'info> BurnVaultTokensCPI for Context<'_, '_, '_, 'info, RedeemTokens<'info>> {
+    fn burn_vault_tokens(&self, vault_name: [u8; 32], vault_bump: u8, amount: u64) -> Result<()> {
         let signature_seeds = Vault::get_vault_signer_seeds(&vault_name, &vault_bump);
         let signers = &[&signature_seeds[..]];
 
@@ -0,0 +1,178 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Transfer};
+use anchor_spl::token::{Token, TokenAccount};
+use drift::cpi::accounts::Withdraw as DriftWithdraw;
+use drift::instructions::optional_accounts::AccountMaps;
+use drift::program::Drift;
+use drift::state::user::{User, UserStats};
+
+use crate::constraints::{
+    is_tokenized_depositor_for_vault, is_user_for_vault, is_user_stats_for_vault,
+};
+use crate::drift_cpi::WithdrawCPI;
+use crate::error::ErrorCode;
+use crate::state::{
+    NavSide, TokenizedRedeemRequest, TokenizedVaultDepositor, Vault, VaultRemainingAccountsProvider,
+};
+use crate::token_cpi::TokenTransferCPI;
+use crate::{declare_vault_seeds, implement_withdraw, validate, AccountMapProvider};
+
+pub fn redeem_tokens_for_underlying<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, RedeemTokensForUnderlying<'info>>,
+    _request_id: u16,
+) -> Result<()> {
+    let clock = &Clock::get()?;
+    let mut vault = ctx.accounts.vault.load_mut()?;
+
+    validate!(!vault.in_liquidation(), ErrorCode::OngoingLiquidation)?;
+
+    let mut tokenized_vault_depositor = ctx.accounts.tokenized_vault_depositor.load_mut()?;
+    let mut redeem_request = ctx.accounts.redeem_request.load_mut()?;
+
+    let remaining_accounts = ctx.vault_remaining_accounts(&vault)?;
+    // backwards compatible: vaults without a [`VaultProtocol`] are legacy vaults.
+    let mut vp = remaining_accounts.vault_protocol;
+    vault.validate_vault_protocol(&vp)?;
+    let mut vp = vp.as_mut().map(|vp| vp.load_mut()).transpose()?;
+
+    let user = ctx.accounts.drift_user.load()?;
+    let user_stats = ctx.accounts.drift_user_stats.load()?;
+    let fuel_overflow = remaining_accounts.fuel_overflow;
+    user_stats.validate_fuel_overflow(&fuel_overflow)?;
+    let spot_market_index = vault.spot_market_index;
+
+    let mut fee_update = remaining_accounts.fee_update;
+    vault.validate_fee_update(&fee_update)?;
+
+    let nav_policy = remaining_accounts.nav_policy;
+    vault.validate_nav_policy(&nav_policy)?;
+
+    let AccountMaps {
+        perp_market_map,
+        spot_market_map,
+        mut oracle_map,
+    } = ctx.load_maps(clock.slot, Some(spot_market_index))?;
+
+    let vault_equity = vault.calculate_swing_equity(
+        &nav_policy,
+        NavSide::Withdraw,
+        &user,
+        &perp_market_map,
+        &spot_market_map,
+        &mut oracle_map,
+    )?;
+
+    // distribute the fuel accrued so far before the request's shares leave the vault
+    vault.update_cumulative_fuel_per_share(clock.unix_timestamp, &user_stats, &fuel_overflow)?;
+
+    let spot_market = spot_market_map.get_ref(&spot_market_index)?;
+    let oracle = oracle_map.get_price_data(&spot_market.oracle_id())?;
+
+    let user_withdraw_amount = redeem_request.redeem(
+        &mut tokenized_vault_depositor,
+        &mut vault,
+        &mut vp,
+        &mut fee_update,
+        vault_equity,
+        clock.unix_timestamp,
+        oracle.price,
+    )?;
+
+    msg!("user_withdraw_amount: {}", user_withdraw_amount);
+
+    drop(spot_market);
+    drop(vault);
+    drop(user);
+    drop(user_stats);
+    drop(vp);
+    drop(tokenized_vault_depositor);
+    drop(redeem_request);
+
+    ctx.drift_withdraw(user_withdraw_amount)?;
+
+    ctx.token_transfer(user_withdraw_amount)?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(request_id: u16)]
+pub struct RedeemTokensForUnderlying<'info> {
+    #[account(mut)]
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        mut,
+        constraint = is_tokenized_depositor_for_vault(&tokenized_vault_depositor, &vault)?,
+    )]
+    pub tokenized_vault_depositor: AccountLoader<'info, TokenizedVaultDepositor>,
+    #[account(
+        mut,
+        seeds = [b"tokenized_redeem_request", tokenized_vault_depositor.key().as_ref(), authority.key().as_ref(), request_id.to_le_bytes().as_ref()],
+        bump,
+        has_one = authority,
+        close = authority
+    )]
+    pub redeem_request: AccountLoader<'info, TokenizedRedeemRequest>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"vault_token_account".as_ref(), vault.key().as_ref()],
+        bump,
+    )]
+    pub vault_token_account: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        constraint = is_user_stats_for_vault(&vault, &drift_user_stats.key())?
+    )]
+    /// CHECK: checked in drift cpi
+    pub drift_user_stats: AccountLoader<'info, UserStats>,
+    #[account(
+        mut,
+        constraint = is_user_for_vault(&vault, &drift_user.key())?
+    )]
+    /// CHECK: checked in drift cpi
+    pub drift_user: AccountLoader<'info, User>,
+    /// CHECK: checked in drift cpi
+    pub drift_state: AccountInfo<'info>,
+    #[account(
+        mut,
+        token::mint = vault_token_account.mint
+    )]
+    pub drift_spot_market_vault: Box<Account<'info, TokenAccount>>,
+    /// CHECK: checked in drift cpi
+    pub drift_signer: AccountInfo<'info>,
+    #[account(
+        mut,
+        token::authority = authority,
+        token::mint = vault_token_account.mint
+    )]
+    pub user_token_account: Box<Account<'info, TokenAccount>>,
+    pub drift_program: Program<'info, Drift>,
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> WithdrawCPI for Context<'_, '_, '_, 'info, RedeemTokensForUnderlying<'info>> {
+    fn drift_withdraw(&self, amount: u64) -> Result<()> {
+        implement_withdraw!(self, amount);
+        Ok(())
+    }
+}
+
+impl<'info> TokenTransferCPI for Context<'_, '_, '_, 'info, RedeemTokensForUnderlying<'info>> {
+    fn token_transfer(&self, amount: u64) -> Result<()> {
+        declare_vault_seeds!(self.accounts.vault, seeds);
+
+        let cpi_accounts = Transfer {
+            from: self.accounts.vault_token_account.to_account_info().clone(),
+            to: self.accounts.user_token_account.to_account_info().clone(),
+            authority: self.accounts.vault.to_account_info().clone(),
+        };
+        let token_program = self.accounts.token_program.to_account_info().clone();
+        let cpi_context = CpiContext::new_with_signer(token_program, cpi_accounts, seeds);
+
+        token::transfer(cpi_context, amount)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,225 @@
+use crate::constraints::{
+    is_authority_for_vault_depositor, is_mint_for_tokenized_depositor,
+    is_tokenized_depositor_for_vault, is_user_for_vault, is_user_stats_for_vault,
+};
+use crate::error::ErrorCode;
+use crate::state::settle_reward_streams;
+use crate::state::traits::VaultDepositorBase;
+use crate::token_cpi::BurnTokensCPI;
+use crate::{validate, AccountMapProvider};
+use crate::{
+    TokenizedVaultDepositor, Vault, VaultDepositor, VaultRemainingAccountsProvider, WithdrawUnit,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{burn, Burn, Mint, TokenAccount, TokenInterface};
+use drift::instructions::optional_accounts::AccountMaps;
+use drift::math::safe_math::SafeMath;
+use drift::state::user::{User, UserStats};
+
+/// Burns share tokens directly from the holder's token account and moves their shares back to the
+/// holder's [`VaultDepositor`]. Unlike `redeem_tokens` it works with Token-2022 share mints, including
+/// ones with the transfer hook, and accrues fuel before the shares move.
+pub fn redeem_tokens_v2<'info>(
+    ctx: Context<'_, '_, 'info, 'info, RedeemTokensV2<'info>>,
+    tokens_to_burn: u64,
+) -> Result<()> {
+    let clock = &Clock::get()?;
+
+    let mut vault = ctx.accounts.vault.load_mut()?;
+
+    validate!(!vault.in_liquidation(), ErrorCode::OngoingLiquidation)?;
+
+    let mut vault_depositor = ctx.accounts.vault_depositor.load_mut()?;
+    let mut tokenized_vault_depositor = ctx.accounts.tokenized_vault_depositor.load_mut()?;
+
+    let remaining_accounts = ctx.vault_remaining_accounts(&vault)?;
+    // backwards compatible: vaults without a [`VaultProtocol`] are legacy vaults.
+    let mut vp = remaining_accounts.vault_protocol;
+    vault.validate_vault_protocol(&vp)?;
+    let mut vp = vp.as_mut().map(|vp| vp.load_mut()).transpose()?;
+
+    let user_stats = ctx.accounts.drift_user_stats.load()?;
+    let fuel_overflow = remaining_accounts.fuel_overflow;
+    user_stats.validate_fuel_overflow(&fuel_overflow)?;
+
+    let mut fee_update = remaining_accounts.fee_update;
+    vault.validate_fee_update(&fee_update)?;
+
+    let manager_shares_before = vault.get_manager_shares(&mut vp)?;
+    let total_shares_before = vault_depositor
+        .get_vault_shares()
+        .safe_add(tokenized_vault_depositor.get_vault_shares())?
+        .safe_add(manager_shares_before)?;
+
+    let user = ctx.accounts.drift_user.load()?;
+    let spot_market_index = vault.spot_market_index;
+    let AccountMaps {
+        perp_market_map,
+        spot_market_map,
+        mut oracle_map,
+    } = ctx.load_maps(clock.slot, Some(spot_market_index))?;
+
+    let vault_equity =
+        vault.calculate_equity(&user, &perp_market_map, &spot_market_map, &mut oracle_map)?;
+
+    validate!(
+        !vault_depositor.last_withdraw_request.pending(),
+        ErrorCode::InvalidVaultDeposit,
+        "Cannot redeem tokens with a pending withdraw request"
+    )?;
+
+    let total_supply_before = ctx.accounts.mint.supply;
+    // accrue fuel to the depositor and the tokenized shares before shares change
+    vault_depositor.update_cumulative_fuel_amount(
+        clock.unix_timestamp,
+        &mut vault,
+        &user_stats,
+        &fuel_overflow,
+    )?;
+    tokenized_vault_depositor.update_cumulative_fuel_amount(
+        clock.unix_timestamp,
+        &mut vault,
+        &user_stats,
+        &fuel_overflow,
+    )?;
+    let spot_market = spot_market_map.get_ref(&spot_market_index)?;
+    let oracle = oracle_map.get_price_data(&spot_market.oracle_id())?;
+    let (shares_to_transfer, mut vp) = tokenized_vault_depositor.redeem_tokens(
+        &mut vault,
+        &mut vp,
+        &mut fee_update,
+        total_supply_before,
+        vault_equity,
+        tokens_to_burn,
+        clock.unix_timestamp,
+        oracle.price,
+    )?;
+    let (shares_transferred, _) = tokenized_vault_depositor.transfer_shares(
+        &mut *vault_depositor,
+        &mut vault,
+        &mut vp,
+        &mut fee_update,
+        shares_to_transfer,
+        WithdrawUnit::Shares,
+        vault_equity,
+        clock.unix_timestamp,
+        oracle.price,
+    )?;
+
+    settle_reward_streams(
+        &remaining_accounts.reward_streams,
+        &remaining_accounts.reward_stream_depositors,
+        &vault_depositor,
+        &vault,
+        clock.unix_timestamp,
+    )?;
+
+    let manager_shares_after = vault.get_manager_shares(&mut vp)?;
+    let total_shares_after = vault_depositor
+        .get_vault_shares()
+        .safe_add(tokenized_vault_depositor.get_vault_shares())?
+        .safe_add(manager_shares_after)?;
+
+    validate!(
+        total_shares_after.eq(&total_shares_before),
+        ErrorCode::InvalidVaultSharesDetected,
+        "Total vault depositor shares before != after"
+    )?;
+
+    validate!(
+        shares_transferred == shares_to_transfer.into(),
+        ErrorCode::InvalidVaultSharesDetected
+    )?;
+
+    drop(spot_market);
+    drop(vault);
+    drop(vault_depositor);
+    drop(tokenized_vault_depositor);
+    drop(user_stats);
+
+    ctx.burn(tokens_to_burn)?;
+
+    msg!(
+        "Burned {} tokens from {}",
+        tokens_to_burn,
+        ctx.accounts.user_token_account.key()
+    );
+
+    ctx.accounts.mint.reload()?;
+    let total_supply_after = ctx.accounts.mint.supply;
+
+    validate!(
+        total_supply_after < total_supply_before,
+        ErrorCode::InvalidTokenization,
+        "Total supply after > total supply before"
+    )?;
+
+    let supply_delta = total_supply_before.safe_sub(total_supply_after)?;
+    validate!(
+        supply_delta.eq(&tokens_to_burn),
+        ErrorCode::InvalidTokenization,
+        "Tokens burned ({}) != supply delta ({})",
+        tokens_to_burn,
+        supply_delta
+    )?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RedeemTokensV2<'info> {
+    #[account(mut)]
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        mut,
+        seeds = [b"vault_depositor", vault.key().as_ref(), authority.key().as_ref()],
+        bump,
+        constraint = is_authority_for_vault_depositor(&vault_depositor, &authority)?,
+    )]
+    pub vault_depositor: AccountLoader<'info, VaultDepositor>,
+    pub authority: Signer<'info>,
+    #[account(
+		mut,
+		constraint = is_tokenized_depositor_for_vault(&tokenized_vault_depositor, &vault)?,
+	)]
+    pub tokenized_vault_depositor: AccountLoader<'info, TokenizedVaultDepositor>,
+    #[account(
+        mut,
+        mint::authority = vault.key(),
+		constraint = is_mint_for_tokenized_depositor(&mint.key(), &tokenized_vault_depositor)?,
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        mut,
+        token::authority = authority,
+        token::mint = tokenized_vault_depositor.load()?.mint
+    )]
+    pub user_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        mut,
+        constraint = is_user_for_vault(&vault, &drift_user.key())?
+    )]
+    pub drift_user: AccountLoader<'info, User>,
+    #[account(
+        constraint = is_user_stats_for_vault(&vault, &drift_user_stats.key())?
+    )]
+    pub drift_user_stats: AccountLoader<'info, UserStats>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> BurnTokensCPI for Context<'_, '_, '_, 'info, RedeemTokensV2<'info>> {
+    fn burn(&self, amount: u64) -> Result<()> {
+        let cpi_accounts = Burn {
+            mint: self.accounts.mint.to_account_info(),
+            from: self.accounts.user_token_account.to_account_info(),
+            authority: self.accounts.authority.to_account_info(),
+        };
+
+        let cpi_context =
+            CpiContext::new(self.accounts.token_program.to_account_info(), cpi_accounts);
+
+        burn(cpi_context, amount)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+use drift::state::user::UserStats;
+
+use crate::constraints::{is_user_stats_for_vault, is_vault_for_vault_depositor};
+use crate::state::{Vault, VaultRemainingAccountsProvider};
+use crate::VaultDepositor;
+
+pub fn remove_expired_fuel_boost<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, RemoveExpiredFuelBoost<'info>>,
+) -> Result<()> {
+    let clock = &Clock::get()?;
+
+    let mut vault = ctx.accounts.vault.load_mut()?;
+    let mut vault_depositor = ctx.accounts.vault_depositor.load_mut()?;
+
+    let remaining_accounts = ctx.vault_remaining_accounts(&vault)?;
+    // backwards compatible: vaults without a [`VaultProtocol`] are legacy vaults.
+    let mut vp = remaining_accounts.vault_protocol;
+    vault.validate_vault_protocol(&vp)?;
+    let vp = vp.as_mut().map(|vp| vp.load_mut()).transpose()?;
+
+    let user_stats = ctx.accounts.drift_user_stats.load()?;
+    let fuel_overflow = remaining_accounts.fuel_overflow;
+    user_stats.validate_fuel_overflow(&fuel_overflow)?;
+
+    vault_depositor.remove_expired_fuel_boost(
+        clock.unix_timestamp,
+        &mut vault,
+        &user_stats,
+        &fuel_overflow,
+    )?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RemoveExpiredFuelBoost<'info> {
+    #[account(mut)]
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        mut,
+        constraint = is_vault_for_vault_depositor(&vault_depositor, &vault)?
+    )]
+    pub vault_depositor: AccountLoader<'info, VaultDepositor>,
+    #[account(
+        constraint = is_user_stats_for_vault(&vault, &drift_user_stats.key())?
+    )]
+    pub drift_user_stats: AccountLoader<'info, UserStats>,
+}
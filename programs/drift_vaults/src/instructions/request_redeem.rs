@@ -0,0 +1,195 @@
+use crate::constraints::{
+    is_mint_for_tokenized_depositor, is_tokenized_depositor_for_vault, is_user_for_vault,
+    is_user_stats_for_vault,
+};
+use crate::error::ErrorCode;
+use crate::token_cpi::BurnTokensCPI;
+use crate::{validate, AccountMapProvider, NavSide, Size};
+use crate::{
+    TokenizedRedeemRequest, TokenizedVaultDepositor, Vault, VaultRemainingAccountsProvider,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{burn, Burn, Mint, TokenAccount, TokenInterface};
+use drift::instructions::optional_accounts::AccountMaps;
+use drift::math::safe_math::SafeMath;
+use drift::state::user::{User, UserStats};
+
+pub fn request_redeem<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, RequestRedeem<'info>>,
+    tokens_to_burn: u64,
+    request_id: u16,
+) -> Result<()> {
+    let clock = &Clock::get()?;
+
+    let mut vault = ctx.accounts.vault.load_mut()?;
+
+    validate!(!vault.in_liquidation(), ErrorCode::OngoingLiquidation)?;
+
+    let mut tokenized_vault_depositor = ctx.accounts.tokenized_vault_depositor.load_mut()?;
+    let mut redeem_request = ctx.accounts.redeem_request.load_init()?;
+    redeem_request.vault = ctx.accounts.vault.key();
+    redeem_request.tokenized_vault_depositor = ctx.accounts.tokenized_vault_depositor.key();
+    redeem_request.authority = ctx.accounts.authority.key();
+    redeem_request.bump = ctx.bumps.redeem_request;
+    redeem_request.request_id = request_id;
+
+    let remaining_accounts = ctx.vault_remaining_accounts(&vault)?;
+    // backwards compatible: vaults without a [`VaultProtocol`] are legacy vaults.
+    let mut vp = remaining_accounts.vault_protocol;
+    vault.validate_vault_protocol(&vp)?;
+    let mut vp = vp.as_mut().map(|vp| vp.load_mut()).transpose()?;
+
+    let user = ctx.accounts.drift_user.load()?;
+    let user_stats = ctx.accounts.drift_user_stats.load()?;
+    let fuel_overflow = remaining_accounts.fuel_overflow;
+    user_stats.validate_fuel_overflow(&fuel_overflow)?;
+    let spot_market_index = vault.spot_market_index;
+
+    let mut fee_update = remaining_accounts.fee_update;
+    vault.validate_fee_update(&fee_update)?;
+
+    let nav_policy = remaining_accounts.nav_policy;
+    vault.validate_nav_policy(&nav_policy)?;
+
+    let AccountMaps {
+        perp_market_map,
+        spot_market_map,
+        mut oracle_map,
+    } = ctx.load_maps(clock.slot, Some(spot_market_index))?;
+
+    let (vault_equity, _) = vault.calculate_nav_policy_equity(
+        &nav_policy,
+        NavSide::Withdraw,
+        &user,
+        &perp_market_map,
+        &spot_market_map,
+        &mut oracle_map,
+    )?;
+
+    vault.check_nav_breaker(&nav_policy, vault_equity, clock.unix_timestamp)?;
+
+    let vault_equity = vault.apply_swing_pricing(
+        &nav_policy,
+        NavSide::Withdraw,
+        vault_equity,
+        &user,
+        &perp_market_map,
+        &spot_market_map,
+        &mut oracle_map,
+    )?;
+
+    let total_supply_before = ctx.accounts.mint.supply;
+    // accrue fuel to the tokenized shares before they change
+    tokenized_vault_depositor.update_cumulative_fuel_amount(
+        clock.unix_timestamp,
+        &mut vault,
+        &user_stats,
+        &fuel_overflow,
+    )?;
+    let spot_market = spot_market_map.get_ref(&spot_market_index)?;
+    let oracle = oracle_map.get_price_data(&spot_market.oracle_id())?;
+
+    tokenized_vault_depositor.request_redeem(
+        &mut redeem_request,
+        &mut vault,
+        &mut vp,
+        &mut fee_update,
+        total_supply_before,
+        vault_equity,
+        tokens_to_burn,
+        clock.unix_timestamp,
+        oracle.price,
+    )?;
+
+    drop(spot_market);
+    drop(vault);
+    drop(user);
+    drop(user_stats);
+    drop(vp);
+    drop(tokenized_vault_depositor);
+    drop(redeem_request);
+
+    ctx.burn(tokens_to_burn)?;
+
+    msg!(
+        "Burned {} tokens from {}",
+        tokens_to_burn,
+        ctx.accounts.user_token_account.key()
+    );
+
+    ctx.accounts.mint.reload()?;
+    let total_supply_after = ctx.accounts.mint.supply;
+
+    let supply_delta = total_supply_before.safe_sub(total_supply_after)?;
+    validate!(
+        supply_delta.eq(&tokens_to_burn),
+        ErrorCode::InvalidTokenization,
+        "Tokens burned ({}) != supply delta ({})",
+        tokens_to_burn,
+        supply_delta
+    )?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(tokens_to_burn: u64, request_id: u16)]
+pub struct RequestRedeem<'info> {
+    #[account(mut)]
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        mut,
+        constraint = is_tokenized_depositor_for_vault(&tokenized_vault_depositor, &vault)?,
+    )]
+    pub tokenized_vault_depositor: AccountLoader<'info, TokenizedVaultDepositor>,
+    #[account(
+        init,
+        seeds = [b"tokenized_redeem_request", tokenized_vault_depositor.key().as_ref(), authority.key().as_ref(), request_id.to_le_bytes().as_ref()],
+        space = TokenizedRedeemRequest::SIZE,
+        bump,
+        payer = authority
+    )]
+    pub redeem_request: AccountLoader<'info, TokenizedRedeemRequest>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        mint::authority = vault.key(),
+        constraint = is_mint_for_tokenized_depositor(&mint.key(), &tokenized_vault_depositor)?,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        token::authority = authority,
+        token::mint = mint.key()
+    )]
+    pub user_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        constraint = is_user_for_vault(&vault, &drift_user.key())?
+    )]
+    pub drift_user: AccountLoader<'info, User>,
+    #[account(
+        constraint = is_user_stats_for_vault(&vault, &drift_user_stats.key())?
+    )]
+    /// CHECK: checked in drift cpi
+    pub drift_user_stats: AccountLoader<'info, UserStats>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> BurnTokensCPI for Context<'_, '_, '_, 'info, RequestRedeem<'info>> {
+    fn burn(&self, amount: u64) -> Result<()> {
+        let cpi_accounts = Burn {
+            mint: self.accounts.mint.to_account_info(),
+            from: self.accounts.user_token_account.to_account_info(),
+            authority: self.accounts.authority.to_account_info(),
+        };
+
+        let cpi_context =
+            CpiContext::new(self.accounts.token_program.to_account_info(), cpi_accounts);
+
+        burn(cpi_context, amount)?;
+
+        Ok(())
+    }
+}
@@ -6,6 +6,7 @@ use drift::state::user::User;
 use crate::constraints::is_user_for_vault;
 use crate::drift_cpi::{UpdateUserDelegateCPI, UpdateUserReduceOnlyCPI};
 use crate::error::ErrorCode;
+use crate::state::events::{VaultConfigAction, VaultConfigRecord};
 use crate::state::Vault;
 use crate::validate;
 use crate::{
@@ -21,16 +22,31 @@ pub fn reset_delegate<'info>(ctx: Context<'_, '_, '_, 'info, ResetDelegate<'info
         "vault not in liquidation"
     )?;
 
+    let old_config = vault.get_config(None);
+
     let now = Clock::get()?.unix_timestamp;
     vault.check_can_exit_liquidation(now)?;
     vault.reset_liquidation_delegate();
 
+    emit!(VaultConfigRecord {
+        ts: now,
+        action: VaultConfigAction::ResetDelegate,
+        vault: vault.pubkey,
+        authority: Pubkey::default(),
+        old_config,
+        new_config: vault.get_config(None),
+    });
+
     let delegate = vault.delegate;
+    // a triggered max drawdown keeps the user reduce-only until the manager re-enables trading
+    let drawdown_triggered = vault.drawdown_triggered;
 
     drop(vault);
 
     ctx.drift_update_user_delegate(delegate)?;
-    ctx.drift_update_user_reduce_only(false)?;
+    if !drawdown_triggered {
+        ctx.drift_update_user_reduce_only(false)?;
+    }
 
     Ok(())
 }
@@ -1,12 +1,15 @@
 use anchor_lang::prelude::*;
 use drift::ids::admin_hot_wallet;
 use drift::state::state::State;
-use drift::state::user::{FuelOverflowStatus, UserStats};
+use drift::state::user::UserStats;
 
 use crate::constraints::{is_user_stats_for_vault, is_vault_for_vault_depositor};
-use crate::state::{FuelOverflowProvider, Vault, VaultProtocolProvider};
-use crate::VaultDepositor;
+use crate::error::ErrorCode;
+use crate::state::{FuelSeasonSnapshot, Vault, VaultRemainingAccountsProvider};
+use crate::{validate, Size, VaultDepositor};
 
+/// Records the depositor's fuel for the vault's current fuel_season in a [`FuelSeasonSnapshot`] and resets
+/// it. Depositors are reset before reset_vault_fuel_season ends the vault's season, each at most once.
 pub fn reset_fuel_season<'c: 'info, 'info>(
     ctx: Context<'_, '_, 'c, 'info, ResetFuelSeason<'info>>,
 ) -> Result<()> {
@@ -15,23 +18,45 @@ pub fn reset_fuel_season<'c: 'info, 'info>(
     let mut vault = ctx.accounts.vault.load_mut()?;
     let mut vault_depositor = ctx.accounts.vault_depositor.load_mut()?;
 
-    // backwards compatible: if last rem acct does not deserialize into [`VaultProtocol`] then it's a legacy vault.
-    let mut vp = ctx.vault_protocol();
+    let remaining_accounts = ctx.vault_remaining_accounts(&vault)?;
+    // backwards compatible: vaults without a [`VaultProtocol`] are legacy vaults.
+    let mut vp = remaining_accounts.vault_protocol;
     vault.validate_vault_protocol(&vp)?;
     let vp = vp.as_mut().map(|vp| vp.load_mut()).transpose()?;
 
     let user_stats = ctx.accounts.drift_user_stats.load()?;
-    let has_fuel_overflow = FuelOverflowStatus::exists(user_stats.fuel_overflow_status);
-    let fuel_overflow = ctx.fuel_overflow(vp.is_some(), has_fuel_overflow);
+    let fuel_overflow = remaining_accounts.fuel_overflow;
     user_stats.validate_fuel_overflow(&fuel_overflow)?;
 
-    vault_depositor.update_cumulative_fuel_amount(
+    // once the vault's season is reset the depositor's fuel is dropped on its next update
+    validate!(
+        vault_depositor.cumulative_fuel_per_share_amount <= vault.cumulative_fuel_per_share,
+        ErrorCode::InvalidFuelSeason,
+        "the vault's fuel season was reset before the vault depositor's"
+    )?;
+
+    let fuel_amount = vault_depositor.settle_fuel(
         clock.unix_timestamp,
         &mut vault,
         &user_stats,
         &fuel_overflow,
     )?;
 
+    let mut fuel_season_snapshot = ctx.accounts.fuel_season_snapshot.load_init()?;
+    fuel_season_snapshot.vault = ctx.accounts.vault.key();
+    fuel_season_snapshot.vault_depositor = ctx.accounts.vault_depositor.key();
+    fuel_season_snapshot.authority = vault_depositor.authority;
+    fuel_season_snapshot.fuel_amount = fuel_amount;
+    fuel_season_snapshot.ts = clock.unix_timestamp;
+    fuel_season_snapshot.season = vault.fuel_season;
+    fuel_season_snapshot.bump = ctx.bumps.fuel_season_snapshot;
+
+    msg!(
+        "fuel season {} snapshot: {}",
+        fuel_season_snapshot.season,
+        fuel_amount
+    );
+
     vault_depositor.reset_fuel_amount(clock.unix_timestamp);
 
     Ok(())
@@ -46,10 +71,20 @@ pub struct ResetFuelSeason<'info> {
         constraint = is_vault_for_vault_depositor(&vault_depositor, &vault)?
     )]
     pub vault_depositor: AccountLoader<'info, VaultDepositor>,
+    #[account(
+        init,
+        seeds = [b"fuel_season_snapshot", vault_depositor.key().as_ref(), &[vault.load()?.fuel_season]],
+        space = FuelSeasonSnapshot::SIZE,
+        bump,
+        payer = payer
+    )]
+    pub fuel_season_snapshot: AccountLoader<'info, FuelSeasonSnapshot>,
     #[account(
         constraint = admin.key() == drift_state.admin || admin.key() == admin_hot_wallet::id()
     )]
     pub admin: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
     #[account(
         mut,
         constraint = is_user_stats_for_vault(&vault, &drift_user_stats.key())?
@@ -57,4 +92,5 @@ pub struct ResetFuelSeason<'info> {
     /// CHECK: checked in drift cpi
     pub drift_user_stats: AccountLoader<'info, UserStats>,
     pub drift_state: Box<Account<'info, State>>,
+    pub system_program: Program<'info, System>,
 }
@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+use crate::constraints::{is_admin, is_manager_for_vault};
+use crate::state::{NavPolicy, Vault};
+
+/// Acknowledges a [`NavPolicy`] circuit breaker tripped by trip_nav_breaker. Deposits and withdraw requests
+/// resume and the next one is taken as the new equity per share observation.
+pub fn reset_nav_circuit_breaker<'info>(
+    ctx: Context<'_, '_, '_, 'info, ResetNavCircuitBreaker<'info>>,
+) -> Result<()> {
+    let clock = &Clock::get()?;
+    let vault = ctx.accounts.vault.load()?;
+    let mut nav_policy = ctx.accounts.nav_policy.load_mut()?;
+
+    msg!(
+        "Resetting nav circuit breaker, tripped: {}, last equity per share: {}",
+        nav_policy.nav_breaker_tripped,
+        nav_policy.last_equity_per_share
+    );
+
+    nav_policy.reset_nav_breaker(&vault, ctx.accounts.authority.key(), clock.unix_timestamp);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ResetNavCircuitBreaker<'info> {
+    #[account(
+        constraint = is_manager_for_vault(&vault, &authority)? || is_admin(&authority)?,
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"nav_policy".as_ref(), vault.key().as_ref()],
+        bump,
+    )]
+    pub nav_policy: AccountLoader<'info, NavPolicy>,
+}
@@ -1,4 +1,4 @@
-use crate::state::Vault;
+use crate::state::{Vault, VaultRemainingAccountsProvider};
 use anchor_lang::prelude::*;
 use drift::ids::admin_hot_wallet;
 use drift::state::state::State;
@@ -9,6 +9,16 @@ pub fn reset_vault_fuel_season<'c: 'info, 'info>(
     let clock = &Clock::get()?;
 
     let mut vault = ctx.accounts.vault.load_mut()?;
+
+    // the manager and protocol fuel of a weighted vault must be settled before the vault's fuel is reset
+    let fuel_distribution = ctx.vault_remaining_accounts(&vault)?.fuel_distribution;
+    vault.validate_fuel_distribution(&fuel_distribution)?;
+    if let Some(fuel_distribution) = fuel_distribution {
+        fuel_distribution
+            .load_mut()?
+            .reset_fuel_amount(&vault, clock.unix_timestamp)?;
+    }
+
     vault.reset_cumulative_fuel_per_share(clock.unix_timestamp);
 
     Ok(())
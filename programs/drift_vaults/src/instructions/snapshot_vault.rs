@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+use drift::instructions::optional_accounts::AccountMaps;
+use drift::state::user::User;
+
+use crate::constraints::is_user_for_vault;
+use crate::error::ErrorCode;
+use crate::state::{NavHistory, Vault, VaultRemainingAccountsProvider};
+use crate::{validate, AccountMapProvider};
+
+/// Permissionless crank that appends the vault's current equity and shares to its [`NavHistory`]. Also
+/// updates the vault's [`crate::state::ManagerStats`] if it is passed in remaining_accounts.
+pub fn snapshot_vault<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, SnapshotVault<'info>>,
+) -> Result<()> {
+    let clock = &Clock::get()?;
+    let vault = ctx.accounts.vault.load()?;
+    let mut nav_history = ctx.accounts.nav_history.load_mut()?;
+    let user = ctx.accounts.drift_user.load()?;
+
+    validate!(
+        nav_history.is_snapshot_due(clock.unix_timestamp)?,
+        ErrorCode::NavSnapshotTooSoon,
+        "last snapshot at {}",
+        nav_history.latest().map_or(0, |snapshot| snapshot.ts)
+    )?;
+
+    let AccountMaps {
+        perp_market_map,
+        spot_market_map,
+        mut oracle_map,
+    } = ctx.load_maps(clock.slot, None)?;
+
+    let vault_equity =
+        vault.calculate_equity(&user, &perp_market_map, &spot_market_map, &mut oracle_map)?;
+
+    nav_history.append(&vault, vault_equity, clock.unix_timestamp)?;
+
+    if let Some(manager_stats) = ctx.vault_remaining_accounts(&vault)?.manager_stats {
+        manager_stats
+            .load_mut()?
+            .observe(&vault, vault_equity, clock.unix_timestamp)?;
+    }
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SnapshotVault<'info> {
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        mut,
+        seeds = [b"nav_history".as_ref(), vault.key().as_ref()],
+        bump,
+    )]
+    pub nav_history: AccountLoader<'info, NavHistory>,
+    #[account(
+        constraint = is_user_for_vault(&vault, &drift_user.key())?
+    )]
+    pub drift_user: AccountLoader<'info, User>,
+}
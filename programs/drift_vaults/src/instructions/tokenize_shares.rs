@@ -1,17 +1,20 @@
 use crate::constraints::{
     is_authority_for_vault_depositor, is_mint_for_tokenized_depositor,
-    is_tokenized_depositor_for_vault, is_user_for_vault,
+    is_tokenized_depositor_for_vault, is_user_for_vault, is_user_stats_for_vault,
 };
 use crate::error::ErrorCode;
+use crate::state::settle_reward_streams;
 use crate::state::traits::VaultDepositorBase;
 use crate::token_cpi::MintTokensCPI;
 use crate::{validate, AccountMapProvider};
-use crate::{TokenizedVaultDepositor, Vault, VaultDepositor, VaultProtocolProvider, WithdrawUnit};
+use crate::{
+    TokenizedVaultDepositor, Vault, VaultDepositor, VaultRemainingAccountsProvider, WithdrawUnit,
+};
 use anchor_lang::prelude::*;
-use anchor_spl::token::{mint_to, Mint, MintTo, Token, TokenAccount};
+use anchor_spl::token_interface::{mint_to, Mint, MintTo, TokenAccount, TokenInterface};
 use drift::instructions::optional_accounts::AccountMaps;
 use drift::math::safe_math::SafeMath;
-use drift::state::user::User;
+use drift::state::user::{User, UserStats};
 
 use super::constraints::is_vault_shares_base_for_tokenized_depositor;
 
@@ -29,11 +32,25 @@ pub fn tokenize_shares<'info>(
     let mut vault_depositor = ctx.accounts.vault_depositor.load_mut()?;
     let mut tokenized_vault_depositor = ctx.accounts.tokenized_vault_depositor.load_mut()?;
 
-    // backwards compatible: if last rem acct does not deserialize into [`VaultProtocol`] then it's a legacy vault.
-    let mut vp = ctx.vault_protocol();
+    validate!(
+        !vault_depositor.is_fuel_locked(clock.unix_timestamp),
+        ErrorCode::VaultDepositorLocked,
+        "Cannot tokenize shares while the vault depositor is locked"
+    )?;
+
+    let remaining_accounts = ctx.vault_remaining_accounts(&vault)?;
+    // backwards compatible: vaults without a [`VaultProtocol`] are legacy vaults.
+    let mut vp = remaining_accounts.vault_protocol;
     vault.validate_vault_protocol(&vp)?;
     let mut vp = vp.as_mut().map(|vp| vp.load_mut()).transpose()?;
 
+    let user_stats = ctx.accounts.drift_user_stats.load()?;
+    let fuel_overflow = remaining_accounts.fuel_overflow;
+    user_stats.validate_fuel_overflow(&fuel_overflow)?;
+
+    let mut fee_update = remaining_accounts.fee_update;
+    vault.validate_fee_update(&fee_update)?;
+
     validate!(
         vault.shares_base == tokenized_vault_depositor.vault_shares_base,
         ErrorCode::InvalidVaultRebase,
@@ -52,13 +69,7 @@ pub fn tokenize_shares<'info>(
         perp_market_map,
         spot_market_map,
         mut oracle_map,
-    } = ctx.load_maps(
-        clock.slot,
-        Some(spot_market_index),
-        vp.is_some(),
-        false,
-        false,
-    )?;
+    } = ctx.load_maps(clock.slot, Some(spot_market_index))?;
 
     let vault_equity =
         vault.calculate_equity(&user, &perp_market_map, &spot_market_map, &mut oracle_map)?;
@@ -70,6 +81,19 @@ pub fn tokenize_shares<'info>(
     )?;
 
     let total_supply_before = ctx.accounts.mint.supply;
+    // accrue fuel to the depositor and the tokenized shares before shares change
+    vault_depositor.update_cumulative_fuel_amount(
+        clock.unix_timestamp,
+        &mut vault,
+        &user_stats,
+        &fuel_overflow,
+    )?;
+    tokenized_vault_depositor.update_cumulative_fuel_amount(
+        clock.unix_timestamp,
+        &mut vault,
+        &user_stats,
+        &fuel_overflow,
+    )?;
 
     let spot_market = spot_market_map.get_ref(&spot_market_index)?;
     let oracle = oracle_map.get_price_data(&spot_market.oracle_id())?;
@@ -78,7 +102,7 @@ pub fn tokenize_shares<'info>(
         &mut *tokenized_vault_depositor,
         &mut vault,
         &mut vp,
-        &mut None,
+        &mut fee_update,
         amount,
         unit,
         vault_equity,
@@ -89,7 +113,7 @@ pub fn tokenize_shares<'info>(
     let tokens_to_mint = tokenized_vault_depositor.tokenize_shares(
         &mut vault,
         &mut vp,
-        &mut None,
+        &mut fee_update,
         total_supply_before,
         vault_equity,
         shares_transferred,
@@ -97,6 +121,14 @@ pub fn tokenize_shares<'info>(
         oracle.price,
     )?;
 
+    settle_reward_streams(
+        &remaining_accounts.reward_streams,
+        &remaining_accounts.reward_stream_depositors,
+        &vault_depositor,
+        &vault,
+        clock.unix_timestamp,
+    )?;
+
     let total_shares_after = vault_depositor
         .get_vault_shares()
         .safe_add(tokenized_vault_depositor.get_vault_shares())?;
@@ -114,6 +146,7 @@ pub fn tokenize_shares<'info>(
     drop(vault);
     drop(vault_depositor);
     drop(tokenized_vault_depositor);
+    drop(user_stats);
 
     ctx.mint(vault_name, vault_bump, tokens_to_mint)?;
 
@@ -169,19 +202,23 @@ pub struct TokenizeShares<'info> {
         mint::authority = vault.key(),
 		constraint = is_mint_for_tokenized_depositor(&mint.key(), &tokenized_vault_depositor)?,
     )]
-    pub mint: Account<'info, Mint>,
+    pub mint: InterfaceAccount<'info, Mint>,
     #[account(
         token::authority = authority,
         token::mint = tokenized_vault_depositor.load()?.mint
     )]
-    pub user_token_account: Account<'info, TokenAccount>,
+    pub user_token_account: InterfaceAccount<'info, TokenAccount>,
     #[account(
         mut,
         constraint = is_user_for_vault(&vault, &drift_user.key())?
     )]
     /// CHECK: checked in drift cpi
     pub drift_user: AccountLoader<'info, User>,
-    pub token_program: Program<'info, Token>,
+    #[account(
+        constraint = is_user_stats_for_vault(&vault, &drift_user_stats.key())?
+    )]
+    pub drift_user_stats: AccountLoader<'info, UserStats>,
+    pub token_program: Interface<'info, TokenInterface>,
 }
 
 impl<'info> MintTokensCPI for Context<'_, '_, '_, 'info, TokenizeShares<'info>> {
@@ -0,0 +1,120 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_2022::spl_token_2022::extension::transfer_hook::TransferHookAccount;
+use anchor_spl::token_2022::spl_token_2022::extension::{
+    BaseStateWithExtensions, StateWithExtensions,
+};
+use anchor_spl::token_2022::spl_token_2022::state::Account as TokenAccountState;
+use anchor_spl::token_interface::{Mint, TokenAccount};
+use spl_tlv_account_resolution::account::ExtraAccountMeta;
+use spl_tlv_account_resolution::seeds::Seed;
+
+use crate::error::ErrorCode;
+use crate::{validate, Vault, VaultDepositor};
+
+/// Seed of the account holding the extra accounts Token-2022 passes to the transfer hook, defined by
+/// the transfer hook interface
+pub const EXTRA_ACCOUNT_METAS_SEED: &[u8] = b"extra-account-metas";
+
+/// The accounts Token-2022 resolves and appends to [`TransferHook`] on every transfer of a share
+/// token: the vault, then the sender's and receiver's [`VaultDepositor`] pdas. Account indices 0 and
+/// 2 are the source and destination token accounts, whose owner is stored at offset 32.
+pub fn transfer_hook_extra_account_metas(vault: &Pubkey) -> Result<Vec<ExtraAccountMeta>> {
+    let vault_depositor_seeds = |token_account_index: u8| {
+        [
+            Seed::Literal {
+                bytes: b"vault_depositor".to_vec(),
+            },
+            Seed::AccountKey { index: 5 },
+            Seed::AccountData {
+                account_index: token_account_index,
+                data_index: 32,
+                length: 32,
+            },
+        ]
+    };
+
+    Ok(vec![
+        ExtraAccountMeta::new_with_pubkey(vault, false, false)?,
+        ExtraAccountMeta::new_with_seeds(&vault_depositor_seeds(0), false, false)?,
+        ExtraAccountMeta::new_with_seeds(&vault_depositor_seeds(2), false, false)?,
+    ])
+}
+
+/// Transfer hook of share token mints created with `enable_transfer_hook`. Token-2022 invokes it
+/// through the program's fallback on every transfer, see [`Vault::validate_share_token_transfer`].
+pub fn transfer_hook<'info>(
+    ctx: Context<'_, '_, '_, 'info, TransferHook<'info>>,
+    _amount: u64,
+) -> Result<()> {
+    // only run as part of a token transfer, not when invoked directly
+    let source_token_info = ctx.accounts.source_token.to_account_info();
+    let source_token_data = source_token_info.try_borrow_data()?;
+    let source_token = StateWithExtensions::<TokenAccountState>::unpack(&source_token_data)?;
+    let transferring = source_token
+        .get_extension::<TransferHookAccount>()
+        .map(|extension| bool::from(extension.transferring))
+        .unwrap_or(false);
+    validate!(
+        transferring,
+        ErrorCode::InvalidTokenization,
+        "transfer hook invoked outside of a transfer"
+    )?;
+
+    let vault = ctx.accounts.vault.load()?;
+    let now = Clock::get()?.unix_timestamp;
+
+    let source_vault_depositor = load_vault_depositor(&ctx.accounts.source_vault_depositor)?;
+    let destination_vault_depositor =
+        load_vault_depositor(&ctx.accounts.destination_vault_depositor)?;
+
+    vault.validate_share_token_transfer(
+        source_vault_depositor.as_ref(),
+        &ctx.accounts.destination_token.owner,
+        destination_vault_depositor.as_ref(),
+        now,
+    )?;
+
+    Ok(())
+}
+
+/// Holders don't need a [`VaultDepositor`], so the pda may not exist
+fn load_vault_depositor(account: &UncheckedAccount) -> Result<Option<VaultDepositor>> {
+    if account.data_is_empty() {
+        return Ok(None);
+    }
+
+    let vault_depositor = AccountLoader::<VaultDepositor>::try_from(&account.to_account_info())?;
+    let vault_depositor = *vault_depositor.load()?;
+    Ok(Some(vault_depositor))
+}
+
+#[derive(Accounts)]
+pub struct TransferHook<'info> {
+    #[account(token::mint = mint)]
+    pub source_token: InterfaceAccount<'info, TokenAccount>,
+    #[account(mint::authority = vault.key())]
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(token::mint = mint)]
+    pub destination_token: InterfaceAccount<'info, TokenAccount>,
+    /// CHECK: owner or delegate of the source token account, checked by the token program
+    pub owner: UncheckedAccount<'info>,
+    /// CHECK: the mint's extra account metas, read by the token program to resolve the accounts below
+    #[account(
+        seeds = [EXTRA_ACCOUNT_METAS_SEED, mint.key().as_ref()],
+        bump,
+    )]
+    pub extra_account_meta_list: UncheckedAccount<'info>,
+    pub vault: AccountLoader<'info, Vault>,
+    /// CHECK: the sender's vault depositor pda, may be uninitialized
+    #[account(
+        seeds = [b"vault_depositor", vault.key().as_ref(), source_token.owner.as_ref()],
+        bump,
+    )]
+    pub source_vault_depositor: UncheckedAccount<'info>,
+    /// CHECK: the receiver's vault depositor pda, may be uninitialized
+    #[account(
+        seeds = [b"vault_depositor", vault.key().as_ref(), destination_token.owner.as_ref()],
+        bump,
+    )]
+    pub destination_vault_depositor: UncheckedAccount<'info>,
+}
@@ -1,15 +1,17 @@
 use anchor_lang::prelude::*;
 use drift::instructions::optional_accounts::AccountMaps;
 use drift::math::safe_math::SafeMath;
-use drift::state::user::User;
+use drift::state::user::{User, UserStats};
 
 use crate::constraints::{
-    is_authority_for_vault_depositor, is_user_for_vault, is_vault_for_vault_depositor,
+    is_authority_for_vault_depositor, is_user_for_vault, is_user_stats_for_vault,
+    is_vault_for_vault_depositor,
 };
 use crate::error::ErrorCode;
+use crate::state::settle_reward_streams;
 use crate::state::traits::VaultDepositorBase;
 use crate::{validate, AccountMapProvider};
-use crate::{Vault, VaultDepositor, VaultProtocolProvider, WithdrawUnit};
+use crate::{Vault, VaultDepositor, VaultRemainingAccountsProvider, WithdrawUnit};
 
 pub fn transfer_vault_depositor_shares<'info>(
     ctx: Context<'_, '_, 'info, 'info, TransferVaultDepositorShares<'info>>,
@@ -37,11 +39,25 @@ pub fn transfer_vault_depositor_shares<'info>(
     let mut vault_depositor = ctx.accounts.vault_depositor.load_mut()?;
     let mut to_vault_depositor = ctx.accounts.to_vault_depositor.load_mut()?;
 
-    // backwards compatible: if last rem acct does not deserialize into [`VaultProtocol`] then it's a legacy vault.
-    let mut vp = ctx.vault_protocol();
+    validate!(
+        !vault_depositor.is_fuel_locked(clock.unix_timestamp),
+        ErrorCode::VaultDepositorLocked,
+        "Cannot transfer shares while the vault depositor is locked"
+    )?;
+
+    let remaining_accounts = ctx.vault_remaining_accounts(&vault)?;
+    // backwards compatible: vaults without a [`VaultProtocol`] are legacy vaults.
+    let mut vp = remaining_accounts.vault_protocol;
     vault.validate_vault_protocol(&vp)?;
     let mut vp = vp.as_mut().map(|vp| vp.load_mut()).transpose()?;
 
+    let user_stats = ctx.accounts.drift_user_stats.load()?;
+    let fuel_overflow = remaining_accounts.fuel_overflow;
+    user_stats.validate_fuel_overflow(&fuel_overflow)?;
+
+    let mut fee_update = remaining_accounts.fee_update;
+    vault.validate_fee_update(&fee_update)?;
+
     let user = ctx.accounts.drift_user.load()?;
     let spot_market_index = vault.spot_market_index;
 
@@ -49,13 +65,7 @@ pub fn transfer_vault_depositor_shares<'info>(
         perp_market_map,
         spot_market_map,
         mut oracle_map,
-    } = ctx.load_maps(
-        clock.slot,
-        Some(spot_market_index),
-        vp.is_some(),
-        false,
-        false,
-    )?;
+    } = ctx.load_maps(clock.slot, Some(spot_market_index))?;
 
     let vault_equity =
         vault.calculate_equity(&user, &perp_market_map, &spot_market_map, &mut oracle_map)?;
@@ -79,11 +89,25 @@ pub fn transfer_vault_depositor_shares<'info>(
         .get_vault_shares()
         .safe_add(to_vault_depositor.get_vault_shares())?;
 
+    // accrue fuel to both depositors before their shares change
+    vault_depositor.update_cumulative_fuel_amount(
+        clock.unix_timestamp,
+        &mut vault,
+        &user_stats,
+        &fuel_overflow,
+    )?;
+    to_vault_depositor.update_cumulative_fuel_amount(
+        clock.unix_timestamp,
+        &mut vault,
+        &user_stats,
+        &fuel_overflow,
+    )?;
+
     vault_depositor.transfer_shares(
         &mut *to_vault_depositor,
         &mut vault,
         &mut vp,
-        &mut None,
+        &mut fee_update,
         amount,
         withdraw_unit,
         vault_equity,
@@ -91,6 +115,22 @@ pub fn transfer_vault_depositor_shares<'info>(
         oracle.price,
     )?;
 
+    settle_reward_streams(
+        &remaining_accounts.reward_streams,
+        &remaining_accounts.reward_stream_depositors,
+        &vault_depositor,
+        &vault,
+        clock.unix_timestamp,
+    )?;
+
+    settle_reward_streams(
+        &remaining_accounts.reward_streams,
+        &remaining_accounts.reward_stream_depositors,
+        &to_vault_depositor,
+        &vault,
+        clock.unix_timestamp,
+    )?;
+
     let total_shares_after = vault_depositor
         .get_vault_shares()
         .safe_add(to_vault_depositor.get_vault_shares())?;
@@ -125,4 +165,8 @@ pub struct TransferVaultDepositorShares<'info> {
         constraint = is_user_for_vault(&vault, &drift_user.key())?
     )]
     pub drift_user: AccountLoader<'info, User>,
+    #[account(
+        constraint = is_user_stats_for_vault(&vault, &drift_user_stats.key())?
+    )]
+    pub drift_user_stats: AccountLoader<'info, UserStats>,
 }
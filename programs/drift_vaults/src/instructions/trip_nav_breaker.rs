@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+use drift::instructions::optional_accounts::AccountMaps;
+use drift::state::user::User;
+
+use crate::constraints::is_user_for_vault;
+use crate::error::ErrorCode;
+use crate::state::{NavPolicy, Vault};
+use crate::{validate, AccountMapProvider};
+
+/// Permissionless crank that trips the [`NavPolicy`] circuit breaker when the vault's equity per share moved
+/// past its threshold. Deposits and withdraw requests fail instead of tripping it themselves, so the trip is
+/// persisted here until the manager or admin resets it.
+pub fn trip_nav_breaker<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, TripNavBreaker<'info>>,
+) -> Result<()> {
+    let clock = &Clock::get()?;
+    let vault = ctx.accounts.vault.load()?;
+    let mut nav_policy = ctx.accounts.nav_policy.load_mut()?;
+    let user = ctx.accounts.drift_user.load()?;
+
+    validate!(
+        nav_policy.is_nav_breaker_enabled(),
+        ErrorCode::InvalidNavPolicy,
+        "nav circuit breaker is not enabled"
+    )?;
+
+    let AccountMaps {
+        perp_market_map,
+        spot_market_map,
+        mut oracle_map,
+    } = ctx.load_maps(clock.slot, None)?;
+
+    let vault_equity =
+        vault.calculate_equity(&user, &perp_market_map, &spot_market_map, &mut oracle_map)?;
+
+    let tripped = nav_policy.check_nav_breaker(&vault, vault_equity, clock.unix_timestamp)?;
+    validate!(
+        tripped,
+        ErrorCode::NavCircuitBreakerNotTripped,
+        "equity per share is within the nav circuit breaker threshold"
+    )?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct TripNavBreaker<'info> {
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        mut,
+        seeds = [b"nav_policy".as_ref(), vault.key().as_ref()],
+        bump,
+    )]
+    pub nav_policy: AccountLoader<'info, NavPolicy>,
+    #[account(
+        constraint = is_user_for_vault(&vault, &drift_user.key())?
+    )]
+    pub drift_user: AccountLoader<'info, User>,
+}
@@ -1,11 +1,11 @@
 use anchor_lang::prelude::*;
-use drift::state::user::{FuelOverflowStatus, UserStats};
+use drift::state::user::UserStats;
 
 use crate::constraints::{
     is_admin, is_delegate_for_vault, is_manager_for_vault, is_user_stats_for_vault,
     is_vault_for_vault_depositor,
 };
-use crate::state::{FuelOverflowProvider, Vault, VaultProtocolProvider};
+use crate::state::{Vault, VaultRemainingAccountsProvider};
 use crate::VaultDepositor;
 
 use super::constraints::is_authority_for_vault_depositor;
@@ -18,14 +18,14 @@ pub fn update_cumulative_fuel_amount<'c: 'info, 'info>(
     let mut vault = ctx.accounts.vault.load_mut()?;
     let mut vault_depositor = ctx.accounts.vault_depositor.load_mut()?;
 
-    // backwards compatible: if last rem acct does not deserialize into [`VaultProtocol`] then it's a legacy vault.
-    let mut vp = ctx.vault_protocol();
+    let remaining_accounts = ctx.vault_remaining_accounts(&vault)?;
+    // backwards compatible: vaults without a [`VaultProtocol`] are legacy vaults.
+    let mut vp = remaining_accounts.vault_protocol;
     vault.validate_vault_protocol(&vp)?;
     let vp = vp.as_mut().map(|vp| vp.load_mut()).transpose()?;
 
     let user_stats = ctx.accounts.drift_user_stats.load()?;
-    let has_fuel_overflow = FuelOverflowStatus::exists(user_stats.fuel_overflow_status);
-    let fuel_overflow = ctx.fuel_overflow(vp.is_some(), has_fuel_overflow);
+    let fuel_overflow = remaining_accounts.fuel_overflow;
     user_stats.validate_fuel_overflow(&fuel_overflow)?;
 
     let fuel_amount = vault_depositor.update_cumulative_fuel_amount(
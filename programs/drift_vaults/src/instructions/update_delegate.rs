@@ -5,6 +5,7 @@ use drift::state::user::User;
 
 use crate::constraints::{is_manager_for_vault, is_user_for_vault};
 use crate::drift_cpi::UpdateUserDelegateCPI;
+use crate::state::events::{VaultConfigAction, VaultConfigRecord};
 use crate::Vault;
 use crate::{declare_vault_seeds, implement_update_user_delegate_cpi};
 
@@ -13,6 +14,7 @@ pub fn update_delegate<'info>(
     delegate: Pubkey,
 ) -> Result<()> {
     let mut vault = ctx.accounts.vault.load_mut()?;
+    let old_config = vault.get_config(None);
 
     if vault.in_liquidation() {
         let now = Clock::get()?.unix_timestamp;
@@ -22,6 +24,15 @@ pub fn update_delegate<'info>(
 
     vault.delegate = delegate;
 
+    emit!(VaultConfigRecord {
+        ts: Clock::get()?.unix_timestamp,
+        action: VaultConfigAction::UpdateDelegate,
+        vault: vault.pubkey,
+        authority: ctx.accounts.manager.key(),
+        old_config,
+        new_config: vault.get_config(None),
+    });
+
     drop(vault);
 
     ctx.drift_update_user_delegate(delegate)?;
@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+use drift::state::user::UserStats;
+
+use crate::constraints::is_user_stats_for_vault;
+use crate::state::{FuelDistribution, Vault, VaultRemainingAccountsProvider};
+
+/// Settles the manager and protocol fuel, and applies pending fuel weights once their timelock has passed.
+pub fn update_fuel_distribution<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, UpdateFuelDistribution<'info>>,
+) -> Result<()> {
+    let clock = &Clock::get()?;
+
+    let mut vault = ctx.accounts.vault.load_mut()?;
+    let mut fuel_distribution = ctx.accounts.fuel_distribution.load_mut()?;
+
+    let remaining_accounts = ctx.vault_remaining_accounts(&vault)?;
+    // backwards compatible: vaults without a [`VaultProtocol`] are legacy vaults.
+    let vp = remaining_accounts.vault_protocol;
+    vault.validate_vault_protocol(&vp)?;
+
+    let user_stats = ctx.accounts.drift_user_stats.load()?;
+    let fuel_overflow = remaining_accounts.fuel_overflow;
+    user_stats.validate_fuel_overflow(&fuel_overflow)?;
+
+    vault.update_cumulative_fuel_per_share(clock.unix_timestamp, &user_stats, &fuel_overflow)?;
+
+    fuel_distribution.update(&mut vault, clock.unix_timestamp)?;
+
+    msg!(
+        "manager_fuel: {}, protocol_fuel: {}",
+        fuel_distribution.manager_fuel,
+        fuel_distribution.protocol_fuel
+    );
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateFuelDistribution<'info> {
+    #[account(mut)]
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        mut,
+        seeds = [b"fuel_distribution".as_ref(), vault.key().as_ref()],
+        bump,
+    )]
+    pub fuel_distribution: AccountLoader<'info, FuelDistribution>,
+    #[account(
+        constraint = is_user_stats_for_vault(&vault, &drift_user_stats.key())?
+    )]
+    pub drift_user_stats: AccountLoader<'info, UserStats>,
+}
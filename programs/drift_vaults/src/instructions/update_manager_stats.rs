@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+use drift::instructions::optional_accounts::AccountMaps;
+use drift::state::user::User;
+
+use crate::constraints::is_user_for_vault;
+use crate::state::{ManagerStats, Vault};
+use crate::AccountMapProvider;
+
+/// Permissionless crank that records the vault's current share price in its [`ManagerStats`].
+pub fn update_manager_stats<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, UpdateManagerStats<'info>>,
+) -> Result<()> {
+    let clock = &Clock::get()?;
+    let vault = ctx.accounts.vault.load()?;
+    let mut manager_stats = ctx.accounts.manager_stats.load_mut()?;
+    let user = ctx.accounts.drift_user.load()?;
+
+    let AccountMaps {
+        perp_market_map,
+        spot_market_map,
+        mut oracle_map,
+    } = ctx.load_maps(clock.slot, None)?;
+
+    let vault_equity =
+        vault.calculate_equity(&user, &perp_market_map, &spot_market_map, &mut oracle_map)?;
+
+    manager_stats.observe(&vault, vault_equity, clock.unix_timestamp)?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateManagerStats<'info> {
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        mut,
+        seeds = [b"manager_stats".as_ref(), vault.key().as_ref()],
+        bump,
+    )]
+    pub manager_stats: AccountLoader<'info, ManagerStats>,
+    #[account(
+        constraint = is_user_for_vault(&vault, &drift_user.key())?
+    )]
+    pub drift_user: AccountLoader<'info, User>,
+}
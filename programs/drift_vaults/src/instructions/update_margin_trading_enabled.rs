@@ -6,6 +6,7 @@ use drift::state::user::User;
 use crate::constraints::{is_manager_for_vault, is_user_for_vault};
 use crate::drift_cpi::UpdateUserMarginTradingEnabledCPI;
 use crate::error::ErrorCode;
+use crate::state::events::{VaultConfigAction, VaultConfigRecord};
 use crate::Vault;
 use crate::{declare_vault_seeds, validate};
 
@@ -18,8 +19,24 @@ pub fn update_margin_trading_enabled<'info>(
         ErrorCode::OngoingLiquidation
     )?;
 
+    let old_config = ctx
+        .accounts
+        .vault
+        .load()?
+        .get_config(Some(&*ctx.accounts.drift_user.load()?));
+
     ctx.drift_update_user_margin_trading_enabled(enabled)?;
 
+    let vault = ctx.accounts.vault.load()?;
+    emit!(VaultConfigRecord {
+        ts: Clock::get()?.unix_timestamp,
+        action: VaultConfigAction::UpdateMarginTradingEnabled,
+        vault: vault.pubkey,
+        authority: ctx.accounts.manager.key(),
+        old_config,
+        new_config: vault.get_config(Some(&*ctx.accounts.drift_user.load()?)),
+    });
+
     Ok(())
 }
 
@@ -6,14 +6,31 @@ use drift::state::user::User;
 use crate::constraints::{is_manager_for_vault, is_user_for_vault};
 use crate::declare_vault_seeds;
 use crate::drift_cpi::UpdatePoolIdCPI;
+use crate::state::events::{VaultConfigAction, VaultConfigRecord};
 use crate::Vault;
 
 pub fn update_pool_id<'info>(
     ctx: Context<'_, '_, '_, 'info, UpdatePoolId<'info>>,
     pool_id: u8,
 ) -> Result<()> {
+    let old_config = ctx
+        .accounts
+        .vault
+        .load()?
+        .get_config(Some(&*ctx.accounts.drift_user.load()?));
+
     ctx.drift_update_pool_id(pool_id)?;
 
+    let vault = ctx.accounts.vault.load()?;
+    emit!(VaultConfigRecord {
+        ts: Clock::get()?.unix_timestamp,
+        action: VaultConfigAction::UpdatePoolId,
+        vault: vault.pubkey,
+        authority: ctx.accounts.manager.key(),
+        old_config,
+        new_config: vault.get_config(Some(&*ctx.accounts.drift_user.load()?)),
+    });
+
     Ok(())
 }
 
@@ -0,0 +1,222 @@
+use crate::constraints::{
+    is_manager_for_vault, is_mint_for_tokenized_depositor, is_tokenized_depositor_for_vault,
+};
+use crate::error::{ErrorCode, VaultResult};
+use crate::{validate, TokenizedVaultDepositor, Vault};
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::invoke_signed;
+use anchor_lang::system_program::{transfer, Transfer};
+use anchor_spl::metadata::mpl_token_metadata::accounts::Metadata as MetadataAccount;
+use anchor_spl::metadata::mpl_token_metadata::types::DataV2;
+use anchor_spl::metadata::{update_metadata_accounts_v2, Metadata, UpdateMetadataAccountsV2};
+use anchor_spl::token_2022::spl_token_2022::extension::{
+    BaseStateWithExtensions, StateWithExtensions,
+};
+use anchor_spl::token_2022::spl_token_2022::state::Mint as MintState;
+use anchor_spl::token_interface::{Mint, TokenInterface};
+use spl_token_metadata_interface::state::{Field, TokenMetadata};
+
+/// Metaplex's limits on token metadata, also applied to Token-2022 mints so both kinds of share
+/// mint accept the same metadata
+pub const MAX_TOKEN_NAME_LENGTH: usize = 32;
+pub const MAX_TOKEN_SYMBOL_LENGTH: usize = 10;
+pub const MAX_TOKEN_URI_LENGTH: usize = 200;
+
+pub fn update_tokenized_vault_depositor_metadata<'info>(
+    ctx: Context<'_, '_, '_, 'info, UpdateTokenizedVaultDepositorMetadata<'info>>,
+    params: UpdateTokenizedVaultDepositorMetadataParams,
+) -> Result<()> {
+    params.validate()?;
+
+    let vault = ctx.accounts.vault.load()?;
+    let signature_seeds = Vault::get_vault_signer_seeds(vault.name.as_ref(), &vault.bump);
+    let signers = &[&signature_seeds[..]];
+
+    let mint_info = ctx.accounts.mint.to_account_info();
+    let metadata_info = ctx.accounts.metadata_account.to_account_info();
+
+    if *mint_info.owner == anchor_spl::token_2022::ID {
+        // Token-2022 mints store metadata on the mint itself
+        validate!(
+            metadata_info.key() == mint_info.key(),
+            ErrorCode::InvalidTokenization,
+            "Token-2022 metadata account must be the mint"
+        )?;
+
+        let mut metadata = {
+            let mint_data = mint_info.try_borrow_data()?;
+            let mint_state = StateWithExtensions::<MintState>::unpack(&mint_data)?;
+            mint_state.get_variable_len_extension::<TokenMetadata>()?
+        };
+        let metadata_space_before = metadata.tlv_size_of()?;
+
+        let mut updates = vec![];
+        if let Some(token_name) = params.token_name {
+            updates.push((Field::Name, token_name));
+        }
+        if let Some(token_symbol) = params.token_symbol {
+            updates.push((Field::Symbol, token_symbol));
+        }
+        if let Some(token_uri) = params.token_uri {
+            updates.push((Field::Uri, token_uri));
+        }
+        for (field, value) in updates.iter() {
+            metadata.update(field.clone(), value.clone());
+        }
+
+        // the token program reallocs the mint for longer metadata, so top up rent beforehand
+        let metadata_space_after = metadata.tlv_size_of()?;
+        if metadata_space_after > metadata_space_before {
+            let new_len = mint_info
+                .data_len()
+                .saturating_add(metadata_space_after - metadata_space_before);
+            let lamports_needed = Rent::get()?
+                .minimum_balance(new_len)
+                .saturating_sub(mint_info.lamports());
+            if lamports_needed > 0 {
+                transfer(
+                    CpiContext::new(
+                        ctx.accounts.system_program.to_account_info(),
+                        Transfer {
+                            from: ctx.accounts.manager.to_account_info(),
+                            to: metadata_info.clone(),
+                        },
+                    ),
+                    lamports_needed,
+                )?;
+            }
+        }
+
+        for (field, value) in updates {
+            invoke_signed(
+                &spl_token_metadata_interface::instruction::update_field(
+                    &ctx.accounts.token_program.key(),
+                    &mint_info.key(),
+                    &ctx.accounts.vault.key(),
+                    field,
+                    value,
+                ),
+                &[metadata_info.clone(), ctx.accounts.vault.to_account_info()],
+                signers,
+            )?;
+        }
+    } else {
+        validate!(
+            metadata_info.key() == MetadataAccount::find_pda(&mint_info.key()).0,
+            ErrorCode::InvalidTokenization,
+            "Invalid metadata account for mint"
+        )?;
+
+        let token_metadata_program = match &ctx.accounts.token_metadata_program {
+            Some(token_metadata_program) => token_metadata_program.to_account_info(),
+            None => {
+                msg!("token_metadata_program is required for SPL Token mints");
+                return Err(ErrorCode::InvalidTokenization.into());
+            }
+        };
+
+        let metadata = MetadataAccount::from_bytes(&metadata_info.try_borrow_data()?)
+            .map_err(|_| ErrorCode::InvalidTokenization)?;
+
+        update_metadata_accounts_v2(
+            CpiContext::new_with_signer(
+                token_metadata_program,
+                UpdateMetadataAccountsV2 {
+                    metadata: metadata_info.clone(),
+                    update_authority: ctx.accounts.vault.to_account_info(),
+                },
+                signers,
+            ),
+            None,
+            Some(DataV2 {
+                name: params
+                    .token_name
+                    .unwrap_or_else(|| metadata.name.trim_end_matches('\0').to_string()),
+                symbol: params
+                    .token_symbol
+                    .unwrap_or_else(|| metadata.symbol.trim_end_matches('\0').to_string()),
+                uri: params
+                    .token_uri
+                    .unwrap_or_else(|| metadata.uri.trim_end_matches('\0').to_string()),
+                seller_fee_basis_points: metadata.seller_fee_basis_points,
+                creators: metadata.creators,
+                collection: metadata.collection,
+                uses: metadata.uses,
+            }),
+            None,
+            None,
+        )?;
+    }
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateTokenizedVaultDepositorMetadata<'info> {
+    #[account(
+        constraint = is_manager_for_vault(&vault, &manager)?,
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(mut)]
+    pub manager: Signer<'info>,
+    #[account(
+        constraint = is_tokenized_depositor_for_vault(&tokenized_vault_depositor, &vault)?,
+    )]
+    pub tokenized_vault_depositor: AccountLoader<'info, TokenizedVaultDepositor>,
+    #[account(
+        constraint = is_mint_for_tokenized_depositor(&mint.key(), &tokenized_vault_depositor)?,
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+    /// CHECK: the Metaplex metadata pda for SPL Token mints, or the mint itself for Token-2022 mints. Validated in the handler
+    #[account(mut)]
+    pub metadata_account: UncheckedAccount<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub token_metadata_program: Option<Program<'info, Metadata>>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Debug, Clone, AnchorSerialize, AnchorDeserialize, PartialEq, Eq)]
+pub struct UpdateTokenizedVaultDepositorMetadataParams {
+    pub token_name: Option<String>,
+    pub token_symbol: Option<String>,
+    pub token_uri: Option<String>,
+}
+
+impl UpdateTokenizedVaultDepositorMetadataParams {
+    pub fn validate(&self) -> VaultResult {
+        validate!(
+            self.token_name.is_some() || self.token_symbol.is_some() || self.token_uri.is_some(),
+            ErrorCode::InvalidTokenization,
+            "no metadata field to update"
+        )?;
+
+        if let Some(token_name) = &self.token_name {
+            validate!(
+                !token_name.is_empty() && token_name.len() <= MAX_TOKEN_NAME_LENGTH,
+                ErrorCode::InvalidTokenization,
+                "token_name must be 1 to {} bytes",
+                MAX_TOKEN_NAME_LENGTH
+            )?;
+        }
+
+        if let Some(token_symbol) = &self.token_symbol {
+            validate!(
+                !token_symbol.is_empty() && token_symbol.len() <= MAX_TOKEN_SYMBOL_LENGTH,
+                ErrorCode::InvalidTokenization,
+                "token_symbol must be 1 to {} bytes",
+                MAX_TOKEN_SYMBOL_LENGTH
+            )?;
+        }
+
+        if let Some(token_uri) = &self.token_uri {
+            validate!(
+                token_uri.len() <= MAX_TOKEN_URI_LENGTH,
+                ErrorCode::InvalidTokenization,
+                "token_uri must be at most {} bytes",
+                MAX_TOKEN_URI_LENGTH
+            )?;
+        }
+
+        Ok(())
+    }
+}
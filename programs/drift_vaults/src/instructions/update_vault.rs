@@ -1,5 +1,7 @@
 use crate::constraints::is_manager_for_vault;
-use crate::state::events::{FeeUpdateAction, FeeUpdateRecord};
+use crate::state::events::{
+    FeeUpdateAction, FeeUpdateRecord, VaultConfigAction, VaultConfigRecord,
+};
 use crate::{error::ErrorCode, validate, Vault};
 use anchor_lang::prelude::*;
 
@@ -11,6 +13,8 @@ pub fn update_vault<'info>(
 
     validate!(!vault.in_liquidation(), ErrorCode::OngoingLiquidation)?;
 
+    let old_config = vault.get_config(None);
+
     if let Some(redeem_period) = params.redeem_period {
         validate!(
             redeem_period < vault.redeem_period,
@@ -67,8 +71,17 @@ pub fn update_vault<'info>(
         vault.permissioned = permissioned;
     }
 
+    if let Some(exclude_pending_withdraws_from_fuel) = params.exclude_pending_withdraws_from_fuel {
+        validate!(
+            vault.total_withdraw_requested == 0,
+            ErrorCode::InvalidVaultUpdate,
+            "exclude_pending_withdraws_from_fuel can only be changed with no pending withdraw requests"
+        )?;
+        vault.exclude_pending_withdraws_from_fuel = exclude_pending_withdraws_from_fuel;
+    }
+
+    let now = Clock::get()?.unix_timestamp;
     if fee_updated {
-        let now = Clock::get()?.unix_timestamp;
         emit!(FeeUpdateRecord {
             ts: now,
             action: FeeUpdateAction::Applied,
@@ -83,6 +96,15 @@ pub fn update_vault<'info>(
         });
     }
 
+    emit!(VaultConfigRecord {
+        ts: now,
+        action: VaultConfigAction::UpdateVault,
+        vault: vault.pubkey,
+        authority: ctx.accounts.manager.key(),
+        old_config,
+        new_config: vault.get_config(None),
+    });
+
     drop(vault);
 
     Ok(())
@@ -97,6 +119,7 @@ pub struct UpdateVaultParams {
     pub profit_share: Option<u32>,
     pub hurdle_rate: Option<u32>,
     pub permissioned: Option<bool>,
+    pub exclude_pending_withdraws_from_fuel: Option<bool>,
 }
 
 #[derive(Accounts)]
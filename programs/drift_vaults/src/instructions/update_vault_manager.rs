@@ -1,3 +1,4 @@
+use crate::state::events::{VaultConfigAction, VaultConfigRecord};
 use crate::{error::ErrorCode, validate};
 use anchor_lang::prelude::*;
 
@@ -27,8 +28,19 @@ pub fn update_vault_manager<'info>(
         "Vault cannot be managerless"
     )?;
 
+    let old_config = vault.get_config(None);
+
     msg!("Updating vault manager {} -> {}", vault.manager, manager);
     vault.manager = manager;
 
+    emit!(VaultConfigRecord {
+        ts: Clock::get()?.unix_timestamp,
+        action: VaultConfigAction::UpdateVaultManager,
+        vault: vault.pubkey,
+        authority: ctx.accounts.manager.key(),
+        old_config,
+        new_config: vault.get_config(None),
+    });
+
     Ok(())
 }
@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
 
 use crate::constraints::{is_protocol_for_vault, is_vault_protocol_for_vault};
+use crate::state::events::{VaultConfig, VaultConfigAction, VaultConfigRecord};
 use crate::state::{Vault, VaultProtocol};
 use crate::{error::ErrorCode, validate};
 
@@ -16,6 +17,11 @@ pub fn update_vault_protocol<'c: 'info, 'info>(
     validate!(!vault.in_liquidation(), ErrorCode::OngoingLiquidation)?;
 
     if let Some(mut vp) = vp {
+        let old_config = VaultConfig {
+            vault_protocol: Some(vp.get_config()),
+            ..vault.get_config(None)
+        };
+
         if let Some(new_protocol_fee) = params.protocol_fee {
             validate!(
                 new_protocol_fee < vp.protocol_fee,
@@ -33,6 +39,18 @@ pub fn update_vault_protocol<'c: 'info, 'info>(
             )?;
             vp.protocol_profit_share = new_protocol_profit_share;
         }
+
+        emit!(VaultConfigRecord {
+            ts: Clock::get()?.unix_timestamp,
+            action: VaultConfigAction::UpdateVaultProtocol,
+            vault: vault.pubkey,
+            authority: ctx.accounts.protocol.key(),
+            old_config,
+            new_config: VaultConfig {
+                vault_protocol: Some(vp.get_config()),
+                ..vault.get_config(None)
+            },
+        });
     }
 
     drop(vault);
@@ -1,8 +1,11 @@
 use anchor_lang::prelude::*;
 use instructions::*;
+use spl_transfer_hook_interface::instruction::TransferHookInstruction;
 use state::*;
 
 mod constants;
+#[cfg(feature = "cpi")]
+pub mod depositor_cpi;
 mod drift_cpi;
 mod error;
 mod instructions;
@@ -93,6 +96,20 @@ pub mod drift_vaults {
         instructions::initialize_tokenized_vault_depositor(ctx, params)
     }
 
+    pub fn initialize_tokenized_vault_depositor_token_2022(
+        ctx: Context<InitializeTokenizedVaultDepositorToken2022>,
+        params: InitializeTokenizedVaultDepositorToken2022Params,
+    ) -> Result<()> {
+        instructions::initialize_tokenized_vault_depositor_token_2022(ctx, params)
+    }
+
+    pub fn update_tokenized_vault_depositor_metadata<'info>(
+        ctx: Context<'_, '_, '_, 'info, UpdateTokenizedVaultDepositorMetadata<'info>>,
+        params: UpdateTokenizedVaultDepositorMetadataParams,
+    ) -> Result<()> {
+        instructions::update_tokenized_vault_depositor_metadata(ctx, params)
+    }
+
     pub fn tokenize_shares<'info>(
         ctx: Context<'_, '_, 'info, 'info, TokenizeShares<'info>>,
         amount: u64,
@@ -108,6 +125,49 @@ pub mod drift_vaults {
         instructions::redeem_tokens(ctx, tokens_to_burn)
     }
 
+    pub fn redeem_tokens_v2<'info>(
+        ctx: Context<'_, '_, 'info, 'info, RedeemTokensV2<'info>>,
+        tokens_to_burn: u64,
+    ) -> Result<()> {
+        instructions::redeem_tokens_v2(ctx, tokens_to_burn)
+    }
+
+    pub fn transfer_hook<'info>(
+        ctx: Context<'_, '_, '_, 'info, TransferHook<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::transfer_hook(ctx, amount)
+    }
+
+    pub fn deposit_and_mint<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, DepositAndMint<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::deposit_and_mint(ctx, amount)
+    }
+
+    pub fn request_redeem<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, RequestRedeem<'info>>,
+        tokens_to_burn: u64,
+        request_id: u16,
+    ) -> Result<()> {
+        instructions::request_redeem(ctx, tokens_to_burn, request_id)
+    }
+
+    pub fn cancel_redeem_request<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, CancelRedeemRequest<'info>>,
+        request_id: u16,
+    ) -> Result<()> {
+        instructions::cancel_redeem_request(ctx, request_id)
+    }
+
+    pub fn redeem_tokens_for_underlying<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, RedeemTokensForUnderlying<'info>>,
+        request_id: u16,
+    ) -> Result<()> {
+        instructions::redeem_tokens_for_underlying(ctx, request_id)
+    }
+
     pub fn deposit<'c: 'info, 'info>(
         ctx: Context<'_, '_, 'c, 'info, Deposit<'info>>,
         amount: u64,
@@ -123,6 +183,13 @@ pub mod drift_vaults {
         instructions::request_withdraw(ctx, withdraw_amount, withdraw_unit)
     }
 
+    pub fn lock_vault_depositor<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, LockVaultDepositor<'info>>,
+        lockup_period: FuelLockupPeriod,
+    ) -> Result<()> {
+        instructions::lock_vault_depositor(ctx, lockup_period)
+    }
+
     pub fn cancel_request_withdraw<'c: 'info, 'info>(
         ctx: Context<'_, '_, 'c, 'info, CancelWithdrawRequest<'info>>,
     ) -> Result<()> {
@@ -159,6 +226,18 @@ pub mod drift_vaults {
         instructions::reset_vault_fuel_season(ctx)
     }
 
+    pub fn settle_fuel<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, SettleFuel<'info>>,
+    ) -> Result<()> {
+        instructions::settle_fuel(ctx)
+    }
+
+    pub fn remove_expired_fuel_boost<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, RemoveExpiredFuelBoost<'info>>,
+    ) -> Result<()> {
+        instructions::remove_expired_fuel_boost(ctx)
+    }
+
     pub fn manager_borrow<'c: 'info, 'info>(
         ctx: Context<'_, '_, 'c, 'info, ManagerBorrow<'info>>,
         borrow_spot_market_index: u16,
@@ -217,6 +296,158 @@ pub mod drift_vaults {
         instructions::manager_update_fuel_distribution_mode(ctx, fuel_distribution_mode)
     }
 
+    pub fn initialize_fuel_distribution<'info>(
+        ctx: Context<'_, '_, '_, 'info, InitializeFuelDistribution<'info>>,
+    ) -> Result<()> {
+        instructions::initialize_fuel_distribution(ctx)
+    }
+
+    pub fn manager_update_fuel_distribution<'info>(
+        ctx: Context<'_, '_, '_, 'info, ManagerUpdateFuelDistribution<'info>>,
+        params: ManagerUpdateFuelDistributionParams,
+    ) -> Result<()> {
+        instructions::manager_update_fuel_distribution(ctx, params)
+    }
+
+    pub fn update_fuel_distribution<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, UpdateFuelDistribution<'info>>,
+    ) -> Result<()> {
+        instructions::update_fuel_distribution(ctx)
+    }
+
+    pub fn initialize_nav_policy<'info>(
+        ctx: Context<'_, '_, '_, 'info, InitializeNavPolicy<'info>>,
+        params: NavPolicyParams,
+    ) -> Result<()> {
+        instructions::initialize_nav_policy(ctx, params)
+    }
+
+    pub fn manager_update_nav_policy<'info>(
+        ctx: Context<'_, '_, '_, 'info, ManagerUpdateNavPolicy<'info>>,
+        params: NavPolicyParams,
+    ) -> Result<()> {
+        instructions::manager_update_nav_policy(ctx, params)
+    }
+
+    pub fn reset_nav_circuit_breaker<'info>(
+        ctx: Context<'_, '_, '_, 'info, ResetNavCircuitBreaker<'info>>,
+    ) -> Result<()> {
+        instructions::reset_nav_circuit_breaker(ctx)
+    }
+
+    pub fn trip_nav_breaker<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, TripNavBreaker<'info>>,
+    ) -> Result<()> {
+        instructions::trip_nav_breaker(ctx)
+    }
+
+    pub fn check_max_drawdown<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, CheckMaxDrawdown<'info>>,
+    ) -> Result<()> {
+        instructions::check_max_drawdown(ctx)
+    }
+
+    pub fn manager_reenable_trading<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, ManagerReenableTrading<'info>>,
+    ) -> Result<()> {
+        instructions::manager_reenable_trading(ctx)
+    }
+
+    pub fn depositor_reenable_trading<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, DepositorReenableTrading<'info>>,
+    ) -> Result<()> {
+        instructions::depositor_reenable_trading(ctx)
+    }
+
+    pub fn initialize_nav_history<'info>(
+        ctx: Context<'_, '_, '_, 'info, InitializeNavHistory<'info>>,
+    ) -> Result<()> {
+        instructions::initialize_nav_history(ctx)
+    }
+
+    pub fn snapshot_vault<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, SnapshotVault<'info>>,
+    ) -> Result<()> {
+        instructions::snapshot_vault(ctx)
+    }
+
+    pub fn initialize_manager_stats<'info>(
+        ctx: Context<'_, '_, '_, 'info, InitializeManagerStats<'info>>,
+    ) -> Result<()> {
+        instructions::initialize_manager_stats(ctx)
+    }
+
+    pub fn update_manager_stats<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, UpdateManagerStats<'info>>,
+    ) -> Result<()> {
+        instructions::update_manager_stats(ctx)
+    }
+
+    pub fn preview_deposit<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, PreviewDeposit<'info>>,
+        amount: u64,
+    ) -> Result<DepositPreview> {
+        instructions::preview_deposit(ctx, amount)
+    }
+
+    pub fn preview_withdraw<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, PreviewWithdraw<'info>>,
+        withdraw_amount: u64,
+        withdraw_unit: WithdrawUnit,
+    ) -> Result<WithdrawPreview> {
+        instructions::preview_withdraw(ctx, withdraw_amount, withdraw_unit)
+    }
+
+    pub fn preview_depositor_equity<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, PreviewDepositorEquity<'info>>,
+    ) -> Result<DepositorEquityPreview> {
+        instructions::preview_depositor_equity(ctx)
+    }
+
+    pub fn preview_fees<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, PreviewFees<'info>>,
+    ) -> Result<FeesPreview> {
+        instructions::preview_fees(ctx)
+    }
+
+    pub fn initialize_reward_stream<'info>(
+        ctx: Context<'_, '_, '_, 'info, InitializeRewardStream<'info>>,
+        params: InitializeRewardStreamParams,
+    ) -> Result<()> {
+        instructions::initialize_reward_stream(ctx, params)
+    }
+
+    pub fn fund_reward_stream<'info>(
+        ctx: Context<'_, '_, '_, 'info, FundRewardStream<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::fund_reward_stream(ctx, amount)
+    }
+
+    pub fn close_reward_stream<'info>(
+        ctx: Context<'_, '_, '_, 'info, CloseRewardStream<'info>>,
+    ) -> Result<()> {
+        instructions::close_reward_stream(ctx)
+    }
+
+    pub fn initialize_reward_stream_depositor<'info>(
+        ctx: Context<'_, '_, '_, 'info, InitializeRewardStreamDepositor<'info>>,
+    ) -> Result<()> {
+        instructions::initialize_reward_stream_depositor(ctx)
+    }
+
+    pub fn close_reward_stream_depositor<'info>(
+        ctx: Context<'_, '_, '_, 'info, CloseRewardStreamDepositor<'info>>,
+    ) -> Result<()> {
+        instructions::close_reward_stream_depositor(ctx)
+    }
+
+    pub fn claim_reward_stream<'info>(
+        ctx: Context<'_, '_, '_, 'info, ClaimRewardStream<'info>>,
+    ) -> Result<()> {
+        instructions::claim_reward_stream(ctx)
+    }
+
     pub fn admin_init_fee_update<'c: 'info, 'info>(
         ctx: Context<'_, '_, 'c, 'info, AdminInitFeeUpdate<'info>>,
     ) -> Result<()> {
@@ -337,4 +568,19 @@ pub mod drift_vaults {
     ) -> Result<()> {
         instructions::protocol_withdraw(ctx)
     }
+
+    /// Token-2022 invokes transfer hooks with the transfer hook interface's execute instruction,
+    /// which doesn't use an anchor discriminator, so route it to transfer_hook here.
+    pub fn fallback<'info>(
+        program_id: &Pubkey,
+        accounts: &'info [AccountInfo<'info>],
+        data: &[u8],
+    ) -> Result<()> {
+        match TransferHookInstruction::unpack(data)? {
+            TransferHookInstruction::Execute { amount } => {
+                __private::__global::transfer_hook(program_id, accounts, &amount.to_le_bytes())
+            }
+            _ => Err(ProgramError::InvalidInstructionData.into()),
+        }
+    }
 }
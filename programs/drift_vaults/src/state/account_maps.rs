@@ -1,12 +1,17 @@
 use anchor_lang::prelude::Context;
-use drift::error::DriftResult;
+use anchor_lang::Discriminator;
+use drift::error::{DriftResult, ErrorCode as DriftErrorCode};
 use drift::instructions::optional_accounts::{load_maps, AccountMaps};
 use drift::state::spot_market_map::get_writable_spot_market_set;
 use drift::state::user::FuelOverflow;
 use std::collections::BTreeSet;
 
-use crate::state::FeeUpdate;
-use crate::state::VaultProtocol;
+use crate::error::ErrorCode;
+use crate::state::{
+    FeeUpdate, FeeUpdateStatus, FuelDistribution, ManagerStats, NavHistory, NavPolicy,
+    RewardStream, RewardStreamDepositor, Vault, VaultProtocol,
+};
+use crate::validate;
 use anchor_lang::prelude::*;
 
 pub trait AccountMapProvider<'a> {
@@ -14,9 +19,6 @@ pub trait AccountMapProvider<'a> {
         &self,
         slot: u64,
         writable_spot_market: Option<u16>,
-        has_vault_protocol: bool,
-        has_fuel_overflow: bool,
-        has_fee_update: bool,
     ) -> DriftResult<AccountMaps<'a>>;
 }
 
@@ -27,18 +29,32 @@ impl<'a: 'info, 'info, T: anchor_lang::Bumps> AccountMapProvider<'a>
         &self,
         slot: u64,
         writable_spot_market_index: Option<u16>,
-        has_vault_protocol: bool,
-        has_fuel_overflow: bool,
-        has_fee_update: bool,
     ) -> DriftResult<AccountMaps<'a>> {
-        // if [`VaultProtocol`] exists it will be the last index in the remaining_accounts, so we need to skip it.
-        let mut end_index = self.remaining_accounts.len() - (has_vault_protocol as usize);
-        // if there is a [`FuelOverflow`], we need to skip one more account
-        end_index -= has_fuel_overflow as usize;
-        // if there is a [`FeeUpdate`], we need to skip one more account
-        end_index -= has_fee_update as usize;
-
-        let remaining_accounts_iter = &mut self.remaining_accounts[..end_index].iter().peekable();
+        let remaining_accounts: &'a [AccountInfo<'info>] = self.remaining_accounts;
+
+        // the maps stop loading at the first account they don't recognize, so the tagged accounts
+        // must come before or after the oracles and markets, not in between
+        let start_index = remaining_accounts
+            .iter()
+            .position(|acct| !TaggedAccount::is_tagged(acct))
+            .unwrap_or(remaining_accounts.len());
+        let end_index = remaining_accounts
+            .iter()
+            .rposition(|acct| !TaggedAccount::is_tagged(acct))
+            .map_or(start_index, |idx| idx + 1);
+        let market_accounts = &remaining_accounts[start_index..end_index];
+        if let Some(acct) = market_accounts
+            .iter()
+            .find(|acct| TaggedAccount::is_tagged(acct))
+        {
+            msg!(
+                "remaining account {} must come before or after the oracle and market accounts",
+                acct.key()
+            );
+            return Err(DriftErrorCode::CouldNotLoadMarketData);
+        }
+
+        let remaining_accounts_iter = &mut market_accounts.iter().peekable();
         load_maps(
             remaining_accounts_iter,
             &BTreeSet::new(),
@@ -51,97 +67,514 @@ impl<'a: 'info, 'info, T: anchor_lang::Bumps> AccountMapProvider<'a>
     }
 }
 
-pub trait VaultProtocolProvider<'a> {
-    fn vault_protocol(&self) -> Option<AccountLoader<'a, VaultProtocol>>;
+/// The optional accounts a vault instruction can take in remaining_accounts. They are identified by
+/// discriminator and expected pda, so clients can pass them in any order before or after the oracle and
+/// market accounts.
+#[derive(Default)]
+pub struct VaultRemainingAccounts<'a> {
+    /// The vault's [`VaultProtocol`], if one was passed
+    pub vault_protocol: Option<AccountLoader<'a, VaultProtocol>>,
+    /// The [`FuelOverflow`] of the vault's drift user stats, if one was passed
+    pub fuel_overflow: Option<AccountLoader<'a, FuelOverflow>>,
+    /// The vault's [`FeeUpdate`], only provided while the vault has a pending fee update
+    pub fee_update: Option<AccountLoader<'a, FeeUpdate>>,
+    /// The vault's [`NavPolicy`], if one was passed
+    pub nav_policy: Option<AccountLoader<'a, NavPolicy>>,
+    /// The vault's [`NavHistory`], if one was passed. Must be writable
+    pub nav_history: Option<AccountLoader<'a, NavHistory>>,
+    /// The vault's [`ManagerStats`], if one was passed. Must be writable
+    pub manager_stats: Option<AccountLoader<'a, ManagerStats>>,
+    /// The vault's [`FuelDistribution`], if one was passed. Must be writable
+    pub fuel_distribution: Option<AccountLoader<'a, FuelDistribution>>,
+    /// The vault's [`RewardStream`]s that were passed. Must be writable
+    pub reward_streams: Vec<AccountLoader<'a, RewardStream>>,
+    /// The [`RewardStreamDepositor`]s that were passed, see [`settle_reward_streams`]. Must be writable
+    ///
+    /// [`settle_reward_streams`]: crate::state::settle_reward_streams
+    pub reward_stream_depositors: Vec<AccountLoader<'a, RewardStreamDepositor>>,
 }
 
-/// Provides the last remaining account as a [`VaultProtocol`].
-impl<'a: 'info, 'info, T: anchor_lang::Bumps> VaultProtocolProvider<'a>
-    for Context<'_, '_, 'a, 'info, T>
-{
-    fn vault_protocol(&self) -> Option<AccountLoader<'a, VaultProtocol>> {
-        let acct = match self.remaining_accounts.last() {
-            Some(acct) => acct,
-            None => return None,
-        };
-        AccountLoader::<'a, VaultProtocol>::try_from(acct).ok()
-    }
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum TaggedAccount {
+    VaultProtocol,
+    FuelOverflow,
+    FeeUpdate,
+    NavPolicy,
+    NavHistory,
+    ManagerStats,
+    FuelDistribution,
+    RewardStream,
+    RewardStreamDepositor,
 }
 
-pub trait FuelOverflowProvider<'a> {
-    fn fuel_overflow(
-        &self,
-        has_vp: bool,
-        has_fuel_overflow: bool,
-    ) -> Option<AccountLoader<'a, FuelOverflow>>;
-}
+impl TaggedAccount {
+    fn from_account_info(acct: &AccountInfo) -> Option<Self> {
+        let data = acct.try_borrow_data().ok()?;
+        if data.len() < 8 {
+            return None;
+        }
+        let discriminator = &data[..8];
 
-/// Provides [`FuelOverflow`] from remaining_accounts, respects whether the vault has a VaultProtocol.
-impl<'a: 'info, 'info, T: anchor_lang::Bumps> FuelOverflowProvider<'a>
-    for Context<'_, '_, 'a, 'info, T>
-{
-    fn fuel_overflow(
-        &self,
-        has_vp: bool,
-        has_fuel_overflow: bool,
-    ) -> Option<AccountLoader<'a, FuelOverflow>> {
-        if !has_fuel_overflow {
+        if acct.owner == &crate::id() {
+            if discriminator == VaultProtocol::discriminator() {
+                Some(TaggedAccount::VaultProtocol)
+            } else if discriminator == FeeUpdate::discriminator() {
+                Some(TaggedAccount::FeeUpdate)
+            } else if discriminator == NavPolicy::discriminator() {
+                Some(TaggedAccount::NavPolicy)
+            } else if discriminator == NavHistory::discriminator() {
+                Some(TaggedAccount::NavHistory)
+            } else if discriminator == ManagerStats::discriminator() {
+                Some(TaggedAccount::ManagerStats)
+            } else if discriminator == FuelDistribution::discriminator() {
+                Some(TaggedAccount::FuelDistribution)
+            } else if discriminator == RewardStream::discriminator() {
+                Some(TaggedAccount::RewardStream)
+            } else if discriminator == RewardStreamDepositor::discriminator() {
+                Some(TaggedAccount::RewardStreamDepositor)
+            } else {
+                None
+            }
+        } else if acct.owner == &drift::id() && discriminator == FuelOverflow::discriminator() {
+            Some(TaggedAccount::FuelOverflow)
+        } else {
             None
+        }
+    }
+
+    /// Accounts the instruction already loaded mutably can't be read. The instruction only loads tagged
+    /// accounts from remaining_accounts, so those owned by this program are tagged.
+    fn is_tagged(acct: &AccountInfo) -> bool {
+        (acct.owner == &crate::id() && acct.try_borrow_data().is_err())
+            || TaggedAccount::from_account_info(acct).is_some()
+    }
+
+    /// Checks the account is the vault's pda for this account type, derived with the bump stored in
+    /// the account. FeeUpdates created before their bump was stored have a bump of 0 and are searched for.
+    fn validate_pda(&self, acct: &AccountInfo, vault: &Pubkey, bump: u8) -> Result<()> {
+        let seed = match self {
+            TaggedAccount::VaultProtocol => b"vault_protocol".as_ref(),
+            TaggedAccount::FeeUpdate => b"fee_update".as_ref(),
+            TaggedAccount::NavPolicy => b"nav_policy".as_ref(),
+            TaggedAccount::NavHistory => b"nav_history".as_ref(),
+            TaggedAccount::ManagerStats => b"manager_stats".as_ref(),
+            TaggedAccount::FuelDistribution => b"fuel_distribution".as_ref(),
+            // not vault pdas, they are validated by the vault or authority they store
+            TaggedAccount::FuelOverflow
+            | TaggedAccount::RewardStream
+            | TaggedAccount::RewardStreamDepositor => return Ok(()),
+        };
+
+        let expected = if bump == 0 {
+            Some(Pubkey::find_program_address(&[seed, vault.as_ref()], &crate::id()).0)
         } else {
-            let acct_idx = if has_vp {
-                // if there is a [`VaultProtocol`], the [`FuelOverflow`] is the second to last account
-                self.remaining_accounts.len() - 2
-            } else {
-                // otherwise [`FuelOverflow`] is the last account
-                self.remaining_accounts.len() - 1
-            };
-            let acct = self.remaining_accounts.get(acct_idx)?;
+            Pubkey::create_program_address(&[seed, vault.as_ref(), &[bump]], &crate::id()).ok()
+        };
+
+        if expected != Some(acct.key()) {
+            return Err(
+                anchor_lang::error::Error::from(error::ErrorCode::ConstraintSeeds)
+                    .with_account_name(self.account_name()),
+            );
+        }
 
-            AccountLoader::<'a, FuelOverflow>::try_from(acct).ok()
+        Ok(())
+    }
+
+    fn account_name(&self) -> &'static str {
+        match self {
+            TaggedAccount::VaultProtocol => "vault_protocol",
+            TaggedAccount::FuelOverflow => "fuel_overflow",
+            TaggedAccount::FeeUpdate => "fee_update",
+            TaggedAccount::NavPolicy => "nav_policy",
+            TaggedAccount::NavHistory => "nav_history",
+            TaggedAccount::ManagerStats => "manager_stats",
+            TaggedAccount::FuelDistribution => "fuel_distribution",
+            TaggedAccount::RewardStream => "reward_stream",
+            TaggedAccount::RewardStreamDepositor => "reward_stream_depositor",
         }
     }
 }
 
-pub trait FeeUpdateProvider<'a> {
-    fn fee_update(
-        &self,
-        has_vp: bool,
-        has_fuel_overflow: bool,
-        has_fee_update: bool,
-    ) -> Option<AccountLoader<'a, FeeUpdate>>;
+pub trait VaultRemainingAccountsProvider<'a> {
+    fn vault_remaining_accounts(&self, vault: &Vault) -> Result<VaultRemainingAccounts<'a>>;
 }
 
-/// Provides [`FeeUpdate`] from remaining_accounts, respects whether the vault has a VaultProtocol and FuelOverflow.
-impl<'a: 'info, 'info, T: anchor_lang::Bumps> FeeUpdateProvider<'a>
+impl<'a: 'info, 'info, T: anchor_lang::Bumps> VaultRemainingAccountsProvider<'a>
     for Context<'_, '_, 'a, 'info, T>
 {
-    fn fee_update(
-        &self,
-        has_vp: bool,
-        has_fuel_overflow: bool,
-        has_fee_update: bool,
-    ) -> Option<AccountLoader<'a, FeeUpdate>> {
-        if !has_fee_update {
-            None
-        } else {
-            let acct_idx = if has_vp {
-                if has_fuel_overflow {
-                    // if there is a [`VaultProtocol`] and [`FuelOverflow`], the [`FeeUpdate`] is the third to last account
-                    self.remaining_accounts.len() - 3
-                } else {
-                    // if there is only a [`VaultProtocol`], the [`FeeUpdate`] is the second to last account
-                    self.remaining_accounts.len() - 2
+    fn vault_remaining_accounts(&self, vault: &Vault) -> Result<VaultRemainingAccounts<'a>> {
+        get_vault_remaining_accounts(self.remaining_accounts, vault)
+    }
+}
+
+/// Resolves the [`VaultRemainingAccounts`] from remaining_accounts, regardless of their position.
+fn get_vault_remaining_accounts<'a: 'info, 'info>(
+    remaining_accounts: &'a [AccountInfo<'info>],
+    vault: &Vault,
+) -> Result<VaultRemainingAccounts<'a>> {
+    let mut accounts = VaultRemainingAccounts::default();
+
+    for acct in remaining_accounts.iter() {
+        let tagged_account = match TaggedAccount::from_account_info(acct) {
+            Some(tagged_account) => tagged_account,
+            None => continue,
+        };
+
+        match tagged_account {
+            TaggedAccount::VaultProtocol => {
+                let vault_protocol: AccountLoader<VaultProtocol> = AccountLoader::try_from(acct)?;
+                let bump = vault_protocol.load()?.bump;
+                tagged_account.validate_pda(acct, &vault.pubkey, bump)?;
+                accounts.vault_protocol = Some(vault_protocol);
+            }
+            TaggedAccount::FuelOverflow => {
+                let fuel_overflow: AccountLoader<FuelOverflow> = AccountLoader::try_from(acct)?;
+                // the vault is the authority of its drift user stats
+                let authority = fuel_overflow.load()?.authority;
+                if authority != vault.pubkey {
+                    return Err(anchor_lang::error::Error::from(
+                        error::ErrorCode::ConstraintHasOne,
+                    )
+                    .with_account_name(tagged_account.account_name())
+                    .with_pubkeys((authority, vault.pubkey)));
                 }
-            } else if has_fuel_overflow {
-                // if there is only a [`FuelOverflow`], the [`FeeUpdate`] is the second to last account
-                self.remaining_accounts.len() - 2
-            } else {
-                // otherwise [`FeeUpdate`] is the last account
-                self.remaining_accounts.len() - 1
-            };
-            let acct = self.remaining_accounts.get(acct_idx)?;
+                accounts.fuel_overflow = Some(fuel_overflow);
+            }
+            TaggedAccount::FeeUpdate => {
+                let fee_update: AccountLoader<FeeUpdate> = AccountLoader::try_from(acct)?;
+                let bump = fee_update.load()?.bump;
+                tagged_account.validate_pda(acct, &vault.pubkey, bump)?;
+                // a FeeUpdate outlives the update it was created for, only use it while one is pending
+                if FeeUpdateStatus::has_pending_fee_update(vault.fee_update_status) {
+                    accounts.fee_update = Some(fee_update);
+                }
+            }
+            TaggedAccount::NavPolicy => {
+                let nav_policy: AccountLoader<NavPolicy> = AccountLoader::try_from(acct)?;
+                let bump = nav_policy.load()?.bump;
+                tagged_account.validate_pda(acct, &vault.pubkey, bump)?;
+                accounts.nav_policy = Some(nav_policy);
+            }
+            TaggedAccount::NavHistory => {
+                let nav_history: AccountLoader<NavHistory> = AccountLoader::try_from(acct)?;
+                let bump = nav_history.load()?.bump;
+                tagged_account.validate_pda(acct, &vault.pubkey, bump)?;
+                accounts.nav_history = Some(nav_history);
+            }
+            TaggedAccount::ManagerStats => {
+                let manager_stats: AccountLoader<ManagerStats> = AccountLoader::try_from(acct)?;
+                let bump = manager_stats.load()?.bump;
+                tagged_account.validate_pda(acct, &vault.pubkey, bump)?;
+                accounts.manager_stats = Some(manager_stats);
+            }
+            TaggedAccount::FuelDistribution => {
+                let fuel_distribution: AccountLoader<FuelDistribution> =
+                    AccountLoader::try_from(acct)?;
+                let bump = fuel_distribution.load()?.bump;
+                tagged_account.validate_pda(acct, &vault.pubkey, bump)?;
+                accounts.fuel_distribution = Some(fuel_distribution);
+            }
+            TaggedAccount::RewardStream => {
+                let reward_stream: AccountLoader<RewardStream> = AccountLoader::try_from(acct)?;
+                validate!(
+                    reward_stream.load()?.vault == vault.pubkey,
+                    ErrorCode::InvalidRewardStream,
+                    "reward stream {} is not for this vault",
+                    acct.key()
+                )?;
+                if !accounts
+                    .reward_streams
+                    .iter()
+                    .any(|passed| passed.key() == acct.key())
+                {
+                    accounts.reward_streams.push(reward_stream);
+                }
+            }
+            TaggedAccount::RewardStreamDepositor => {
+                // passing a depositor twice must not count it twice
+                if !accounts
+                    .reward_stream_depositors
+                    .iter()
+                    .any(|passed| passed.key() == acct.key())
+                {
+                    accounts
+                        .reward_stream_depositors
+                        .push(AccountLoader::try_from(acct)?);
+                }
+            }
+        }
+    }
+
+    Ok(accounts)
+}
 
-            AccountLoader::<'a, FeeUpdate>::try_from(acct).ok()
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::create_account_info;
+
+    struct TestAccount {
+        key: Pubkey,
+        owner: Pubkey,
+        lamports: u64,
+        data: Vec<u8>,
+    }
+
+    impl TestAccount {
+        fn new<T: Discriminator + bytemuck::Pod>(key: Pubkey, owner: Pubkey, account: &T) -> Self {
+            let mut data = T::discriminator().to_vec();
+            data.extend_from_slice(bytemuck::bytes_of(account));
+            TestAccount {
+                key,
+                owner,
+                lamports: 0,
+                data,
+            }
+        }
+
+        /// A vault pda account of this program, with its bump stored like the real accounts do
+        fn vault_pda<T: Discriminator + bytemuck::Pod>(
+            seed: &[u8],
+            vault: &Pubkey,
+            account: impl FnOnce(u8) -> T,
+        ) -> Self {
+            let (key, bump) = Pubkey::find_program_address(&[seed, vault.as_ref()], &crate::id());
+            TestAccount::new(key, crate::id(), &account(bump))
+        }
+
+        fn account_info(&mut self) -> AccountInfo {
+            create_account_info(
+                &self.key,
+                true,
+                &mut self.lamports,
+                &mut self.data,
+                &self.owner,
+            )
+        }
+    }
+
+    fn test_vault() -> Vault {
+        Vault {
+            pubkey: Pubkey::new_unique(),
+            ..Vault::default()
         }
     }
+
+    fn vault_protocol(vault: &Vault) -> TestAccount {
+        TestAccount::vault_pda(b"vault_protocol", &vault.pubkey, |bump| VaultProtocol {
+            bump,
+            ..VaultProtocol::default()
+        })
+    }
+
+    fn fee_update(vault: &Vault) -> TestAccount {
+        TestAccount::vault_pda(b"fee_update", &vault.pubkey, |bump| FeeUpdate {
+            bump,
+            ..FeeUpdate::default()
+        })
+    }
+
+    fn nav_policy(vault: &Vault) -> TestAccount {
+        TestAccount::vault_pda(b"nav_policy", &vault.pubkey, |bump| NavPolicy {
+            vault: vault.pubkey,
+            bump,
+            ..NavPolicy::default()
+        })
+    }
+
+    fn reward_stream(key: Pubkey, vault: &Pubkey) -> TestAccount {
+        TestAccount::new(
+            key,
+            crate::id(),
+            &RewardStream {
+                vault: *vault,
+                ..RewardStream::default()
+            },
+        )
+    }
+
+    fn fuel_overflow(authority: &Pubkey) -> TestAccount {
+        let mut fuel_overflow: FuelOverflow = bytemuck::Zeroable::zeroed();
+        fuel_overflow.authority = *authority;
+        TestAccount::new(Pubkey::new_unique(), drift::id(), &fuel_overflow)
+    }
+
+    /// Stands in for the oracle and market accounts, which aren't tagged
+    fn market_account() -> TestAccount {
+        TestAccount {
+            key: Pubkey::new_unique(),
+            owner: drift::id(),
+            lamports: 0,
+            data: vec![1; 64],
+        }
+    }
+
+    #[test]
+    fn test_missing_accounts() {
+        let vault = test_vault();
+
+        let accounts = get_vault_remaining_accounts(&[], &vault).unwrap();
+        assert!(accounts.vault_protocol.is_none());
+        assert!(accounts.fuel_overflow.is_none());
+        assert!(accounts.fee_update.is_none());
+        assert!(accounts.nav_policy.is_none());
+        assert!(accounts.nav_history.is_none());
+        assert!(accounts.manager_stats.is_none());
+        assert!(accounts.fuel_distribution.is_none());
+        assert!(accounts.reward_streams.is_empty());
+        assert!(accounts.reward_stream_depositors.is_empty());
+
+        // only the market accounts
+        let mut test_accounts = [market_account(), market_account()];
+        let infos: Vec<AccountInfo> = test_accounts
+            .iter_mut()
+            .map(|acct| acct.account_info())
+            .collect();
+        let accounts = get_vault_remaining_accounts(&infos, &vault).unwrap();
+        assert!(accounts.vault_protocol.is_none());
+        assert!(accounts.nav_policy.is_none());
+
+        // a FeeUpdate is ignored without a pending fee update
+        let mut test_accounts = [fee_update(&vault)];
+        let infos: Vec<AccountInfo> = test_accounts
+            .iter_mut()
+            .map(|acct| acct.account_info())
+            .collect();
+        let accounts = get_vault_remaining_accounts(&infos, &vault).unwrap();
+        assert!(accounts.fee_update.is_none());
+    }
+
+    #[test]
+    fn test_out_of_order_accounts() {
+        let mut vault = test_vault();
+        vault.fee_update_status = FeeUpdateStatus::PendingFeeUpdate as u8;
+        let reward_stream_key = Pubkey::new_unique();
+
+        let mut test_accounts = [
+            reward_stream(reward_stream_key, &vault.pubkey),
+            nav_policy(&vault),
+            market_account(),
+            fuel_overflow(&vault.pubkey),
+            market_account(),
+            fee_update(&vault),
+            vault_protocol(&vault),
+        ];
+        let expected_keys: Vec<Pubkey> = test_accounts.iter().map(|acct| acct.key).collect();
+        let infos: Vec<AccountInfo> = test_accounts
+            .iter_mut()
+            .map(|acct| acct.account_info())
+            .collect();
+
+        let accounts = get_vault_remaining_accounts(&infos, &vault).unwrap();
+        assert_eq!(
+            accounts
+                .reward_streams
+                .iter()
+                .map(|rs| rs.key())
+                .collect::<Vec<_>>(),
+            vec![reward_stream_key]
+        );
+        assert_eq!(accounts.nav_policy.unwrap().key(), expected_keys[1]);
+        assert_eq!(accounts.fuel_overflow.unwrap().key(), expected_keys[3]);
+        assert_eq!(accounts.fee_update.unwrap().key(), expected_keys[5]);
+        assert_eq!(accounts.vault_protocol.unwrap().key(), expected_keys[6]);
+        assert!(accounts.manager_stats.is_none());
+    }
+
+    #[test]
+    fn test_duplicate_accounts() {
+        let vault = test_vault();
+        let reward_stream_key = Pubkey::new_unique();
+        let other_reward_stream_key = Pubkey::new_unique();
+        let depositor_key = Pubkey::new_unique();
+        let depositor = RewardStreamDepositor {
+            reward_stream: reward_stream_key,
+            ..RewardStreamDepositor::default()
+        };
+
+        let mut test_accounts = [
+            reward_stream(reward_stream_key, &vault.pubkey),
+            TestAccount::new(depositor_key, crate::id(), &depositor),
+            nav_policy(&vault),
+            reward_stream(other_reward_stream_key, &vault.pubkey),
+            reward_stream(reward_stream_key, &vault.pubkey),
+            TestAccount::new(depositor_key, crate::id(), &depositor),
+            nav_policy(&vault),
+        ];
+        let infos: Vec<AccountInfo> = test_accounts
+            .iter_mut()
+            .map(|acct| acct.account_info())
+            .collect();
+
+        // passing an account twice doesn't count it twice
+        let accounts = get_vault_remaining_accounts(&infos, &vault).unwrap();
+        assert_eq!(
+            accounts
+                .reward_streams
+                .iter()
+                .map(|rs| rs.key())
+                .collect::<Vec<_>>(),
+            vec![reward_stream_key, other_reward_stream_key]
+        );
+        assert_eq!(accounts.reward_stream_depositors.len(), 1);
+        assert_eq!(accounts.reward_stream_depositors[0].key(), depositor_key);
+        assert!(accounts.nav_policy.is_some());
+    }
+
+    #[test]
+    fn test_wrong_pda_accounts() {
+        let vault = test_vault();
+        let other_vault = test_vault();
+
+        // another vault's nav policy
+        let mut test_accounts = [nav_policy(&other_vault)];
+        let infos: Vec<AccountInfo> = test_accounts
+            .iter_mut()
+            .map(|acct| acct.account_info())
+            .collect();
+        assert!(get_vault_remaining_accounts(&infos, &vault).is_err());
+
+        // a vault protocol at an address that isn't its pda
+        let mut protocol = vault_protocol(&vault);
+        protocol.key = Pubkey::new_unique();
+        let mut test_accounts = [protocol];
+        let infos: Vec<AccountInfo> = test_accounts
+            .iter_mut()
+            .map(|acct| acct.account_info())
+            .collect();
+        assert!(get_vault_remaining_accounts(&infos, &vault).is_err());
+
+        // the right pda with the wrong stored bump
+        let mut test_accounts = [TestAccount::vault_pda(
+            b"nav_policy",
+            &vault.pubkey,
+            |bump| NavPolicy {
+                vault: vault.pubkey,
+                bump: bump.wrapping_sub(1),
+                ..NavPolicy::default()
+            },
+        )];
+        let infos: Vec<AccountInfo> = test_accounts
+            .iter_mut()
+            .map(|acct| acct.account_info())
+            .collect();
+        assert!(get_vault_remaining_accounts(&infos, &vault).is_err());
+
+        // another vault's fuel overflow
+        let mut test_accounts = [fuel_overflow(&other_vault.pubkey)];
+        let infos: Vec<AccountInfo> = test_accounts
+            .iter_mut()
+            .map(|acct| acct.account_info())
+            .collect();
+        assert!(get_vault_remaining_accounts(&infos, &vault).is_err());
+
+        // another vault's reward stream
+        let mut test_accounts = [reward_stream(Pubkey::new_unique(), &other_vault.pubkey)];
+        let infos: Vec<AccountInfo> = test_accounts
+            .iter_mut()
+            .map(|acct| acct.account_info())
+            .collect();
+        assert!(get_vault_remaining_accounts(&infos, &vault).is_err());
+    }
 }
@@ -5,10 +5,14 @@ use borsh::{BorshDeserialize, BorshSerialize};
 #[derive(Default)]
 pub struct VaultRecord {
     pub ts: i64,
+    pub vault: Pubkey,
     pub spot_market_index: u16,
     pub vault_equity_before: u64,
+    pub total_shares: u128,
+    pub shares_base: u32,
 }
 
+/// No longer emitted, replaced by [`VaultDepositorV2Record`]. Kept to decode older transactions.
 #[event]
 #[derive(Default)]
 pub struct VaultDepositorRecord {
@@ -38,6 +42,7 @@ pub struct VaultDepositorRecord {
     pub deposit_oracle_price: i64,
 }
 
+/// No longer emitted, replaced by [`VaultDepositorV2Record`]. Kept to decode older transactions.
 #[event]
 #[derive(Default)]
 pub struct VaultDepositorV1Record {
@@ -74,6 +79,57 @@ pub struct VaultDepositorV1Record {
     pub deposit_oracle_price: i64,
 }
 
+/// Emitted for every change to a depositor's shares, whether or not the vault has a VaultProtocol
+#[event]
+#[derive(Default)]
+pub struct VaultDepositorV2Record {
+    pub ts: i64,
+    pub vault: Pubkey,
+    pub depositor_authority: Pubkey,
+    pub action: VaultDepositorAction,
+    pub amount: u64,
+
+    pub spot_market_index: u16,
+    /// The shares below are in this base
+    pub shares_base: u32,
+    pub vault_shares_before: u128,
+    pub vault_shares_after: u128,
+
+    /// Includes manager_borrowed_value
+    pub vault_equity_before: u64,
+    pub manager_borrowed_value: u64,
+    /// See [`crate::state::FeeUpdateStatus`]
+    pub fee_update_status: u8,
+
+    pub user_vault_shares_before: u128,
+    pub total_vault_shares_before: u128,
+
+    pub user_vault_shares_after: u128,
+    pub total_vault_shares_after: u128,
+
+    pub manager_profit_share: u64,
+    pub management_fee: i64,
+    pub management_fee_shares: i64,
+
+    /// The protocol fields are only set for vaults with a VaultProtocol
+    pub protocol_shares_before: Option<u128>,
+    pub protocol_shares_after: Option<u128>,
+
+    pub protocol_profit_share: Option<u64>,
+    pub protocol_fee: Option<i64>,
+    pub protocol_fee_shares: Option<i64>,
+
+    /// precision: PRICE_PRECISION
+    pub deposit_oracle_price: i64,
+
+    /// The depositor's cost basis after the action, see [`crate::state::VaultDepositor::cost_basis`]. Only set
+    /// for vault depositors
+    pub cost_basis_after: Option<u64>,
+    /// The value of the shares withdrawn or transferred out less their cost basis. Only set when the action
+    /// removed shares at a value
+    pub realized_pnl: Option<i64>,
+}
+
 #[derive(Clone, Copy, BorshSerialize, BorshDeserialize, PartialEq, Eq, Default)]
 pub enum VaultDepositorAction {
     #[default]
@@ -114,6 +170,20 @@ pub struct FuelSeasonRecord {
     pub fuel_total: u128,
 }
 
+#[event]
+pub struct VaultDepositorFuelRecord {
+    pub ts: i64,
+    pub vault: Pubkey,
+    pub vault_depositor: Pubkey,
+    pub depositor_authority: Pubkey,
+    pub fuel_amount_before: u128,
+    pub fuel_amount_after: u128,
+    /// The depositor's shares accruing fuel, including fuel_boost_shares
+    pub fuel_shares: u128,
+    /// precision: FUEL_SHARE_PRECISION
+    pub cumulative_fuel_per_share: u128,
+}
+
 #[derive(Clone, Copy, BorshSerialize, BorshDeserialize, PartialEq, Eq)]
 pub enum FeeUpdateAction {
     Pending,
@@ -135,6 +205,24 @@ pub struct FeeUpdateRecord {
     pub new_hurdle_rate: u32,
 }
 
+#[derive(Clone, Copy, BorshSerialize, BorshDeserialize, PartialEq, Eq)]
+pub enum FuelDistributionUpdateAction {
+    Pending,
+    Applied,
+}
+
+#[event]
+pub struct FuelDistributionUpdateRecord {
+    pub ts: i64,
+    pub action: FuelDistributionUpdateAction,
+    pub timelock_end_ts: i64,
+    pub vault: Pubkey,
+    pub old_manager_fuel_bps: u16,
+    pub old_protocol_fuel_bps: u16,
+    pub new_manager_fuel_bps: u16,
+    pub new_protocol_fuel_bps: u16,
+}
+
 #[event]
 pub struct ManagerBorrowRecord {
     pub ts: i64,
@@ -174,3 +262,158 @@ pub struct ManagerUpdateBorrowRecord {
     pub vault_equity_before: u64,
     pub vault_equity_after: u64,
 }
+
+#[derive(Clone, Copy, BorshSerialize, BorshDeserialize, PartialEq, Eq)]
+pub enum NavCircuitBreakerAction {
+    Tripped,
+    Reset,
+}
+
+#[event]
+pub struct NavCircuitBreakerRecord {
+    pub ts: i64,
+    pub action: NavCircuitBreakerAction,
+    pub vault: Pubkey,
+    /// The signer that reset the breaker, default when tripped
+    pub authority: Pubkey,
+    /// precision: NAV_PER_SHARE_PRECISION
+    pub last_equity_per_share: u128,
+    pub last_equity_ts: i64,
+    /// The equity per share that tripped the breaker, 0 on reset
+    /// precision: NAV_PER_SHARE_PRECISION
+    pub equity_per_share: u128,
+    pub threshold_bps: u16,
+}
+
+#[derive(Clone, Copy, BorshSerialize, BorshDeserialize, PartialEq, Eq)]
+pub enum DrawdownProtectionAction {
+    Triggered,
+    ReenablePending,
+    Reenabled,
+}
+
+#[event]
+pub struct DrawdownProtectionRecord {
+    pub ts: i64,
+    pub action: DrawdownProtectionAction,
+    pub vault: Pubkey,
+    /// precision: NAV_PER_SHARE_PRECISION
+    pub hwm_equity_per_share: u128,
+    /// The equity per share that triggered the protection, 0 otherwise
+    /// precision: NAV_PER_SHARE_PRECISION
+    pub equity_per_share: u128,
+    pub max_drawdown_bps: u16,
+    pub reenable_trading_ts: i64,
+}
+
+#[derive(Clone, Copy, BorshSerialize, BorshDeserialize, PartialEq, Eq)]
+pub enum VaultConfigAction {
+    UpdateVault,
+    UpdateDelegate,
+    ResetDelegate,
+    UpdateMarginTradingEnabled,
+    UpdatePoolId,
+    UpdateVaultManager,
+    UpdateVaultClass,
+    UpdateFuelDistributionMode,
+    InitializeNavPolicy,
+    UpdateNavPolicy,
+    UpdateVaultProtocol,
+    UpdateFuelDistribution,
+    ReenableTrading,
+    UpdateFees,
+    ApplyFeeUpdate,
+    InitializeFeeUpdate,
+    DeleteFeeUpdate,
+    UpdateBorrow,
+    ApplyFuelDistribution,
+}
+
+/// The vault's configurable settings, see [`crate::state::Vault::get_config`]
+#[derive(Clone, Copy, AnchorSerialize, AnchorDeserialize, PartialEq, Eq, Default)]
+pub struct VaultConfig {
+    pub manager: Pubkey,
+    pub delegate: Pubkey,
+    pub liquidation_delegate: Pubkey,
+    pub redeem_period: i64,
+    pub max_tokens: u64,
+    pub min_deposit_amount: u64,
+    pub management_fee: i64,
+    pub profit_share: u32,
+    pub hurdle_rate: u32,
+    pub permissioned: bool,
+    pub exclude_pending_withdraws_from_fuel: bool,
+    pub vault_class: u8,
+    pub fuel_distribution_mode: u8,
+    pub manager_fuel_bps: u16,
+    pub protocol_fuel_bps: u16,
+    pub fee_update_status: u8,
+    pub manager_borrowed_value: u64,
+    /// Settings of the vault's drift user, only set by the instructions that update the drift user
+    pub margin_trading_enabled: Option<bool>,
+    pub pool_id: Option<u8>,
+    /// Settings of the vault's optional accounts, only set by the instructions that update them
+    pub nav_policy: Option<NavPolicyConfig>,
+    pub vault_protocol: Option<VaultProtocolConfig>,
+    pub fuel_distribution: Option<FuelDistributionConfig>,
+    pub fee_update: Option<FeeUpdateConfig>,
+}
+
+/// The [`crate::state::NavPolicy`] settings, see [`crate::state::NavPolicy::get_config`]
+#[derive(Clone, Copy, AnchorSerialize, AnchorDeserialize, PartialEq, Eq, Default)]
+pub struct NavPolicyConfig {
+    pub swing_pricing_mode: u8,
+    pub deposit_spread_bps: u16,
+    pub withdraw_spread_bps: u16,
+    pub max_swing_bps: u16,
+    pub oracle_validity_mode: u8,
+    pub nav_breaker_window: i64,
+    pub nav_breaker_threshold_bps: u16,
+    pub nav_breaker_tripped: bool,
+    pub max_drawdown_bps: u16,
+    pub drawdown_triggered_ts: i64,
+    pub reenable_trading_ts: i64,
+    /// The queued increase to the withdraw swing, 0 if none is queued
+    pub incoming_update_ts: i64,
+    pub incoming_withdraw_spread_bps: u16,
+    pub incoming_max_swing_bps: u16,
+    pub incoming_swing_pricing_mode: u8,
+}
+
+/// The [`crate::state::VaultProtocol`] settings, see [`crate::state::VaultProtocol::get_config`]
+#[derive(Clone, Copy, AnchorSerialize, AnchorDeserialize, PartialEq, Eq, Default)]
+pub struct VaultProtocolConfig {
+    pub protocol: Pubkey,
+    pub protocol_fee: u64,
+    pub protocol_profit_share: u32,
+}
+
+/// The [`crate::state::FuelDistribution`] settings, see [`crate::state::FuelDistribution::get_config`]
+#[derive(Clone, Copy, AnchorSerialize, AnchorDeserialize, PartialEq, Eq, Default)]
+pub struct FuelDistributionConfig {
+    /// The queued fuel weights, 0 if none are queued
+    pub incoming_update_ts: i64,
+    pub incoming_manager_fuel_bps: u16,
+    pub incoming_protocol_fuel_bps: u16,
+}
+
+/// The [`crate::state::FeeUpdate`] settings, see [`crate::state::FeeUpdate::get_config`]
+#[derive(Clone, Copy, AnchorSerialize, AnchorDeserialize, PartialEq, Eq, Default)]
+pub struct FeeUpdateConfig {
+    /// The queued fees, 0 if none are queued
+    pub incoming_update_ts: i64,
+    pub incoming_management_fee: i64,
+    pub incoming_profit_share: u32,
+    pub incoming_hurdle_rate: u32,
+}
+
+#[event]
+pub struct VaultConfigRecord {
+    pub ts: i64,
+    pub action: VaultConfigAction,
+    pub vault: Pubkey,
+    /// The manager or admin that signed the update, default if it was permissionless
+    pub authority: Pubkey,
+    pub old_config: VaultConfig,
+    pub new_config: VaultConfig,
+}
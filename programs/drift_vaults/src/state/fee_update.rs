@@ -1,4 +1,7 @@
-use crate::events::{FeeUpdateAction, FeeUpdateRecord};
+use crate::events::{
+    FeeUpdateAction, FeeUpdateConfig, FeeUpdateRecord, VaultConfig, VaultConfigAction,
+    VaultConfigRecord,
+};
 use crate::state::{FeeUpdateStatus, Vault};
 use crate::Size;
 use anchor_lang::prelude::*;
@@ -15,7 +18,9 @@ pub struct FeeUpdate {
     pub incoming_profit_share: u32,
     pub incoming_hurdle_rate: u32,
     pub padding: [u128; 10],
-    pub padding2: [u8; 8],
+    /// The bump for the fee update pda, 0 for fee updates created before it was stored
+    pub bump: u8,
+    pub padding2: [u8; 7],
 }
 
 impl Size for FeeUpdate {
@@ -35,7 +40,24 @@ impl FeeUpdate {
         self.incoming_update_ts > 0
     }
 
-    pub fn try_update_vault_fees(&mut self, now: i64, vault: &mut Vault) -> Result<()> {
+    /// The settings logged by [`crate::state::events::VaultConfigRecord`]
+    pub fn get_config(&self) -> FeeUpdateConfig {
+        FeeUpdateConfig {
+            incoming_update_ts: self.incoming_update_ts,
+            incoming_management_fee: self.incoming_management_fee,
+            incoming_profit_share: self.incoming_profit_share,
+            incoming_hurdle_rate: self.incoming_hurdle_rate,
+        }
+    }
+
+    /// Applies the incoming fees once the timelock has passed. `authority` is logged on the
+    /// [`VaultConfigRecord`], default if the fees were applied by a permissionless instruction.
+    pub fn try_update_vault_fees(
+        &mut self,
+        now: i64,
+        vault: &mut Vault,
+        authority: Pubkey,
+    ) -> Result<()> {
         if !self.is_pending() {
             return Ok(());
         }
@@ -54,15 +76,37 @@ impl FeeUpdate {
                 new_hurdle_rate: self.incoming_hurdle_rate,
             });
 
-            vault.management_fee = self.incoming_management_fee;
-            vault.profit_share = self.incoming_profit_share;
-            vault.hurdle_rate = self.incoming_hurdle_rate;
+            let old_config = VaultConfig {
+                fee_update: Some(self.get_config()),
+                ..vault.get_config(None)
+            };
 
-            vault.fee_update_status = FeeUpdateStatus::None as u8;
+            self.apply_incoming_fees(vault);
 
             self.reset();
+
+            emit!(VaultConfigRecord {
+                ts: now,
+                action: VaultConfigAction::ApplyFeeUpdate,
+                vault: vault.pubkey,
+                authority,
+                old_config,
+                new_config: VaultConfig {
+                    fee_update: Some(self.get_config()),
+                    ..vault.get_config(None)
+                },
+            });
         }
 
         Ok(())
     }
+
+    /// Sets the incoming fees on the vault, without checking the timelock.
+    pub fn apply_incoming_fees(&self, vault: &mut Vault) {
+        vault.management_fee = self.incoming_management_fee;
+        vault.profit_share = self.incoming_profit_share;
+        vault.hurdle_rate = self.incoming_hurdle_rate;
+
+        vault.fee_update_status = FeeUpdateStatus::None as u8;
+    }
 }
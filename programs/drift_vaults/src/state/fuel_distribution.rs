@@ -0,0 +1,179 @@
+use anchor_lang::prelude::*;
+use drift::math::casting::Cast;
+use drift::math::safe_math::SafeMath;
+use drift_macros::assert_no_slop;
+use static_assertions::const_assert_eq;
+
+use crate::constants::FUEL_DISTRIBUTION_PRECISION;
+use crate::events::{
+    FuelDistributionConfig, FuelDistributionUpdateAction, FuelDistributionUpdateRecord,
+    VaultConfig, VaultConfigAction, VaultConfigRecord,
+};
+use crate::state::{FuelDistributionMode, Vault};
+use crate::Size;
+
+/// Holds the fuel accrued by the manager and the protocol when the vault's fuel_distribution_mode is
+/// `Weighted`, and any timelocked change to the weights. Users accrue their share through
+/// vault.cumulative_fuel_per_share, the rest of vault.cumulative_fuel is settled here. `Vault` and
+/// `VaultProtocol` have no room left for the accrued fuel, so it lives in its own account.
+///
+/// Settling accrues the weighted share of the vault's fuel since the last settle, so it is exact however
+/// rarely it runs as long as it runs before the weights change and before the vault's fuel season is reset.
+/// `update` and `reset_fuel_amount` settle first for that reason.
+#[assert_no_slop]
+#[account(zero_copy(unsafe))]
+#[derive(Default, Eq, PartialEq, Debug)]
+#[repr(C)]
+pub struct FuelDistribution {
+    /// The vault this account distributes fuel for
+    pub vault: Pubkey,
+    /// The vault.cumulative_fuel at the last settle
+    pub last_cumulative_fuel: u128,
+    /// Total fuel accrued by the manager
+    pub manager_fuel: u128,
+    /// Total fuel accrued by the protocol
+    pub protocol_fuel: u128,
+    /// The ts the incoming weights can be applied at, 0 if there is no pending update
+    pub incoming_update_ts: i64,
+    pub incoming_manager_fuel_bps: u16,
+    pub incoming_protocol_fuel_bps: u16,
+    /// Last ts fuel was settled
+    pub last_settle_ts: u32,
+    pub bump: u8,
+    pub padding1: [u8; 7],
+    pub padding: [u64; 5],
+}
+
+impl Size for FuelDistribution {
+    const SIZE: usize = 144 + 8;
+}
+
+const_assert_eq!(
+    FuelDistribution::SIZE,
+    std::mem::size_of::<FuelDistribution>() + 8
+);
+
+impl FuelDistribution {
+    pub fn is_pending(&self) -> bool {
+        self.incoming_update_ts > 0
+    }
+
+    /// The settings logged by [`crate::state::events::VaultConfigRecord`]
+    pub fn get_config(&self) -> FuelDistributionConfig {
+        FuelDistributionConfig {
+            incoming_update_ts: self.incoming_update_ts,
+            incoming_manager_fuel_bps: self.incoming_manager_fuel_bps,
+            incoming_protocol_fuel_bps: self.incoming_protocol_fuel_bps,
+        }
+    }
+
+    pub fn reset_incoming(&mut self) {
+        self.incoming_update_ts = 0;
+        self.incoming_manager_fuel_bps = 0;
+        self.incoming_protocol_fuel_bps = 0;
+    }
+
+    /// Accrues the manager and protocol share of the vault fuel since the last settle. The vault's
+    /// cumulative fuel should be updated beforehand.
+    pub fn settle(&mut self, vault: &Vault, now: i64) -> Result<()> {
+        if self.last_cumulative_fuel > vault.cumulative_fuel {
+            // the vault's fuel was reset for a new season
+            msg!(
+                "Resetting fuel distribution. manager_fuel: {}, protocol_fuel: {}",
+                self.manager_fuel,
+                self.protocol_fuel
+            );
+            self.manager_fuel = 0;
+            self.protocol_fuel = 0;
+        } else if FuelDistributionMode::try_from(vault.fuel_distribution_mode)?
+            == FuelDistributionMode::Weighted
+        {
+            let fuel_delta = vault.cumulative_fuel.safe_sub(self.last_cumulative_fuel)?;
+
+            self.manager_fuel = self.manager_fuel.safe_add(
+                fuel_delta
+                    .safe_mul(vault.manager_fuel_bps.cast()?)?
+                    .safe_div(FUEL_DISTRIBUTION_PRECISION.cast()?)?,
+            )?;
+            self.protocol_fuel = self.protocol_fuel.safe_add(
+                fuel_delta
+                    .safe_mul(vault.protocol_fuel_bps.cast()?)?
+                    .safe_div(FUEL_DISTRIBUTION_PRECISION.cast()?)?,
+            )?;
+        }
+
+        self.last_cumulative_fuel = vault.cumulative_fuel;
+        self.last_settle_ts = now as u32;
+
+        Ok(())
+    }
+
+    /// Settles the manager and protocol fuel and applies the incoming weights once the timelock has
+    /// passed, so fuel accrued under the old weights is never redistributed. The vault's cumulative fuel
+    /// should be updated beforehand.
+    pub fn update(&mut self, vault: &mut Vault, now: i64) -> Result<()> {
+        self.settle(vault, now)?;
+        self.try_update_vault_fuel_weights(now, vault)
+    }
+
+    fn try_update_vault_fuel_weights(&mut self, now: i64, vault: &mut Vault) -> Result<()> {
+        if !self.is_pending() {
+            return Ok(());
+        }
+
+        if now >= self.incoming_update_ts {
+            emit!(FuelDistributionUpdateRecord {
+                ts: now,
+                action: FuelDistributionUpdateAction::Applied,
+                timelock_end_ts: self.incoming_update_ts,
+                vault: vault.pubkey,
+                old_manager_fuel_bps: vault.manager_fuel_bps,
+                old_protocol_fuel_bps: vault.protocol_fuel_bps,
+                new_manager_fuel_bps: self.incoming_manager_fuel_bps,
+                new_protocol_fuel_bps: self.incoming_protocol_fuel_bps,
+            });
+
+            let old_config = VaultConfig {
+                fuel_distribution: Some(self.get_config()),
+                ..vault.get_config(None)
+            };
+
+            vault.manager_fuel_bps = self.incoming_manager_fuel_bps;
+            vault.protocol_fuel_bps = self.incoming_protocol_fuel_bps;
+            vault.fuel_distribution_mode = FuelDistributionMode::Weighted as u8;
+
+            self.reset_incoming();
+
+            // applied by the permissionless update_fuel_distribution
+            emit!(VaultConfigRecord {
+                ts: now,
+                action: VaultConfigAction::ApplyFuelDistribution,
+                vault: vault.pubkey,
+                authority: Pubkey::default(),
+                old_config,
+                new_config: VaultConfig {
+                    fuel_distribution: Some(self.get_config()),
+                    ..vault.get_config(None)
+                },
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Settles the fuel accrued this season and resets it, before the vault's fuel is reset for a new season
+    pub fn reset_fuel_amount(&mut self, vault: &Vault, now: i64) -> Result<()> {
+        self.settle(vault, now)?;
+
+        msg!(
+            "Resetting fuel distribution. manager_fuel: {}, protocol_fuel: {}",
+            self.manager_fuel,
+            self.protocol_fuel
+        );
+        self.manager_fuel = 0;
+        self.protocol_fuel = 0;
+        self.last_cumulative_fuel = 0;
+
+        Ok(())
+    }
+}
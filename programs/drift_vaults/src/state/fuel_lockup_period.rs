@@ -0,0 +1,31 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+
+use crate::constants::{FUEL_LOCKUP_MULTIPLIER_PRECISION, ONE_DAY};
+
+/// Voluntary lockups a depositor can opt into. While locked, `request_withdraw` is blocked and the
+/// depositor's shares accrue fuel with the lockup's multiplier.
+#[derive(Debug, Clone, Copy, BorshSerialize, BorshDeserialize, PartialEq, Eq)]
+pub enum FuelLockupPeriod {
+    ThirtyDays,
+    NinetyDays,
+    OneHundredEightyDays,
+}
+
+impl FuelLockupPeriod {
+    pub fn duration(&self) -> i64 {
+        match self {
+            FuelLockupPeriod::ThirtyDays => 30 * ONE_DAY,
+            FuelLockupPeriod::NinetyDays => 90 * ONE_DAY,
+            FuelLockupPeriod::OneHundredEightyDays => 180 * ONE_DAY,
+        }
+    }
+
+    /// precision: FUEL_LOCKUP_MULTIPLIER_PRECISION
+    pub fn fuel_multiplier(&self) -> u32 {
+        match self {
+            FuelLockupPeriod::ThirtyDays => FUEL_LOCKUP_MULTIPLIER_PRECISION * 11 / 10,
+            FuelLockupPeriod::NinetyDays => FUEL_LOCKUP_MULTIPLIER_PRECISION * 5 / 4,
+            FuelLockupPeriod::OneHundredEightyDays => FUEL_LOCKUP_MULTIPLIER_PRECISION * 3 / 2,
+        }
+    }
+}
@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+use drift_macros::assert_no_slop;
+use static_assertions::const_assert_eq;
+
+use crate::Size;
+
+/// A depositor's fuel recorded by reset_fuel_season at the end of a fuel season, so external programs can read
+/// it without parsing logs. There is one snapshot per depositor and season and it can't be overwritten.
+#[assert_no_slop]
+#[account(zero_copy(unsafe))]
+#[derive(Default, Eq, PartialEq, Debug)]
+#[repr(C)]
+pub struct FuelSeasonSnapshot {
+    /// The vault the depositor is in
+    pub vault: Pubkey,
+    /// The vault depositor whose fuel was recorded
+    pub vault_depositor: Pubkey,
+    /// The vault depositor's authority
+    pub authority: Pubkey,
+    /// The depositor's fuel_amount at the snapshot
+    pub fuel_amount: u128,
+    /// The ts the snapshot was taken
+    pub ts: i64,
+    /// The vault's fuel_season the fuel was accrued in, part of the snapshot's pda seeds
+    pub season: u8,
+    pub bump: u8,
+    pub padding: [u8; 6],
+}
+
+impl Size for FuelSeasonSnapshot {
+    const SIZE: usize = 128 + 8;
+}
+
+const_assert_eq!(
+    FuelSeasonSnapshot::SIZE,
+    std::mem::size_of::<FuelSeasonSnapshot>() + 8
+);
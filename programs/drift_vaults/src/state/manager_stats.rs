@@ -0,0 +1,312 @@
+use anchor_lang::prelude::*;
+use drift::math::casting::Cast;
+use drift::math::constants::{PERCENTAGE_PRECISION, PERCENTAGE_PRECISION_I128};
+use drift::math::safe_math::SafeMath;
+use drift_macros::assert_no_slop;
+use static_assertions::const_assert_eq;
+
+use crate::constants::MANAGER_STATS_RETURN_PERIOD;
+use crate::state::{calculate_share_price, rebase_share_price};
+use crate::{Size, Vault};
+
+/// The vault's track record, built from its share price each time its equity is observed: by the
+/// update_manager_stats crank, the snapshot_vault crank, and deposits and withdrawals that pass it in
+/// remaining_accounts.
+///
+/// Share prices are the vault's equity over its total_shares, in the vault's current shares_base. They are net
+/// of management and protocol fees, which mint shares, and gross of profit share, which only moves shares.
+#[assert_no_slop]
+#[account(zero_copy(unsafe))]
+#[derive(Default, Eq, PartialEq, Debug)]
+#[repr(C)]
+pub struct ManagerStats {
+    /// The vault these stats are for
+    pub vault: Pubkey,
+    /// The highest share price observed. precision: NAV_PER_SHARE_PRECISION
+    pub hwm_share_price: u128,
+    /// The share price at the last observation. precision: NAV_PER_SHARE_PRECISION
+    pub last_share_price: u128,
+    /// The share price the current return period started at. precision: NAV_PER_SHARE_PRECISION
+    pub period_start_share_price: u128,
+    /// Sum of the period returns. precision: PERCENTAGE_PRECISION
+    pub sum_returns: i128,
+    /// Sum of the squared period returns. precision: PERCENTAGE_PRECISION^2
+    pub sum_squared_returns: u128,
+    /// The vault's init_ts
+    pub inception_ts: i64,
+    /// The ts of the first observation
+    pub first_observation_ts: i64,
+    pub last_observation_ts: i64,
+    /// The ts the high-water mark was set
+    pub hwm_ts: i64,
+    pub period_start_ts: i64,
+    /// The largest fall of the share price from its high-water mark. precision: PERCENTAGE_PRECISION
+    pub max_drawdown: u64,
+    /// The ts the max drawdown was observed
+    pub max_drawdown_ts: i64,
+    /// The number of period returns recorded
+    pub return_count: u32,
+    /// The shares_base the share prices are in
+    pub shares_base: u32,
+    pub bump: u8,
+    pub padding1: [u8; 3],
+    /// The number of periods without a return because the stats weren't observed in the period after them
+    pub missed_period_count: u32,
+    pub padding: [u64; 8],
+}
+
+impl Size for ManagerStats {
+    const SIZE: usize = 240 + 8;
+}
+
+const_assert_eq!(ManagerStats::SIZE, std::mem::size_of::<ManagerStats>() + 8);
+
+impl ManagerStats {
+    /// Records the vault's share price at `vault_equity`. Return periods are MANAGER_STATS_RETURN_PERIOD long,
+    /// on a fixed schedule from the first observation. A period's return is recorded by the first observation in
+    /// the next period. If an observation skips over whole periods, their returns are not recorded and they are
+    /// counted in missed_period_count instead, so every recorded return spans about one period.
+    pub fn observe(&mut self, vault: &Vault, vault_equity: u64, now: i64) -> Result<()> {
+        // the share price is undefined until the vault has shares
+        if vault.total_shares == 0 {
+            return Ok(());
+        }
+
+        self.apply_rebase(vault.shares_base)?;
+        let share_price = calculate_share_price(vault_equity, vault.total_shares)?;
+
+        if self.first_observation_ts == 0 {
+            self.first_observation_ts = now;
+            self.period_start_share_price = share_price;
+            self.period_start_ts = now;
+        }
+
+        if share_price >= self.hwm_share_price {
+            self.hwm_share_price = share_price;
+            self.hwm_ts = now;
+        } else {
+            let drawdown: u64 = self
+                .hwm_share_price
+                .safe_sub(share_price)?
+                .safe_mul(PERCENTAGE_PRECISION)?
+                .safe_div(self.hwm_share_price)?
+                .cast()?;
+            if drawdown > self.max_drawdown {
+                self.max_drawdown = drawdown;
+                self.max_drawdown_ts = now;
+            }
+        }
+
+        let elapsed_periods = now
+            .safe_sub(self.period_start_ts)?
+            .safe_div(MANAGER_STATS_RETURN_PERIOD)?;
+
+        // a period that started at a share price of 0 has no return, the next one starts now
+        if self.period_start_share_price == 0 {
+            self.period_start_share_price = share_price;
+            self.period_start_ts = now;
+        } else if elapsed_periods > 0 {
+            if elapsed_periods == 1 {
+                self.record_return(share_price)?;
+            } else {
+                self.missed_period_count =
+                    self.missed_period_count.safe_add(elapsed_periods.cast()?)?;
+            }
+
+            self.period_start_share_price = share_price;
+            self.period_start_ts = self
+                .period_start_ts
+                .safe_add(elapsed_periods.safe_mul(MANAGER_STATS_RETURN_PERIOD)?)?;
+        }
+
+        self.last_share_price = share_price;
+        self.last_observation_ts = now;
+
+        Ok(())
+    }
+
+    fn record_return(&mut self, share_price: u128) -> Result<()> {
+        let period_return = share_price
+            .cast::<i128>()?
+            .safe_mul(PERCENTAGE_PRECISION_I128)?
+            .safe_div(self.period_start_share_price.cast()?)?
+            .safe_sub(PERCENTAGE_PRECISION_I128)?;
+
+        self.sum_returns = self.sum_returns.safe_add(period_return)?;
+        self.sum_squared_returns = self.sum_squared_returns.safe_add(
+            period_return
+                .unsigned_abs()
+                .safe_mul(period_return.unsigned_abs())?,
+        )?;
+        self.return_count = self.return_count.safe_add(1)?;
+
+        Ok(())
+    }
+
+    /// A rebase divides the vault's shares, so the recorded share prices are multiplied to stay comparable
+    fn apply_rebase(&mut self, shares_base: u32) -> Result<()> {
+        if self.first_observation_ts != 0 {
+            self.hwm_share_price =
+                rebase_share_price(self.hwm_share_price, self.shares_base, shares_base)?;
+            self.last_share_price =
+                rebase_share_price(self.last_share_price, self.shares_base, shares_base)?;
+            self.period_start_share_price =
+                rebase_share_price(self.period_start_share_price, self.shares_base, shares_base)?;
+        }
+        self.shares_base = shares_base;
+
+        Ok(())
+    }
+
+    pub fn get_seconds_since_inception(&self, now: i64) -> Result<i64> {
+        Ok(now.safe_sub(self.inception_ts)?)
+    }
+
+    /// The share price's fall from its high-water mark at the last observation. precision: PERCENTAGE_PRECISION
+    pub fn get_current_drawdown(&self) -> Result<u64> {
+        if self.hwm_share_price == 0 {
+            return Ok(0);
+        }
+
+        Ok(self
+            .hwm_share_price
+            .safe_sub(self.last_share_price)?
+            .safe_mul(PERCENTAGE_PRECISION)?
+            .safe_div(self.hwm_share_price)?
+            .cast()?)
+    }
+
+    /// The mean period return. precision: PERCENTAGE_PRECISION
+    pub fn get_mean_return(&self) -> Result<i128> {
+        if self.return_count == 0 {
+            return Ok(0);
+        }
+
+        Ok(self.sum_returns.safe_div(self.return_count.cast()?)?)
+    }
+
+    /// The population variance of the period returns. precision: PERCENTAGE_PRECISION^2
+    pub fn get_return_variance(&self) -> Result<u128> {
+        if self.return_count == 0 {
+            return Ok(0);
+        }
+
+        let mean_return = self.get_mean_return()?.unsigned_abs();
+        Ok(self
+            .sum_squared_returns
+            .safe_div(self.return_count.cast()?)?
+            .saturating_sub(mean_return.safe_mul(mean_return)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::{NAV_PER_SHARE_PRECISION, ONE_DAY};
+    use drift::math::constants::QUOTE_PRECISION_U64;
+
+    #[test]
+    fn test_manager_stats_observe() {
+        let mut stats = ManagerStats::default();
+        let mut vault = Vault {
+            total_shares: 100 * QUOTE_PRECISION_U64 as u128,
+            ..Vault::default()
+        };
+        let now = 1_000_000;
+
+        // no share price before the vault has shares
+        stats
+            .observe(&Vault::default(), 100 * QUOTE_PRECISION_U64, now)
+            .unwrap();
+        assert_eq!(stats.first_observation_ts, 0);
+
+        stats
+            .observe(&vault, 100 * QUOTE_PRECISION_U64, now)
+            .unwrap();
+        assert_eq!(stats.hwm_share_price, NAV_PER_SHARE_PRECISION);
+        assert_eq!(stats.first_observation_ts, now);
+
+        // +20% over the first day, observations within the period don't record a return
+        stats
+            .observe(&vault, 80 * QUOTE_PRECISION_U64, now + ONE_DAY / 2)
+            .unwrap();
+        assert_eq!(stats.return_count, 0);
+        assert_eq!(stats.max_drawdown, PERCENTAGE_PRECISION as u64 / 5);
+        assert_eq!(stats.max_drawdown_ts, now + ONE_DAY / 2);
+
+        stats
+            .observe(&vault, 120 * QUOTE_PRECISION_U64, now + ONE_DAY)
+            .unwrap();
+        assert_eq!(stats.return_count, 1);
+        assert_eq!(stats.hwm_share_price, 12 * NAV_PER_SHARE_PRECISION / 10);
+        assert_eq!(stats.hwm_ts, now + ONE_DAY);
+
+        // the vault rebases, share prices are scaled into the new shares_base
+        vault.total_shares /= 100;
+        vault.shares_base = 2;
+
+        // -10% over the second day
+        stats
+            .observe(&vault, 108 * QUOTE_PRECISION_U64, now + 2 * ONE_DAY)
+            .unwrap();
+        assert_eq!(stats.shares_base, 2);
+        assert_eq!(stats.hwm_share_price, 120 * NAV_PER_SHARE_PRECISION);
+        assert_eq!(stats.return_count, 2);
+        assert_eq!(stats.get_current_drawdown().unwrap(), 100_000);
+        // the deeper drawdown within the first day is kept
+        assert_eq!(stats.max_drawdown, 200_000);
+
+        // returns of +20% and -10%: mean 5%, variance 0.0225
+        assert_eq!(stats.get_mean_return().unwrap(), 50_000);
+        assert_eq!(stats.get_return_variance().unwrap(), 22_500_000_000);
+        assert_eq!(stats.missed_period_count, 0);
+
+        // returns are recorded on the daily schedule, not from when the previous period was observed
+        stats
+            .observe(
+                &vault,
+                108 * QUOTE_PRECISION_U64,
+                now + 3 * ONE_DAY + ONE_DAY / 2,
+            )
+            .unwrap();
+        assert_eq!(stats.return_count, 3);
+        assert_eq!(stats.period_start_ts, now + 3 * ONE_DAY);
+
+        // the stats aren't observed during the fifth and sixth days, the fourth to sixth days' returns are skipped
+        stats
+            .observe(&vault, 150 * QUOTE_PRECISION_U64, now + 6 * ONE_DAY + 1)
+            .unwrap();
+        assert_eq!(stats.return_count, 3);
+        assert_eq!(stats.missed_period_count, 3);
+        assert_eq!(stats.period_start_ts, now + 6 * ONE_DAY);
+        assert_eq!(
+            stats.period_start_share_price,
+            150 * NAV_PER_SHARE_PRECISION
+        );
+        assert_eq!(stats.get_mean_return().unwrap(), 100_000 / 3);
+
+        // the next day's return starts from the observation after the gap
+        stats
+            .observe(&vault, 165 * QUOTE_PRECISION_U64, now + 7 * ONE_DAY)
+            .unwrap();
+        assert_eq!(stats.return_count, 4);
+        assert_eq!(stats.sum_returns, 200_000 - 100_000 + 0 + 100_000);
+    }
+
+    #[test]
+    fn test_manager_stats_seconds_since_inception() {
+        let inception_ts = 1_700_000_000;
+        let stats = ManagerStats {
+            inception_ts,
+            ..ManagerStats::default()
+        };
+
+        assert_eq!(stats.get_seconds_since_inception(inception_ts).unwrap(), 0);
+        assert_eq!(
+            stats
+                .get_seconds_since_inception(inception_ts + ONE_DAY)
+                .unwrap(),
+            ONE_DAY
+        );
+    }
+}
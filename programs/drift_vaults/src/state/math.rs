@@ -1,6 +1,9 @@
 use anchor_lang::prelude::*;
+use drift::math::casting::Cast;
 use drift::math::safe_math::SafeMath;
 
+use crate::constants::NAV_PER_SHARE_PRECISION;
+
 pub fn token_a_to_token_b(
     token_a_amount: u64,
     token_a_price: i64,
@@ -17,6 +20,29 @@ pub fn token_a_to_token_b(
     Ok(result as u64)
 }
 
+/// The value of one vault share at `vault_equity`, the share price [`crate::state::NavPolicy`] and
+/// [`crate::state::ManagerStats`] track. precision: NAV_PER_SHARE_PRECISION
+pub fn calculate_share_price(vault_equity: u64, total_shares: u128) -> Result<u128> {
+    Ok(vault_equity
+        .cast::<u128>()?
+        .safe_mul(NAV_PER_SHARE_PRECISION)?
+        .safe_div(total_shares)?)
+}
+
+/// Scales a share price recorded at `shares_base` to `new_shares_base`. A rebase divides the vault's shares by
+/// 10^(shares_base delta), so share prices are multiplied by the same.
+pub fn rebase_share_price(
+    share_price: u128,
+    shares_base: u32,
+    new_shares_base: u32,
+) -> Result<u128> {
+    if new_shares_base <= shares_base {
+        return Ok(share_price);
+    }
+
+    Ok(share_price.safe_mul(10_u128.pow(new_shares_base.safe_sub(shares_base)?))?)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -104,4 +130,18 @@ mod tests {
         .unwrap();
         assert_eq!(result, 2_000_000_000); // Should get 2000 tokenB (with 6 decimals)
     }
+
+    #[test]
+    fn test_rebase_share_price() {
+        let share_price = calculate_share_price(1_500_000_000, 1_000_000_000).unwrap();
+        assert_eq!(share_price, NAV_PER_SHARE_PRECISION * 3 / 2);
+
+        // after a rebase of 2 the same equity is spread over 100x fewer shares
+        let rebased = rebase_share_price(share_price, 0, 2).unwrap();
+        assert_eq!(
+            rebased,
+            calculate_share_price(1_500_000_000, 10_000_000).unwrap()
+        );
+        assert_eq!(rebase_share_price(rebased, 2, 2).unwrap(), rebased);
+    }
 }
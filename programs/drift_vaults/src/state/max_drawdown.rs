@@ -0,0 +1,307 @@
+use anchor_lang::prelude::*;
+use drift::math::casting::Cast;
+use drift::math::safe_math::SafeMath;
+
+use crate::constants::{NAV_POLICY_BPS_PRECISION, ONE_WEEK};
+use crate::error::ErrorCode;
+use crate::events::{DrawdownProtectionAction, DrawdownProtectionRecord};
+use crate::state::{calculate_share_price, rebase_share_price, ManagerStats, NavPolicy};
+use crate::validate;
+use crate::Vault;
+
+/// Max drawdown protection. It sets the vault reduce-only once equity per share falls more than
+/// max_drawdown_bps below its high-water mark, until trading is re-enabled after a timelock.
+impl NavPolicy {
+    pub fn is_drawdown_protection_enabled(&self) -> bool {
+        self.max_drawdown_bps > 0
+    }
+
+    /// Updates the share price high-water mark and checks the drawdown from it. If it exceeds
+    /// max_drawdown_bps, marks the vault as drawdown triggered and waives its redeem period, and returns
+    /// true so the caller sets the drift user reduce-only. The high-water mark starts from the vault's
+    /// [`ManagerStats`] one if it is passed.
+    pub fn check_max_drawdown(
+        &mut self,
+        vault: &mut Vault,
+        manager_stats: Option<&ManagerStats>,
+        vault_equity: u64,
+        now: i64,
+    ) -> Result<bool> {
+        if !self.is_drawdown_protection_enabled() || vault.drawdown_triggered {
+            return Ok(false);
+        }
+
+        if vault.total_shares == 0 {
+            return Ok(false);
+        }
+
+        if self.hwm_equity_per_share == 0 {
+            if let Some(manager_stats) = manager_stats {
+                self.hwm_equity_per_share = manager_stats.hwm_share_price;
+                self.hwm_shares_base = manager_stats.shares_base;
+            }
+        }
+
+        let equity_per_share = calculate_share_price(vault_equity, vault.total_shares)?;
+        let drawdown_bps = self.update_hwm(vault, equity_per_share)?;
+        if drawdown_bps <= self.max_drawdown_bps.cast()? {
+            return Ok(false);
+        }
+
+        self.trigger_drawdown(vault, equity_per_share, drawdown_bps, now);
+
+        Ok(true)
+    }
+
+    /// Scales the high-water mark to the vault's shares_base and raises it to `equity_per_share`. Returns the
+    /// drawdown from it in bps.
+    fn update_hwm(&mut self, vault: &Vault, equity_per_share: u128) -> Result<u128> {
+        self.hwm_equity_per_share = rebase_share_price(
+            self.hwm_equity_per_share,
+            self.hwm_shares_base,
+            vault.shares_base,
+        )?;
+        self.hwm_shares_base = vault.shares_base;
+
+        if equity_per_share >= self.hwm_equity_per_share {
+            self.hwm_equity_per_share = equity_per_share;
+            return Ok(0);
+        }
+
+        Ok(self
+            .hwm_equity_per_share
+            .safe_sub(equity_per_share)?
+            .safe_mul(NAV_POLICY_BPS_PRECISION.cast()?)?
+            .safe_div(self.hwm_equity_per_share)?)
+    }
+
+    /// Sets the vault reduce-only until trading is re-enabled, cancelling a pending re-enable. The redeem
+    /// period is only waived the first time, a drawdown during the re-enable timelock keeps it waived.
+    fn trigger_drawdown(
+        &mut self,
+        vault: &mut Vault,
+        equity_per_share: u128,
+        drawdown_bps: u128,
+        now: i64,
+    ) {
+        msg!(
+            "max drawdown triggered: equity per share {} -> {} ({} bps)",
+            self.hwm_equity_per_share,
+            equity_per_share,
+            drawdown_bps
+        );
+
+        self.drawdown_triggered_ts = now;
+        self.reenable_trading_ts = 0;
+        if !vault.drawdown_triggered {
+            self.redeem_period_before_drawdown = vault.redeem_period;
+            vault.redeem_period = 0;
+            vault.drawdown_triggered = true;
+        }
+
+        emit!(DrawdownProtectionRecord {
+            ts: now,
+            action: DrawdownProtectionAction::Triggered,
+            vault: vault.pubkey,
+            hwm_equity_per_share: self.hwm_equity_per_share,
+            equity_per_share,
+            max_drawdown_bps: self.max_drawdown_bps,
+            reenable_trading_ts: 0,
+        });
+    }
+
+    /// Stages or applies re-enabling trading after a triggered drawdown. The first call starts a timelock of
+    /// at least max(1 week, 2 redeem periods) during which depositors can still withdraw without a redeem
+    /// period, and restarts the high-water mark from the current share price. Once the timelock has passed,
+    /// the drawdown from it is checked again: trading is re-enabled and true returned if it is within
+    /// max_drawdown_bps, otherwise the drawdown is triggered again and the timelock must be restarted.
+    pub fn reenable_trading(
+        &mut self,
+        vault: &mut Vault,
+        vault_equity: u64,
+        now: i64,
+    ) -> Result<bool> {
+        validate!(
+            vault.drawdown_triggered,
+            ErrorCode::InvalidNavPolicy,
+            "max drawdown is not triggered"
+        )?;
+
+        // the share price is undefined until the vault has shares again
+        let equity_per_share = if vault.total_shares > 0 {
+            calculate_share_price(vault_equity, vault.total_shares)?
+        } else {
+            0
+        };
+
+        if self.reenable_trading_ts == 0 {
+            let timelock = self
+                .redeem_period_before_drawdown
+                .safe_mul(2)?
+                .max(ONE_WEEK);
+            self.reenable_trading_ts = now.safe_add(timelock)?;
+            self.hwm_equity_per_share = equity_per_share;
+            self.hwm_shares_base = vault.shares_base;
+
+            msg!("Trading can be re-enabled at {}", self.reenable_trading_ts);
+            emit!(DrawdownProtectionRecord {
+                ts: now,
+                action: DrawdownProtectionAction::ReenablePending,
+                vault: vault.pubkey,
+                hwm_equity_per_share: self.hwm_equity_per_share,
+                equity_per_share,
+                max_drawdown_bps: self.max_drawdown_bps,
+                reenable_trading_ts: self.reenable_trading_ts,
+            });
+
+            return Ok(false);
+        }
+
+        validate!(
+            now >= self.reenable_trading_ts,
+            ErrorCode::InvalidNavPolicy,
+            "trading can't be re-enabled until {}",
+            self.reenable_trading_ts
+        )?;
+
+        if equity_per_share > 0 {
+            let drawdown_bps = self.update_hwm(vault, equity_per_share)?;
+            if drawdown_bps > self.max_drawdown_bps.cast()? {
+                self.trigger_drawdown(vault, equity_per_share, drawdown_bps, now);
+                return Ok(false);
+            }
+        }
+
+        emit!(DrawdownProtectionRecord {
+            ts: now,
+            action: DrawdownProtectionAction::Reenabled,
+            vault: vault.pubkey,
+            hwm_equity_per_share: self.hwm_equity_per_share,
+            equity_per_share,
+            max_drawdown_bps: self.max_drawdown_bps,
+            reenable_trading_ts: self.reenable_trading_ts,
+        });
+
+        vault.redeem_period = self.redeem_period_before_drawdown;
+        vault.drawdown_triggered = false;
+        self.redeem_period_before_drawdown = 0;
+        self.drawdown_triggered_ts = 0;
+        self.reenable_trading_ts = 0;
+
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::{NAV_PER_SHARE_PRECISION, ONE_DAY};
+
+    #[test]
+    fn test_max_drawdown() {
+        let now = 1_000_000;
+        let mut vault = Vault {
+            total_shares: 1_000_000_000,
+            redeem_period: ONE_DAY,
+            ..Vault::default()
+        };
+        let mut np = NavPolicy {
+            max_drawdown_bps: 2000, // 20%
+            ..NavPolicy::default()
+        };
+
+        assert!(!np
+            .check_max_drawdown(&mut vault, None, 1_000_000_000, now)
+            .unwrap());
+        assert_eq!(np.hwm_equity_per_share, NAV_PER_SHARE_PRECISION);
+
+        // new high
+        assert!(!np
+            .check_max_drawdown(&mut vault, None, 1_500_000_000, now)
+            .unwrap());
+        assert_eq!(np.hwm_equity_per_share, NAV_PER_SHARE_PRECISION * 3 / 2);
+
+        // 20% drawdown is within the limit
+        assert!(!np
+            .check_max_drawdown(&mut vault, None, 1_200_000_000, now)
+            .unwrap());
+        assert!(!vault.drawdown_triggered);
+
+        // can't re-enable before triggering
+        assert!(np.reenable_trading(&mut vault, 1_200_000_000, now).is_err());
+
+        // 25% drawdown triggers
+        assert!(np
+            .check_max_drawdown(&mut vault, None, 1_125_000_000, now + 10)
+            .unwrap());
+        assert!(vault.drawdown_triggered);
+        assert_eq!(vault.redeem_period, 0);
+        assert_eq!(np.redeem_period_before_drawdown, ONE_DAY);
+        assert_eq!(np.drawdown_triggered_ts, now + 10);
+
+        // only triggers once
+        assert!(!np
+            .check_max_drawdown(&mut vault, None, 1_000_000_000, now + 20)
+            .unwrap());
+
+        // timelocked re-enable, the high-water mark restarts from the share price it was requested at
+        assert!(!np
+            .reenable_trading(&mut vault, 1_000_000_000, now + 30)
+            .unwrap());
+        assert_eq!(np.reenable_trading_ts, now + 30 + ONE_WEEK);
+        assert_eq!(np.hwm_equity_per_share, NAV_PER_SHARE_PRECISION);
+        assert!(np
+            .reenable_trading(&mut vault, 1_000_000_000, now + 40)
+            .is_err());
+
+        // a 25% drawdown during the timelock triggers again instead
+        let now = now + 30 + ONE_WEEK;
+        assert!(!np.reenable_trading(&mut vault, 750_000_000, now).unwrap());
+        assert!(vault.drawdown_triggered);
+        assert_eq!(vault.redeem_period, 0);
+        assert_eq!(np.redeem_period_before_drawdown, ONE_DAY);
+        assert_eq!(np.drawdown_triggered_ts, now);
+        assert_eq!(np.reenable_trading_ts, 0);
+
+        assert!(!np.reenable_trading(&mut vault, 750_000_000, now).unwrap());
+        assert!(np
+            .reenable_trading(&mut vault, 800_000_000, now + ONE_WEEK)
+            .unwrap());
+        assert!(!vault.drawdown_triggered);
+        assert_eq!(vault.redeem_period, ONE_DAY);
+        assert_eq!(np.hwm_equity_per_share, NAV_PER_SHARE_PRECISION * 4 / 5);
+
+        // hwm is scaled on rebase
+        assert!(!np
+            .check_max_drawdown(&mut vault, None, 1_000_000_000, now)
+            .unwrap());
+        vault.shares_base = 1;
+        vault.total_shares = 100_000_000;
+        assert!(!np
+            .check_max_drawdown(&mut vault, None, 900_000_000, now)
+            .unwrap());
+        assert_eq!(np.hwm_equity_per_share, NAV_PER_SHARE_PRECISION * 10);
+        assert!(np
+            .check_max_drawdown(&mut vault, None, 700_000_000, now)
+            .unwrap());
+
+        // the high-water mark starts from the manager stats one, in its shares_base
+        let mut vault = Vault {
+            total_shares: 100_000_000,
+            shares_base: 1,
+            ..Vault::default()
+        };
+        let mut np = NavPolicy {
+            max_drawdown_bps: 2000,
+            ..NavPolicy::default()
+        };
+        let manager_stats = ManagerStats {
+            hwm_share_price: 2 * NAV_PER_SHARE_PRECISION,
+            ..ManagerStats::default()
+        };
+        assert!(np
+            .check_max_drawdown(&mut vault, Some(&manager_stats), 1_500_000_000, now)
+            .unwrap());
+        assert_eq!(np.hwm_equity_per_share, 20 * NAV_PER_SHARE_PRECISION);
+    }
+}
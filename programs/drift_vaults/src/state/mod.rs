@@ -1,6 +1,16 @@
 pub use account_maps::*;
 pub use fee_update::*;
+pub use fuel_distribution::*;
+pub use fuel_lockup_period::*;
+pub use fuel_season_snapshot::*;
+pub use manager_stats::*;
 pub use math::*;
+pub use nav_history::*;
+pub use nav_policy::*;
+pub use preview::*;
+pub use reward_stream::*;
+pub use reward_stream_depositor::*;
+pub use tokenized_redeem_request::*;
 pub use tokenized_vault_depositor::*;
 pub use traits::*;
 pub use vault::*;
@@ -11,7 +21,19 @@ pub use withdraw_unit::*;
 pub mod account_maps;
 pub mod events;
 pub mod fee_update;
+pub mod fuel_distribution;
+pub mod fuel_lockup_period;
+pub mod fuel_season_snapshot;
+pub mod manager_stats;
 pub mod math;
+pub mod max_drawdown;
+pub mod nav_breaker;
+pub mod nav_history;
+pub mod nav_policy;
+pub mod preview;
+pub mod reward_stream;
+pub mod reward_stream_depositor;
+pub mod tokenized_redeem_request;
 pub mod tokenized_vault_depositor;
 pub mod traits;
 pub mod vault;
@@ -0,0 +1,166 @@
+use anchor_lang::prelude::*;
+use drift::math::casting::Cast;
+use drift::math::safe_math::SafeMath;
+
+use crate::constants::NAV_POLICY_BPS_PRECISION;
+use crate::error::ErrorCode;
+use crate::events::{NavCircuitBreakerAction, NavCircuitBreakerRecord};
+use crate::state::{calculate_share_price, NavPolicy};
+use crate::validate;
+use crate::Vault;
+
+/// The nav circuit breaker. It rejects deposits, withdraw requests and redeem requests once equity per
+/// share moves more than nav_breaker_threshold_bps within nav_breaker_window, until it's reset.
+impl NavPolicy {
+    pub fn is_nav_breaker_enabled(&self) -> bool {
+        self.nav_breaker_threshold_bps > 0
+    }
+
+    /// Compares the vault's equity per share against the last observation within nav_breaker_window. Trips
+    /// the breaker and returns true if it moved by more than nav_breaker_threshold_bps, otherwise records it
+    /// as the new observation.
+    pub fn check_nav_breaker(
+        &mut self,
+        vault: &Vault,
+        vault_equity: u64,
+        now: i64,
+    ) -> Result<bool> {
+        if !self.is_nav_breaker_enabled() {
+            return Ok(false);
+        }
+
+        validate!(
+            !self.nav_breaker_tripped,
+            ErrorCode::NavCircuitBreakerTripped,
+            "nav circuit breaker tripped at equity per share {}, must be reset",
+            self.last_equity_per_share
+        )?;
+
+        if vault.total_shares == 0 {
+            return Ok(false);
+        }
+
+        let equity_per_share = calculate_share_price(vault_equity, vault.total_shares)?;
+
+        let has_observation = self.last_equity_per_share > 0
+            && self.last_shares_base == vault.shares_base
+            && now.safe_sub(self.last_equity_ts)? <= self.nav_breaker_window;
+
+        if has_observation {
+            let deviation_bps = equity_per_share
+                .abs_diff(self.last_equity_per_share)
+                .safe_mul(NAV_POLICY_BPS_PRECISION.cast()?)?
+                .safe_div(self.last_equity_per_share)?;
+
+            if deviation_bps > self.nav_breaker_threshold_bps.cast()? {
+                msg!(
+                    "nav circuit breaker tripped: equity per share {} -> {} ({} bps) in {}s",
+                    self.last_equity_per_share,
+                    equity_per_share,
+                    deviation_bps,
+                    now.safe_sub(self.last_equity_ts)?
+                );
+                emit!(NavCircuitBreakerRecord {
+                    ts: now,
+                    action: NavCircuitBreakerAction::Tripped,
+                    vault: vault.pubkey,
+                    authority: Pubkey::default(),
+                    last_equity_per_share: self.last_equity_per_share,
+                    last_equity_ts: self.last_equity_ts,
+                    equity_per_share,
+                    threshold_bps: self.nav_breaker_threshold_bps,
+                });
+                self.nav_breaker_tripped = true;
+                return Ok(true);
+            }
+        }
+
+        self.last_equity_per_share = equity_per_share;
+        self.last_equity_ts = now;
+        self.last_shares_base = vault.shares_base;
+
+        Ok(false)
+    }
+
+    /// Clears the breaker and its last observation, the next deposit or withdraw request starts a new one
+    pub fn reset_nav_breaker(&mut self, vault: &Vault, authority: Pubkey, now: i64) {
+        emit!(NavCircuitBreakerRecord {
+            ts: now,
+            action: NavCircuitBreakerAction::Reset,
+            vault: vault.pubkey,
+            authority,
+            last_equity_per_share: self.last_equity_per_share,
+            last_equity_ts: self.last_equity_ts,
+            equity_per_share: 0,
+            threshold_bps: self.nav_breaker_threshold_bps,
+        });
+
+        self.nav_breaker_tripped = false;
+        self.last_equity_per_share = 0;
+        self.last_equity_ts = 0;
+        self.last_shares_base = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::NAV_PER_SHARE_PRECISION;
+
+    #[test]
+    fn test_nav_breaker() {
+        let now = 1_000_000;
+        let mut vault = Vault {
+            total_shares: 1_000_000_000,
+            ..Vault::default()
+        };
+        let mut np = NavPolicy {
+            nav_breaker_threshold_bps: 500, // 5%
+            nav_breaker_window: 3600,
+            ..NavPolicy::default()
+        };
+
+        // first observation
+        assert!(!np.check_nav_breaker(&vault, 1_000_000_000, now).unwrap());
+        assert_eq!(np.last_equity_per_share, NAV_PER_SHARE_PRECISION);
+        assert_eq!(np.last_equity_ts, now);
+
+        // 4% move within the window
+        assert!(!np
+            .check_nav_breaker(&vault, 1_040_000_000, now + 60)
+            .unwrap());
+        assert_eq!(np.last_equity_ts, now + 60);
+
+        // 20% move after the window is not compared
+        assert!(!np
+            .check_nav_breaker(&vault, 1_248_000_000, now + 60 + 3601)
+            .unwrap());
+
+        // 10% drop within the window trips
+        let last_equity_per_share = np.last_equity_per_share;
+        assert!(np
+            .check_nav_breaker(&vault, 1_123_200_000, now + 60 + 3700)
+            .unwrap());
+        assert!(np.nav_breaker_tripped);
+        assert_eq!(np.last_equity_per_share, last_equity_per_share);
+
+        // rejected until reset
+        assert!(np
+            .check_nav_breaker(&vault, 1_248_000_000, now + 60 + 3800)
+            .is_err());
+
+        np.reset_nav_breaker(&vault, Pubkey::default(), now + 60 + 3900);
+        assert!(!np.nav_breaker_tripped);
+        assert!(!np
+            .check_nav_breaker(&vault, 1_123_200_000, now + 60 + 4000)
+            .unwrap());
+
+        // a rebase invalidates the observation
+        vault.shares_base = 2;
+        vault.total_shares = 10_000_000;
+        assert!(!np
+            .check_nav_breaker(&vault, 1_123_200_000, now + 60 + 4100)
+            .unwrap());
+        assert_eq!(np.last_shares_base, 2);
+    }
+}
@@ -0,0 +1,165 @@
+use anchor_lang::prelude::*;
+use bytemuck::Zeroable;
+use drift::math::safe_math::SafeMath;
+use drift_macros::assert_no_slop;
+use static_assertions::const_assert_eq;
+
+use crate::constants::NAV_HISTORY_MIN_SNAPSHOT_INTERVAL;
+use crate::events::VaultRecord;
+use crate::{Size, Vault};
+
+pub const NAV_HISTORY_LENGTH: usize = 128;
+
+#[assert_no_slop]
+#[derive(
+    Default, AnchorSerialize, AnchorDeserialize, Copy, Clone, Eq, PartialEq, Debug, Zeroable,
+)]
+pub struct NavSnapshot {
+    /// The ts the snapshot was taken
+    pub ts: i64,
+    /// The vault's equity (in vault spot_market_index) at the snapshot
+    pub equity: u64,
+    /// The vault's total_shares at the snapshot
+    pub total_shares: u128,
+    /// The vault's shares_base at the snapshot, total_shares are only comparable across equal shares_base
+    pub shares_base: u32,
+    pub padding: [u8; 12],
+}
+
+const_assert_eq!(std::mem::size_of::<NavSnapshot>(), 48);
+
+/// Fixed size ring buffer of the vault's equity and shares, so share price history can be read without
+/// replaying logs. Appended by the snapshot_vault crank and by deposits and withdrawals that pass it in
+/// remaining_accounts, at most once every NAV_HISTORY_MIN_SNAPSHOT_INTERVAL.
+#[assert_no_slop]
+#[account(zero_copy(unsafe))]
+#[derive(Eq, PartialEq, Debug)]
+#[repr(C)]
+pub struct NavHistory {
+    /// The vault this history is for
+    pub vault: Pubkey,
+    /// The index the next snapshot is written to
+    pub head: u32,
+    /// The number of snapshots recorded, up to NAV_HISTORY_LENGTH
+    pub len: u32,
+    pub bump: u8,
+    pub padding: [u8; 7],
+    pub snapshots: [NavSnapshot; NAV_HISTORY_LENGTH],
+}
+
+impl Default for NavHistory {
+    fn default() -> Self {
+        NavHistory {
+            vault: Pubkey::default(),
+            head: 0,
+            len: 0,
+            bump: 0,
+            padding: [0; 7],
+            snapshots: [NavSnapshot::default(); NAV_HISTORY_LENGTH],
+        }
+    }
+}
+
+impl Size for NavHistory {
+    const SIZE: usize = 6192 + 8;
+}
+
+const_assert_eq!(NavHistory::SIZE, std::mem::size_of::<NavHistory>() + 8);
+
+impl NavHistory {
+    /// Returns the nth most recent snapshot, 0 being the latest.
+    pub fn get(&self, n: usize) -> Option<&NavSnapshot> {
+        if n >= self.len as usize {
+            return None;
+        }
+
+        let index = (self.head as usize + NAV_HISTORY_LENGTH - 1 - n) % NAV_HISTORY_LENGTH;
+        Some(&self.snapshots[index])
+    }
+
+    pub fn latest(&self) -> Option<&NavSnapshot> {
+        self.get(0)
+    }
+
+    pub fn is_snapshot_due(&self, now: i64) -> Result<bool> {
+        Ok(match self.latest() {
+            Some(latest) => now.safe_sub(latest.ts)? >= NAV_HISTORY_MIN_SNAPSHOT_INTERVAL,
+            None => true,
+        })
+    }
+
+    /// Records the vault's equity and shares, overwriting the oldest snapshot once the buffer is full.
+    pub fn append(&mut self, vault: &Vault, vault_equity: u64, now: i64) -> Result<()> {
+        self.snapshots[self.head as usize] = NavSnapshot {
+            ts: now,
+            equity: vault_equity,
+            total_shares: vault.total_shares,
+            shares_base: vault.shares_base,
+            padding: [0; 12],
+        };
+        self.head = self.head.safe_add(1)? % NAV_HISTORY_LENGTH as u32;
+        self.len = self.len.safe_add(1)?.min(NAV_HISTORY_LENGTH as u32);
+
+        emit!(VaultRecord {
+            ts: now,
+            vault: vault.pubkey,
+            spot_market_index: vault.spot_market_index,
+            vault_equity_before: vault_equity,
+            total_shares: vault.total_shares,
+            shares_base: vault.shares_base,
+        });
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_nav_history_ring_buffer() {
+        let mut history = NavHistory::default();
+        let mut vault = Vault {
+            total_shares: 1_000_000,
+            ..Vault::default()
+        };
+        let now = 1_000_000;
+
+        assert!(history.latest().is_none());
+        assert!(history.is_snapshot_due(now).unwrap());
+
+        history.append(&vault, 1_000_000, now).unwrap();
+        assert_eq!(history.len, 1);
+        assert_eq!(history.latest().unwrap().equity, 1_000_000);
+        assert!(!history.is_snapshot_due(now + 1).unwrap());
+        assert!(history
+            .is_snapshot_due(now + NAV_HISTORY_MIN_SNAPSHOT_INTERVAL)
+            .unwrap());
+
+        for i in 1..(NAV_HISTORY_LENGTH as i64 + 10) {
+            vault.total_shares += 1;
+            history
+                .append(
+                    &vault,
+                    1_000_000 + i as u64,
+                    now + i * NAV_HISTORY_MIN_SNAPSHOT_INTERVAL,
+                )
+                .unwrap();
+        }
+
+        let last = NAV_HISTORY_LENGTH as i64 + 9;
+        assert_eq!(history.len as usize, NAV_HISTORY_LENGTH);
+        assert_eq!(history.head, 10);
+
+        let latest = history.latest().unwrap();
+        assert_eq!(latest.ts, now + last * NAV_HISTORY_MIN_SNAPSHOT_INTERVAL);
+        assert_eq!(latest.equity, 1_000_000 + last as u64);
+        assert_eq!(latest.total_shares, vault.total_shares);
+
+        // the oldest snapshots were overwritten
+        let oldest = history.get(NAV_HISTORY_LENGTH - 1).unwrap();
+        assert_eq!(oldest.ts, now + 10 * NAV_HISTORY_MIN_SNAPSHOT_INTERVAL);
+        assert!(history.get(NAV_HISTORY_LENGTH).is_none());
+    }
+}
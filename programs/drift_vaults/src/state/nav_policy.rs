@@ -0,0 +1,617 @@
+use anchor_lang::prelude::*;
+use drift::math::casting::Cast;
+use drift::math::safe_math::SafeMath;
+use drift_macros::assert_no_slop;
+use static_assertions::const_assert_eq;
+
+use crate::constants::{MAX_SWING_PRICING_BPS, NAV_POLICY_BPS_PRECISION, ONE_DAY};
+use crate::error::ErrorCode;
+use crate::events::NavPolicyConfig;
+use crate::validate;
+use crate::{Size, Vault};
+
+/// Optional policy on how the vault's equity is priced for deposits and withdrawals. Once a vault has
+/// one, it must be passed in remaining_accounts to every instruction that mints or burns shares.
+/// It also holds the nav circuit breaker state, so it must be writable for deposits and withdraw requests,
+/// and the max drawdown protection state.
+#[assert_no_slop]
+#[account(zero_copy(unsafe))]
+#[derive(Default, Eq, PartialEq, Debug)]
+#[repr(C)]
+pub struct NavPolicy {
+    /// The vault this policy applies to
+    pub vault: Pubkey,
+
+    // swing pricing and oracle validity
+    /// How deposits and withdrawals are swing priced [`SwingPricingMode`]. Default is `Disabled`
+    pub swing_pricing_mode: u8,
+    pub bump: u8,
+    /// Estimated cost to deploy a deposit in bps of equity, added to the equity deposits are priced at
+    pub deposit_spread_bps: u16,
+    /// Estimated cost to unwind a withdrawal in bps of equity, removed from the equity withdrawals are priced at
+    pub withdraw_spread_bps: u16,
+    /// Caps the total swing on either side in bps of equity, including the perp market spread estimate
+    pub max_swing_bps: u16,
+    /// How positions with an invalid oracle are valued [`OracleValidityMode`]. Default is `Strict`
+    pub oracle_validity_mode: u8,
+    pub padding1: [u8; 7],
+
+    // nav circuit breaker, see nav_breaker.rs
+    /// The vault's equity per share last observed by the circuit breaker
+    /// precision: NAV_PER_SHARE_PRECISION
+    pub last_equity_per_share: u128,
+    /// The ts last_equity_per_share was observed at
+    pub last_equity_ts: i64,
+    /// Equity per share moves are only compared against observations younger than this, in seconds
+    pub nav_breaker_window: i64,
+    /// Max move in equity per share within nav_breaker_window before the breaker trips, in bps. 0 disables it
+    pub nav_breaker_threshold_bps: u16,
+    /// Deposits and withdraw requests are rejected until the breaker is reset
+    pub nav_breaker_tripped: bool,
+    pub padding2: [u8; 1],
+    /// The vault's shares_base at last_equity_per_share, a rebase invalidates the observation
+    pub last_shares_base: u32,
+
+    // max drawdown protection, see max_drawdown.rs
+    /// The ts max drawdown protection was triggered, 0 if it isn't
+    pub drawdown_triggered_ts: i64,
+    /// The vault's highest equity per share seen by the max drawdown crank
+    /// precision: NAV_PER_SHARE_PRECISION
+    pub hwm_equity_per_share: u128,
+    /// The ts trading can be re-enabled after a triggered drawdown, 0 if not requested
+    pub reenable_trading_ts: i64,
+    /// The vault's redeem_period before the drawdown waived it, restored when trading is re-enabled
+    pub redeem_period_before_drawdown: i64,
+    /// Max drop in equity per share from hwm_equity_per_share before the vault is set reduce-only, in bps.
+    /// 0 disables it. Once enabled it can only be lowered.
+    pub max_drawdown_bps: u16,
+    pub padding3: [u8; 2],
+    /// The vault's shares_base at hwm_equity_per_share
+    pub hwm_shares_base: u32,
+
+    // queued increase to the withdraw swing
+    /// The ts the queued increase to the withdraw swing can be applied at, 0 if none is queued
+    pub incoming_update_ts: i64,
+    /// The withdraw_spread_bps applied at incoming_update_ts
+    pub incoming_withdraw_spread_bps: u16,
+    /// The max_swing_bps applied at incoming_update_ts
+    pub incoming_max_swing_bps: u16,
+    /// The swing_pricing_mode applied at incoming_update_ts
+    pub incoming_swing_pricing_mode: u8,
+    pub padding4: [u8; 3],
+
+    pub padding: [u64; 3],
+}
+
+impl Size for NavPolicy {
+    const SIZE: usize = 176 + 8;
+}
+
+const_assert_eq!(NavPolicy::SIZE, std::mem::size_of::<NavPolicy>() + 8);
+
+#[derive(Clone, Copy, PartialEq, Debug, Eq)]
+pub enum NavSide {
+    /// Shares are minted at equity plus the cost to deploy
+    Deposit,
+    /// Shares are burned at equity less the cost to unwind
+    Withdraw,
+}
+
+#[derive(Clone, Copy, BorshSerialize, BorshDeserialize, PartialEq, Debug, Eq)]
+#[repr(u8)]
+pub enum SwingPricingMode {
+    /// Deposits and withdrawals are priced at the vault's oracle equity
+    Disabled = 0,
+    /// Deposits and withdrawals are swung by deposit_spread_bps and withdraw_spread_bps
+    Fixed = 1,
+    /// Same as `Fixed`, plus the cost to trade the vault's perp positions at the perp markets' current spreads
+    PerpMarketSpread = 2,
+}
+
+impl TryFrom<u8> for SwingPricingMode {
+    type Error = ErrorCode;
+
+    fn try_from(value: u8) -> std::result::Result<Self, ErrorCode> {
+        match value {
+            0 => Ok(SwingPricingMode::Disabled),
+            1 => Ok(SwingPricingMode::Fixed),
+            2 => Ok(SwingPricingMode::PerpMarketSpread),
+            _ => Err(ErrorCode::InvalidNavPolicy),
+        }
+    }
+}
+
+#[derive(Clone, Copy, BorshSerialize, BorshDeserialize, PartialEq, Debug, Eq)]
+#[repr(u8)]
+pub enum OracleValidityMode {
+    /// Deposits and withdrawals fail while any of the vault's oracles is invalid
+    Strict = 0,
+    /// Withdrawals value positions with an invalid oracle conservatively: assets at zero and liabilities at
+    /// the worse of the oracle price and twap. Deposits still fail until the oracles are valid.
+    ConservativeWithdraws = 1,
+}
+
+impl TryFrom<u8> for OracleValidityMode {
+    type Error = ErrorCode;
+
+    fn try_from(value: u8) -> std::result::Result<Self, ErrorCode> {
+        match value {
+            0 => Ok(OracleValidityMode::Strict),
+            1 => Ok(OracleValidityMode::ConservativeWithdraws),
+            _ => Err(ErrorCode::InvalidNavPolicy),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, AnchorSerialize, AnchorDeserialize, PartialEq, Eq)]
+pub struct NavPolicyParams {
+    pub swing_pricing_mode: u8,
+    pub deposit_spread_bps: u16,
+    pub withdraw_spread_bps: u16,
+    pub max_swing_bps: u16,
+    pub oracle_validity_mode: u8,
+    pub nav_breaker_window: i64,
+    pub nav_breaker_threshold_bps: u16,
+    pub max_drawdown_bps: u16,
+}
+
+impl NavPolicy {
+    /// The settings logged by [`crate::state::events::VaultConfigRecord`]
+    pub fn get_config(&self) -> NavPolicyConfig {
+        NavPolicyConfig {
+            swing_pricing_mode: self.swing_pricing_mode,
+            deposit_spread_bps: self.deposit_spread_bps,
+            withdraw_spread_bps: self.withdraw_spread_bps,
+            max_swing_bps: self.max_swing_bps,
+            oracle_validity_mode: self.oracle_validity_mode,
+            nav_breaker_window: self.nav_breaker_window,
+            nav_breaker_threshold_bps: self.nav_breaker_threshold_bps,
+            nav_breaker_tripped: self.nav_breaker_tripped,
+            max_drawdown_bps: self.max_drawdown_bps,
+            drawdown_triggered_ts: self.drawdown_triggered_ts,
+            reenable_trading_ts: self.reenable_trading_ts,
+            incoming_update_ts: self.incoming_update_ts,
+            incoming_withdraw_spread_bps: self.incoming_withdraw_spread_bps,
+            incoming_max_swing_bps: self.incoming_max_swing_bps,
+            incoming_swing_pricing_mode: self.incoming_swing_pricing_mode,
+        }
+    }
+
+    pub fn swing_pricing_mode(&self) -> Result<SwingPricingMode> {
+        Ok(SwingPricingMode::try_from(self.swing_pricing_mode)?)
+    }
+
+    pub fn oracle_validity_mode(&self) -> Result<OracleValidityMode> {
+        Ok(OracleValidityMode::try_from(self.oracle_validity_mode)?)
+    }
+
+    /// Whether positions with an invalid oracle are tolerated when pricing `side`
+    pub fn tolerates_invalid_oracles(&self, side: NavSide) -> Result<bool> {
+        Ok(side == NavSide::Withdraw
+            && self.oracle_validity_mode()? == OracleValidityMode::ConservativeWithdraws)
+    }
+
+    /// Updates the policy. Changes that raise the swing charged on withdrawals are queued for at least one
+    /// redeem period, so depositors can request a withdraw at the current price before they apply. Everything
+    /// else, including lowering the withdraw swing, applies immediately. Vaults without shares apply
+    /// everything immediately.
+    pub fn update(&mut self, params: NavPolicyParams, vault: &Vault, now: i64) -> Result<()> {
+        SwingPricingMode::try_from(params.swing_pricing_mode)?;
+        OracleValidityMode::try_from(params.oracle_validity_mode)?;
+        validate!(
+            params.nav_breaker_threshold_bps == 0 || params.nav_breaker_window > 0,
+            ErrorCode::InvalidNavPolicy,
+            "nav_breaker_window must be > 0 when the breaker is enabled"
+        )?;
+        validate!(
+            params.max_drawdown_bps <= NAV_POLICY_BPS_PRECISION,
+            ErrorCode::InvalidNavPolicy,
+            "max_drawdown_bps must be <= {}",
+            NAV_POLICY_BPS_PRECISION
+        )?;
+        validate!(
+            self.max_drawdown_bps == 0
+                || (params.max_drawdown_bps > 0
+                    && params.max_drawdown_bps <= self.max_drawdown_bps),
+            ErrorCode::InvalidNavPolicy,
+            "max_drawdown_bps can only be lowered once enabled"
+        )?;
+        validate!(
+            params.max_swing_bps <= MAX_SWING_PRICING_BPS,
+            ErrorCode::InvalidNavPolicy,
+            "max_swing_bps {} > {}",
+            params.max_swing_bps,
+            MAX_SWING_PRICING_BPS
+        )?;
+        validate!(
+            params.deposit_spread_bps <= params.max_swing_bps
+                && params.withdraw_spread_bps <= params.max_swing_bps,
+            ErrorCode::InvalidNavPolicy,
+            "spreads must be <= max_swing_bps"
+        )?;
+
+        self.try_apply_incoming_update(now);
+
+        msg!(
+            "Updating swing pricing mode {} -> {}, deposit spread {} -> {}, withdraw spread {} -> {}, max swing {} -> {}",
+            self.swing_pricing_mode,
+            params.swing_pricing_mode,
+            self.deposit_spread_bps,
+            params.deposit_spread_bps,
+            self.withdraw_spread_bps,
+            params.withdraw_spread_bps,
+            self.max_swing_bps,
+            params.max_swing_bps
+        );
+        msg!(
+            "Updating oracle validity mode {} -> {}",
+            self.oracle_validity_mode,
+            params.oracle_validity_mode
+        );
+        msg!(
+            "Updating nav breaker threshold {} -> {}, window {} -> {}",
+            self.nav_breaker_threshold_bps,
+            params.nav_breaker_threshold_bps,
+            self.nav_breaker_window,
+            params.nav_breaker_window
+        );
+        msg!(
+            "Updating max drawdown {} -> {}",
+            self.max_drawdown_bps,
+            params.max_drawdown_bps
+        );
+
+        let increases_withdraw_swing = params.swing_pricing_mode > self.swing_pricing_mode
+            || params.withdraw_spread_bps > self.withdraw_spread_bps
+            || params.max_swing_bps > self.max_swing_bps;
+
+        if increases_withdraw_swing && vault.total_shares > 0 {
+            self.incoming_update_ts = now.safe_add(vault.redeem_period.max(ONE_DAY))?;
+            self.incoming_swing_pricing_mode = params.swing_pricing_mode;
+            self.incoming_withdraw_spread_bps = params.withdraw_spread_bps;
+            self.incoming_max_swing_bps = params.max_swing_bps;
+            msg!(
+                "Withdraw swing increase queued until {}",
+                self.incoming_update_ts
+            );
+
+            // the parts of the update that lower the withdraw swing still apply now
+            self.swing_pricing_mode = self.swing_pricing_mode.min(params.swing_pricing_mode);
+            self.withdraw_spread_bps = self.withdraw_spread_bps.min(params.withdraw_spread_bps);
+            self.max_swing_bps = self.max_swing_bps.min(params.max_swing_bps);
+        } else {
+            self.swing_pricing_mode = params.swing_pricing_mode;
+            self.withdraw_spread_bps = params.withdraw_spread_bps;
+            self.max_swing_bps = params.max_swing_bps;
+            self.reset_incoming_update();
+        }
+
+        self.deposit_spread_bps = params.deposit_spread_bps;
+        self.oracle_validity_mode = params.oracle_validity_mode;
+        self.nav_breaker_threshold_bps = params.nav_breaker_threshold_bps;
+        self.nav_breaker_window = params.nav_breaker_window;
+        self.max_drawdown_bps = params.max_drawdown_bps;
+
+        Ok(())
+    }
+
+    pub fn has_incoming_update(&self) -> bool {
+        self.incoming_update_ts > 0
+    }
+
+    /// Applies the queued withdraw swing increase once its timelock has passed
+    pub fn try_apply_incoming_update(&mut self, now: i64) {
+        if !self.has_incoming_update() || now < self.incoming_update_ts {
+            return;
+        }
+
+        msg!(
+            "Applying queued swing pricing mode {} -> {}, withdraw spread {} -> {}, max swing {} -> {}",
+            self.swing_pricing_mode,
+            self.incoming_swing_pricing_mode,
+            self.withdraw_spread_bps,
+            self.incoming_withdraw_spread_bps,
+            self.max_swing_bps,
+            self.incoming_max_swing_bps
+        );
+
+        self.swing_pricing_mode = self.incoming_swing_pricing_mode;
+        self.withdraw_spread_bps = self.incoming_withdraw_spread_bps;
+        self.max_swing_bps = self.incoming_max_swing_bps;
+        self.reset_incoming_update();
+    }
+
+    fn reset_incoming_update(&mut self) {
+        self.incoming_update_ts = 0;
+        self.incoming_swing_pricing_mode = 0;
+        self.incoming_withdraw_spread_bps = 0;
+        self.incoming_max_swing_bps = 0;
+    }
+
+    /// Prices the vault equity for `side`. `perp_spread_cost` is the estimated cost (in deposit asset) to
+    /// trade the vault's perp positions and is only used in `PerpMarketSpread` mode.
+    pub fn apply_swing(
+        &self,
+        side: NavSide,
+        vault_equity: u64,
+        perp_spread_cost: u64,
+    ) -> Result<u64> {
+        let spread_bps = match self.swing_pricing_mode()? {
+            SwingPricingMode::Disabled => return Ok(vault_equity),
+            SwingPricingMode::Fixed | SwingPricingMode::PerpMarketSpread => match side {
+                NavSide::Deposit => self.deposit_spread_bps,
+                NavSide::Withdraw => self.withdraw_spread_bps,
+            },
+        };
+
+        let mut swing = bps_of(vault_equity, spread_bps)?;
+        if self.swing_pricing_mode()? == SwingPricingMode::PerpMarketSpread {
+            swing = swing.safe_add(perp_spread_cost)?;
+        }
+        let swing = swing.min(bps_of(vault_equity, self.max_swing_bps)?);
+
+        Ok(match side {
+            NavSide::Deposit => vault_equity.safe_add(swing)?,
+            NavSide::Withdraw => vault_equity.safe_sub(swing)?,
+        })
+    }
+}
+
+fn bps_of(amount: u64, bps: u16) -> Result<u64> {
+    Ok(amount
+        .cast::<u128>()?
+        .safe_mul(bps.cast()?)?
+        .safe_div(NAV_POLICY_BPS_PRECISION.cast()?)?
+        .cast()?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn nav_policy(mode: SwingPricingMode) -> NavPolicy {
+        NavPolicy {
+            swing_pricing_mode: mode as u8,
+            deposit_spread_bps: 10,
+            withdraw_spread_bps: 30,
+            max_swing_bps: 100,
+            ..NavPolicy::default()
+        }
+    }
+
+    #[test]
+    fn test_apply_swing() {
+        let vault_equity = 1_000_000_000;
+
+        let np = nav_policy(SwingPricingMode::Disabled);
+        assert_eq!(
+            np.apply_swing(NavSide::Deposit, vault_equity, 5_000_000)
+                .unwrap(),
+            vault_equity
+        );
+        assert_eq!(
+            np.apply_swing(NavSide::Withdraw, vault_equity, 5_000_000)
+                .unwrap(),
+            vault_equity
+        );
+
+        // perp spread cost is ignored in fixed mode
+        let np = nav_policy(SwingPricingMode::Fixed);
+        assert_eq!(
+            np.apply_swing(NavSide::Deposit, vault_equity, 5_000_000)
+                .unwrap(),
+            1_001_000_000
+        );
+        assert_eq!(
+            np.apply_swing(NavSide::Withdraw, vault_equity, 5_000_000)
+                .unwrap(),
+            997_000_000
+        );
+
+        let np = nav_policy(SwingPricingMode::PerpMarketSpread);
+        assert_eq!(
+            np.apply_swing(NavSide::Deposit, vault_equity, 5_000_000)
+                .unwrap(),
+            1_006_000_000
+        );
+        assert_eq!(
+            np.apply_swing(NavSide::Withdraw, vault_equity, 5_000_000)
+                .unwrap(),
+            992_000_000
+        );
+
+        // swing is capped at max_swing_bps
+        assert_eq!(
+            np.apply_swing(NavSide::Deposit, vault_equity, 50_000_000)
+                .unwrap(),
+            1_010_000_000
+        );
+        assert_eq!(
+            np.apply_swing(NavSide::Withdraw, vault_equity, 50_000_000)
+                .unwrap(),
+            990_000_000
+        );
+        assert_eq!(np.apply_swing(NavSide::Withdraw, 0, 50_000_000).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_update_nav_policy() {
+        let now = 1_000_000;
+        let vault = Vault::default();
+        let mut np = NavPolicy::default();
+
+        let params = NavPolicyParams {
+            swing_pricing_mode: SwingPricingMode::Fixed as u8,
+            deposit_spread_bps: 10,
+            withdraw_spread_bps: 30,
+            max_swing_bps: MAX_SWING_PRICING_BPS + 1,
+            oracle_validity_mode: OracleValidityMode::Strict as u8,
+            nav_breaker_window: 0,
+            nav_breaker_threshold_bps: 0,
+            max_drawdown_bps: 0,
+        };
+        assert!(np.update(params, &vault, now).is_err());
+
+        let params = NavPolicyParams {
+            max_swing_bps: 20,
+            ..params
+        };
+        assert!(np.update(params, &vault, now).is_err());
+
+        let params = NavPolicyParams {
+            swing_pricing_mode: 3,
+            max_swing_bps: 100,
+            ..params
+        };
+        assert!(np.update(params, &vault, now).is_err());
+
+        let params = NavPolicyParams {
+            swing_pricing_mode: SwingPricingMode::PerpMarketSpread as u8,
+            oracle_validity_mode: 2,
+            ..params
+        };
+        assert!(np.update(params, &vault, now).is_err());
+
+        let params = NavPolicyParams {
+            oracle_validity_mode: OracleValidityMode::ConservativeWithdraws as u8,
+            ..params
+        };
+        np.update(params, &vault, now).unwrap();
+        assert_eq!(
+            np.swing_pricing_mode().unwrap(),
+            SwingPricingMode::PerpMarketSpread
+        );
+        assert_eq!(np.deposit_spread_bps, 10);
+        assert_eq!(np.withdraw_spread_bps, 30);
+        assert_eq!(np.max_swing_bps, 100);
+        assert_eq!(
+            np.oracle_validity_mode().unwrap(),
+            OracleValidityMode::ConservativeWithdraws
+        );
+
+        // breaker needs a window
+        let params = NavPolicyParams {
+            nav_breaker_threshold_bps: 500,
+            ..params
+        };
+        assert!(np.update(params, &vault, now).is_err());
+
+        let params = NavPolicyParams {
+            nav_breaker_window: 3600,
+            ..params
+        };
+        np.update(params, &vault, now).unwrap();
+        assert!(np.is_nav_breaker_enabled());
+
+        // max drawdown can only be lowered once enabled
+        let params = NavPolicyParams {
+            max_drawdown_bps: 2000,
+            ..params
+        };
+        np.update(params, &vault, now).unwrap();
+        assert!(np.is_drawdown_protection_enabled());
+        let params = NavPolicyParams {
+            max_drawdown_bps: 2001,
+            ..params
+        };
+        assert!(np.update(params, &vault, now).is_err());
+        let params = NavPolicyParams {
+            max_drawdown_bps: 0,
+            ..params
+        };
+        assert!(np.update(params, &vault, now).is_err());
+        let params = NavPolicyParams {
+            max_drawdown_bps: 1500,
+            ..params
+        };
+        np.update(params, &vault, now).unwrap();
+        assert_eq!(np.max_drawdown_bps, 1500);
+    }
+
+    #[test]
+    fn test_update_nav_policy_timelock() {
+        let mut now = 1_000_000;
+        let vault = Vault {
+            total_shares: 1_000_000,
+            redeem_period: 2 * ONE_DAY,
+            ..Vault::default()
+        };
+        let mut np = nav_policy(SwingPricingMode::Fixed);
+
+        let params = NavPolicyParams {
+            swing_pricing_mode: SwingPricingMode::Fixed as u8,
+            deposit_spread_bps: 20,
+            withdraw_spread_bps: 30,
+            max_swing_bps: 100,
+            oracle_validity_mode: OracleValidityMode::Strict as u8,
+            nav_breaker_window: 0,
+            nav_breaker_threshold_bps: 0,
+            max_drawdown_bps: 0,
+        };
+
+        // raising the deposit spread applies immediately
+        np.update(params, &vault, now).unwrap();
+        assert_eq!(np.deposit_spread_bps, 20);
+        assert!(!np.has_incoming_update());
+
+        // raising the withdraw spread waits a redeem period, lowering max swing applies now
+        let params = NavPolicyParams {
+            withdraw_spread_bps: 50,
+            max_swing_bps: 80,
+            ..params
+        };
+        np.update(params, &vault, now).unwrap();
+        assert_eq!(np.withdraw_spread_bps, 30);
+        assert_eq!(np.max_swing_bps, 80);
+        assert_eq!(np.incoming_update_ts, now + 2 * ONE_DAY);
+
+        now += ONE_DAY;
+        np.try_apply_incoming_update(now);
+        assert_eq!(np.withdraw_spread_bps, 30);
+
+        now += ONE_DAY;
+        np.try_apply_incoming_update(now);
+        assert_eq!(np.withdraw_spread_bps, 50);
+        assert_eq!(np.max_swing_bps, 80);
+        assert!(!np.has_incoming_update());
+
+        // switching to perp market spreads raises the withdraw swing too
+        let params = NavPolicyParams {
+            swing_pricing_mode: SwingPricingMode::PerpMarketSpread as u8,
+            ..params
+        };
+        np.update(params, &vault, now).unwrap();
+        assert_eq!(np.swing_pricing_mode().unwrap(), SwingPricingMode::Fixed);
+        assert!(np.has_incoming_update());
+
+        // lowering it again cancels the queued increase
+        let params = NavPolicyParams {
+            swing_pricing_mode: SwingPricingMode::Fixed as u8,
+            withdraw_spread_bps: 10,
+            ..params
+        };
+        np.update(params, &vault, now).unwrap();
+        assert_eq!(np.withdraw_spread_bps, 10);
+        assert!(!np.has_incoming_update());
+
+        // vaults without shares apply increases immediately
+        let params = NavPolicyParams {
+            withdraw_spread_bps: 50,
+            ..params
+        };
+        np.update(params, &Vault::default(), now).unwrap();
+        assert_eq!(np.withdraw_spread_bps, 50);
+        assert!(!np.has_incoming_update());
+    }
+
+    #[test]
+    fn test_tolerates_invalid_oracles() {
+        let mut np = nav_policy(SwingPricingMode::Disabled);
+        assert!(!np.tolerates_invalid_oracles(NavSide::Deposit).unwrap());
+        assert!(!np.tolerates_invalid_oracles(NavSide::Withdraw).unwrap());
+
+        np.oracle_validity_mode = OracleValidityMode::ConservativeWithdraws as u8;
+        assert!(!np.tolerates_invalid_oracles(NavSide::Deposit).unwrap());
+        assert!(np.tolerates_invalid_oracles(NavSide::Withdraw).unwrap());
+    }
+}
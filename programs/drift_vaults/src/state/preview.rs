@@ -0,0 +1,276 @@
+use std::cell::RefCell;
+
+use anchor_lang::prelude::*;
+use drift::math::insurance::if_shares_to_vault_amount as depositor_shares_to_vault_amount;
+use drift::math::safe_math::SafeMath;
+
+use crate::state::{
+    FeeUpdate, Vault, VaultDepositor, VaultDepositorBase, VaultFee, VaultProtocol, WithdrawUnit,
+};
+
+/// Returned by preview_deposit
+#[derive(Default, AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DepositPreview {
+    /// The vault equity the deposit is priced at
+    pub vault_equity: u64,
+    /// The amount deposited, capped to stay within the vault's max_tokens
+    pub amount: u64,
+    /// The shares minted for the deposit
+    pub shares: u128,
+    /// The depositor's shares after the deposit
+    pub vault_shares_after: u128,
+    /// The vault's total_shares after the deposit
+    pub total_shares_after: u128,
+    pub shares_base: u32,
+    /// Profit share paid by the depositor's existing shares before the deposit
+    pub manager_profit_share: u64,
+    pub protocol_profit_share: u64,
+}
+
+/// Returned by preview_withdraw
+#[derive(Default, AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct WithdrawPreview {
+    /// The vault equity the withdraw request is priced at
+    pub vault_equity: u64,
+    /// The value of the withdraw request. The withdraw pays the lesser of this and the shares' value once
+    /// the redeem period is over
+    pub withdraw_value: u64,
+    /// The shares in the withdraw request
+    pub shares: u128,
+    /// The depositor's shares, after profit share, that the request is taken from
+    pub vault_shares: u128,
+    pub shares_base: u32,
+    /// The ts the withdraw can be completed at
+    pub redeem_period_end_ts: i64,
+    /// Profit share paid by the depositor before the request
+    pub manager_profit_share: u64,
+    pub protocol_profit_share: u64,
+}
+
+/// Returned by preview_depositor_equity
+#[derive(Default, AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DepositorEquityPreview {
+    /// The vault equity the depositor's shares are valued at
+    pub vault_equity: u64,
+    /// The depositor's shares after fees and profit share
+    pub vault_shares: u128,
+    pub shares_base: u32,
+    /// The value of the depositor's shares after fees and profit share
+    pub equity: u64,
+    /// Profit share the depositor would pay now. Not charged while a withdraw request is pending
+    pub manager_profit_share: u64,
+    pub protocol_profit_share: u64,
+    /// The deposit asset paid for the depositor's shares, equity less cost_basis is its unrealized pnl
+    pub cost_basis: u64,
+    /// cost_basis per share in shares_base. precision: NAV_PER_SHARE_PRECISION
+    pub average_entry_price: u64,
+}
+
+/// Returned by preview_fees
+#[derive(Default, AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FeesPreview {
+    pub vault_equity: u64,
+    /// The vault's fees, including a pending fee update whose timelock has passed
+    pub management_fee: i64,
+    pub profit_share: u32,
+    pub hurdle_rate: u32,
+    /// Fees accrued since the vault's last_fee_update_ts
+    pub management_fee_payment: i64,
+    pub management_fee_shares: i64,
+    pub protocol_fee_payment: i64,
+    pub protocol_fee_shares: i64,
+    /// The vault's total_shares after fees
+    pub total_shares_after: u128,
+    pub shares_base: u32,
+}
+
+/// Copies of the state a deposit or withdraw changes, so the vault's state machine can be run for previews
+/// without writing to accounts or emitting records.
+pub struct VaultPreview {
+    vault: Vault,
+    vault_protocol: Option<RefCell<VaultProtocol>>,
+    vault_equity: u64,
+    now: i64,
+}
+
+impl VaultPreview {
+    pub fn new(
+        vault: &Vault,
+        vault_protocol: &Option<AccountLoader<VaultProtocol>>,
+        fee_update: &Option<AccountLoader<FeeUpdate>>,
+        vault_equity: u64,
+        now: i64,
+    ) -> Result<Self> {
+        let mut vault = *vault;
+
+        // applied to the copy here, apply_fee would write to the FeeUpdate account
+        if let Some(fee_update) = fee_update {
+            let fee_update = fee_update.load()?;
+            if fee_update.is_pending() && now >= fee_update.incoming_update_ts {
+                fee_update.apply_incoming_fees(&mut vault);
+            }
+        }
+
+        let vault_protocol = match vault_protocol {
+            Some(vp) => Some(RefCell::new(*vp.load()?)),
+            None => None,
+        };
+
+        Ok(VaultPreview {
+            vault,
+            vault_protocol,
+            vault_equity,
+            now,
+        })
+    }
+
+    pub fn preview_fees(mut self) -> Result<FeesPreview> {
+        let mut vp = self.vault_protocol.as_ref().map(|vp| vp.borrow_mut());
+
+        let VaultFee {
+            management_fee_payment,
+            management_fee_shares,
+            protocol_fee_payment,
+            protocol_fee_shares,
+        } = self
+            .vault
+            .apply_fee(&mut vp, &mut None, self.vault_equity, self.now)?;
+
+        Ok(FeesPreview {
+            vault_equity: self.vault_equity,
+            management_fee: self.vault.management_fee,
+            profit_share: self.vault.profit_share,
+            hurdle_rate: self.vault.hurdle_rate,
+            management_fee_payment,
+            management_fee_shares,
+            protocol_fee_payment,
+            protocol_fee_shares,
+            total_shares_after: self.vault.total_shares,
+            shares_base: self.vault.shares_base,
+        })
+    }
+
+    /// Runs [`VaultDepositor::apply_deposit`] on the copies
+    pub fn preview_deposit(
+        mut self,
+        mut vault_depositor: VaultDepositor,
+        amount: u64,
+    ) -> Result<DepositPreview> {
+        let vault_equity = self.vault_equity;
+
+        let mut vp = self.vault_protocol.as_ref().map(|vp| vp.borrow_mut());
+        let change = vault_depositor.apply_deposit(
+            amount,
+            vault_equity,
+            &mut self.vault,
+            &mut vp,
+            &mut None,
+            self.now,
+            None,
+        )?;
+
+        Ok(DepositPreview {
+            vault_equity,
+            amount,
+            shares: change.shares,
+            vault_shares_after: vault_depositor.get_vault_shares(),
+            total_shares_after: self.vault.total_shares,
+            shares_base: self.vault.shares_base,
+            manager_profit_share: change.manager_profit_share,
+            protocol_profit_share: change.protocol_profit_share,
+        })
+    }
+
+    /// Runs [`VaultDepositor::apply_withdraw_request`] on the copies
+    pub fn preview_withdraw(
+        mut self,
+        mut vault_depositor: VaultDepositor,
+        withdraw_amount: u64,
+        withdraw_unit: WithdrawUnit,
+    ) -> Result<WithdrawPreview> {
+        let vault_equity = self.vault_equity;
+
+        let mut vp = self.vault_protocol.as_ref().map(|vp| vp.borrow_mut());
+        let change = vault_depositor.apply_withdraw_request(
+            withdraw_amount,
+            withdraw_unit,
+            vault_equity,
+            &mut self.vault,
+            &mut vp,
+            &mut None,
+            self.now,
+            None,
+        )?;
+
+        Ok(WithdrawPreview {
+            vault_equity,
+            withdraw_value: change.amount,
+            shares: change.shares,
+            vault_shares: change.vault_shares_before,
+            shares_base: self.vault.shares_base,
+            redeem_period_end_ts: self.now.safe_add(self.vault.redeem_period)?,
+            manager_profit_share: change.manager_profit_share,
+            protocol_profit_share: change.protocol_profit_share,
+        })
+    }
+
+    pub fn preview_depositor_equity(
+        mut self,
+        mut vault_depositor: VaultDepositor,
+    ) -> Result<DepositorEquityPreview> {
+        let vault_equity = self.vault_equity;
+
+        let mut vp = self.vault_protocol.as_ref().map(|vp| vp.borrow_mut());
+        vault_depositor.apply_rebase(&mut self.vault, &mut vp, vault_equity)?;
+        self.vault
+            .apply_fee(&mut vp, &mut None, vault_equity, self.now)?;
+
+        // profit share is charged when the request is made, not while it is pending
+        let (manager_profit_share, protocol_profit_share) =
+            if vault_depositor.last_withdraw_request.pending() {
+                (0, 0)
+            } else {
+                VaultDepositorBase::apply_profit_share(
+                    &mut vault_depositor,
+                    vault_equity,
+                    &mut self.vault,
+                    &mut vp,
+                )?
+            };
+
+        let vault_shares = vault_depositor.checked_vault_shares(&self.vault)?;
+        let equity =
+            depositor_shares_to_vault_amount(vault_shares, self.vault.total_shares, vault_equity)?;
+
+        Ok(DepositorEquityPreview {
+            vault_equity,
+            vault_shares,
+            shares_base: self.vault.shares_base,
+            equity,
+            manager_profit_share,
+            protocol_profit_share,
+            cost_basis: vault_depositor.checked_cost_basis(),
+            average_entry_price: vault_depositor.get_average_entry_price(&self.vault)?,
+        })
+    }
+
+    /// Runs [`VaultDepositor::apply_withdraw`] on the copies, without the redeem period check: the amount
+    /// the depositor's pending withdraw request pays out at the current equity, 0 if there is none.
+    pub fn preview_withdraw_payout(mut self, mut vault_depositor: VaultDepositor) -> Result<u64> {
+        if !vault_depositor.last_withdraw_request.pending() {
+            return Ok(0);
+        }
+
+        let mut vp = self.vault_protocol.as_ref().map(|vp| vp.borrow_mut());
+        let change = vault_depositor.apply_withdraw(
+            self.vault_equity,
+            &mut self.vault,
+            &mut vp,
+            &mut None,
+            self.now,
+            None,
+        )?;
+
+        Ok(change.amount)
+    }
+}
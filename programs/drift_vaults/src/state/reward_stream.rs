@@ -0,0 +1,313 @@
+use anchor_lang::prelude::*;
+use drift::math::casting::Cast;
+use drift::math::safe_math::SafeMath;
+use drift_macros::assert_no_slop;
+use static_assertions::const_assert_eq;
+
+use crate::constants::REWARD_SHARE_PRECISION;
+use crate::error::ErrorCode;
+use crate::validate;
+use crate::Size;
+
+/// Streams an SPL token to the vault's depositors at a fixed rate. Depositors opt in with a
+/// [`RewardStreamDepositor`], which registers their shares in `total_shares`, and emitted rewards are spread
+/// over the registered shares through `cumulative_reward_per_share`. The stream only emits while it has
+/// funds that haven't been distributed.
+///
+/// [`RewardStreamDepositor`]: crate::state::RewardStreamDepositor
+#[assert_no_slop]
+#[account(zero_copy(unsafe))]
+#[derive(Default, Eq, PartialEq, Debug)]
+#[repr(C)]
+pub struct RewardStream {
+    /// The vault the stream pays depositors of
+    pub vault: Pubkey,
+    /// The reward token mint
+    pub mint: Pubkey,
+    /// The token account holding the rewards, owned by the vault
+    pub token_account: Pubkey,
+    /// precision: REWARD_SHARE_PRECISION, per share in `shares_base`
+    pub cumulative_reward_per_share: u128,
+    /// Reward tokens emitted per second
+    pub reward_rate: u64,
+    /// Lifetime reward tokens funded, net of the remainder returned on close
+    pub total_funded: u64,
+    /// Lifetime reward tokens emitted to depositors
+    pub total_distributed: u64,
+    /// Lifetime reward tokens claimed by depositors
+    pub total_claimed: u64,
+    /// The ts the stream starts emitting
+    pub start_ts: i64,
+    /// The ts the stream stops emitting
+    pub end_ts: i64,
+    /// Last ts rewards were emitted
+    pub last_update_ts: i64,
+    /// The vault.shares_base when the stream was created, shares from later bases are scaled to it
+    pub shares_base: u32,
+    /// Distinguishes the vault's streams, part of the stream's pda seeds
+    pub reward_stream_id: u16,
+    pub bump: u8,
+    pub padding1: [u8; 1],
+    /// The shares registered by the stream's depositors, in `shares_base`
+    pub total_shares: u128,
+    /// The number of open [`RewardStreamDepositor`]s, the stream can only be closed once there are none
+    ///
+    /// [`RewardStreamDepositor`]: crate::state::RewardStreamDepositor
+    pub depositor_count: u32,
+    pub padding2: [u8; 4],
+    pub padding: [u64; 1],
+}
+
+impl Size for RewardStream {
+    const SIZE: usize = 208 + 8;
+}
+
+const_assert_eq!(RewardStream::SIZE, std::mem::size_of::<RewardStream>() + 8);
+
+impl RewardStream {
+    pub fn is_ended(&self, now: i64) -> bool {
+        now >= self.end_ts
+    }
+
+    pub fn get_undistributed_rewards(&self) -> Result<u64> {
+        self.total_funded.safe_sub(self.total_distributed)
+    }
+
+    /// Converts shares in `shares_base` to the stream's shares base. A rebase divides shares by
+    /// 10^expo, so each share in a later base is worth 10^expo shares of the stream's base.
+    pub fn to_stream_shares(&self, shares: u128, shares_base: u32) -> Result<u128> {
+        validate!(
+            shares_base >= self.shares_base,
+            ErrorCode::InvalidVaultRebase,
+            "shares base {} < reward stream shares base {}",
+            shares_base,
+            self.shares_base
+        )?;
+
+        shares.safe_mul(10_u128.pow(shares_base.safe_sub(self.shares_base)?))
+    }
+
+    /// Emits rewards for the time elapsed since the last update over the registered shares.
+    /// Time where no shares are registered or the stream has no undistributed funds emits nothing.
+    pub fn update(&mut self, now: i64) -> Result<u128> {
+        let from_ts = self.last_update_ts.max(self.start_ts);
+        let to_ts = now.min(self.end_ts);
+
+        if to_ts > from_ts {
+            let undistributed_rewards = self.get_undistributed_rewards()?;
+
+            if self.total_shares > 0 && undistributed_rewards > 0 {
+                let rewards = self
+                    .reward_rate
+                    .safe_mul(to_ts.safe_sub(from_ts)?.cast()?)?
+                    .min(undistributed_rewards);
+
+                self.cumulative_reward_per_share = self.cumulative_reward_per_share.safe_add(
+                    rewards
+                        .cast::<u128>()?
+                        .safe_mul(REWARD_SHARE_PRECISION)?
+                        .safe_div(self.total_shares)?,
+                )?;
+                self.total_distributed = self.total_distributed.safe_add(rewards)?;
+            }
+        }
+
+        self.last_update_ts = self.last_update_ts.max(now);
+
+        Ok(self.cumulative_reward_per_share)
+    }
+
+    /// Adds funds to the stream. The stream should be updated beforehand so the time it spent
+    /// unfunded doesn't emit the new funds.
+    pub fn fund(&mut self, amount: u64, now: i64) -> Result<()> {
+        validate!(
+            !self.is_ended(now),
+            ErrorCode::InvalidRewardStream,
+            "reward stream ended at {}",
+            self.end_ts
+        )?;
+
+        self.total_funded = self.total_funded.safe_add(amount)?;
+
+        Ok(())
+    }
+
+    /// Ends the stream and returns the rewards that haven't been emitted. Rewards already emitted
+    /// stay claimable by depositors. The stream should be updated beforehand.
+    pub fn close(&mut self, now: i64) -> Result<u64> {
+        let remainder = self.get_undistributed_rewards()?;
+
+        self.end_ts = self.end_ts.min(now);
+        self.total_funded = self.total_distributed;
+
+        Ok(remainder)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anchor_lang::prelude::Pubkey;
+
+    use crate::constants::REWARD_SHARE_PRECISION;
+    use crate::state::{RewardStream, RewardStreamDepositor};
+
+    #[test]
+    fn test_reward_stream() {
+        let now = 1000;
+        let mut stream = RewardStream {
+            reward_rate: 10,
+            start_ts: now,
+            end_ts: now + 100,
+            last_update_ts: now,
+            ..RewardStream::default()
+        };
+
+        // no registered shares, nothing emitted
+        stream.fund(500, now).unwrap();
+        stream.update(now + 10).unwrap();
+        assert_eq!(stream.cumulative_reward_per_share, 0);
+        assert_eq!(stream.total_distributed, 0);
+
+        let mut depositor =
+            RewardStreamDepositor::new(Pubkey::default(), Pubkey::default(), Pubkey::default(), 0);
+        depositor
+            .update_rewards(&mut stream, 100, now + 10)
+            .unwrap();
+        assert_eq!(stream.total_shares, 100);
+
+        // 10 per second over 100 shares
+        stream.update(now + 20).unwrap();
+        assert_eq!(stream.cumulative_reward_per_share, REWARD_SHARE_PRECISION);
+        assert_eq!(stream.total_distributed, 100);
+
+        // depositor doubled their shares, they earn on them from now on
+        let rewards = depositor
+            .update_rewards(&mut stream, 200, now + 20)
+            .unwrap();
+        assert_eq!(rewards, 100);
+        assert_eq!(stream.total_shares, 200);
+
+        // underfunded, emits what's left
+        stream.update(now + 80).unwrap();
+        assert_eq!(stream.total_distributed, 500);
+        assert_eq!(
+            stream.cumulative_reward_per_share,
+            3 * REWARD_SHARE_PRECISION
+        );
+        let rewards = depositor
+            .update_rewards(&mut stream, 200, now + 80)
+            .unwrap();
+        assert_eq!(rewards, 500);
+
+        let claimed = depositor.claim(&mut stream).unwrap();
+        assert_eq!(claimed, 500);
+        assert_eq!(depositor.unclaimed_rewards, 0);
+        assert_eq!(stream.total_claimed, 500);
+
+        // funds after the gap only emit from now on
+        stream.update(now + 90).unwrap();
+        assert_eq!(stream.total_distributed, 500);
+        stream.fund(1000, now + 90).unwrap();
+        stream.update(now + 95).unwrap();
+        assert_eq!(stream.total_distributed, 550);
+
+        // closing returns what wasn't emitted
+        let remainder = stream.close(now + 95).unwrap();
+        assert_eq!(remainder, 950);
+        assert_eq!(stream.total_funded, 550);
+        assert_eq!(stream.end_ts, now + 95);
+        assert!(stream.fund(100, now + 96).is_err());
+
+        stream.update(now + 200).unwrap();
+        assert_eq!(stream.total_distributed, 550);
+        let rewards = depositor
+            .update_rewards(&mut stream, 200, now + 200)
+            .unwrap();
+        assert_eq!(rewards, 50);
+    }
+
+    #[test]
+    fn test_reward_stream_transfer_round_trip() {
+        let now = 1000;
+        let mut stream = RewardStream {
+            reward_rate: 10,
+            total_funded: 1000,
+            start_ts: now,
+            end_ts: now + 100,
+            last_update_ts: now,
+            ..RewardStream::default()
+        };
+
+        let mut depositor_0 =
+            RewardStreamDepositor::new(Pubkey::default(), Pubkey::default(), Pubkey::default(), 0);
+        let mut depositor_1 =
+            RewardStreamDepositor::new(Pubkey::default(), Pubkey::default(), Pubkey::default(), 0);
+        depositor_0.update_rewards(&mut stream, 100, now).unwrap();
+        depositor_1.update_rewards(&mut stream, 100, now).unwrap();
+
+        // depositor_0 transfers all their shares to depositor_1 and gets them back, both sides are
+        // settled on each transfer so the shares never earn twice
+        stream.update(now + 10).unwrap();
+        depositor_0
+            .update_rewards(&mut stream, 0, now + 10)
+            .unwrap();
+        depositor_1
+            .update_rewards(&mut stream, 200, now + 10)
+            .unwrap();
+        assert_eq!(stream.total_shares, 200);
+
+        stream.update(now + 20).unwrap();
+        depositor_1
+            .update_rewards(&mut stream, 100, now + 20)
+            .unwrap();
+        depositor_0
+            .update_rewards(&mut stream, 100, now + 20)
+            .unwrap();
+        assert_eq!(stream.total_shares, 200);
+
+        stream.update(now + 30).unwrap();
+        let rewards_0 = depositor_0
+            .update_rewards(&mut stream, 100, now + 30)
+            .unwrap();
+        let rewards_1 = depositor_1
+            .update_rewards(&mut stream, 100, now + 30)
+            .unwrap();
+
+        // 50 + 0 + 50 and 50 + 100 + 50
+        assert_eq!(rewards_0, 100);
+        assert_eq!(rewards_1, 200);
+        assert_eq!(rewards_0 + rewards_1, stream.total_distributed);
+    }
+
+    #[test]
+    fn test_reward_stream_rebase() {
+        let now = 1000;
+        let mut stream = RewardStream {
+            reward_rate: 10,
+            total_funded: 1000,
+            start_ts: now,
+            end_ts: now + 100,
+            last_update_ts: now,
+            ..RewardStream::default()
+        };
+        let mut depositor =
+            RewardStreamDepositor::new(Pubkey::default(), Pubkey::default(), Pubkey::default(), 0);
+        depositor.update_rewards(&mut stream, 1000, now).unwrap();
+
+        stream.update(now + 10).unwrap();
+        assert_eq!(
+            stream.cumulative_reward_per_share,
+            REWARD_SHARE_PRECISION / 10
+        );
+
+        // shares divided by 10, each new share counts as 10 in the stream's base
+        let vault_shares = stream.to_stream_shares(100, 1).unwrap();
+        assert_eq!(vault_shares, 1000);
+        let rewards = depositor
+            .update_rewards(&mut stream, vault_shares, now + 10)
+            .unwrap();
+        assert_eq!(rewards, 100);
+        assert_eq!(stream.total_shares, 1000);
+        assert!(stream.to_stream_shares(100, 0).is_ok());
+    }
+}
@@ -0,0 +1,156 @@
+use anchor_lang::prelude::*;
+use drift::math::casting::Cast;
+use drift::math::safe_math::SafeMath;
+use drift_macros::assert_no_slop;
+use static_assertions::const_assert_eq;
+
+use crate::constants::REWARD_SHARE_PRECISION;
+use crate::error::ErrorCode;
+use crate::state::{RewardStream, Vault, VaultDepositor, VaultDepositorBase};
+use crate::{validate, Size};
+
+/// Tracks the rewards a [`VaultDepositor`] has earned from a [`RewardStream`]. The depositor's shares are
+/// registered in the stream and settled whenever they change, so every instruction changing the depositor's
+/// shares must pass all of its reward stream depositors, see vault_depositor.reward_stream_count.
+///
+/// [`VaultDepositor`]: crate::state::VaultDepositor
+#[assert_no_slop]
+#[account(zero_copy(unsafe))]
+#[derive(Default, Eq, PartialEq, Debug)]
+#[repr(C)]
+pub struct RewardStreamDepositor {
+    /// The reward stream paying the depositor
+    pub reward_stream: Pubkey,
+    /// The vault depositor earning rewards
+    pub vault_depositor: Pubkey,
+    /// The vault depositor's authority, rewards are claimed to its associated token account
+    pub authority: Pubkey,
+    /// The reward_stream.cumulative_reward_per_share at the last update
+    pub last_cumulative_reward_per_share: u128,
+    /// The depositor's shares registered in the reward stream, in the reward stream's shares base
+    pub vault_shares: u128,
+    /// Rewards earned but not claimed yet
+    pub unclaimed_rewards: u64,
+    /// Lifetime rewards claimed
+    pub total_claimed: u64,
+    /// Last ts rewards were accrued to the depositor
+    pub last_update_ts: i64,
+    pub bump: u8,
+    pub padding1: [u8; 7],
+    pub padding: [u64; 4],
+}
+
+impl Size for RewardStreamDepositor {
+    const SIZE: usize = 192 + 8;
+}
+
+const_assert_eq!(
+    RewardStreamDepositor::SIZE,
+    std::mem::size_of::<RewardStreamDepositor>() + 8
+);
+
+impl RewardStreamDepositor {
+    pub fn new(
+        reward_stream: Pubkey,
+        vault_depositor: Pubkey,
+        authority: Pubkey,
+        bump: u8,
+    ) -> Self {
+        Self {
+            reward_stream,
+            vault_depositor,
+            authority,
+            last_cumulative_reward_per_share: 0,
+            vault_shares: 0,
+            unclaimed_rewards: 0,
+            total_claimed: 0,
+            last_update_ts: 0,
+            bump,
+            padding1: [0; 7],
+            padding: [0; 4],
+        }
+    }
+
+    /// Accrues rewards on the registered shares since the last update and registers the depositor's
+    /// current shares. The reward stream should be updated beforehand and `vault_shares` be in its shares base.
+    pub fn update_rewards(
+        &mut self,
+        reward_stream: &mut RewardStream,
+        vault_shares: u128,
+        now: i64,
+    ) -> Result<u64> {
+        let reward_per_share_delta = reward_stream
+            .cumulative_reward_per_share
+            .safe_sub(self.last_cumulative_reward_per_share)?;
+        let new_rewards = reward_per_share_delta
+            .safe_mul(self.vault_shares)?
+            .safe_div(REWARD_SHARE_PRECISION)?;
+
+        self.unclaimed_rewards = self.unclaimed_rewards.safe_add(new_rewards.cast()?)?;
+        self.last_cumulative_reward_per_share = reward_stream.cumulative_reward_per_share;
+
+        reward_stream.total_shares = reward_stream
+            .total_shares
+            .safe_sub(self.vault_shares)?
+            .safe_add(vault_shares)?;
+        self.vault_shares = vault_shares;
+        self.last_update_ts = now;
+
+        Ok(self.unclaimed_rewards)
+    }
+
+    /// Returns the rewards to transfer to the depositor.
+    pub fn claim(&mut self, reward_stream: &mut RewardStream) -> Result<u64> {
+        let amount = self.unclaimed_rewards;
+
+        self.unclaimed_rewards = 0;
+        self.total_claimed = self.total_claimed.safe_add(amount)?;
+        reward_stream.total_claimed = reward_stream.total_claimed.safe_add(amount)?;
+
+        Ok(amount)
+    }
+}
+
+/// Settles the rewards of each of the depositor's reward streams at its current shares. Every
+/// [`RewardStreamDepositor`] of the depositor must be passed with its [`RewardStream`], the accounts of other
+/// depositors are skipped.
+pub fn settle_reward_streams(
+    reward_streams: &[AccountLoader<RewardStream>],
+    reward_stream_depositors: &[AccountLoader<RewardStreamDepositor>],
+    vault_depositor: &VaultDepositor,
+    vault: &Vault,
+    now: i64,
+) -> Result<()> {
+    let mut settled_count = 0_u8;
+    for reward_stream_depositor in reward_stream_depositors.iter() {
+        let mut reward_stream_depositor = reward_stream_depositor.load_mut()?;
+        if reward_stream_depositor.vault_depositor != vault_depositor.pubkey {
+            continue;
+        }
+
+        let mut reward_stream = reward_streams
+            .iter()
+            .find(|reward_stream| reward_stream.key() == reward_stream_depositor.reward_stream)
+            .ok_or(ErrorCode::RewardStreamDepositorMissing)?
+            .load_mut()?;
+
+        reward_stream.update(now)?;
+        let vault_shares = reward_stream.to_stream_shares(
+            vault_depositor.checked_vault_shares(vault)?,
+            vault.shares_base,
+        )?;
+        reward_stream_depositor.update_rewards(&mut reward_stream, vault_shares, now)?;
+
+        settled_count = settled_count.safe_add(1)?;
+    }
+
+    validate!(
+        settled_count == vault_depositor.reward_stream_count,
+        ErrorCode::RewardStreamDepositorMissing,
+        "vault depositor has {} reward stream depositors, {} were passed",
+        vault_depositor.reward_stream_count,
+        settled_count
+    )?;
+
+    Ok(())
+}
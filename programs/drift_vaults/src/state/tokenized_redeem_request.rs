@@ -0,0 +1,185 @@
+use std::cell::RefMut;
+
+use anchor_lang::prelude::*;
+use drift::math::casting::Cast;
+use drift::math::insurance::if_shares_to_vault_amount as depositor_shares_to_vault_amount;
+use drift::math::safe_math::SafeMath;
+use drift_macros::assert_no_slop;
+use static_assertions::const_assert_eq;
+
+use crate::error::ErrorCode;
+use crate::events::VaultDepositorAction;
+use crate::state::vault::{VaultDepositorRecordParams, VaultDepositorRecordProtocolParams};
+use crate::state::withdraw_request::WithdrawRequest;
+use crate::state::{FeeUpdate, TokenizedVaultDepositor, Vault, VaultFee, VaultProtocol};
+use crate::{validate, Size};
+
+/// A pending redemption of tokenized vault shares. Created by `request_redeem` when a holder burns
+/// share tokens, and closed by `redeem_tokens_for_underlying` once the vault's redeem period has passed,
+/// or by `cancel_redeem_request` which mints the shares back to the holder as tokens.
+/// The shares queued here have already left the [`TokenizedVaultDepositor`] but are still counted in
+/// `vault.user_shares` until the redemption is finalized.
+///
+/// A holder may have several requests outstanding, each keyed by its `request_id`.
+///
+/// [`TokenizedVaultDepositor`]: crate::state::TokenizedVaultDepositor
+#[assert_no_slop]
+#[account(zero_copy(unsafe))]
+#[derive(Default, Eq, PartialEq, Debug)]
+#[repr(C)]
+pub struct TokenizedRedeemRequest {
+    /// The vault being redeemed from
+    pub vault: Pubkey,
+    /// The tokenized vault depositor whose tokens were burned
+    pub tokenized_vault_depositor: Pubkey,
+    /// The token holder that will receive the underlying tokens
+    pub authority: Pubkey,
+    /// The queued withdraw
+    pub withdraw_request: WithdrawRequest,
+    /// The vault shares_base at the time withdraw_request.shares was last updated
+    pub vault_shares_base: u32,
+    pub bump: u8,
+    pub padding1: u8,
+    /// Chosen by the holder, distinguishes their outstanding requests
+    pub request_id: u16,
+    pub padding: [u64; 5],
+}
+
+impl Size for TokenizedRedeemRequest {
+    const SIZE: usize = 176 + 8;
+}
+
+const_assert_eq!(
+    TokenizedRedeemRequest::SIZE,
+    std::mem::size_of::<TokenizedRedeemRequest>() + 8
+);
+
+impl TokenizedRedeemRequest {
+    pub fn apply_rebase(&mut self, vault: &Vault) -> Result<()> {
+        if vault.shares_base != self.vault_shares_base {
+            validate!(
+                vault.shares_base > self.vault_shares_base,
+                ErrorCode::InvalidVaultRebase,
+                "Rebase expo out of bounds"
+            )?;
+
+            let expo_diff = vault.shares_base.safe_sub(self.vault_shares_base)?;
+            let rebase_divisor = 10_u128.pow(expo_diff);
+
+            msg!(
+                "rebasing redeem request: base: {} -> {} ",
+                self.vault_shares_base,
+                vault.shares_base,
+            );
+
+            self.withdraw_request.rebase(rebase_divisor)?;
+            self.vault_shares_base = vault.shares_base;
+        }
+
+        Ok(())
+    }
+
+    /// Finalizes the redemption, burning the queued shares from the vault and booking the amount paid
+    /// out as a withdraw of the [`TokenizedVaultDepositor`].
+    ///
+    /// Returns the amount of the vault's spot token owed to the holder
+    #[allow(clippy::too_many_arguments)]
+    pub fn redeem(
+        &mut self,
+        tokenized_vault_depositor: &mut TokenizedVaultDepositor,
+        vault: &mut Vault,
+        vault_protocol: &mut Option<RefMut<VaultProtocol>>,
+        fee_update: &mut Option<AccountLoader<FeeUpdate>>,
+        vault_equity: u64,
+        now: i64,
+        deposit_oracle_price: i64,
+    ) -> Result<u64> {
+        validate!(!vault.in_liquidation(), ErrorCode::OngoingLiquidation)?;
+
+        self.withdraw_request
+            .check_redeem_period_finished(vault, now)?;
+
+        vault.apply_rebase(vault_protocol, vault_equity)?;
+        self.apply_rebase(vault)?;
+
+        let VaultFee {
+            management_fee_payment,
+            management_fee_shares,
+            protocol_fee_payment,
+            protocol_fee_shares,
+        } = vault.apply_fee(vault_protocol, fee_update, vault_equity, now)?;
+
+        let total_vault_shares_before = vault.total_shares;
+        let user_vault_shares_before = vault.user_shares;
+        let protocol_shares_before = vault.get_protocol_shares(vault_protocol);
+
+        let n_shares = self.withdraw_request.shares;
+
+        validate!(
+            n_shares > 0,
+            ErrorCode::InvalidVaultWithdraw,
+            "No withdraw_request.shares found, must call request_redeem first",
+        )?;
+
+        let amount: u64 =
+            depositor_shares_to_vault_amount(n_shares, vault.total_shares, vault_equity)?;
+
+        let withdraw_amount = amount.min(self.withdraw_request.value);
+        msg!(
+            "amount={}, withdraw_request_value={}",
+            amount,
+            self.withdraw_request.value
+        );
+
+        tokenized_vault_depositor.total_withdraws = tokenized_vault_depositor
+            .total_withdraws
+            .saturating_add(withdraw_amount);
+        tokenized_vault_depositor.net_deposits = tokenized_vault_depositor
+            .net_deposits
+            .safe_sub(withdraw_amount.cast()?)?;
+
+        vault.total_withdraws = vault.total_withdraws.saturating_add(withdraw_amount);
+        vault.net_deposits = vault.net_deposits.safe_sub(withdraw_amount.cast()?)?;
+        vault.total_shares = vault.total_shares.safe_sub(n_shares)?;
+        vault.user_shares = vault.user_shares.safe_sub(n_shares)?;
+        vault.total_withdraw_requested = vault
+            .total_withdraw_requested
+            .safe_sub(self.withdraw_request.value)?;
+        vault.decrease_redeem_requested_shares(self.withdraw_request.shares)?;
+
+        self.withdraw_request.reset(now)?;
+
+        let protocol_shares_after = vault.get_protocol_shares(vault_protocol);
+
+        vault.emit_vault_depositor_record(
+            VaultDepositorRecordParams {
+                ts: now,
+                action: VaultDepositorAction::Withdraw,
+                amount: withdraw_amount,
+                depositor_authority: self.authority,
+                vault_equity_before: vault_equity,
+                vault_shares_before: n_shares,
+                user_vault_shares_before,
+                total_vault_shares_before,
+                vault_shares_after: 0,
+                manager_profit_share: 0,
+                management_fee: management_fee_payment,
+                management_fee_shares,
+                deposit_oracle_price,
+                cost_basis_after: None,
+                realized_pnl: None,
+            },
+            vault_protocol
+                .as_ref()
+                .map(|_| VaultDepositorRecordProtocolParams {
+                    protocol_profit_share: 0,
+                    protocol_fee: protocol_fee_payment,
+                    protocol_fee_shares,
+                    protocol_shares_before,
+                    protocol_shares_after,
+                }),
+        )?;
+
+        Ok(withdraw_amount)
+    }
+}
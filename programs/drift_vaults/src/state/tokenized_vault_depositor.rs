@@ -1,8 +1,10 @@
 use std::cell::RefMut;
 
+use crate::constants::{FUEL_SHARE_PRECISION, MAGIC_FUEL_START_TS};
 use crate::error::ErrorCode;
-use crate::events::{VaultDepositorAction, VaultDepositorRecord, VaultDepositorV1Record};
-use crate::state::vault::Vault;
+use crate::events::VaultDepositorAction;
+use crate::state::vault::{Vault, VaultDepositorRecordParams, VaultDepositorRecordProtocolParams};
+use crate::state::TokenizedRedeemRequest;
 use crate::{validate, FeeUpdate, VaultFee, VaultProtocol};
 use crate::{Size, VaultDepositorBase};
 use static_assertions::const_assert_eq;
@@ -14,6 +16,8 @@ use drift::math::insurance::{
     vault_amount_to_if_shares as vault_amount_to_depositor_shares,
 };
 use drift::math::safe_math::SafeMath;
+use drift::state::events::FuelSeasonRecord;
+use drift::state::user::{FuelOverflow, UserStats};
 use drift_macros::assert_no_slop;
 
 #[assert_no_slop]
@@ -50,8 +54,20 @@ pub struct TokenizedVaultDepositor {
     pub vault_shares_base: u32,
     /// The bump for the vault pda
     pub bump: u8,
-    pub padding1: [u8; 3],
-    pub padding: [u64; 10],
+    /// See [`crate::state::VaultDepositor::cost_basis_migrated`]
+    pub cost_basis_migrated: bool,
+    pub padding1: [u8; 2],
+    /// Last ts fuel was accrued to this depositor
+    pub last_fuel_update_ts: u32,
+    pub padding2: [u8; 4],
+    /// The vault's cumulative_fuel_per_share at the last fuel accrual
+    pub cumulative_fuel_per_share_amount: u128,
+    /// Total fuel accrued by the tokenized shares. Fuel is attributed to the tokenized vault depositor as a
+    /// whole, token holders don't accrue fuel individually
+    pub fuel_amount: u128,
+    /// The deposit asset paid for the tokenized shares, see [`crate::state::VaultDepositor::cost_basis`]
+    pub cost_basis: u64,
+    pub padding: [u64; 4],
 }
 
 impl Size for TokenizedVaultDepositor {
@@ -105,6 +121,17 @@ impl VaultDepositorBase for TokenizedVaultDepositor {
     fn set_profit_share_fee_paid(&mut self, amount: u64) {
         self.profit_share_fee_paid = amount;
     }
+
+    fn get_cost_basis(&self) -> u64 {
+        self.cost_basis
+    }
+    fn set_cost_basis(&mut self, amount: u64) {
+        self.cost_basis = amount;
+        self.cost_basis_migrated = true;
+    }
+    fn is_cost_basis_migrated(&self) -> bool {
+        self.cost_basis_migrated
+    }
 }
 
 impl TokenizedVaultDepositor {
@@ -130,8 +157,14 @@ impl TokenizedVaultDepositor {
             profit_share_fee_paid: 0,
             vault_shares_base,
             bump,
-            padding1: [0; 3],
-            padding: [0; 10],
+            cost_basis_migrated: true,
+            padding1: [0; 2],
+            last_fuel_update_ts: MAGIC_FUEL_START_TS,
+            padding2: [0; 4],
+            cumulative_fuel_per_share_amount: 0,
+            fuel_amount: 0,
+            cost_basis: 0,
+            padding: [0; 4],
         }
     }
 
@@ -151,6 +184,65 @@ impl TokenizedVaultDepositor {
         }
     }
 
+    /// Accrues fuel to the tokenized shares. Must be called before the depositor's shares change.
+    pub fn update_cumulative_fuel_amount(
+        &mut self,
+        now: i64,
+        vault: &mut Vault,
+        user_stats: &UserStats,
+        fuel_overflow: &Option<AccountLoader<FuelOverflow>>,
+    ) -> Result<u128> {
+        let cumulative_fuel_per_share =
+            vault.update_cumulative_fuel_per_share(now, user_stats, fuel_overflow)?;
+        self.accrue_fuel(now, cumulative_fuel_per_share)
+    }
+
+    fn accrue_fuel(&mut self, now: i64, cumulative_fuel_per_share: u128) -> Result<u128> {
+        // self.last_fuel_update_ts == 0:
+        //   - TokenizedVaultDepositors created before they accrued fuel. When their shares were tokenized is
+        //     unknown, so fuel accumulated before the first update is not applied.
+        // self.last_fuel_update_ts == MAGIC_FUEL_START_TS:
+        //   - TokenizedVaultDepositors created since, that have no fuel applied yet
+        //   - Do not apply fuel accumulated before they held shares, only after.
+        if self.last_fuel_update_ts != 0 && self.last_fuel_update_ts != MAGIC_FUEL_START_TS {
+            if self.cumulative_fuel_per_share_amount > cumulative_fuel_per_share {
+                // the vault's fuel was reset for a new season
+                msg!("self.cumulative_fuel_per_share_amount > cumulative_fuel_per_share. Resetting the tvd.");
+                self.reset_fuel_amount(now);
+            } else {
+                let fuel_per_share_delta =
+                    cumulative_fuel_per_share.safe_sub(self.cumulative_fuel_per_share_amount)?;
+                let new_fuel = fuel_per_share_delta
+                    .safe_mul(self.vault_shares)?
+                    .safe_div(FUEL_SHARE_PRECISION)?;
+
+                self.fuel_amount = self.fuel_amount.safe_add(new_fuel)?;
+            }
+        }
+
+        self.cumulative_fuel_per_share_amount = cumulative_fuel_per_share;
+        self.last_fuel_update_ts = now as u32;
+
+        Ok(self.fuel_amount)
+    }
+
+    pub fn reset_fuel_amount(&mut self, now: i64) {
+        emit!(FuelSeasonRecord {
+            ts: now,
+            authority: self.pubkey,
+            fuel_insurance: 0,
+            fuel_deposits: 0,
+            fuel_borrows: 0,
+            fuel_positions: 0,
+            fuel_taker: 0,
+            fuel_maker: 0,
+            fuel_total: self.fuel_amount,
+        });
+        self.fuel_amount = 0;
+        self.cumulative_fuel_per_share_amount = 0;
+        self.last_fuel_update_ts = now as u32;
+    }
+
     #[allow(clippy::too_many_arguments)]
     pub fn tokenize_shares(
         self: &mut TokenizedVaultDepositor,
@@ -208,57 +300,36 @@ impl TokenizedVaultDepositor {
 
         self.last_vault_shares = self.checked_vault_shares(vault)?;
 
-        match vault_protocol {
-            None => {
-                emit!(VaultDepositorRecord {
-                    ts: now,
-                    vault: vault.pubkey,
-                    depositor_authority: vault.pubkey,
-                    action: VaultDepositorAction::TokenizeShares,
-                    amount: shares_transferred.cast()?,
-                    spot_market_index: vault.spot_market_index,
-                    vault_equity_before: vault_equity,
-                    vault_shares_before,
-                    user_vault_shares_before,
-                    total_vault_shares_before,
-                    vault_shares_after: self.last_vault_shares,
-                    total_vault_shares_after: vault.total_shares,
-                    user_vault_shares_after: vault.user_shares,
-                    profit_share: manager_profit_share
-                        .safe_add(protocol_profit_share)?
-                        .cast()?,
-                    management_fee: management_fee_payment,
-                    management_fee_shares,
-                    deposit_oracle_price,
-                });
-            }
-            Some(_) => {
-                emit!(VaultDepositorV1Record {
-                    ts: now,
-                    vault: vault.pubkey,
-                    depositor_authority: vault.pubkey,
-                    action: VaultDepositorAction::TokenizeShares,
-                    amount: shares_transferred.cast()?,
-                    spot_market_index: vault.spot_market_index,
-                    vault_equity_before: vault_equity,
-                    vault_shares_before,
-                    user_vault_shares_before,
-                    total_vault_shares_before,
-                    vault_shares_after: self.last_vault_shares,
-                    total_vault_shares_after: vault.total_shares,
-                    user_vault_shares_after: vault.user_shares,
+        let protocol_shares_after = vault.get_protocol_shares(vault_protocol);
+
+        vault.emit_vault_depositor_record(
+            VaultDepositorRecordParams {
+                ts: now,
+                action: VaultDepositorAction::TokenizeShares,
+                amount: shares_transferred.cast()?,
+                depositor_authority: vault.pubkey,
+                vault_equity_before: vault_equity,
+                vault_shares_before,
+                user_vault_shares_before,
+                total_vault_shares_before,
+                vault_shares_after: self.last_vault_shares,
+                manager_profit_share,
+                management_fee: management_fee_payment,
+                management_fee_shares,
+                deposit_oracle_price,
+                cost_basis_after: Some(self.checked_cost_basis()),
+                realized_pnl: None,
+            },
+            vault_protocol
+                .as_ref()
+                .map(|_| VaultDepositorRecordProtocolParams {
                     protocol_profit_share,
                     protocol_fee: protocol_fee_payment,
                     protocol_fee_shares,
-                    manager_profit_share,
-                    management_fee: management_fee_payment,
-                    management_fee_shares,
                     protocol_shares_before,
-                    protocol_shares_after: vault.get_protocol_shares(vault_protocol),
-                    deposit_oracle_price,
-                });
-            }
-        }
+                    protocol_shares_after,
+                }),
+        )?;
 
         Ok(tokens_to_mint.cast()?)
     }
@@ -307,65 +378,405 @@ impl TokenizedVaultDepositor {
             shares_to_redeem
         );
 
-        match vault_protocol {
-            None => {
-                emit!(VaultDepositorRecord {
-                    ts: now,
-                    vault: vault.pubkey,
-                    depositor_authority: vault.pubkey,
-                    action: VaultDepositorAction::RedeemTokens,
-                    amount: tokens_to_burn,
-                    spot_market_index: vault.spot_market_index,
-                    vault_equity_before: vault_equity,
-                    vault_shares_before,
-                    user_vault_shares_before,
-                    total_vault_shares_before,
-                    vault_shares_after: self.last_vault_shares,
-                    total_vault_shares_after: vault.total_shares,
-                    user_vault_shares_after: vault.user_shares,
-                    profit_share: manager_profit_share
-                        .safe_add(protocol_profit_share)?
-                        .cast()?,
-                    management_fee: management_fee_payment,
-                    management_fee_shares,
-                    deposit_oracle_price
-                });
-            }
-            Some(_) => {
-                emit!(VaultDepositorV1Record {
-                    ts: now,
-                    vault: vault.pubkey,
-                    depositor_authority: vault.pubkey,
-                    action: VaultDepositorAction::RedeemTokens,
-                    amount: tokens_to_burn,
-                    spot_market_index: vault.spot_market_index,
-                    vault_equity_before: vault_equity,
-                    vault_shares_before,
-                    user_vault_shares_before,
-                    total_vault_shares_before,
-                    vault_shares_after: self.vault_shares,
-                    total_vault_shares_after: vault.total_shares,
-                    user_vault_shares_after: vault.user_shares,
+        let protocol_shares_after = vault.get_protocol_shares(vault_protocol);
+
+        vault.emit_vault_depositor_record(
+            VaultDepositorRecordParams {
+                ts: now,
+                action: VaultDepositorAction::RedeemTokens,
+                amount: tokens_to_burn,
+                depositor_authority: vault.pubkey,
+                vault_equity_before: vault_equity,
+                vault_shares_before,
+                user_vault_shares_before,
+                total_vault_shares_before,
+                vault_shares_after: self.vault_shares,
+                manager_profit_share,
+                management_fee: management_fee_payment,
+                management_fee_shares,
+                deposit_oracle_price,
+                cost_basis_after: Some(self.checked_cost_basis()),
+                realized_pnl: None,
+            },
+            vault_protocol
+                .as_ref()
+                .map(|_| VaultDepositorRecordProtocolParams {
                     protocol_profit_share,
                     protocol_fee: protocol_fee_payment,
                     protocol_fee_shares,
-                    manager_profit_share,
-                    management_fee: management_fee_payment,
-                    management_fee_shares,
                     protocol_shares_before,
-                    protocol_shares_after: vault.get_protocol_shares(vault_protocol),
-                    deposit_oracle_price
-                });
-            }
-        }
+                    protocol_shares_after,
+                }),
+        )?;
 
         Ok((shares_to_redeem, vault_protocol.take()))
     }
+
+    /// Deposits `amount` of the vault's spot token on behalf of a token holder, crediting the new vault
+    /// shares to this TokenizedVaultDepositor.
+    ///
+    /// Returns the number of share tokens to mint to the holder
+    #[allow(clippy::too_many_arguments)]
+    pub fn deposit(
+        self: &mut TokenizedVaultDepositor,
+        vault: &mut Vault,
+        vault_protocol: &mut Option<RefMut<VaultProtocol>>,
+        fee_update: &mut Option<AccountLoader<FeeUpdate>>,
+        mint_supply: u64,
+        amount: u64,
+        vault_equity: u64,
+        depositor_authority: Pubkey,
+        now: i64,
+        deposit_oracle_price: i64,
+    ) -> Result<u64> {
+        validate!(
+            vault.max_tokens == 0 || vault.max_tokens >= vault_equity.safe_add(amount)?,
+            ErrorCode::VaultIsAtCapacity,
+            "after deposit vault equity is {} > {}",
+            vault_equity.safe_add(amount)?,
+            vault.max_tokens
+        )?;
+
+        validate!(
+            vault.min_deposit_amount == 0 || amount >= vault.min_deposit_amount,
+            ErrorCode::InvalidVaultDeposit,
+            "deposit amount {} is below vault min_deposit_amount {}",
+            amount,
+            vault.min_deposit_amount
+        )?;
+
+        validate!(
+            !(vault_equity == 0 && vault.total_shares != 0),
+            ErrorCode::InvalidVaultForNewDepositors,
+            "Vault balance should be non-zero for new depositors to enter"
+        )?;
+
+        let rebase_divisor = self.apply_rebase(vault, vault_protocol, vault_equity)?;
+        if rebase_divisor.is_some() {
+            return Err(ErrorCode::InvalidVaultRebase.into());
+        }
+
+        let VaultFee {
+            management_fee_payment,
+            management_fee_shares,
+            protocol_fee_payment,
+            protocol_fee_shares,
+        } = vault.apply_fee(vault_protocol, fee_update, vault_equity, now)?;
+        let (manager_profit_share, protocol_profit_share) =
+            self.apply_profit_share(vault_equity, vault, vault_protocol)?;
+
+        let vault_shares_before = self.checked_vault_shares(vault)?;
+        let total_vault_shares_before = vault.total_shares;
+        let user_vault_shares_before = vault.user_shares;
+        let protocol_shares_before = vault.get_protocol_shares(vault_protocol);
+
+        let n_shares = vault_amount_to_depositor_shares(amount, vault.total_shares, vault_equity)?;
+
+        let tokens_to_mint = vault_amount_to_depositor_shares(
+            n_shares.cast()?,
+            mint_supply.cast()?,
+            vault_shares_before.cast()?,
+        )?;
+
+        validate!(
+            tokens_to_mint > 0,
+            ErrorCode::InvalidVaultDeposit,
+            "deposit amount {} too small to mint tokens",
+            amount
+        )?;
+
+        msg!(
+            "n_shares: {}, tokenized_vd.vault_shares: {}, token_supply_before: {}, tokens_to_mint: {}",
+            n_shares,
+            vault_shares_before,
+            mint_supply,
+            tokens_to_mint
+        );
+
+        self.increase_cost_basis(amount)?;
+        self.total_deposits = self.total_deposits.saturating_add(amount);
+        self.net_deposits = self.net_deposits.safe_add(amount.cast()?)?;
+
+        vault.total_deposits = vault.total_deposits.saturating_add(amount);
+        vault.net_deposits = vault.net_deposits.safe_add(amount.cast()?)?;
+
+        self.increase_vault_shares(n_shares, vault)?;
+
+        vault.total_shares = vault.total_shares.safe_add(n_shares)?;
+        vault.user_shares = vault.user_shares.safe_add(n_shares)?;
+
+        self.last_vault_shares = self.checked_vault_shares(vault)?;
+
+        let protocol_shares_after = vault.get_protocol_shares(vault_protocol);
+
+        vault.emit_vault_depositor_record(
+            VaultDepositorRecordParams {
+                ts: now,
+                action: VaultDepositorAction::Deposit,
+                amount,
+                depositor_authority,
+                vault_equity_before: vault_equity,
+                vault_shares_before,
+                user_vault_shares_before,
+                total_vault_shares_before,
+                vault_shares_after: self.last_vault_shares,
+                manager_profit_share,
+                management_fee: management_fee_payment,
+                management_fee_shares,
+                deposit_oracle_price,
+                cost_basis_after: Some(self.checked_cost_basis()),
+                realized_pnl: None,
+            },
+            vault_protocol
+                .as_ref()
+                .map(|_| VaultDepositorRecordProtocolParams {
+                    protocol_profit_share,
+                    protocol_fee: protocol_fee_payment,
+                    protocol_fee_shares,
+                    protocol_shares_before,
+                    protocol_shares_after,
+                }),
+        )?;
+
+        Ok(tokens_to_mint.cast()?)
+    }
+
+    /// Burns `tokens_to_burn` worth of vault shares out of this TokenizedVaultDepositor and queues them
+    /// in `redeem_request` for withdrawal after the vault's redeem period.
+    ///
+    /// Returns the number of vault shares queued
+    #[allow(clippy::too_many_arguments)]
+    pub fn request_redeem(
+        self: &mut TokenizedVaultDepositor,
+        redeem_request: &mut TokenizedRedeemRequest,
+        vault: &mut Vault,
+        vault_protocol: &mut Option<RefMut<VaultProtocol>>,
+        fee_update: &mut Option<AccountLoader<FeeUpdate>>,
+        mint_supply: u64,
+        vault_equity: u64,
+        tokens_to_burn: u64,
+        now: i64,
+        deposit_oracle_price: i64,
+    ) -> Result<u128> {
+        self.apply_rebase(vault, vault_protocol, vault_equity)?;
+
+        let VaultFee {
+            management_fee_payment,
+            management_fee_shares,
+            protocol_fee_payment,
+            protocol_fee_shares,
+        } = vault.apply_fee(vault_protocol, fee_update, vault_equity, now)?;
+        let (manager_profit_share, protocol_profit_share) =
+            self.apply_profit_share(vault_equity, vault, vault_protocol)?;
+
+        let vault_shares_before = self.checked_vault_shares(vault)?;
+        let total_vault_shares_before = vault.total_shares;
+        let user_vault_shares_before = vault.user_shares;
+        let protocol_shares_before = vault.get_protocol_shares(vault_protocol);
+
+        let n_shares: u128 = depositor_shares_to_vault_amount(
+            tokens_to_burn.cast()?,
+            mint_supply.cast()?,
+            vault_shares_before.cast()?,
+        )?
+        .cast()?;
+
+        validate!(
+            n_shares > 0,
+            ErrorCode::InvalidVaultWithdrawSize,
+            "Requested n_shares = 0"
+        )?;
+
+        let withdraw_value =
+            depositor_shares_to_vault_amount(n_shares, vault.total_shares, vault_equity)?;
+
+        msg!(
+            "tokens_to_burn: {}, tokenized_vd.vault_shares: {}, token_supply_before: {}, n_shares: {}, withdraw_value: {}",
+            tokens_to_burn,
+            vault_shares_before,
+            mint_supply,
+            n_shares,
+            withdraw_value
+        );
+
+        redeem_request.vault_shares_base = vault.shares_base;
+        redeem_request.withdraw_request.set(
+            vault_shares_before,
+            n_shares,
+            withdraw_value,
+            vault_equity,
+            now,
+        )?;
+        vault.total_withdraw_requested = vault.total_withdraw_requested.safe_add(withdraw_value)?;
+        vault.increase_redeem_requested_shares(n_shares)?;
+
+        // the holder's own cost basis is tracked off-chain through the share tokens
+        self.realize_pnl(n_shares, withdraw_value, vault)?;
+        // total_withdraws and net_deposits are booked when the request is paid out
+        self.decrease_vault_shares(n_shares, vault)?;
+
+        self.last_vault_shares = self.checked_vault_shares(vault)?;
+
+        let protocol_shares_after = vault.get_protocol_shares(vault_protocol);
+
+        vault.emit_vault_depositor_record(
+            VaultDepositorRecordParams {
+                ts: now,
+                action: VaultDepositorAction::WithdrawRequest,
+                amount: withdraw_value,
+                depositor_authority: redeem_request.authority,
+                vault_equity_before: vault_equity,
+                vault_shares_before,
+                user_vault_shares_before,
+                total_vault_shares_before,
+                vault_shares_after: self.last_vault_shares,
+                manager_profit_share,
+                management_fee: management_fee_payment,
+                management_fee_shares,
+                deposit_oracle_price,
+                cost_basis_after: None,
+                realized_pnl: None,
+            },
+            vault_protocol
+                .as_ref()
+                .map(|_| VaultDepositorRecordProtocolParams {
+                    protocol_profit_share,
+                    protocol_fee: protocol_fee_payment,
+                    protocol_fee_shares,
+                    protocol_shares_before,
+                    protocol_shares_after,
+                }),
+        )?;
+
+        Ok(n_shares)
+    }
+
+    /// Returns the shares queued in `redeem_request` to this TokenizedVaultDepositor. Like
+    /// [`VaultDepositor::cancel_withdraw_request`], shares worth more than the requested value are
+    /// forfeited to the vault unless the request holds every share of the vault.
+    ///
+    /// Returns the number of tokens to mint back to the holder
+    ///
+    /// [`VaultDepositor::cancel_withdraw_request`]: crate::state::VaultDepositor::cancel_withdraw_request
+    #[allow(clippy::too_many_arguments)]
+    pub fn cancel_redeem_request(
+        self: &mut TokenizedVaultDepositor,
+        redeem_request: &mut TokenizedRedeemRequest,
+        vault: &mut Vault,
+        vault_protocol: &mut Option<RefMut<VaultProtocol>>,
+        fee_update: &mut Option<AccountLoader<FeeUpdate>>,
+        mint_supply: u64,
+        vault_equity: u64,
+        now: i64,
+        deposit_oracle_price: i64,
+    ) -> Result<u64> {
+        validate!(!vault.in_liquidation(), ErrorCode::OngoingLiquidation)?;
+
+        validate!(
+            redeem_request.withdraw_request.pending(),
+            ErrorCode::InvalidVaultWithdraw,
+            "No redeem request to cancel"
+        )?;
+
+        self.apply_rebase(vault, vault_protocol, vault_equity)?;
+        redeem_request.apply_rebase(vault)?;
+
+        let VaultFee {
+            management_fee_payment,
+            management_fee_shares,
+            protocol_fee_payment,
+            protocol_fee_shares,
+        } = vault.apply_fee(vault_protocol, fee_update, vault_equity, now)?;
+
+        let vault_shares_before = self.checked_vault_shares(vault)?;
+        let total_vault_shares_before = vault.total_shares;
+        let user_vault_shares_before = vault.user_shares;
+        let protocol_shares_before = vault.get_protocol_shares(vault_protocol);
+
+        let n_shares = redeem_request.withdraw_request.shares;
+        let vault_shares_lost = redeem_request
+            .withdraw_request
+            .calculate_shares_lost(vault, vault_equity)?;
+
+        // only deduct lost shares if the request doesn't hold 100% of the vault
+        let request_owns_entire_vault = total_vault_shares_before == n_shares;
+
+        let shares_returned = if vault_shares_lost > 0 && !request_owns_entire_vault {
+            vault.total_shares = vault.total_shares.safe_sub(vault_shares_lost)?;
+            vault.user_shares = vault.user_shares.safe_sub(vault_shares_lost)?;
+            n_shares.safe_sub(vault_shares_lost)?
+        } else {
+            n_shares
+        };
+
+        let tokens_to_mint = vault_amount_to_depositor_shares(
+            shares_returned.cast()?,
+            mint_supply.cast()?,
+            vault_shares_before.cast()?,
+        )?;
+
+        let value_returned =
+            depositor_shares_to_vault_amount(shares_returned, vault.total_shares, vault_equity)?;
+
+        msg!(
+            "shares_returned: {}, shares_lost: {}, tokenized_vd.vault_shares: {}, token_supply_before: {}, tokens_to_mint: {}",
+            shares_returned,
+            vault_shares_lost,
+            vault_shares_before,
+            mint_supply,
+            tokens_to_mint
+        );
+
+        // the returned shares come back at their current value, as if bought again
+        self.increase_cost_basis(value_returned)?;
+        self.increase_vault_shares(shares_returned, vault)?;
+        self.last_vault_shares = self.checked_vault_shares(vault)?;
+
+        vault.total_withdraw_requested = vault
+            .total_withdraw_requested
+            .safe_sub(redeem_request.withdraw_request.value)?;
+        vault.decrease_redeem_requested_shares(n_shares)?;
+        redeem_request.withdraw_request.reset(now)?;
+
+        let protocol_shares_after = vault.get_protocol_shares(vault_protocol);
+
+        vault.emit_vault_depositor_record(
+            VaultDepositorRecordParams {
+                ts: now,
+                action: VaultDepositorAction::CancelWithdrawRequest,
+                amount: 0,
+                depositor_authority: redeem_request.authority,
+                vault_equity_before: vault_equity,
+                vault_shares_before,
+                user_vault_shares_before,
+                total_vault_shares_before,
+                vault_shares_after: self.last_vault_shares,
+                manager_profit_share: 0,
+                management_fee: management_fee_payment,
+                management_fee_shares,
+                deposit_oracle_price,
+                cost_basis_after: Some(self.checked_cost_basis()),
+                realized_pnl: None,
+            },
+            vault_protocol
+                .as_ref()
+                .map(|_| VaultDepositorRecordProtocolParams {
+                    protocol_profit_share: 0,
+                    protocol_fee: protocol_fee_payment,
+                    protocol_fee_shares,
+                    protocol_shares_before,
+                    protocol_shares_after,
+                }),
+        )?;
+
+        Ok(tokens_to_mint.cast()?)
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{TokenizedVaultDepositor, Vault, VaultDepositorBase};
+    use crate::constants::FUEL_SHARE_PRECISION;
+    use crate::{TokenizedRedeemRequest, TokenizedVaultDepositor, Vault, VaultDepositorBase};
     use anchor_lang::prelude::Pubkey;
     use drift::math::constants::PERCENTAGE_PRECISION;
     use drift::math::safe_math::SafeMath;
@@ -605,4 +1016,415 @@ mod tests {
             "tvd shares should decrease after profit share"
         );
     }
+
+    #[test]
+    fn test_deposit_and_request_redeem() {
+        let mut now = 1337;
+        let vault = &mut Vault::default();
+        vault.redeem_period = 3600;
+        let mut tvd = TokenizedVaultDepositor::new(
+            Pubkey::default(),
+            Pubkey::default(),
+            Pubkey::default(),
+            0,
+            0,
+            now,
+        );
+
+        let mut total_supply = 0;
+        let mut vault_equity = 0;
+
+        // first deposit mints tokens 1:1 with shares
+        let tokens_minted_1 = tvd
+            .deposit(
+                vault,
+                &mut None,
+                &mut None,
+                total_supply,
+                1_000_000,
+                vault_equity,
+                Pubkey::default(),
+                now,
+                0,
+            )
+            .unwrap();
+        assert_eq!(tokens_minted_1, 1_000_000);
+        assert_eq!(tvd.vault_shares, 1_000_000);
+        assert_eq!(tvd.last_vault_shares, tvd.vault_shares);
+        assert_eq!(vault.total_shares, 1_000_000);
+        assert_eq!(vault.user_shares, 1_000_000);
+        assert_eq!(tvd.net_deposits, 1_000_000);
+        total_supply += tokens_minted_1;
+        vault_equity += 1_000_000;
+
+        // vault doubles in value, second deposit mints at the new price
+        vault_equity *= 2;
+        let tokens_minted_2 = tvd
+            .deposit(
+                vault,
+                &mut None,
+                &mut None,
+                total_supply,
+                1_000_000,
+                vault_equity,
+                Pubkey::default(),
+                now,
+                0,
+            )
+            .unwrap();
+        assert_eq!(tokens_minted_2, 500_000);
+        assert_eq!(tvd.vault_shares, 1_500_000);
+        assert_eq!(vault.total_shares, 1_500_000);
+        total_supply += tokens_minted_2;
+        vault_equity += 1_000_000;
+
+        // burn half the tokens, shares leave the tvd but stay in the vault until redeemed
+        let mut redeem_request = TokenizedRedeemRequest::default();
+        let shares_queued = tvd
+            .request_redeem(
+                &mut redeem_request,
+                vault,
+                &mut None,
+                &mut None,
+                total_supply,
+                vault_equity,
+                total_supply / 2,
+                now,
+                0,
+            )
+            .unwrap();
+        assert_eq!(shares_queued, 750_000);
+        assert_eq!(tvd.vault_shares, 750_000);
+        assert_eq!(tvd.last_vault_shares, tvd.vault_shares);
+        assert_eq!(vault.total_shares, 1_500_000);
+        assert_eq!(redeem_request.withdraw_request.shares, 750_000);
+        assert_eq!(redeem_request.withdraw_request.value, 1_500_000);
+        assert_eq!(vault.total_withdraw_requested, 1_500_000);
+        // the queued shares accrue fuel to no one, even without exclude_pending_withdraws_from_fuel
+        assert!(!vault.exclude_pending_withdraws_from_fuel);
+        assert_eq!(vault.get_fuel_eligible_user_shares().unwrap(), 750_000);
+        // the withdraw is booked when the request is paid out
+        assert_eq!(tvd.total_withdraws, 0);
+        assert_eq!(tvd.net_deposits, 2_000_000);
+
+        // cannot redeem before the redeem period elapses
+        now += 60;
+        assert!(redeem_request
+            .redeem(&mut tvd, vault, &mut None, &mut None, vault_equity, now, 0)
+            .is_err());
+
+        now += 3600;
+        let withdraw_amount = redeem_request
+            .redeem(&mut tvd, vault, &mut None, &mut None, vault_equity, now, 0)
+            .unwrap();
+        assert_eq!(withdraw_amount, 1_500_000);
+        assert_eq!(vault.total_shares, 750_000);
+        assert_eq!(vault.user_shares, 750_000);
+        assert_eq!(vault.total_withdraw_requested, 0);
+        assert_eq!(vault.fuel_shares_adjustment, 0);
+        assert_eq!(vault.get_fuel_eligible_user_shares().unwrap(), 750_000);
+        assert_eq!(tvd.total_withdraws, 1_500_000);
+        assert_eq!(tvd.net_deposits, 500_000);
+        assert!(!redeem_request.withdraw_request.pending());
+    }
+
+    /// Deposits 1_000_000 through `tvd` into a vault where another depositor already holds 1_000_000
+    /// shares, so the vault ends at 2_000_000 shares and equity with 1_000_000 tokens minted.
+    fn tokenized_vault_with_other_depositor(
+        vault: &mut Vault,
+        tvd: &mut TokenizedVaultDepositor,
+        now: i64,
+    ) -> u64 {
+        vault.total_shares = 1_000_000;
+        vault.user_shares = 1_000_000;
+        let tokens_minted = tvd
+            .deposit(
+                vault,
+                &mut None,
+                &mut None,
+                0,
+                1_000_000,
+                1_000_000,
+                Pubkey::default(),
+                now,
+                0,
+            )
+            .unwrap();
+        assert_eq!(tokens_minted, 1_000_000);
+        assert_eq!(tvd.checked_cost_basis(), 1_000_000);
+        assert_eq!(vault.total_shares, 2_000_000);
+
+        tokens_minted
+    }
+
+    #[test]
+    fn test_redeem_request_after_price_change() {
+        let mut now = 1337;
+        let vault = &mut Vault::default();
+        vault.redeem_period = 3600;
+        let mut tvd = TokenizedVaultDepositor::new(
+            Pubkey::default(),
+            Pubkey::default(),
+            Pubkey::default(),
+            0,
+            0,
+            now,
+        );
+        let mut total_supply = tokenized_vault_with_other_depositor(vault, &mut tvd, now);
+        let mut vault_equity = 2_000_000;
+
+        let mut redeem_request_1 = TokenizedRedeemRequest {
+            request_id: 1,
+            ..TokenizedRedeemRequest::default()
+        };
+        tvd.request_redeem(
+            &mut redeem_request_1,
+            vault,
+            &mut None,
+            &mut None,
+            total_supply,
+            vault_equity,
+            total_supply / 2,
+            now,
+            0,
+        )
+        .unwrap();
+        total_supply /= 2;
+        assert_eq!(redeem_request_1.withdraw_request.shares, 500_000);
+        assert_eq!(redeem_request_1.withdraw_request.value, 500_000);
+
+        // a second request is held independently of the first
+        let mut redeem_request_2 = TokenizedRedeemRequest {
+            request_id: 2,
+            ..TokenizedRedeemRequest::default()
+        };
+        tvd.request_redeem(
+            &mut redeem_request_2,
+            vault,
+            &mut None,
+            &mut None,
+            total_supply,
+            vault_equity,
+            total_supply / 2,
+            now,
+            0,
+        )
+        .unwrap();
+        assert_eq!(redeem_request_2.withdraw_request.shares, 250_000);
+        assert_eq!(redeem_request_2.withdraw_request.value, 250_000);
+        assert_eq!(vault.total_withdraw_requested, 750_000);
+        assert_eq!(tvd.vault_shares, 250_000);
+
+        // vault halves in value, the holder is paid the lower current value
+        now += 3600;
+        vault_equity /= 2;
+        let withdraw_amount = redeem_request_1
+            .redeem(&mut tvd, vault, &mut None, &mut None, vault_equity, now, 0)
+            .unwrap();
+        assert_eq!(withdraw_amount, 250_000);
+        assert_eq!(tvd.total_withdraws, 250_000);
+        assert_eq!(tvd.net_deposits, 750_000);
+        assert_eq!(vault.total_withdraws, 250_000);
+        assert_eq!(vault.total_shares, 1_500_000);
+        assert_eq!(vault.total_withdraw_requested, 250_000);
+        vault_equity -= withdraw_amount;
+
+        // vault quadruples in value, the holder is paid no more than the requested value
+        vault_equity *= 4;
+        let withdraw_amount = redeem_request_2
+            .redeem(&mut tvd, vault, &mut None, &mut None, vault_equity, now, 0)
+            .unwrap();
+        assert_eq!(withdraw_amount, 250_000);
+        assert_eq!(tvd.total_withdraws, 500_000);
+        assert_eq!(tvd.net_deposits, 500_000);
+        assert_eq!(vault.total_shares, 1_250_000);
+        assert_eq!(vault.total_withdraw_requested, 0);
+    }
+
+    #[test]
+    fn test_cancel_redeem_request() {
+        let mut now = 1337;
+        let vault = &mut Vault::default();
+        vault.redeem_period = 3600;
+        let mut tvd = TokenizedVaultDepositor::new(
+            Pubkey::default(),
+            Pubkey::default(),
+            Pubkey::default(),
+            0,
+            0,
+            now,
+        );
+        let mut total_supply = tokenized_vault_with_other_depositor(vault, &mut tvd, now);
+        let mut vault_equity = 2_000_000;
+
+        let mut redeem_request = TokenizedRedeemRequest::default();
+        tvd.request_redeem(
+            &mut redeem_request,
+            vault,
+            &mut None,
+            &mut None,
+            total_supply,
+            vault_equity,
+            total_supply / 2,
+            now,
+            0,
+        )
+        .unwrap();
+        total_supply /= 2;
+        assert_eq!(tvd.vault_shares, 500_000);
+        assert_eq!(tvd.checked_cost_basis(), 500_000);
+        assert_eq!(vault.get_fuel_eligible_user_shares().unwrap(), 1_500_000);
+
+        // the request expires unredeemed and the vault doubles in value. Cancelling forfeits the
+        // shares above the requested value to the rest of the vault
+        now += 7200;
+        vault_equity *= 2;
+        let tokens_minted = tvd
+            .cancel_redeem_request(
+                &mut redeem_request,
+                vault,
+                &mut None,
+                &mut None,
+                total_supply,
+                vault_equity,
+                now,
+                0,
+            )
+            .unwrap();
+        assert_eq!(tokens_minted, 214_285);
+        assert_eq!(tvd.vault_shares, 714_285);
+        assert_eq!(tvd.last_vault_shares, tvd.vault_shares);
+        assert_eq!(vault.total_shares, 1_714_285);
+        assert_eq!(vault.user_shares, 1_714_285);
+        assert_eq!(vault.total_withdraw_requested, 0);
+        // the returned shares accrue fuel again
+        assert_eq!(vault.fuel_shares_adjustment, 0);
+        assert_eq!(vault.get_fuel_eligible_user_shares().unwrap(), 1_714_285);
+        assert_eq!(tvd.checked_cost_basis(), 500_000 + 499_998);
+        assert_eq!(tvd.total_withdraws, 0);
+        assert!(!redeem_request.withdraw_request.pending());
+
+        // nothing left to cancel or redeem
+        assert!(tvd
+            .cancel_redeem_request(
+                &mut redeem_request,
+                vault,
+                &mut None,
+                &mut None,
+                total_supply + tokens_minted,
+                vault_equity,
+                now,
+                0,
+            )
+            .is_err());
+        assert!(redeem_request
+            .redeem(&mut tvd, vault, &mut None, &mut None, vault_equity, now, 0)
+            .is_err());
+    }
+
+    #[test]
+    fn test_redeem_request_in_liquidation() {
+        let mut now = 1337;
+        let vault = &mut Vault::default();
+        vault.redeem_period = 3600;
+        let mut tvd = TokenizedVaultDepositor::new(
+            Pubkey::default(),
+            Pubkey::default(),
+            Pubkey::default(),
+            0,
+            0,
+            now,
+        );
+        let total_supply = tokenized_vault_with_other_depositor(vault, &mut tvd, now);
+        let vault_equity = 2_000_000;
+
+        let mut redeem_request = TokenizedRedeemRequest::default();
+        tvd.request_redeem(
+            &mut redeem_request,
+            vault,
+            &mut None,
+            &mut None,
+            total_supply,
+            vault_equity,
+            total_supply / 2,
+            now,
+            0,
+        )
+        .unwrap();
+
+        now += 3600;
+        vault.liquidation_delegate = Pubkey::new_unique();
+        assert!(redeem_request
+            .redeem(&mut tvd, vault, &mut None, &mut None, vault_equity, now, 0)
+            .is_err());
+        assert!(tvd
+            .cancel_redeem_request(
+                &mut redeem_request,
+                vault,
+                &mut None,
+                &mut None,
+                total_supply / 2,
+                vault_equity,
+                now,
+                0,
+            )
+            .is_err());
+        assert!(redeem_request.withdraw_request.pending());
+        assert_eq!(vault.total_shares, 2_000_000);
+
+        // once the liquidation ends the request is paid out
+        vault.liquidation_delegate = Pubkey::default();
+        let withdraw_amount = redeem_request
+            .redeem(&mut tvd, vault, &mut None, &mut None, vault_equity, now, 0)
+            .unwrap();
+        assert_eq!(withdraw_amount, 500_000);
+        assert_eq!(vault.total_shares, 1_500_000);
+    }
+
+    #[test]
+    fn test_accrue_fuel() {
+        let now = 1337;
+        let mut tvd = TokenizedVaultDepositor::new(
+            Pubkey::default(),
+            Pubkey::default(),
+            Pubkey::default(),
+            0,
+            0,
+            now,
+        );
+        tvd.vault_shares = 1_000_000;
+
+        // fuel accumulated before the first update isn't applied
+        let fuel = tvd.accrue_fuel(now + 10, 2 * FUEL_SHARE_PRECISION).unwrap();
+        assert_eq!(fuel, 0);
+        assert_eq!(
+            tvd.cumulative_fuel_per_share_amount,
+            2 * FUEL_SHARE_PRECISION
+        );
+        assert_eq!(tvd.last_fuel_update_ts, (now + 10) as u32);
+
+        // 1 fuel per share
+        let fuel = tvd.accrue_fuel(now + 20, 3 * FUEL_SHARE_PRECISION).unwrap();
+        assert_eq!(fuel, 1_000_000);
+
+        // no new fuel
+        let fuel = tvd.accrue_fuel(now + 30, 3 * FUEL_SHARE_PRECISION).unwrap();
+        assert_eq!(fuel, 1_000_000);
+        assert_eq!(tvd.last_fuel_update_ts, (now + 30) as u32);
+
+        // new fuel season
+        let fuel = tvd.accrue_fuel(now + 40, 0).unwrap();
+        assert_eq!(fuel, 0);
+        assert_eq!(tvd.cumulative_fuel_per_share_amount, 0);
+
+        // tokenized depositors from before fuel accrued to them start at their first update too
+        tvd.last_fuel_update_ts = 0;
+        tvd.cumulative_fuel_per_share_amount = 0;
+        let fuel = tvd.accrue_fuel(now + 50, 5 * FUEL_SHARE_PRECISION).unwrap();
+        assert_eq!(fuel, 0);
+        assert_eq!(
+            tvd.cumulative_fuel_per_share_amount,
+            5 * FUEL_SHARE_PRECISION
+        );
+    }
 }
@@ -1,10 +1,9 @@
 use std::cell::RefMut;
 
+use crate::constants::NAV_PER_SHARE_PRECISION;
 use crate::error::ErrorCode;
-use crate::events::{
-    ShareTransferRecord, VaultDepositorAction, VaultDepositorRecord, VaultDepositorV1Record,
-};
-use crate::state::vault::Vault;
+use crate::events::{ShareTransferRecord, VaultDepositorAction};
+use crate::state::vault::{Vault, VaultDepositorRecordParams, VaultDepositorRecordProtocolParams};
 use crate::{validate, FeeUpdate, VaultFee, VaultProtocol, WithdrawUnit};
 use anchor_lang::prelude::*;
 
@@ -39,6 +38,11 @@ pub trait VaultDepositorBase {
     fn get_profit_share_fee_paid(&self) -> u64;
     fn set_profit_share_fee_paid(&mut self, amount: u64);
 
+    fn get_cost_basis(&self) -> u64;
+    /// Also marks the cost basis migrated
+    fn set_cost_basis(&mut self, amount: u64);
+    fn is_cost_basis_migrated(&self) -> bool;
+
     fn validate_base(&self, vault: &Vault) -> Result<()> {
         validate!(
             self.get_vault_shares_base() == vault.shares_base,
@@ -78,6 +82,60 @@ pub trait VaultDepositorBase {
         Ok(())
     }
 
+    /// Depositors holding shares from before cost basis was tracked start from their net deposits, until their
+    /// cost basis is first updated
+    fn checked_cost_basis(&self) -> u64 {
+        if self.is_cost_basis_migrated() || self.get_vault_shares() == 0 {
+            self.get_cost_basis()
+        } else {
+            self.get_net_deposits().max(0).unsigned_abs()
+        }
+    }
+
+    /// Must be called before the shares bought are added and before net deposits are updated
+    fn increase_cost_basis(&mut self, amount: u64) -> Result<()> {
+        self.set_cost_basis(self.checked_cost_basis().safe_add(amount)?);
+        Ok(())
+    }
+
+    /// Removes the cost basis of the shares leaving the depositor, in proportion to its shares before, and
+    /// returns the pnl realized by selling them for amount. Must be called before the shares are removed and
+    /// before net deposits are updated
+    fn realize_pnl(&mut self, shares: u128, amount: u64, vault: &Vault) -> Result<i64> {
+        let vault_shares = self.checked_vault_shares(vault)?;
+        let cost_basis = self.checked_cost_basis();
+
+        let removed_cost_basis: u64 = if vault_shares == 0 {
+            0
+        } else {
+            cost_basis
+                .cast::<u128>()?
+                .safe_mul(shares.min(vault_shares))?
+                .safe_div(vault_shares)?
+                .cast()?
+        };
+        self.set_cost_basis(cost_basis.safe_sub(removed_cost_basis)?);
+
+        Ok(amount
+            .cast::<i64>()?
+            .safe_sub(removed_cost_basis.cast::<i64>()?)?)
+    }
+
+    /// The cost basis per share, in the vault's current shares_base. precision: NAV_PER_SHARE_PRECISION
+    fn get_average_entry_price(&self, vault: &Vault) -> Result<u64> {
+        let vault_shares = self.checked_vault_shares(vault)?;
+        if vault_shares == 0 {
+            return Ok(0);
+        }
+
+        Ok(self
+            .checked_cost_basis()
+            .cast::<u128>()?
+            .safe_mul(NAV_PER_SHARE_PRECISION)?
+            .safe_div(vault_shares)?
+            .cast()?)
+    }
+
     fn calculate_profit_share_and_update(
         &mut self,
         total_amount: u64,
@@ -297,6 +355,10 @@ pub trait VaultDepositorBase {
         let from_depositor_shares_before = self.checked_vault_shares(vault)?;
         let to_depositor_shares_before = to.checked_vault_shares(vault)?;
 
+        // the shares are sold to the receiver at their value
+        let from_realized_pnl = self.realize_pnl(n_shares, withdraw_value, vault)?;
+        to.increase_cost_basis(withdraw_value)?;
+
         self.decrease_vault_shares(n_shares, vault)?;
         to.increase_vault_shares(n_shares, vault)?;
 
@@ -327,104 +389,65 @@ pub trait VaultDepositorBase {
             to_depositor_shares_after,
         });
 
-        match vault_protocol {
-            None => {
-                emit!(VaultDepositorRecord {
-                    ts: now,
-                    vault: vault.pubkey,
-                    depositor_authority: self.get_authority(),
-                    action: VaultDepositorAction::Withdraw,
-                    amount: withdraw_amount,
-                    spot_market_index: vault.spot_market_index,
-                    vault_equity_before: vault_equity,
-                    vault_shares_before: from_vault_shares_before,
-                    user_vault_shares_before,
-                    total_vault_shares_before,
-                    vault_shares_after: self.checked_vault_shares(vault)?,
-                    total_vault_shares_after: vault.total_shares,
-                    user_vault_shares_after: vault.user_shares,
-                    profit_share: from_manager_profit_share
-                        .safe_add(from_protocol_profit_share)?
-                        .cast()?,
-                    management_fee: management_fee_payment,
-                    management_fee_shares,
-                    deposit_oracle_price,
-                });
-
-                emit!(VaultDepositorRecord {
-                    ts: now,
-                    vault: vault.pubkey,
-                    depositor_authority: to.get_authority(),
-                    action: VaultDepositorAction::Deposit,
-                    amount: withdraw_amount,
-                    spot_market_index: vault.spot_market_index,
-                    vault_equity_before: vault_equity,
-                    vault_shares_before: to_vault_shares_before,
-                    user_vault_shares_before,
-                    total_vault_shares_before,
-                    vault_shares_after: to.checked_vault_shares(vault)?,
-                    total_vault_shares_after: vault.total_shares,
-                    user_vault_shares_after: vault.user_shares,
-                    profit_share: to_manager_profit_share
-                        .safe_add(to_protocol_profit_share)?
-                        .cast()?,
-                    management_fee: management_fee_payment,
-                    management_fee_shares,
-                    deposit_oracle_price,
-                });
-            }
-            Some(_) => {
-                emit!(VaultDepositorV1Record {
-                    ts: now,
-                    vault: vault.pubkey,
-                    depositor_authority: self.get_authority(),
-                    action: VaultDepositorAction::Withdraw,
-                    amount: withdraw_amount,
-                    spot_market_index: vault.spot_market_index,
-                    vault_equity_before: vault_equity,
-                    vault_shares_before: from_vault_shares_before,
-                    user_vault_shares_before,
-                    total_vault_shares_before,
-                    vault_shares_after: self.checked_vault_shares(vault)?,
-                    total_vault_shares_after: vault.total_shares,
-                    user_vault_shares_after: vault.user_shares,
+        let protocol_shares_after = vault.get_protocol_shares(vault_protocol);
+
+        vault.emit_vault_depositor_record(
+            VaultDepositorRecordParams {
+                ts: now,
+                action: VaultDepositorAction::Withdraw,
+                amount: withdraw_amount,
+                depositor_authority: self.get_authority(),
+                vault_equity_before: vault_equity,
+                vault_shares_before: from_vault_shares_before,
+                user_vault_shares_before,
+                total_vault_shares_before,
+                vault_shares_after: self.checked_vault_shares(vault)?,
+                manager_profit_share: from_manager_profit_share,
+                management_fee: management_fee_payment,
+                management_fee_shares,
+                deposit_oracle_price,
+                cost_basis_after: Some(self.checked_cost_basis()),
+                realized_pnl: Some(from_realized_pnl),
+            },
+            vault_protocol
+                .as_ref()
+                .map(|_| VaultDepositorRecordProtocolParams {
                     protocol_profit_share: from_protocol_profit_share,
                     protocol_fee: protocol_fee_payment,
                     protocol_fee_shares,
-                    manager_profit_share: from_manager_profit_share,
-                    management_fee: management_fee_payment,
-                    management_fee_shares,
                     protocol_shares_before,
-                    protocol_shares_after: vault.get_protocol_shares(vault_protocol),
-                    deposit_oracle_price,
-                });
-
-                emit!(VaultDepositorV1Record {
-                    ts: now,
-                    vault: vault.pubkey,
-                    depositor_authority: to.get_authority(),
-                    action: VaultDepositorAction::Deposit,
-                    amount: withdraw_amount,
-                    spot_market_index: vault.spot_market_index,
-                    vault_equity_before: vault_equity,
-                    vault_shares_before: to_vault_shares_before,
-                    user_vault_shares_before,
-                    total_vault_shares_before,
-                    vault_shares_after: to.checked_vault_shares(vault)?,
-                    total_vault_shares_after: vault.total_shares,
-                    user_vault_shares_after: vault.user_shares,
+                    protocol_shares_after,
+                }),
+        )?;
+
+        vault.emit_vault_depositor_record(
+            VaultDepositorRecordParams {
+                ts: now,
+                action: VaultDepositorAction::Deposit,
+                amount: withdraw_amount,
+                depositor_authority: to.get_authority(),
+                vault_equity_before: vault_equity,
+                vault_shares_before: to_vault_shares_before,
+                user_vault_shares_before,
+                total_vault_shares_before,
+                vault_shares_after: to.checked_vault_shares(vault)?,
+                manager_profit_share: to_manager_profit_share,
+                management_fee: management_fee_payment,
+                management_fee_shares,
+                deposit_oracle_price,
+                cost_basis_after: Some(to.checked_cost_basis()),
+                realized_pnl: None,
+            },
+            vault_protocol
+                .as_ref()
+                .map(|_| VaultDepositorRecordProtocolParams {
                     protocol_profit_share: to_protocol_profit_share,
                     protocol_fee: protocol_fee_payment,
                     protocol_fee_shares,
-                    manager_profit_share: from_manager_profit_share,
-                    management_fee: management_fee_payment,
-                    management_fee_shares,
                     protocol_shares_before,
-                    protocol_shares_after: vault.get_protocol_shares(vault_protocol),
-                    deposit_oracle_price,
-                });
-            }
-        }
+                    protocol_shares_after,
+                }),
+        )?;
 
         Ok((n_shares, vault_protocol.take()))
     }
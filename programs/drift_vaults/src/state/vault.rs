@@ -3,27 +3,38 @@ use std::cell::RefMut;
 use anchor_lang::prelude::*;
 use borsh::{BorshDeserialize, BorshSerialize};
 use drift::math::casting::Cast;
-use drift::math::constants::{ONE_YEAR, PERCENTAGE_PRECISION, PERCENTAGE_PRECISION_I128};
+use drift::math::constants::{
+    AMM_RESERVE_PRECISION, ONE_YEAR, PERCENTAGE_PRECISION, PERCENTAGE_PRECISION_I128,
+    PRICE_PRECISION_I128,
+};
+use drift::math::funding::calculate_funding_payment;
 use drift::math::insurance::calculate_rebase_info;
 use drift::math::insurance::{
     if_shares_to_vault_amount as depositor_shares_to_vault_amount,
     vault_amount_to_if_shares as vault_amount_to_depositor_shares,
 };
 use drift::math::margin::calculate_user_equity;
+use drift::math::oracle::{is_oracle_valid_for_action, DriftAction};
+use drift::math::position::calculate_base_asset_value_and_pnl_with_oracle_price;
 use drift::math::safe_math::SafeMath;
+use drift::math::spot_balance::get_token_value;
+use drift::state::oracle::OracleIdentifier;
 use drift::state::oracle_map::OracleMap;
+use drift::state::perp_market::MarketStatus;
 use drift::state::perp_market_map::PerpMarketMap;
 use drift::state::spot_market_map::SpotMarketMap;
-use drift::state::user::{FuelOverflow, User, UserStats};
+use drift::state::user::{FuelOverflow, MarketType, User, UserStats};
 use drift_macros::assert_no_slop;
 use static_assertions::const_assert_eq;
 
-use crate::constants::{FUEL_SHARE_PRECISION, TIME_FOR_LIQUIDATION};
+use crate::constants::{FUEL_DISTRIBUTION_PRECISION, FUEL_SHARE_PRECISION, TIME_FOR_LIQUIDATION};
 use crate::error::{ErrorCode, VaultResult};
-use crate::events::{VaultDepositorAction, VaultDepositorV1Record};
-use crate::state::events::VaultDepositorRecord;
+use crate::events::{VaultConfig, VaultDepositorAction, VaultDepositorV2Record};
 use crate::state::withdraw_request::WithdrawRequest;
-use crate::state::{FeeUpdate, VaultFee, VaultProtocol};
+use crate::state::{
+    FeeUpdate, FuelDistribution, ManagerStats, NavHistory, NavPolicy, NavSide, SwingPricingMode,
+    VaultDepositor, VaultFee, VaultProtocol,
+};
 use crate::{validate, Size, WithdrawUnit};
 
 #[assert_no_slop]
@@ -120,7 +131,28 @@ pub struct Vault {
     /// Purely for informational purposes for assets that have left the vault that the manager
     /// is expected to return.
     pub manager_borrowed_value: u64,
-    pub padding: [u64; 2],
+    /// The share of fuel allocated to the manager in bps when fuel_distribution_mode is `Weighted`
+    pub manager_fuel_bps: u16,
+    /// The share of fuel allocated to the [`VaultProtocol`] in bps when fuel_distribution_mode is `Weighted`
+    pub protocol_fuel_bps: u16,
+    /// Whether shares in pending depositor withdraw requests stop accruing fuel.
+    /// Can only be changed while there are no pending withdraw requests.
+    pub exclude_pending_withdraws_from_fuel: bool,
+    /// Whether the vault has a [`NavPolicy`] account, which must then be passed in remaining_accounts to
+    /// instructions that price deposits and withdrawals.
+    pub nav_policy: bool,
+    /// Whether the vault breached its [`NavPolicy`] max drawdown. The drift user is kept reduce-only and
+    /// the redeem period is waived until the manager re-enables trading.
+    pub drawdown_triggered: bool,
+    /// The number of fuel seasons the vault has been reset for, the current season's [`FuelSeasonSnapshot`]
+    /// pda seed. Wraps after 255 seasons
+    ///
+    /// [`FuelSeasonSnapshot`]: crate::state::FuelSeasonSnapshot
+    pub fuel_season: u8,
+    /// Net shares added to user_shares when distributing fuel: the extra shares from depositor fuel
+    /// lockups, less the shares in pending tokenized redeem requests and, if exclude_pending_withdraws_from_fuel
+    /// is set, the shares in pending depositor withdraw requests
+    pub fuel_shares_adjustment: i64,
 }
 
 impl Vault {
@@ -138,6 +170,7 @@ impl Vault {
         self.cumulative_fuel_per_share = 0;
         self.cumulative_fuel = 0;
         self.last_cumulative_fuel_per_share_ts = now as u32;
+        self.fuel_season = self.fuel_season.wrapping_add(1);
     }
 
     pub fn update_cumulative_fuel_per_share(
@@ -161,21 +194,31 @@ impl Vault {
                 self.reset_cumulative_fuel_per_share(now);
             } else {
                 // calculate the user's pro-rata share of pending fuel
-                let share_denominator =
-                    match FuelDistributionMode::try_from(self.fuel_distribution_mode)? {
-                        FuelDistributionMode::UsersOnly => {
-                            if self.user_shares == 0 {
-                                // if no users, then all shares are manager shares
-                                self.total_shares
-                            } else {
-                                self.user_shares
-                            }
+                let fuel_distribution_mode =
+                    FuelDistributionMode::try_from(self.fuel_distribution_mode)?;
+                let share_denominator = match fuel_distribution_mode {
+                    FuelDistributionMode::UsersOnly => {
+                        if self.user_shares == 0 {
+                            // if no users, then all shares are manager shares
+                            self.total_shares
+                        } else {
+                            self.get_fuel_eligible_user_shares()?
                         }
-                        FuelDistributionMode::UsersAndManager => self.total_shares,
-                    };
+                    }
+                    FuelDistributionMode::UsersAndManager => {
+                        self.apply_fuel_shares_adjustment(self.total_shares)?
+                    }
+                    FuelDistributionMode::Weighted => self.get_fuel_eligible_user_shares()?,
+                };
 
                 if share_denominator > 0 {
-                    let fuel_delta = total_fuel.safe_sub(self.cumulative_fuel)?;
+                    let mut fuel_delta = total_fuel.safe_sub(self.cumulative_fuel)?;
+                    if fuel_distribution_mode == FuelDistributionMode::Weighted {
+                        // the manager and protocol portions are settled into the FuelDistribution account
+                        fuel_delta = fuel_delta
+                            .safe_mul(self.get_users_fuel_bps()?.cast()?)?
+                            .safe_div(FUEL_DISTRIBUTION_PRECISION.cast()?)?;
+                    }
                     let fuel_delta_per_share = fuel_delta
                         .safe_mul(FUEL_SHARE_PRECISION)?
                         .safe_div(share_denominator)?;
@@ -193,6 +236,65 @@ impl Vault {
         Ok(self.cumulative_fuel_per_share)
     }
 
+    fn apply_fuel_shares_adjustment(&self, shares: u128) -> Result<u128> {
+        let adjustment = self.fuel_shares_adjustment.unsigned_abs().cast::<u128>()?;
+        if self.fuel_shares_adjustment >= 0 {
+            shares.safe_add(adjustment)
+        } else {
+            // requests are rebased individually, so rounding can leave the adjustment slightly off
+            Ok(shares.saturating_sub(adjustment))
+        }
+    }
+
+    /// The user shares fuel is distributed over, see fuel_shares_adjustment
+    pub fn get_fuel_eligible_user_shares(&self) -> Result<u128> {
+        self.apply_fuel_shares_adjustment(self.user_shares)
+    }
+
+    pub fn increase_withdraw_requested_shares(&mut self, shares: u128) -> Result<()> {
+        if self.exclude_pending_withdraws_from_fuel {
+            self.fuel_shares_adjustment = self.fuel_shares_adjustment.safe_sub(shares.cast()?)?;
+        }
+        Ok(())
+    }
+
+    pub fn decrease_withdraw_requested_shares(&mut self, shares: u128) -> Result<()> {
+        if self.exclude_pending_withdraws_from_fuel {
+            self.fuel_shares_adjustment = self.fuel_shares_adjustment.safe_add(shares.cast()?)?;
+        }
+        Ok(())
+    }
+
+    /// Shares in a [`TokenizedRedeemRequest`] have left the [`TokenizedVaultDepositor`] and belong to no
+    /// one accruing fuel, so they're always excluded from the fuel eligible shares
+    ///
+    /// [`TokenizedRedeemRequest`]: crate::state::TokenizedRedeemRequest
+    /// [`TokenizedVaultDepositor`]: crate::state::TokenizedVaultDepositor
+    pub fn increase_redeem_requested_shares(&mut self, shares: u128) -> Result<()> {
+        self.fuel_shares_adjustment = self.fuel_shares_adjustment.safe_sub(shares.cast()?)?;
+        Ok(())
+    }
+
+    pub fn decrease_redeem_requested_shares(&mut self, shares: u128) -> Result<()> {
+        self.fuel_shares_adjustment = self.fuel_shares_adjustment.safe_add(shares.cast()?)?;
+        Ok(())
+    }
+
+    pub fn update_fuel_boost_shares(&mut self, before: u64, after: u64) -> Result<()> {
+        self.fuel_shares_adjustment = self
+            .fuel_shares_adjustment
+            .safe_sub(before.cast()?)?
+            .safe_add(after.cast()?)?;
+        Ok(())
+    }
+
+    /// The share of fuel allocated to users in bps when fuel_distribution_mode is `Weighted`
+    pub fn get_users_fuel_bps(&self) -> Result<u16> {
+        Ok(FUEL_DISTRIBUTION_PRECISION
+            .safe_sub(self.manager_fuel_bps)?
+            .safe_sub(self.protocol_fuel_bps)?)
+    }
+
     pub fn is_normal_vault_class(&self) -> bool {
         self.vault_class == VaultClass::Normal as u8
     }
@@ -216,7 +318,9 @@ impl Vault {
         now: i64,
     ) -> Result<VaultFee> {
         if let Some(ref mut fee_update) = fee_update {
-            fee_update.load_mut()?.try_update_vault_fees(now, self)?;
+            fee_update
+                .load_mut()?
+                .try_update_vault_fees(now, self, Pubkey::default())?;
         }
 
         let depositor_equity =
@@ -474,6 +578,10 @@ impl Vault {
                     self.last_manager_withdraw_request.rebase(_rebase_divisor)?;
                 }
 
+                self.fuel_shares_adjustment = self
+                    .fuel_shares_adjustment
+                    .safe_div(_rebase_divisor.cast()?)?;
+
                 rebase_divisor = Some(_rebase_divisor);
 
                 msg!("rebasing vault: expo_diff={}", expo_diff);
@@ -502,6 +610,68 @@ impl Vault {
             ErrorCode::InvalidEquityValue,
             "oracle invalid"
         )?;
+
+        self.equity_to_deposit_asset(vault_equity, spot_market_map, oracle_map)
+    }
+
+    /// Same as [`Self::calculate_equity`] but tolerates invalid oracles: positions whose oracle is invalid are
+    /// valued conservatively, assets at zero and liabilities at the worse of the oracle price and twap. The
+    /// deposit asset's oracle must still be valid to convert the equity. Also returns whether all oracles were
+    /// valid.
+    pub fn calculate_conservative_equity(
+        &self,
+        user: &User,
+        perp_market_map: &PerpMarketMap,
+        spot_market_map: &SpotMarketMap,
+        oracle_map: &mut OracleMap,
+    ) -> VaultResult<(u64, bool)> {
+        let (mut vault_equity, all_oracles_valid) =
+            calculate_user_equity(user, perp_market_map, spot_market_map, oracle_map)?;
+
+        if !all_oracles_valid {
+            let adjustment = calculate_invalid_oracle_adjustment(
+                user,
+                perp_market_map,
+                spot_market_map,
+                oracle_map,
+            )?;
+            msg!(
+                "oracle invalid, valuing positions conservatively. vault equity (usd): {}, adjustment: {}",
+                vault_equity,
+                adjustment
+            );
+            vault_equity = vault_equity.safe_add(adjustment)?;
+        }
+
+        let spot_market = spot_market_map.get_ref(&self.spot_market_index)?;
+        let (_, deposit_oracle_valid) = get_oracle_price_and_validity(
+            oracle_map,
+            MarketType::Spot,
+            spot_market.market_index,
+            &spot_market.oracle_id(),
+            spot_market.historical_oracle_data.last_oracle_price_twap,
+            spot_market.get_max_confidence_interval_multiplier()?,
+        )?;
+        drop(spot_market);
+        validate!(
+            deposit_oracle_valid,
+            ErrorCode::InvalidEquityValue,
+            "deposit asset oracle invalid"
+        )?;
+
+        let vault_equity =
+            self.equity_to_deposit_asset(vault_equity, spot_market_map, oracle_map)?;
+
+        Ok((vault_equity, all_oracles_valid))
+    }
+
+    /// Converts the vault's usd equity to the deposit asset, adding back the manager's outstanding borrows
+    fn equity_to_deposit_asset(
+        &self,
+        vault_equity: i128,
+        spot_market_map: &SpotMarketMap,
+        oracle_map: &mut OracleMap,
+    ) -> VaultResult<u64> {
         validate!(
             vault_equity >= 0,
             ErrorCode::InvalidEquityValue,
@@ -522,6 +692,241 @@ impl Vault {
             .cast::<u64>()?)
     }
 
+    /// The vault equity deposits or withdrawals are priced at. Same as [`Self::calculate_equity`] unless the
+    /// vault has a [`NavPolicy`] with swing pricing or a tolerant oracle validity mode.
+    pub fn calculate_swing_equity(
+        &self,
+        nav_policy: &Option<AccountLoader<NavPolicy>>,
+        side: NavSide,
+        user: &User,
+        perp_market_map: &PerpMarketMap,
+        spot_market_map: &SpotMarketMap,
+        oracle_map: &mut OracleMap,
+    ) -> Result<u64> {
+        let (vault_equity, _) = self.calculate_nav_policy_equity(
+            nav_policy,
+            side,
+            user,
+            perp_market_map,
+            spot_market_map,
+            oracle_map,
+        )?;
+
+        self.apply_swing_pricing(
+            nav_policy,
+            side,
+            vault_equity,
+            user,
+            perp_market_map,
+            spot_market_map,
+            oracle_map,
+        )
+    }
+
+    /// The vault equity before swing pricing and whether all of the user's oracles were valid. Invalid oracles
+    /// fail unless the vault's [`NavPolicy`] tolerates them for `side`. Instructions that run the circuit
+    /// breaker, nav history and manager stats compute it once and pass it to each of them.
+    pub fn calculate_nav_policy_equity(
+        &self,
+        nav_policy: &Option<AccountLoader<NavPolicy>>,
+        side: NavSide,
+        user: &User,
+        perp_market_map: &PerpMarketMap,
+        spot_market_map: &SpotMarketMap,
+        oracle_map: &mut OracleMap,
+    ) -> Result<(u64, bool)> {
+        let tolerates_invalid_oracles = match nav_policy {
+            Some(nav_policy) => nav_policy.load()?.tolerates_invalid_oracles(side)?,
+            None => false,
+        };
+
+        if tolerates_invalid_oracles {
+            Ok(self.calculate_conservative_equity(
+                user,
+                perp_market_map,
+                spot_market_map,
+                oracle_map,
+            )?)
+        } else {
+            let vault_equity =
+                self.calculate_equity(user, perp_market_map, spot_market_map, oracle_map)?;
+            Ok((vault_equity, true))
+        }
+    }
+
+    /// Applies the [`NavPolicy`] swing pricing for `side` to `vault_equity` from
+    /// [`Self::calculate_nav_policy_equity`]. Returns it unchanged if the vault has no [`NavPolicy`].
+    #[allow(clippy::too_many_arguments)]
+    pub fn apply_swing_pricing(
+        &self,
+        nav_policy: &Option<AccountLoader<NavPolicy>>,
+        side: NavSide,
+        vault_equity: u64,
+        user: &User,
+        perp_market_map: &PerpMarketMap,
+        spot_market_map: &SpotMarketMap,
+        oracle_map: &mut OracleMap,
+    ) -> Result<u64> {
+        let nav_policy = match nav_policy {
+            Some(nav_policy) => nav_policy.load()?,
+            None => return Ok(vault_equity),
+        };
+
+        let perp_spread_cost =
+            if nav_policy.swing_pricing_mode()? == SwingPricingMode::PerpMarketSpread {
+                self.calculate_perp_spread_cost(
+                    side,
+                    user,
+                    perp_market_map,
+                    spot_market_map,
+                    oracle_map,
+                )?
+            } else {
+                0
+            };
+
+        let swing_equity = nav_policy.apply_swing(side, vault_equity, perp_spread_cost)?;
+        msg!(
+            "vault equity: {}, {:?} swing equity: {}",
+            vault_equity,
+            side,
+            swing_equity
+        );
+
+        Ok(swing_equity)
+    }
+
+    /// Runs the [`NavPolicy`] circuit breaker against `vault_equity` from [`Self::calculate_nav_policy_equity`].
+    /// Fails if the breaker is tripped or would trip, a trip is persisted by the permissionless trip_nav_breaker
+    /// crank. A queued withdraw swing increase whose timelock has passed is applied first.
+    pub fn check_nav_breaker(
+        &self,
+        nav_policy: &Option<AccountLoader<NavPolicy>>,
+        vault_equity: u64,
+        now: i64,
+    ) -> Result<()> {
+        let mut nav_policy = match nav_policy {
+            Some(nav_policy) => nav_policy.load_mut()?,
+            None => return Ok(()),
+        };
+
+        nav_policy.try_apply_incoming_update(now);
+
+        if !nav_policy.is_nav_breaker_enabled() {
+            return Ok(());
+        }
+
+        let tripped = nav_policy.check_nav_breaker(self, vault_equity, now)?;
+        validate!(
+            !tripped,
+            ErrorCode::NavCircuitBreakerTripped,
+            "nav circuit breaker would trip, it must be tripped with trip_nav_breaker and reset"
+        )?;
+
+        Ok(())
+    }
+
+    /// Appends the vault's equity and shares to its [`NavHistory`] if one was passed and a snapshot is due.
+    /// `vault_equity` is `None` if it was valued with invalid oracles, the snapshot is then skipped rather
+    /// than failing the instruction.
+    pub fn snapshot_nav_history(
+        &self,
+        nav_history: &Option<AccountLoader<NavHistory>>,
+        vault_equity: Option<u64>,
+        now: i64,
+    ) -> Result<()> {
+        let mut nav_history = match nav_history {
+            Some(nav_history) => nav_history.load_mut()?,
+            None => return Ok(()),
+        };
+
+        if !nav_history.is_snapshot_due(now)? {
+            return Ok(());
+        }
+
+        match vault_equity {
+            Some(vault_equity) => nav_history.append(self, vault_equity, now)?,
+            None => msg!("Skipping nav history snapshot, vault equity unavailable"),
+        }
+
+        Ok(())
+    }
+
+    /// Records the vault's share price in its [`ManagerStats`] if one was passed. Like snapshot_nav_history,
+    /// it is skipped if `vault_equity` was valued with invalid oracles.
+    pub fn update_manager_stats(
+        &self,
+        manager_stats: &Option<AccountLoader<ManagerStats>>,
+        vault_equity: Option<u64>,
+        now: i64,
+    ) -> Result<()> {
+        let mut manager_stats = match manager_stats {
+            Some(manager_stats) => manager_stats.load_mut()?,
+            None => return Ok(()),
+        };
+
+        match vault_equity {
+            Some(vault_equity) => manager_stats.observe(self, vault_equity, now)?,
+            None => msg!("Skipping manager stats update, vault equity unavailable"),
+        }
+
+        Ok(())
+    }
+
+    /// Estimates the cost (in deposit asset) to trade the vault's perp positions at the perp markets'
+    /// current spreads: out of them for withdrawals, into more of them for deposits.
+    pub fn calculate_perp_spread_cost(
+        &self,
+        side: NavSide,
+        user: &User,
+        perp_market_map: &PerpMarketMap,
+        spot_market_map: &SpotMarketMap,
+        oracle_map: &mut OracleMap,
+    ) -> VaultResult<u64> {
+        let mut spread_cost: u128 = 0;
+        for perp_position in user.perp_positions.iter() {
+            if perp_position.base_asset_amount == 0 {
+                continue;
+            }
+
+            let perp_market = perp_market_map.get_ref(&perp_position.market_index)?;
+            let oracle_price = oracle_map.get_price_data(&perp_market.oracle_id())?.price;
+
+            // buying pays the long spread, selling the short spread
+            let is_buy = (perp_position.base_asset_amount > 0) == (side == NavSide::Deposit);
+            let spread = if is_buy {
+                perp_market.amm.long_spread
+            } else {
+                perp_market.amm.short_spread
+            };
+
+            let notional = perp_position
+                .base_asset_amount
+                .unsigned_abs()
+                .cast::<u128>()?
+                .safe_mul(oracle_price.unsigned_abs().cast()?)?
+                .safe_div(AMM_RESERVE_PRECISION)?;
+
+            spread_cost = spread_cost.safe_add(
+                notional
+                    .safe_mul(spread.cast()?)?
+                    .safe_div(PERCENTAGE_PRECISION)?,
+            )?;
+        }
+
+        let spot_market = spot_market_map.get_ref(&self.spot_market_index)?;
+        let spot_market_precision = spot_market.get_precision().cast::<u128>()?;
+        let oracle_price = oracle_map
+            .get_price_data(&spot_market.oracle_id())?
+            .price
+            .cast::<u128>()?;
+
+        Ok(spread_cost
+            .safe_mul(spot_market_precision)?
+            .safe_div(oracle_price)?
+            .cast::<u64>()?)
+    }
+
     pub fn manager_deposit(
         &mut self,
         vault_protocol: &mut Option<RefMut<VaultProtocol>>,
@@ -571,6 +976,8 @@ impl Vault {
                 management_fee: management_fee_payment,
                 management_fee_shares,
                 deposit_oracle_price,
+                cost_basis_after: None,
+                realized_pnl: None,
             },
             vault_protocol
                 .as_mut()
@@ -580,7 +987,6 @@ impl Vault {
                     protocol_fee_shares,
                     protocol_shares_before,
                     protocol_shares_after,
-                    deposit_oracle_price,
                 }),
         )?;
 
@@ -676,6 +1082,8 @@ impl Vault {
                 management_fee: management_fee_payment,
                 management_fee_shares,
                 deposit_oracle_price,
+                cost_basis_after: None,
+                realized_pnl: None,
             },
             Some(VaultDepositorRecordProtocolParams {
                 protocol_profit_share: 0,
@@ -683,7 +1091,6 @@ impl Vault {
                 protocol_fee_shares,
                 protocol_shares_before,
                 protocol_shares_after,
-                deposit_oracle_price,
             }),
         )?;
 
@@ -741,6 +1148,8 @@ impl Vault {
                 management_fee: management_fee_payment,
                 management_fee_shares,
                 deposit_oracle_price,
+                cost_basis_after: None,
+                realized_pnl: None,
             },
             Some(VaultDepositorRecordProtocolParams {
                 protocol_profit_share: 0,
@@ -748,7 +1157,6 @@ impl Vault {
                 protocol_fee_shares,
                 protocol_shares_before,
                 protocol_shares_after,
-                deposit_oracle_price,
             }),
         )?;
 
@@ -837,6 +1245,8 @@ impl Vault {
                 management_fee: management_fee_payment,
                 management_fee_shares,
                 deposit_oracle_price,
+                cost_basis_after: None,
+                realized_pnl: None,
             },
             Some(VaultDepositorRecordProtocolParams {
                 protocol_profit_share: 0,
@@ -844,7 +1254,6 @@ impl Vault {
                 protocol_fee_shares,
                 protocol_shares_before,
                 protocol_shares_after,
-                deposit_oracle_price,
             }),
         )?;
 
@@ -880,6 +1289,35 @@ impl Vault {
         self.liquidation_start_ts = 0;
     }
 
+    /// Checks a secondary transfer of the vault's share tokens, run by the Token-2022 transfer hook.
+    /// Share tokens can't leave a holder whose [`VaultDepositor`] is in a fuel lockup, and permissioned
+    /// vaults only let them move to holders with a [`VaultDepositor`], which the manager must approve.
+    pub fn validate_share_token_transfer(
+        &self,
+        source_vault_depositor: Option<&VaultDepositor>,
+        destination_owner: &Pubkey,
+        destination_vault_depositor: Option<&VaultDepositor>,
+        now: i64,
+    ) -> VaultResult {
+        if let Some(source_vault_depositor) = source_vault_depositor {
+            validate!(
+                !source_vault_depositor.is_fuel_locked(now),
+                ErrorCode::VaultDepositorLocked,
+                "share tokens can't be transferred until the holder's lockup ends at {}",
+                source_vault_depositor.fuel_lockup_end_ts
+            )?;
+        }
+
+        validate!(
+            !self.permissioned || destination_vault_depositor.is_some(),
+            ErrorCode::PermissionedVault,
+            "{} has no vault depositor in this permissioned vault",
+            destination_owner
+        )?;
+
+        Ok(())
+    }
+
     #[allow(clippy::too_many_arguments)]
     pub fn protocol_request_withdraw(
         &mut self,
@@ -965,6 +1403,8 @@ impl Vault {
                     management_fee: management_fee_payment,
                     management_fee_shares,
                     deposit_oracle_price,
+                    cost_basis_after: None,
+                    realized_pnl: None,
                 },
                 Some(VaultDepositorRecordProtocolParams {
                     protocol_profit_share: 0,
@@ -972,7 +1412,6 @@ impl Vault {
                     protocol_fee_shares,
                     protocol_shares_before,
                     protocol_shares_after,
-                    deposit_oracle_price,
                 }),
             )?;
         }
@@ -1056,6 +1495,8 @@ impl Vault {
                     management_fee: management_fee_payment,
                     management_fee_shares,
                     deposit_oracle_price,
+                    cost_basis_after: None,
+                    realized_pnl: None,
                 },
                 Some(VaultDepositorRecordProtocolParams {
                     protocol_profit_share: 0,
@@ -1063,7 +1504,6 @@ impl Vault {
                     protocol_fee_shares,
                     protocol_shares_before,
                     protocol_shares_after,
-                    deposit_oracle_price,
                 }),
             )?;
         }
@@ -1165,6 +1605,8 @@ impl Vault {
                     management_fee: management_fee_payment,
                     management_fee_shares,
                     deposit_oracle_price,
+                    cost_basis_after: None,
+                    realized_pnl: None,
                 },
                 Some(VaultDepositorRecordProtocolParams {
                     protocol_profit_share: 0,
@@ -1172,7 +1614,6 @@ impl Vault {
                     protocol_fee_shares,
                     protocol_shares_before,
                     protocol_shares_after,
-                    deposit_oracle_price,
                 }),
             )?;
 
@@ -1271,79 +1712,293 @@ impl Vault {
         }
     }
 
-    fn emit_vault_depositor_record(
+    pub fn validate_nav_policy(&self, nav_policy: &Option<AccountLoader<NavPolicy>>) -> Result<()> {
+        // the pda is checked when the account is resolved from remaining_accounts
+        validate!(
+            !self.nav_policy || nav_policy.is_some(),
+            ErrorCode::NavPolicyMissing,
+            "NavPolicy missing in remaining accounts"
+        )?;
+
+        Ok(())
+    }
+
+    pub fn validate_fuel_distribution(
+        &self,
+        fuel_distribution: &Option<AccountLoader<FuelDistribution>>,
+    ) -> Result<()> {
+        // the pda is checked when the account is resolved from remaining_accounts
+        validate!(
+            !FuelDistributionMode::is_weighted(self.fuel_distribution_mode)
+                || fuel_distribution.is_some(),
+            ErrorCode::FuelDistributionMissing,
+            "FuelDistribution missing in remaining accounts"
+        )?;
+
+        Ok(())
+    }
+
+    /// The settings logged by [`crate::state::events::VaultConfigRecord`]. The drift user's settings are only
+    /// included if it is passed, the optional accounts' settings are set by the instructions that update them.
+    pub fn get_config(&self, drift_user: Option<&User>) -> VaultConfig {
+        VaultConfig {
+            manager: self.manager,
+            delegate: self.delegate,
+            liquidation_delegate: self.liquidation_delegate,
+            redeem_period: self.redeem_period,
+            max_tokens: self.max_tokens,
+            min_deposit_amount: self.min_deposit_amount,
+            management_fee: self.management_fee,
+            profit_share: self.profit_share,
+            hurdle_rate: self.hurdle_rate,
+            permissioned: self.permissioned,
+            exclude_pending_withdraws_from_fuel: self.exclude_pending_withdraws_from_fuel,
+            vault_class: self.vault_class,
+            fuel_distribution_mode: self.fuel_distribution_mode,
+            manager_fuel_bps: self.manager_fuel_bps,
+            protocol_fuel_bps: self.protocol_fuel_bps,
+            fee_update_status: self.fee_update_status,
+            manager_borrowed_value: self.manager_borrowed_value,
+            margin_trading_enabled: drift_user.map(|user| user.is_margin_trading_enabled),
+            pool_id: drift_user.map(|user| user.pool_id),
+            nav_policy: None,
+            vault_protocol: None,
+            fuel_distribution: None,
+            fee_update: None,
+        }
+    }
+
+    pub(crate) fn emit_vault_depositor_record(
         &self,
         params: VaultDepositorRecordParams,
-        optional_params: Option<VaultDepositorRecordProtocolParams>,
+        protocol_params: Option<VaultDepositorRecordProtocolParams>,
     ) -> Result<()> {
-        match optional_params {
-            None => {
-                emit!(VaultDepositorRecord {
-                    ts: params.ts,
-                    vault: self.pubkey,
-                    depositor_authority: params.depositor_authority,
-                    action: params.action,
-                    amount: params.amount,
-                    spot_market_index: self.spot_market_index,
-                    vault_equity_before: params.vault_equity_before,
-                    vault_shares_before: params.vault_shares_before,
-                    user_vault_shares_before: params.user_vault_shares_before,
-                    total_vault_shares_before: params.total_vault_shares_before,
-                    vault_shares_after: params.vault_shares_after,
-                    total_vault_shares_after: self.total_shares,
-                    user_vault_shares_after: self.user_shares,
-                    profit_share: params.manager_profit_share,
-                    management_fee: params.management_fee,
-                    management_fee_shares: params.management_fee_shares,
-                    deposit_oracle_price: params.deposit_oracle_price,
-                });
-            }
-            Some(protocol_params) => {
-                emit!(VaultDepositorV1Record {
-                    ts: params.ts,
-                    vault: self.pubkey,
-                    depositor_authority: params.depositor_authority,
-                    action: params.action,
-                    amount: params.amount,
-                    spot_market_index: self.spot_market_index,
-                    vault_equity_before: params.vault_equity_before,
-                    vault_shares_before: params.vault_shares_before,
-                    user_vault_shares_before: params.user_vault_shares_before,
-                    total_vault_shares_before: params.total_vault_shares_before,
-                    vault_shares_after: params.vault_shares_after,
-                    total_vault_shares_after: self.total_shares,
-                    user_vault_shares_after: self.user_shares,
-                    manager_profit_share: params.manager_profit_share,
-                    management_fee: params.management_fee,
-                    management_fee_shares: params.management_fee_shares,
-
-                    protocol_profit_share: protocol_params.protocol_profit_share,
-                    protocol_fee: protocol_params.protocol_fee,
-                    protocol_fee_shares: protocol_params.protocol_fee_shares,
-                    protocol_shares_before: protocol_params.protocol_shares_before,
-                    protocol_shares_after: protocol_params.protocol_shares_after,
-                    deposit_oracle_price: protocol_params.deposit_oracle_price,
-                });
-            }
-        };
+        emit!(VaultDepositorV2Record {
+            ts: params.ts,
+            vault: self.pubkey,
+            depositor_authority: params.depositor_authority,
+            action: params.action,
+            amount: params.amount,
+            spot_market_index: self.spot_market_index,
+            shares_base: self.shares_base,
+            vault_shares_before: params.vault_shares_before,
+            vault_shares_after: params.vault_shares_after,
+            vault_equity_before: params.vault_equity_before,
+            manager_borrowed_value: self.manager_borrowed_value,
+            fee_update_status: self.fee_update_status,
+            user_vault_shares_before: params.user_vault_shares_before,
+            total_vault_shares_before: params.total_vault_shares_before,
+            user_vault_shares_after: self.user_shares,
+            total_vault_shares_after: self.total_shares,
+            manager_profit_share: params.manager_profit_share,
+            management_fee: params.management_fee,
+            management_fee_shares: params.management_fee_shares,
+            protocol_shares_before: protocol_params
+                .as_ref()
+                .map(|params| params.protocol_shares_before),
+            protocol_shares_after: protocol_params
+                .as_ref()
+                .map(|params| params.protocol_shares_after),
+            protocol_profit_share: protocol_params
+                .as_ref()
+                .map(|params| params.protocol_profit_share),
+            protocol_fee: protocol_params.as_ref().map(|params| params.protocol_fee),
+            protocol_fee_shares: protocol_params
+                .as_ref()
+                .map(|params| params.protocol_fee_shares),
+            deposit_oracle_price: params.deposit_oracle_price,
+            cost_basis_after: params.cost_basis_after,
+            realized_pnl: params.realized_pnl,
+        });
         Ok(())
     }
 
-    pub fn update_fuel_distribution_mode(&mut self, mode: u8) {
+    pub fn update_fuel_distribution_mode(&mut self, mode: u8) -> Result<()> {
+        let new_mode = FuelDistributionMode::try_from(mode)?;
+        let current_mode = FuelDistributionMode::try_from(self.fuel_distribution_mode)?;
+        validate!(
+            new_mode != FuelDistributionMode::Weighted
+                && current_mode != FuelDistributionMode::Weighted,
+            ErrorCode::InvalidFuelDistributionMode,
+            "Weighted fuel distribution can only be changed with manager_update_fuel_distribution"
+        )?;
+
         msg!(
             "Updating fuel distribution mode {} -> {}",
             self.fuel_distribution_mode,
             mode
         );
         self.fuel_distribution_mode = mode;
+
+        Ok(())
     }
 }
 
+fn get_oracle_price_and_validity(
+    oracle_map: &mut OracleMap,
+    market_type: MarketType,
+    market_index: u16,
+    oracle_id: &OracleIdentifier,
+    last_oracle_price_twap: i64,
+    max_confidence_interval_multiplier: u64,
+) -> VaultResult<(i64, bool)> {
+    let (oracle_price_data, oracle_validity) = oracle_map.get_price_data_and_validity(
+        market_type,
+        market_index,
+        oracle_id,
+        last_oracle_price_twap,
+        max_confidence_interval_multiplier,
+    )?;
+    let is_valid = is_oracle_valid_for_action(oracle_validity, Some(DriftAction::MarginCalc))?;
+
+    Ok((oracle_price_data.price, is_valid))
+}
+
+/// The change (in usd) from valuing the positions with an invalid oracle, or perp positions with an invalid
+/// quote oracle, conservatively instead of at their oracle price, as [`calculate_user_equity`] does.
+/// Always <= 0.
+fn calculate_invalid_oracle_adjustment(
+    user: &User,
+    perp_market_map: &PerpMarketMap,
+    spot_market_map: &SpotMarketMap,
+    oracle_map: &mut OracleMap,
+) -> VaultResult<i128> {
+    let mut adjustment: i128 = 0;
+
+    for spot_position in user.spot_positions.iter() {
+        if spot_position.is_available() {
+            continue;
+        }
+
+        let spot_market = spot_market_map.get_ref(&spot_position.market_index)?;
+        let oracle_price_twap = spot_market.historical_oracle_data.last_oracle_price_twap;
+        let (oracle_price, is_valid) = get_oracle_price_and_validity(
+            oracle_map,
+            MarketType::Spot,
+            spot_market.market_index,
+            &spot_market.oracle_id(),
+            oracle_price_twap,
+            spot_market.get_max_confidence_interval_multiplier()?,
+        )?;
+        if is_valid {
+            continue;
+        }
+
+        let token_amount = spot_position.get_signed_token_amount(&spot_market)?;
+        let token_value = get_token_value(token_amount, spot_market.decimals, oracle_price)?;
+        let conservative_value = if token_amount > 0 {
+            0
+        } else {
+            get_token_value(
+                token_amount,
+                spot_market.decimals,
+                oracle_price.max(oracle_price_twap),
+            )?
+        };
+
+        adjustment = adjustment.safe_add(conservative_value.safe_sub(token_value)?)?;
+    }
+
+    for perp_position in user.perp_positions.iter() {
+        if perp_position.is_available() {
+            continue;
+        }
+
+        let perp_market = perp_market_map.get_ref(&perp_position.market_index)?;
+
+        let quote_spot_market = spot_market_map.get_ref(&perp_market.quote_spot_market_index)?;
+        let quote_oracle_price_twap = quote_spot_market
+            .historical_oracle_data
+            .last_oracle_price_twap;
+        let (quote_oracle_price, quote_is_valid) = get_oracle_price_and_validity(
+            oracle_map,
+            MarketType::Spot,
+            quote_spot_market.market_index,
+            &quote_spot_market.oracle_id(),
+            quote_oracle_price_twap,
+            quote_spot_market.get_max_confidence_interval_multiplier()?,
+        )?;
+        drop(quote_spot_market);
+
+        let oracle_price_twap = perp_market
+            .amm
+            .historical_oracle_data
+            .last_oracle_price_twap;
+        let (oracle_price, is_valid) = get_oracle_price_and_validity(
+            oracle_map,
+            MarketType::Perp,
+            perp_market.market_index,
+            &perp_market.oracle_id(),
+            oracle_price_twap,
+            perp_market.get_max_confidence_interval_multiplier()?,
+        )?;
+        if is_valid && quote_is_valid {
+            continue;
+        }
+
+        // valued like calculate_user_equity: pnl plus unsettled funding in the quote asset, priced at the
+        // expiry price once the market is settling
+        let is_settling = perp_market.status == MarketStatus::Settlement;
+        let valuation_price = if is_settling {
+            perp_market.expiry_price
+        } else {
+            oracle_price
+        };
+        let unrealized_funding = calculate_funding_payment(
+            if perp_position.base_asset_amount > 0 {
+                perp_market.amm.cumulative_funding_rate_long
+            } else {
+                perp_market.amm.cumulative_funding_rate_short
+            },
+            perp_position,
+        )?
+        .cast::<i128>()?;
+        let (_, pnl) =
+            calculate_base_asset_value_and_pnl_with_oracle_price(perp_position, valuation_price)?;
+        let pnl = pnl.safe_add(unrealized_funding)?;
+        let pnl_value = pnl
+            .safe_mul(quote_oracle_price.cast()?)?
+            .safe_div(PRICE_PRECISION_I128)?;
+
+        // the expiry price doesn't depend on the oracle
+        let conservative_pnl = if is_valid || is_settling {
+            pnl
+        } else {
+            let (_, twap_pnl) = calculate_base_asset_value_and_pnl_with_oracle_price(
+                perp_position,
+                oracle_price_twap,
+            )?;
+            pnl.min(twap_pnl.safe_add(unrealized_funding)?).min(0)
+        };
+        let conservative_pnl_value = if quote_is_valid {
+            conservative_pnl
+                .safe_mul(quote_oracle_price.cast()?)?
+                .safe_div(PRICE_PRECISION_I128)?
+        } else if conservative_pnl > 0 {
+            0
+        } else {
+            conservative_pnl
+                .safe_mul(quote_oracle_price.max(quote_oracle_price_twap).cast()?)?
+                .safe_div(PRICE_PRECISION_I128)?
+        };
+
+        adjustment = adjustment.safe_add(conservative_pnl_value.safe_sub(pnl_value)?)?;
+    }
+
+    Ok(adjustment)
+}
+
 #[derive(Clone, Copy, BorshSerialize, BorshDeserialize, PartialEq, Debug, Eq)]
 #[repr(u8)]
 pub enum FuelDistributionMode {
     UsersOnly = 0b00000000,
     UsersAndManager = 0b00000001,
+    /// Fuel is split between users, the manager and the protocol by vault.manager_fuel_bps and
+    /// vault.protocol_fuel_bps. Users accrue through cumulative_fuel_per_share, the manager and protocol
+    /// through the [`FuelDistribution`] account.
+    ///
+    /// [`FuelDistribution`]: crate::state::FuelDistribution
+    Weighted = 0b00000010,
 }
 
 impl TryFrom<u8> for FuelDistributionMode {
@@ -1353,6 +2008,7 @@ impl TryFrom<u8> for FuelDistributionMode {
         match value {
             0 => Ok(FuelDistributionMode::UsersOnly),
             1 => Ok(FuelDistributionMode::UsersAndManager),
+            2 => Ok(FuelDistributionMode::Weighted),
             _ => Err(ErrorCode::InvalidFuelDistributionMode),
         }
     }
@@ -1366,6 +2022,10 @@ impl FuelDistributionMode {
     pub fn is_users_and_manager(mode: u8) -> bool {
         mode & FuelDistributionMode::UsersAndManager as u8 != 0
     }
+
+    pub fn is_weighted(mode: u8) -> bool {
+        mode == FuelDistributionMode::Weighted as u8
+    }
 }
 
 pub enum FeeUpdateStatus {
@@ -1412,7 +2072,7 @@ impl TryFrom<u8> for VaultClass {
     }
 }
 
-struct VaultDepositorRecordParams {
+pub(crate) struct VaultDepositorRecordParams {
     pub ts: i64,
     pub action: VaultDepositorAction,
     pub amount: u64,
@@ -1431,17 +2091,18 @@ struct VaultDepositorRecordParams {
     pub management_fee_shares: i64,
 
     pub deposit_oracle_price: i64,
+
+    pub cost_basis_after: Option<u64>,
+    pub realized_pnl: Option<i64>,
 }
 
-struct VaultDepositorRecordProtocolParams {
+pub(crate) struct VaultDepositorRecordProtocolParams {
     pub protocol_profit_share: u64,
     pub protocol_fee: i64,
     pub protocol_fee_shares: i64,
 
     pub protocol_shares_before: u128,
     pub protocol_shares_after: u128,
-
-    pub deposit_oracle_price: i64,
 }
 
 #[cfg(test)]
@@ -1529,3 +2190,248 @@ mod vault_fuel_tests {
         }
     }
 }
+
+#[cfg(test)]
+mod share_token_transfer_tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_share_token_transfer() {
+        let now = 1_000_000;
+        let receiver = Pubkey::new_unique();
+        let mut vault = Vault::default();
+
+        let mut source = VaultDepositor::default();
+        let destination = VaultDepositor::default();
+
+        // anyone can receive share tokens of a permissionless vault
+        assert!(vault
+            .validate_share_token_transfer(None, &receiver, None, now)
+            .is_ok());
+        assert!(vault
+            .validate_share_token_transfer(Some(&source), &receiver, None, now)
+            .is_ok());
+
+        // permissioned vaults only allow receivers with a vault depositor
+        vault.permissioned = true;
+        assert_eq!(
+            vault.validate_share_token_transfer(Some(&source), &receiver, None, now),
+            Err(ErrorCode::PermissionedVault)
+        );
+        assert!(vault
+            .validate_share_token_transfer(Some(&source), &receiver, Some(&destination), now)
+            .is_ok());
+
+        // a holder in a lockup can't move share tokens until it ends
+        source.fuel_lockup_end_ts = now + 1;
+        assert_eq!(
+            vault.validate_share_token_transfer(Some(&source), &receiver, Some(&destination), now),
+            Err(ErrorCode::VaultDepositorLocked)
+        );
+        assert!(vault
+            .validate_share_token_transfer(Some(&source), &receiver, Some(&destination), now + 1)
+            .is_ok());
+    }
+}
+
+#[cfg(test)]
+mod invalid_oracle_tests {
+    use std::collections::BTreeSet;
+
+    use anchor_lang::Discriminator;
+    use drift::instructions::optional_accounts::{load_maps, AccountMaps};
+    use drift::math::constants::{
+        BASE_PRECISION_I64, PRICE_PRECISION_I64, QUOTE_PRECISION_I128, QUOTE_PRECISION_I64,
+        SPOT_BALANCE_PRECISION_U64, SPOT_CUMULATIVE_INTEREST_PRECISION,
+    };
+    use drift::state::oracle::{HistoricalOracleData, OracleSource, PrelaunchOracle};
+    use drift::state::perp_market::{PerpMarket, AMM};
+    use drift::state::spot_market::SpotMarket;
+    use drift::state::user::{PerpPosition, SpotPosition};
+
+    use super::*;
+    use crate::test_utils::create_account_info;
+
+    fn account_bytes<T: bytemuck::Pod + Discriminator>(account: &T) -> Vec<u8> {
+        let mut bytes = T::discriminator().to_vec();
+        bytes.extend_from_slice(bytemuck::bytes_of(account));
+        bytes
+    }
+
+    fn prelaunch_oracle(price: i64) -> PrelaunchOracle {
+        PrelaunchOracle {
+            price,
+            max_price: price,
+            ..PrelaunchOracle::default()
+        }
+    }
+
+    fn perp_market(
+        market_index: u16,
+        oracle: Pubkey,
+        oracle_price_twap: i64,
+        status: MarketStatus,
+    ) -> PerpMarket {
+        PerpMarket {
+            market_index,
+            status,
+            amm: AMM {
+                oracle,
+                oracle_source: OracleSource::Prelaunch,
+                historical_oracle_data: HistoricalOracleData {
+                    last_oracle_price_twap: oracle_price_twap,
+                    ..HistoricalOracleData::default()
+                },
+                ..AMM::default()
+            },
+            ..PerpMarket::default()
+        }
+    }
+
+    #[test]
+    fn test_invalid_oracle_adjustment() {
+        let sol_oracle_key = Pubkey::new_unique();
+        let perp_oracle_key = Pubkey::new_unique();
+        let settling_perp_oracle_key = Pubkey::new_unique();
+
+        // oracles 10x away from their market's twap are too volatile to be valid
+        let mut sol_oracle = account_bytes(&prelaunch_oracle(100 * PRICE_PRECISION_I64));
+        let mut perp_oracle = account_bytes(&prelaunch_oracle(50 * PRICE_PRECISION_I64));
+        let mut settling_perp_oracle = account_bytes(&prelaunch_oracle(100 * PRICE_PRECISION_I64));
+
+        let mut usdc_spot_market = account_bytes(&SpotMarket {
+            market_index: 0,
+            oracle_source: OracleSource::QuoteAsset,
+            decimals: 6,
+            cumulative_deposit_interest: SPOT_CUMULATIVE_INTEREST_PRECISION,
+            cumulative_borrow_interest: SPOT_CUMULATIVE_INTEREST_PRECISION,
+            historical_oracle_data: HistoricalOracleData {
+                last_oracle_price_twap: PRICE_PRECISION_I64,
+                ..HistoricalOracleData::default()
+            },
+            ..SpotMarket::default()
+        });
+        let mut sol_spot_market = account_bytes(&SpotMarket {
+            market_index: 1,
+            oracle: sol_oracle_key,
+            oracle_source: OracleSource::Prelaunch,
+            decimals: 9,
+            cumulative_deposit_interest: SPOT_CUMULATIVE_INTEREST_PRECISION,
+            cumulative_borrow_interest: SPOT_CUMULATIVE_INTEREST_PRECISION,
+            historical_oracle_data: HistoricalOracleData {
+                last_oracle_price_twap: 1000 * PRICE_PRECISION_I64,
+                ..HistoricalOracleData::default()
+            },
+            ..SpotMarket::default()
+        });
+        let mut perp_market_0 = perp_market(
+            0,
+            perp_oracle_key,
+            500 * PRICE_PRECISION_I64,
+            MarketStatus::Active,
+        );
+        perp_market_0.amm.cumulative_funding_rate_long = 1_000_000;
+        let mut perp_market_0 = account_bytes(&perp_market_0);
+        let mut perp_market_1 = account_bytes(&PerpMarket {
+            expiry_price: 100 * PRICE_PRECISION_I64,
+            ..perp_market(
+                1,
+                settling_perp_oracle_key,
+                10 * PRICE_PRECISION_I64,
+                MarketStatus::Settlement,
+            )
+        });
+
+        let drift_program = drift::id();
+        let keys = [
+            sol_oracle_key,
+            perp_oracle_key,
+            settling_perp_oracle_key,
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        ];
+        let mut lamports = [0_u64; 7];
+        let [l0, l1, l2, l3, l4, l5, l6] = &mut lamports;
+        let account_infos = vec![
+            create_account_info(&keys[0], false, l0, &mut sol_oracle, &drift_program),
+            create_account_info(&keys[1], false, l1, &mut perp_oracle, &drift_program),
+            create_account_info(
+                &keys[2],
+                false,
+                l2,
+                &mut settling_perp_oracle,
+                &drift_program,
+            ),
+            create_account_info(&keys[3], false, l3, &mut usdc_spot_market, &drift_program),
+            create_account_info(&keys[4], false, l4, &mut sol_spot_market, &drift_program),
+            create_account_info(&keys[5], false, l5, &mut perp_market_0, &drift_program),
+            create_account_info(&keys[6], false, l6, &mut perp_market_1, &drift_program),
+        ];
+        let AccountMaps {
+            perp_market_map,
+            spot_market_map,
+            mut oracle_map,
+        } = load_maps(
+            &mut account_infos.iter().peekable(),
+            &BTreeSet::new(),
+            &BTreeSet::new(),
+            0,
+            None,
+        )
+        .unwrap();
+
+        let mut user = User::default();
+        user.spot_positions[0] = SpotPosition {
+            market_index: 0,
+            scaled_balance: 10_000 * SPOT_BALANCE_PRECISION_U64,
+            ..SpotPosition::default()
+        };
+        user.spot_positions[1] = SpotPosition {
+            market_index: 1,
+            scaled_balance: 10 * SPOT_BALANCE_PRECISION_U64,
+            ..SpotPosition::default()
+        };
+        // long at 40, in profit at the oracle price and the twap
+        user.perp_positions[0] = PerpPosition {
+            market_index: 0,
+            base_asset_amount: BASE_PRECISION_I64,
+            quote_asset_amount: -40 * QUOTE_PRECISION_I64,
+            quote_entry_amount: -40 * QUOTE_PRECISION_I64,
+            quote_break_even_amount: -40 * QUOTE_PRECISION_I64,
+            ..PerpPosition::default()
+        };
+        // long at the expiry price, in loss at the twap
+        user.perp_positions[1] = PerpPosition {
+            market_index: 1,
+            base_asset_amount: BASE_PRECISION_I64,
+            quote_asset_amount: -100 * QUOTE_PRECISION_I64,
+            quote_entry_amount: -100 * QUOTE_PRECISION_I64,
+            quote_break_even_amount: -100 * QUOTE_PRECISION_I64,
+            ..PerpPosition::default()
+        };
+
+        let (equity, all_oracles_valid) =
+            calculate_user_equity(&user, &perp_market_map, &spot_market_map, &mut oracle_map)
+                .unwrap();
+        assert!(!all_oracles_valid);
+
+        let adjustment = calculate_invalid_oracle_adjustment(
+            &user,
+            &perp_market_map,
+            &spot_market_map,
+            &mut oracle_map,
+        )
+        .unwrap();
+
+        // the sol deposit and the perp profit net of funding are valued at zero, the settling perp position
+        // at its expiry price
+        assert!(equity > 11_000 * QUOTE_PRECISION_I128);
+        assert!(equity < 11_010 * QUOTE_PRECISION_I128);
+        assert_eq!(
+            equity.safe_add(adjustment).unwrap(),
+            10_000 * QUOTE_PRECISION_I128
+        );
+    }
+}
@@ -20,13 +20,18 @@ use drift::state::user::{FuelOverflow, User, UserStats};
 use drift_macros::assert_no_slop;
 use static_assertions::const_assert_eq;
 
-use crate::constants::{FUEL_SHARE_PRECISION, MAGIC_FUEL_START_TS};
+use crate::constants::{
+    FUEL_LOCKUP_MULTIPLIER_PRECISION, FUEL_SHARE_PRECISION, MAGIC_FUEL_START_TS,
+};
 use crate::error::ErrorCode;
 use crate::events::VaultDepositorAction;
-use crate::state::events::{VaultDepositorRecord, VaultDepositorV1Record};
+use crate::state::events::VaultDepositorFuelRecord;
+use crate::state::vault::{VaultDepositorRecordParams, VaultDepositorRecordProtocolParams};
 use crate::state::withdraw_request::WithdrawRequest;
 use crate::state::withdraw_unit::WithdrawUnit;
-use crate::state::{FeeUpdate, Vault, VaultDepositorBase, VaultFee, VaultProtocol};
+use crate::state::{
+    FeeUpdate, FuelLockupPeriod, Vault, VaultDepositorBase, VaultFee, VaultProtocol,
+};
 use crate::validate;
 use crate::Size;
 
@@ -63,7 +68,24 @@ pub struct VaultDepositor {
     pub cumulative_fuel_per_share_amount: u128,
     /// precision: none
     pub fuel_amount: u128,
-    pub padding: [u64; 4],
+    /// The ts the depositor's fuel lockup ends, request_withdraw is blocked until then
+    pub fuel_lockup_end_ts: i64,
+    /// The extra shares from the fuel lockup counted in vault.fuel_shares_adjustment
+    pub fuel_boost_shares: u64,
+    /// precision: FUEL_LOCKUP_MULTIPLIER_PRECISION
+    pub fuel_lockup_multiplier: u32,
+    /// The number of [`RewardStreamDepositor`]s of the depositor. They must all be passed in remaining_accounts
+    /// to instructions that change the depositor's shares
+    ///
+    /// [`RewardStreamDepositor`]: crate::state::RewardStreamDepositor
+    pub reward_stream_count: u8,
+    /// Set once cost_basis is tracked. Depositors from before it was tracked value their shares at their net
+    /// deposits until then
+    pub cost_basis_migrated: bool,
+    pub padding1: [u8; 2],
+    /// The deposit asset paid for the depositor's shares: deposits and the value of shares transferred in, less
+    /// the cost of the shares withdrawn or transferred out. Unchanged by rebases and profit share
+    pub cost_basis: u64,
 }
 
 impl Size for VaultDepositor {
@@ -117,6 +139,35 @@ impl VaultDepositorBase for VaultDepositor {
     fn set_profit_share_fee_paid(&mut self, amount: u64) {
         self.profit_share_fee_paid = amount;
     }
+
+    fn get_cost_basis(&self) -> u64 {
+        self.cost_basis
+    }
+    fn set_cost_basis(&mut self, amount: u64) {
+        self.cost_basis = amount;
+        self.cost_basis_migrated = true;
+    }
+    fn is_cost_basis_migrated(&self) -> bool {
+        self.cost_basis_migrated
+    }
+}
+
+/// The shares before a deposit, withdraw request or withdraw and what it paid in fees and profit share.
+/// Returned by the state changes the instructions share with their previews.
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct VaultDepositorChange {
+    pub vault_shares_before: u128,
+    pub user_vault_shares_before: u128,
+    pub total_vault_shares_before: u128,
+    pub protocol_shares_before: u128,
+    /// The shares minted, requested or redeemed
+    pub shares: u128,
+    /// The amount deposited, requested or withdrawn
+    pub amount: u64,
+    pub fee: VaultFee,
+    pub manager_profit_share: u64,
+    pub protocol_profit_share: u64,
+    pub realized_pnl: Option<i64>,
 }
 
 impl VaultDepositor {
@@ -137,7 +188,13 @@ impl VaultDepositor {
             last_fuel_update_ts: MAGIC_FUEL_START_TS,
             cumulative_fuel_per_share_amount: 0,
             fuel_amount: 0,
-            padding: [0u64; 4],
+            fuel_lockup_end_ts: 0,
+            fuel_boost_shares: 0,
+            fuel_lockup_multiplier: 0,
+            reward_stream_count: 0,
+            cost_basis_migrated: true,
+            padding1: [0; 2],
+            cost_basis: 0,
         }
     }
 
@@ -191,6 +248,16 @@ impl VaultDepositor {
             VaultDepositorBase::apply_rebase(self, vault, vault_protocol, vault_equity)?
         {
             self.last_withdraw_request.rebase(rebase_divisor)?;
+            if self.fuel_boost_shares != 0 {
+                // the vault divided its fuel_shares_adjustment as a whole, swap this depositor's part of it
+                // for the boost on its rebased shares
+                let fuel_boost_shares = self.calculate_fuel_boost_shares(vault)?;
+                vault.update_fuel_boost_shares(
+                    self.fuel_boost_shares.safe_div(rebase_divisor.cast()?)?,
+                    fuel_boost_shares,
+                )?;
+                self.fuel_boost_shares = fuel_boost_shares;
+            }
             Ok(Some(rebase_divisor))
         } else {
             Ok(None)
@@ -233,19 +300,8 @@ impl VaultDepositor {
         Ok((0, 0))
     }
 
-    #[allow(clippy::too_many_arguments)]
-    pub fn deposit(
-        &mut self,
-        amount: u64,
-        vault_equity: u64,
-        vault: &mut Vault,
-        vault_protocol: &mut Option<RefMut<VaultProtocol>>,
-        fee_update: &mut Option<AccountLoader<FeeUpdate>>,
-        now: i64,
-        user_stats: &UserStats,
-        fuel_overflow: &Option<AccountLoader<FuelOverflow>>,
-        deposit_oracle_price: i64,
-    ) -> Result<()> {
+    /// Checks a deposit of amount at vault_equity is allowed, before any state is changed.
+    pub fn validate_deposit(&self, amount: u64, vault_equity: u64, vault: &Vault) -> Result<()> {
         validate!(
             vault.max_tokens == 0 || vault.max_tokens >= vault_equity.safe_add(amount)?,
             ErrorCode::VaultIsAtCapacity,
@@ -274,6 +330,58 @@ impl VaultDepositor {
             "withdraw request is in progress"
         )?;
 
+        Ok(())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn deposit(
+        &mut self,
+        amount: u64,
+        vault_equity: u64,
+        vault: &mut Vault,
+        vault_protocol: &mut Option<RefMut<VaultProtocol>>,
+        fee_update: &mut Option<AccountLoader<FeeUpdate>>,
+        now: i64,
+        user_stats: &UserStats,
+        fuel_overflow: &Option<AccountLoader<FuelOverflow>>,
+        deposit_oracle_price: i64,
+    ) -> Result<()> {
+        let change = self.apply_deposit(
+            amount,
+            vault_equity,
+            vault,
+            vault_protocol,
+            fee_update,
+            now,
+            Some((user_stats, fuel_overflow)),
+        )?;
+
+        self.emit_vault_depositor_change_record(
+            VaultDepositorAction::Deposit,
+            &change,
+            vault_equity,
+            vault,
+            vault_protocol,
+            now,
+            deposit_oracle_price,
+        )
+    }
+
+    /// The state changes of [`Self::deposit`], shared with preview_deposit. Fuel is only accrued if `fuel` is
+    /// passed, previews run without it since it doesn't change shares or equity.
+    #[allow(clippy::too_many_arguments)]
+    pub fn apply_deposit(
+        &mut self,
+        amount: u64,
+        vault_equity: u64,
+        vault: &mut Vault,
+        vault_protocol: &mut Option<RefMut<VaultProtocol>>,
+        fee_update: &mut Option<AccountLoader<FeeUpdate>>,
+        now: i64,
+        fuel: Option<(&UserStats, &Option<AccountLoader<FuelOverflow>>)>,
+    ) -> Result<VaultDepositorChange> {
+        self.validate_deposit(amount, vault_equity, vault)?;
+
         self.apply_rebase(vault, vault_protocol, vault_equity)?;
 
         let vault_shares_before = self.checked_vault_shares(vault)?;
@@ -281,23 +389,13 @@ impl VaultDepositor {
         let user_vault_shares_before = vault.user_shares;
         let protocol_shares_before = vault.get_protocol_shares(vault_protocol);
 
-        let VaultFee {
-            management_fee_payment,
-            management_fee_shares,
-            protocol_fee_payment,
-            protocol_fee_shares,
-        } = vault.apply_fee(vault_protocol, fee_update, vault_equity, now)?;
-        let (manager_profit_share, protocol_profit_share) = self.apply_profit_share(
-            vault_equity,
-            vault,
-            vault_protocol,
-            now,
-            user_stats,
-            fuel_overflow,
-        )?;
+        let fee = vault.apply_fee(vault_protocol, fee_update, vault_equity, now)?;
+        let (manager_profit_share, protocol_profit_share) = self
+            .accrue_fuel_and_apply_profit_share(vault_equity, vault, vault_protocol, now, fuel)?;
 
         let n_shares = vault_amount_to_depositor_shares(amount, vault.total_shares, vault_equity)?;
 
+        self.increase_cost_basis(amount)?;
         self.total_deposits = self.total_deposits.saturating_add(amount);
         self.net_deposits = self.net_deposits.safe_add(amount.cast()?)?;
 
@@ -309,60 +407,23 @@ impl VaultDepositor {
         vault.total_shares = vault.total_shares.safe_add(n_shares)?;
         vault.user_shares = vault.user_shares.safe_add(n_shares)?;
 
-        let vault_shares_after = self.checked_vault_shares(vault)?;
-        let protocol_shares_after = vault.get_protocol_shares(vault_protocol);
-
-        match vault_protocol {
-            None => {
-                emit!(VaultDepositorRecord {
-                    ts: now,
-                    vault: vault.pubkey,
-                    depositor_authority: self.authority,
-                    action: VaultDepositorAction::Deposit,
-                    amount,
-                    spot_market_index: vault.spot_market_index,
-                    vault_equity_before: vault_equity,
-                    vault_shares_before,
-                    user_vault_shares_before,
-                    total_vault_shares_before,
-                    vault_shares_after,
-                    total_vault_shares_after: vault.total_shares,
-                    user_vault_shares_after: vault.user_shares,
-                    profit_share: manager_profit_share,
-                    management_fee: management_fee_payment,
-                    management_fee_shares,
-                    deposit_oracle_price,
-                });
-            }
-            Some(_) => {
-                emit!(VaultDepositorV1Record {
-                    ts: now,
-                    vault: vault.pubkey,
-                    depositor_authority: self.authority,
-                    action: VaultDepositorAction::Deposit,
-                    amount,
-                    spot_market_index: vault.spot_market_index,
-                    vault_equity_before: vault_equity,
-                    vault_shares_before,
-                    user_vault_shares_before,
-                    total_vault_shares_before,
-                    vault_shares_after,
-                    total_vault_shares_after: vault.total_shares,
-                    user_vault_shares_after: vault.user_shares,
-                    protocol_profit_share,
-                    protocol_fee: protocol_fee_payment,
-                    protocol_fee_shares,
-                    manager_profit_share,
-                    management_fee: management_fee_payment,
-                    management_fee_shares,
-                    protocol_shares_before,
-                    protocol_shares_after,
-                    deposit_oracle_price,
-                });
-            }
+        if self.is_fuel_locked(now) {
+            // deposits made during a lockup are boosted too
+            self.update_fuel_boost_shares(now, vault)?;
         }
 
-        Ok(())
+        Ok(VaultDepositorChange {
+            vault_shares_before,
+            user_vault_shares_before,
+            total_vault_shares_before,
+            protocol_shares_before,
+            shares: n_shares,
+            amount,
+            fee,
+            manager_profit_share,
+            protocol_profit_share,
+            realized_pnl: None,
+        })
     }
 
     #[allow(clippy::too_many_arguments)]
@@ -379,22 +440,54 @@ impl VaultDepositor {
         fuel_overflow: &Option<AccountLoader<FuelOverflow>>,
         deposit_oracle_price: i64,
     ) -> Result<()> {
-        let rebase_divisor = self.apply_rebase(vault, vault_protocol, vault_equity)?;
-        let VaultFee {
-            management_fee_payment,
-            management_fee_shares,
-            protocol_fee_payment,
-            protocol_fee_shares,
-        } = vault.apply_fee(vault_protocol, fee_update, vault_equity, now)?;
-        let (manager_profit_share, protocol_profit_share) = self.apply_profit_share(
+        let change = self.apply_withdraw_request(
+            withdraw_amount,
+            withdraw_unit,
             vault_equity,
             vault,
             vault_protocol,
+            fee_update,
             now,
-            user_stats,
-            fuel_overflow,
+            Some((user_stats, fuel_overflow)),
+        )?;
+
+        self.emit_vault_depositor_change_record(
+            VaultDepositorAction::WithdrawRequest,
+            &change,
+            vault_equity,
+            vault,
+            vault_protocol,
+            now,
+            deposit_oracle_price,
+        )
+    }
+
+    /// The state changes of [`Self::request_withdraw`], shared with preview_withdraw. See
+    /// [`Self::apply_deposit`] for `fuel`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn apply_withdraw_request(
+        &mut self,
+        withdraw_amount: u64,
+        withdraw_unit: WithdrawUnit,
+        vault_equity: u64,
+        vault: &mut Vault,
+        vault_protocol: &mut Option<RefMut<VaultProtocol>>,
+        fee_update: &mut Option<AccountLoader<FeeUpdate>>,
+        now: i64,
+        fuel: Option<(&UserStats, &Option<AccountLoader<FuelOverflow>>)>,
+    ) -> Result<VaultDepositorChange> {
+        validate!(
+            !self.is_fuel_locked(now),
+            ErrorCode::VaultDepositorLocked,
+            "Vault depositor is locked until {}",
+            self.fuel_lockup_end_ts
         )?;
 
+        let rebase_divisor = self.apply_rebase(vault, vault_protocol, vault_equity)?;
+        let fee = vault.apply_fee(vault_protocol, fee_update, vault_equity, now)?;
+        let (manager_profit_share, protocol_profit_share) = self
+            .accrue_fuel_and_apply_profit_share(vault_equity, vault, vault_protocol, now, fuel)?;
+
         let (withdraw_value, n_shares) = withdraw_unit.get_withdraw_value_and_shares(
             withdraw_amount,
             vault_equity,
@@ -422,61 +515,20 @@ impl VaultDepositor {
             now,
         )?;
         vault.total_withdraw_requested = vault.total_withdraw_requested.safe_add(withdraw_value)?;
+        vault.increase_withdraw_requested_shares(n_shares)?;
 
-        let vault_shares_after = self.checked_vault_shares(vault)?;
-        let protocol_shares_after = vault.get_protocol_shares(vault_protocol);
-
-        match vault_protocol {
-            None => {
-                emit!(VaultDepositorRecord {
-                    ts: now,
-                    vault: vault.pubkey,
-                    depositor_authority: self.authority,
-                    action: VaultDepositorAction::WithdrawRequest,
-                    amount: self.last_withdraw_request.value,
-                    spot_market_index: vault.spot_market_index,
-                    vault_equity_before: vault_equity,
-                    vault_shares_before,
-                    user_vault_shares_before,
-                    total_vault_shares_before,
-                    vault_shares_after,
-                    total_vault_shares_after: vault.total_shares,
-                    user_vault_shares_after: vault.user_shares,
-                    profit_share: manager_profit_share,
-                    management_fee: management_fee_payment,
-                    management_fee_shares,
-                    deposit_oracle_price,
-                });
-            }
-            Some(_) => {
-                emit!(VaultDepositorV1Record {
-                    ts: now,
-                    vault: vault.pubkey,
-                    depositor_authority: self.authority,
-                    action: VaultDepositorAction::WithdrawRequest,
-                    amount: self.last_withdraw_request.value,
-                    spot_market_index: vault.spot_market_index,
-                    vault_equity_before: vault_equity,
-                    vault_shares_before,
-                    user_vault_shares_before,
-                    total_vault_shares_before,
-                    vault_shares_after,
-                    total_vault_shares_after: vault.total_shares,
-                    user_vault_shares_after: vault.user_shares,
-                    protocol_profit_share,
-                    protocol_fee: protocol_fee_payment,
-                    protocol_fee_shares,
-                    manager_profit_share,
-                    management_fee: management_fee_payment,
-                    management_fee_shares,
-                    protocol_shares_before,
-                    protocol_shares_after,
-                    deposit_oracle_price,
-                });
-            }
-        }
-
-        Ok(())
+        Ok(VaultDepositorChange {
+            vault_shares_before,
+            user_vault_shares_before,
+            total_vault_shares_before,
+            protocol_shares_before,
+            shares: n_shares,
+            amount: self.last_withdraw_request.value,
+            fee,
+            manager_profit_share,
+            protocol_profit_share,
+            realized_pnl: None,
+        })
     }
 
     #[allow(clippy::too_many_arguments)]
@@ -524,59 +576,39 @@ impl VaultDepositor {
         let vault_shares_after = self.checked_vault_shares(vault)?;
         let protocol_shares_after = vault.get_protocol_shares(vault_protocol);
 
-        match vault_protocol {
-            None => {
-                emit!(VaultDepositorRecord {
-                    ts: now,
-                    vault: vault.pubkey,
-                    depositor_authority: self.authority,
-                    action: VaultDepositorAction::CancelWithdrawRequest,
-                    amount: 0,
-                    spot_market_index: vault.spot_market_index,
-                    vault_equity_before: vault_equity,
-                    vault_shares_before: vd_vault_shares_before,
-                    user_vault_shares_before,
-                    total_vault_shares_before,
-                    vault_shares_after,
-                    total_vault_shares_after: vault.total_shares,
-                    user_vault_shares_after: vault.user_shares,
-                    profit_share: 0,
-                    management_fee: management_fee_payment,
-                    management_fee_shares,
-                    deposit_oracle_price,
-                });
-            }
-            Some(_) => {
-                emit!(VaultDepositorV1Record {
-                    ts: now,
-                    vault: vault.pubkey,
-                    depositor_authority: self.authority,
-                    action: VaultDepositorAction::CancelWithdrawRequest,
-                    amount: 0,
-                    spot_market_index: vault.spot_market_index,
-                    vault_equity_before: vault_equity,
-                    vault_shares_before: vd_vault_shares_before,
-                    user_vault_shares_before,
-                    total_vault_shares_before,
-                    vault_shares_after,
-                    total_vault_shares_after: vault.total_shares,
-                    user_vault_shares_after: vault.user_shares,
+        vault.emit_vault_depositor_record(
+            VaultDepositorRecordParams {
+                ts: now,
+                action: VaultDepositorAction::CancelWithdrawRequest,
+                amount: 0,
+                depositor_authority: self.authority,
+                vault_equity_before: vault_equity,
+                vault_shares_before: vd_vault_shares_before,
+                user_vault_shares_before,
+                total_vault_shares_before,
+                vault_shares_after,
+                manager_profit_share: 0,
+                management_fee: management_fee_payment,
+                management_fee_shares,
+                deposit_oracle_price,
+                cost_basis_after: Some(self.checked_cost_basis()),
+                realized_pnl: None,
+            },
+            vault_protocol
+                .as_ref()
+                .map(|_| VaultDepositorRecordProtocolParams {
                     protocol_profit_share: 0,
                     protocol_fee: protocol_fee_payment,
                     protocol_fee_shares,
-                    manager_profit_share: 0,
-                    management_fee: management_fee_payment,
-                    management_fee_shares,
                     protocol_shares_before,
                     protocol_shares_after,
-                    deposit_oracle_price,
-                });
-            }
-        }
+                }),
+        )?;
 
         vault.total_withdraw_requested = vault
             .total_withdraw_requested
             .safe_sub(self.last_withdraw_request.value)?;
+        vault.decrease_withdraw_requested_shares(self.last_withdraw_request.shares)?;
 
         self.last_withdraw_request.reset(now)?;
 
@@ -598,9 +630,46 @@ impl VaultDepositor {
         self.last_withdraw_request
             .check_redeem_period_finished(vault, now)?;
 
+        let change = self.apply_withdraw(
+            vault_equity,
+            vault,
+            vault_protocol,
+            fee_update,
+            now,
+            Some((user_stats, fuel_overflow)),
+        )?;
+
+        self.emit_vault_depositor_change_record(
+            VaultDepositorAction::Withdraw,
+            &change,
+            vault_equity,
+            vault,
+            vault_protocol,
+            now,
+            deposit_oracle_price,
+        )?;
+
+        let finishing_liquidation = vault.liquidation_delegate == self.authority;
+
+        Ok((change.amount, finishing_liquidation))
+    }
+
+    /// The state changes of [`Self::withdraw`] after the redeem period check, shared with
+    /// preview_withdraw_payout. See [`Self::apply_deposit`] for `fuel`.
+    pub fn apply_withdraw(
+        &mut self,
+        vault_equity: u64,
+        vault: &mut Vault,
+        vault_protocol: &mut Option<RefMut<VaultProtocol>>,
+        fee_update: &mut Option<AccountLoader<FeeUpdate>>,
+        now: i64,
+        fuel: Option<(&UserStats, &Option<AccountLoader<FuelOverflow>>)>,
+    ) -> Result<VaultDepositorChange> {
         self.apply_rebase(vault, vault_protocol, vault_equity)?;
 
-        self.update_cumulative_fuel_amount(now, vault, user_stats, fuel_overflow)?;
+        if let Some((user_stats, fuel_overflow)) = fuel {
+            self.update_cumulative_fuel_amount(now, vault, user_stats, fuel_overflow)?;
+        }
 
         let vault_shares_before: u128 = self.checked_vault_shares(vault)?;
         let total_vault_shares_before = vault.total_shares;
@@ -620,12 +689,7 @@ impl VaultDepositor {
             ErrorCode::InsufficientVaultShares
         )?;
 
-        let VaultFee {
-            management_fee_payment,
-            management_fee_shares,
-            protocol_fee_payment,
-            protocol_fee_shares,
-        } = vault.apply_fee(vault_protocol, fee_update, vault_equity, now)?;
+        let fee = vault.apply_fee(vault_protocol, fee_update, vault_equity, now)?;
         msg!("after management_fee vault_shares={}", self.vault_shares);
 
         let amount: u64 =
@@ -643,6 +707,7 @@ impl VaultDepositor {
             self.last_withdraw_request.shares
         );
 
+        let realized_pnl = self.realize_pnl(n_shares, withdraw_amount, vault)?;
         self.decrease_vault_shares(n_shares, vault)?;
 
         self.total_withdraws = self.total_withdraws.saturating_add(withdraw_amount);
@@ -655,65 +720,66 @@ impl VaultDepositor {
         vault.total_withdraw_requested = vault
             .total_withdraw_requested
             .safe_sub(self.last_withdraw_request.value)?;
+        vault.decrease_withdraw_requested_shares(self.last_withdraw_request.shares)?;
 
         self.last_withdraw_request.reset(now)?;
 
+        Ok(VaultDepositorChange {
+            vault_shares_before,
+            user_vault_shares_before,
+            total_vault_shares_before,
+            protocol_shares_before,
+            shares: n_shares,
+            amount: withdraw_amount,
+            fee,
+            manager_profit_share: 0,
+            protocol_profit_share: 0,
+            realized_pnl: Some(realized_pnl),
+        })
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn emit_vault_depositor_change_record(
+        &self,
+        action: VaultDepositorAction,
+        change: &VaultDepositorChange,
+        vault_equity: u64,
+        vault: &Vault,
+        vault_protocol: &mut Option<RefMut<VaultProtocol>>,
+        now: i64,
+        deposit_oracle_price: i64,
+    ) -> Result<()> {
         let vault_shares_after = self.checked_vault_shares(vault)?;
         let protocol_shares_after = vault.get_protocol_shares(vault_protocol);
 
-        match vault_protocol {
-            None => {
-                emit!(VaultDepositorRecord {
-                    ts: now,
-                    vault: vault.pubkey,
-                    depositor_authority: self.authority,
-                    action: VaultDepositorAction::Withdraw,
-                    amount: withdraw_amount,
-                    spot_market_index: vault.spot_market_index,
-                    vault_equity_before: vault_equity,
-                    vault_shares_before,
-                    user_vault_shares_before,
-                    total_vault_shares_before,
-                    vault_shares_after,
-                    total_vault_shares_after: vault.total_shares,
-                    user_vault_shares_after: vault.user_shares,
-                    profit_share: 0,
-                    management_fee: management_fee_payment,
-                    management_fee_shares,
-                    deposit_oracle_price,
-                });
-            }
-            Some(_) => {
-                emit!(VaultDepositorV1Record {
-                    ts: now,
-                    vault: vault.pubkey,
-                    depositor_authority: self.authority,
-                    action: VaultDepositorAction::Withdraw,
-                    amount: withdraw_amount,
-                    spot_market_index: vault.spot_market_index,
-                    vault_equity_before: vault_equity,
-                    vault_shares_before,
-                    user_vault_shares_before,
-                    total_vault_shares_before,
-                    vault_shares_after,
-                    total_vault_shares_after: vault.total_shares,
-                    user_vault_shares_after: vault.user_shares,
-                    protocol_profit_share: 0,
-                    protocol_fee: protocol_fee_payment,
-                    protocol_fee_shares,
-                    manager_profit_share: 0,
-                    management_fee: management_fee_payment,
-                    management_fee_shares,
-                    protocol_shares_before,
+        vault.emit_vault_depositor_record(
+            VaultDepositorRecordParams {
+                ts: now,
+                action,
+                amount: change.amount,
+                depositor_authority: self.authority,
+                vault_equity_before: vault_equity,
+                vault_shares_before: change.vault_shares_before,
+                user_vault_shares_before: change.user_vault_shares_before,
+                total_vault_shares_before: change.total_vault_shares_before,
+                vault_shares_after,
+                manager_profit_share: change.manager_profit_share,
+                management_fee: change.fee.management_fee_payment,
+                management_fee_shares: change.fee.management_fee_shares,
+                deposit_oracle_price,
+                cost_basis_after: Some(self.checked_cost_basis()),
+                realized_pnl: change.realized_pnl,
+            },
+            vault_protocol
+                .as_ref()
+                .map(|_| VaultDepositorRecordProtocolParams {
+                    protocol_profit_share: change.protocol_profit_share,
+                    protocol_fee: change.fee.protocol_fee_payment,
+                    protocol_fee_shares: change.fee.protocol_fee_shares,
+                    protocol_shares_before: change.protocol_shares_before,
                     protocol_shares_after,
-                    deposit_oracle_price,
-                });
-            }
-        }
-
-        let finishing_liquidation = vault.liquidation_delegate == self.authority;
-
-        Ok((withdraw_amount, finishing_liquidation))
+                }),
+        )
     }
 
     #[allow(clippy::too_many_arguments)]
@@ -725,13 +791,33 @@ impl VaultDepositor {
         now: i64,
         user_stats: &UserStats,
         fuel_overflow: &Option<AccountLoader<FuelOverflow>>,
+    ) -> Result<(u64, u64)> {
+        self.accrue_fuel_and_apply_profit_share(
+            vault_equity,
+            vault,
+            vault_protocol,
+            now,
+            Some((user_stats, fuel_overflow)),
+        )
+    }
+
+    /// [`Self::apply_profit_share`] that only accrues fuel if `fuel` is passed
+    fn accrue_fuel_and_apply_profit_share(
+        &mut self,
+        vault_equity: u64,
+        vault: &mut Vault,
+        vault_protocol: &mut Option<RefMut<VaultProtocol>>,
+        now: i64,
+        fuel: Option<(&UserStats, &Option<AccountLoader<FuelOverflow>>)>,
     ) -> Result<(u64, u64)> {
         validate!(
             !self.last_withdraw_request.pending(),
             ErrorCode::InvalidVaultDeposit,
             "Cannot apply profit share to depositor with pending withdraw request"
         )?;
-        self.update_cumulative_fuel_amount(now, vault, user_stats, fuel_overflow)?;
+        if let Some((user_stats, fuel_overflow)) = fuel {
+            self.update_cumulative_fuel_amount(now, vault, user_stats, fuel_overflow)?;
+        }
         VaultDepositorBase::apply_profit_share(self, vault_equity, vault, vault_protocol)
     }
 
@@ -770,55 +856,34 @@ impl VaultDepositor {
         let profit_share = manager_profit_share.saturating_add(protocol_profit_share);
         let protocol_shares_after = vault.get_protocol_shares(vault_protocol);
 
-        match vault_protocol {
-            None => {
-                emit!(VaultDepositorRecord {
-                    ts: now,
-                    vault: vault.pubkey,
-                    depositor_authority: self.authority,
-                    action: VaultDepositorAction::FeePayment,
-                    amount: 0,
-                    spot_market_index: vault.spot_market_index,
-                    vault_equity_before: vault_equity,
-                    vault_shares_before,
-                    user_vault_shares_before,
-                    total_vault_shares_before,
-                    vault_shares_after: self.vault_shares,
-                    total_vault_shares_after: vault.total_shares,
-                    user_vault_shares_after: vault.user_shares,
-                    profit_share: manager_profit_share,
-                    management_fee: management_fee_payment,
-                    management_fee_shares,
-                    deposit_oracle_price,
-                });
-            }
-            Some(_) => {
-                emit!(VaultDepositorV1Record {
-                    ts: now,
-                    vault: vault.pubkey,
-                    depositor_authority: self.authority,
-                    action: VaultDepositorAction::FeePayment,
-                    amount: 0,
-                    spot_market_index: vault.spot_market_index,
-                    vault_equity_before: vault_equity,
-                    vault_shares_before,
-                    user_vault_shares_before,
-                    total_vault_shares_before,
-                    vault_shares_after: self.vault_shares,
-                    total_vault_shares_after: vault.total_shares,
-                    user_vault_shares_after: vault.user_shares,
+        vault.emit_vault_depositor_record(
+            VaultDepositorRecordParams {
+                ts: now,
+                action: VaultDepositorAction::FeePayment,
+                amount: 0,
+                depositor_authority: self.authority,
+                vault_equity_before: vault_equity,
+                vault_shares_before,
+                user_vault_shares_before,
+                total_vault_shares_before,
+                vault_shares_after: self.vault_shares,
+                manager_profit_share,
+                management_fee: management_fee_payment,
+                management_fee_shares,
+                deposit_oracle_price,
+                cost_basis_after: Some(self.checked_cost_basis()),
+                realized_pnl: None,
+            },
+            vault_protocol
+                .as_ref()
+                .map(|_| VaultDepositorRecordProtocolParams {
                     protocol_profit_share,
                     protocol_fee: protocol_fee_payment,
                     protocol_fee_shares,
-                    manager_profit_share,
-                    management_fee: management_fee_payment,
-                    management_fee_shares,
                     protocol_shares_before,
                     protocol_shares_after,
-                    deposit_oracle_price,
-                });
-            }
-        }
+                }),
+        )?;
 
         Ok(profit_share)
     }
@@ -922,11 +987,13 @@ impl VaultDepositor {
                     msg!("self.cumulative_fuel_amount > total_fuel. Resetting the vd.");
                     self.reset_fuel_amount(now);
                 } else {
-                    let vd_shares = self.checked_vault_shares(vault)?;
                     let fuel_per_share_delta = cumulative_fuel_per_share
                         .safe_sub(self.cumulative_fuel_per_share_amount)?;
                     let new_fuel = fuel_per_share_delta
-                        .safe_mul(vd_shares)?
+                        .safe_mul(self.get_fuel_eligible_shares(vault)?)?
+                        .safe_add(
+                            self.calculate_boosted_fuel_per_share(fuel_per_share_delta, now)?,
+                        )?
                         .safe_div(FUEL_SHARE_PRECISION)?;
 
                     self.fuel_amount = self.fuel_amount.safe_add(new_fuel)?;
@@ -937,50 +1004,217 @@ impl VaultDepositor {
             self.last_fuel_update_ts = now as u32;
         }
 
+        if self.fuel_boost_shares != 0 || self.is_fuel_locked(now) {
+            self.update_fuel_boost_shares(now, vault)?;
+        }
+
         Ok(self.fuel_amount)
     }
 
-    pub fn reset_fuel_amount(&mut self, now: i64) {
-        emit!(FuelSeasonRecord {
+    /// Updates the depositor's fuel and emits a [`VaultDepositorFuelRecord`] so fuel can be tracked
+    /// without waiting for the season reset
+    pub fn settle_fuel(
+        &mut self,
+        now: i64,
+        vault: &mut Vault,
+        user_stats: &UserStats,
+        fuel_overflow: &Option<AccountLoader<FuelOverflow>>,
+    ) -> Result<u128> {
+        let fuel_amount_before = self.fuel_amount;
+        let fuel_amount_after =
+            self.update_cumulative_fuel_amount(now, vault, user_stats, fuel_overflow)?;
+
+        emit!(VaultDepositorFuelRecord {
             ts: now,
-            authority: self.authority,
-            fuel_insurance: 0,
-            fuel_deposits: 0,
-            fuel_borrows: 0,
-            fuel_positions: 0,
-            fuel_taker: 0,
-            fuel_maker: 0,
-            fuel_total: self.fuel_amount,
+            vault: vault.pubkey,
+            vault_depositor: self.pubkey,
+            depositor_authority: self.authority,
+            fuel_amount_before,
+            fuel_amount_after,
+            fuel_shares: self
+                .get_fuel_eligible_shares(vault)?
+                .safe_add(self.fuel_boost_shares.cast()?)?,
+            cumulative_fuel_per_share: self.cumulative_fuel_per_share_amount,
         });
-        self.fuel_amount = 0;
-        self.cumulative_fuel_per_share_amount = 0;
-        self.last_fuel_update_ts = now as u32;
+
+        Ok(fuel_amount_after)
     }
-}
 
-#[cfg(test)]
-mod vault_v1_tests {
-    use std::cell::RefCell;
+    pub fn is_fuel_locked(&self, now: i64) -> bool {
+        now < self.fuel_lockup_end_ts
+    }
 
-    use anchor_lang::prelude::Pubkey;
-    use drift::math::casting::Cast;
-    use drift::math::constants::{PERCENTAGE_PRECISION_U64, QUOTE_PRECISION_U64};
-    use drift::math::insurance::if_shares_to_vault_amount;
-    use drift::state::user::UserStats;
+    /// The fuel_boost_shares' part of a fuel_per_share_delta accrued since last_fuel_update_ts, scaled by
+    /// FUEL_SHARE_PRECISION. If the lockup ended since then, the boost only applies to the part of the delta
+    /// before fuel_lockup_end_ts, pro rata by time.
+    fn calculate_boosted_fuel_per_share(
+        &self,
+        fuel_per_share_delta: u128,
+        now: i64,
+    ) -> Result<u128> {
+        let boosted_fuel = fuel_per_share_delta.safe_mul(self.fuel_boost_shares.cast()?)?;
+        let last_fuel_update_ts = self.last_fuel_update_ts.cast::<i64>()?;
+        if self.is_fuel_locked(now) || boosted_fuel == 0 {
+            return Ok(boosted_fuel);
+        }
 
-    use crate::state::FuelDistributionMode;
-    use crate::{assert_eq_within, Vault, VaultDepositor, VaultProtocol, WithdrawUnit};
+        if self.fuel_lockup_end_ts <= last_fuel_update_ts {
+            return Ok(0);
+        }
 
-    #[test]
-    fn base_init() {
-        let now = 1337;
-        let vd = VaultDepositor::new(Pubkey::default(), Pubkey::default(), Pubkey::default(), now);
-        assert_eq!(vd.vault_shares_base, 0);
-        assert_eq!(vd.last_valid_ts, now);
+        Ok(boosted_fuel
+            .safe_mul(
+                self.fuel_lockup_end_ts
+                    .safe_sub(last_fuel_update_ts)?
+                    .cast()?,
+            )?
+            .safe_div(now.safe_sub(last_fuel_update_ts)?.cast()?)?)
     }
 
-    #[test]
-    fn test_deposit_withdraw() {
+    fn calculate_fuel_boost_shares(&self, vault: &Vault) -> Result<u64> {
+        Ok(self
+            .get_fuel_eligible_shares(vault)?
+            .safe_mul(
+                self.fuel_lockup_multiplier
+                    .saturating_sub(FUEL_LOCKUP_MULTIPLIER_PRECISION)
+                    .cast()?,
+            )?
+            .safe_div(FUEL_LOCKUP_MULTIPLIER_PRECISION.cast()?)?
+            .cast()?)
+    }
+
+    /// Recomputes the extra shares from the fuel lockup, they are dropped once the lockup has ended
+    fn update_fuel_boost_shares(&mut self, now: i64, vault: &mut Vault) -> Result<()> {
+        let fuel_boost_shares = if self.is_fuel_locked(now) {
+            self.calculate_fuel_boost_shares(vault)?
+        } else {
+            0
+        };
+
+        vault.update_fuel_boost_shares(self.fuel_boost_shares, fuel_boost_shares)?;
+        self.fuel_boost_shares = fuel_boost_shares;
+
+        Ok(())
+    }
+
+    /// Settles the depositor's fuel and drops its fuel_boost_shares once the lockup has ended, so the boost
+    /// stops diluting other depositors' fuel before the depositor's next update
+    pub fn remove_expired_fuel_boost(
+        &mut self,
+        now: i64,
+        vault: &mut Vault,
+        user_stats: &UserStats,
+        fuel_overflow: &Option<AccountLoader<FuelOverflow>>,
+    ) -> Result<u128> {
+        validate!(
+            self.fuel_boost_shares != 0 && !self.is_fuel_locked(now),
+            ErrorCode::FuelBoostNotExpired,
+            "Vault depositor has no expired fuel boost (fuel_lockup_end_ts: {})",
+            self.fuel_lockup_end_ts
+        )?;
+
+        self.settle_fuel(now, vault, user_stats, fuel_overflow)
+    }
+
+    /// Locks the depositor for `lockup_period` in exchange for a fuel multiplier. An active lockup
+    /// can only be extended, never shortened or downgraded.
+    pub fn fuel_lockup(
+        &mut self,
+        lockup_period: FuelLockupPeriod,
+        now: i64,
+        vault: &mut Vault,
+        user_stats: &UserStats,
+        fuel_overflow: &Option<AccountLoader<FuelOverflow>>,
+    ) -> Result<()> {
+        validate!(
+            !self.last_withdraw_request.pending(),
+            ErrorCode::WithdrawInProgress,
+            "Cannot lock with a pending withdraw request"
+        )?;
+
+        // accrue fuel under the current lockup first
+        self.update_cumulative_fuel_amount(now, vault, user_stats, fuel_overflow)?;
+
+        let lockup_end_ts = now.safe_add(lockup_period.duration())?;
+        let fuel_lockup_multiplier = lockup_period.fuel_multiplier();
+        if self.is_fuel_locked(now) {
+            validate!(
+                lockup_end_ts >= self.fuel_lockup_end_ts
+                    && fuel_lockup_multiplier >= self.fuel_lockup_multiplier,
+                ErrorCode::VaultDepositorLocked,
+                "An active lockup can only be extended (lockup_end_ts: {} < {})",
+                lockup_end_ts,
+                self.fuel_lockup_end_ts
+            )?;
+        }
+
+        msg!(
+            "Locking vault depositor until {} with fuel multiplier {}",
+            lockup_end_ts,
+            fuel_lockup_multiplier
+        );
+
+        self.fuel_lockup_end_ts = lockup_end_ts;
+        self.fuel_lockup_multiplier = fuel_lockup_multiplier;
+
+        self.update_fuel_boost_shares(now, vault)
+    }
+
+    /// The shares that accrue fuel, excluding shares in a pending withdraw request if the vault has
+    /// exclude_pending_withdraws_from_fuel set
+    pub fn get_fuel_eligible_shares(&self, vault: &Vault) -> Result<u128> {
+        let vd_shares = self.checked_vault_shares(vault)?;
+        if vault.exclude_pending_withdraws_from_fuel && self.last_withdraw_request.pending() {
+            Ok(vd_shares.saturating_sub(self.last_withdraw_request.shares))
+        } else {
+            Ok(vd_shares)
+        }
+    }
+
+    pub fn reset_fuel_amount(&mut self, now: i64) {
+        emit!(FuelSeasonRecord {
+            ts: now,
+            authority: self.authority,
+            fuel_insurance: 0,
+            fuel_deposits: 0,
+            fuel_borrows: 0,
+            fuel_positions: 0,
+            fuel_taker: 0,
+            fuel_maker: 0,
+            fuel_total: self.fuel_amount,
+        });
+        self.fuel_amount = 0;
+        self.cumulative_fuel_per_share_amount = 0;
+        self.last_fuel_update_ts = now as u32;
+    }
+}
+
+#[cfg(test)]
+mod vault_v1_tests {
+    use std::cell::RefCell;
+
+    use anchor_lang::prelude::Pubkey;
+    use drift::math::casting::Cast;
+    use drift::math::constants::{PERCENTAGE_PRECISION_U64, QUOTE_PRECISION_U64};
+    use drift::math::insurance::if_shares_to_vault_amount;
+    use drift::state::user::UserStats;
+
+    use crate::constants::{NAV_PER_SHARE_PRECISION, ONE_DAY};
+    use crate::state::{
+        FuelDistribution, FuelDistributionMode, FuelLockupPeriod, VaultDepositorBase,
+    };
+    use crate::{assert_eq_within, Vault, VaultDepositor, VaultProtocol, WithdrawUnit};
+
+    #[test]
+    fn base_init() {
+        let now = 1337;
+        let vd = VaultDepositor::new(Pubkey::default(), Pubkey::default(), Pubkey::default(), now);
+        assert_eq!(vd.vault_shares_base, 0);
+        assert_eq!(vd.last_valid_ts, now);
+    }
+
+    #[test]
+    fn test_deposit_withdraw() {
         let now = 1000;
         let mut vault = Vault::default();
         let vp = RefCell::new(VaultProtocol::default());
@@ -1593,6 +1827,116 @@ mod vault_v1_tests {
         );
     }
 
+    #[test]
+    fn test_cost_basis_and_realized_pnl() {
+        let now = 1000;
+        let mut vault = Vault::default();
+
+        let vd1 =
+            &mut VaultDepositor::new(Pubkey::default(), Pubkey::default(), Pubkey::default(), now);
+        let vd2 = &mut VaultDepositor::new(
+            Pubkey::default(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            now,
+        );
+
+        vd1.deposit(
+            100 * QUOTE_PRECISION_U64,
+            0,
+            &mut vault,
+            &mut None,
+            &mut None,
+            now,
+            &UserStats::default(),
+            &None,
+            0,
+        )
+        .unwrap();
+        assert_eq!(vd1.cost_basis, 100 * QUOTE_PRECISION_U64);
+        assert_eq!(
+            vd1.get_average_entry_price(&vault).unwrap(),
+            NAV_PER_SHARE_PRECISION as u64
+        );
+
+        // the vault doubles, a quarter of vd1's shares are sold to vd2 at their value
+        let vault_equity = 200 * QUOTE_PRECISION_U64;
+        vd1.transfer_shares(
+            vd2,
+            &mut vault,
+            &mut None,
+            &mut None,
+            50 * QUOTE_PRECISION_U64,
+            WithdrawUnit::Token,
+            vault_equity,
+            now,
+            0,
+        )
+        .unwrap();
+        assert_eq!(vd1.cost_basis, 75 * QUOTE_PRECISION_U64);
+        assert_eq!(vd2.cost_basis, 50 * QUOTE_PRECISION_U64);
+        assert_eq!(
+            vd2.get_average_entry_price(&vault).unwrap(),
+            2 * NAV_PER_SHARE_PRECISION as u64
+        );
+
+        // half of vd1's remaining shares are withdrawn
+        vd1.request_withdraw(
+            75 * QUOTE_PRECISION_U64,
+            WithdrawUnit::Token,
+            vault_equity,
+            &mut vault,
+            &mut None,
+            &mut None,
+            now,
+            &UserStats::default(),
+            &None,
+            0,
+        )
+        .unwrap();
+        // the pnl the withdraw realizes, on a copy
+        let mut vd1_copy = *vd1;
+        let realized_pnl = vd1_copy
+            .realize_pnl(
+                vd1.last_withdraw_request.shares,
+                75 * QUOTE_PRECISION_U64,
+                &vault,
+            )
+            .unwrap();
+        assert_eq!(realized_pnl, 37_500_000);
+
+        let (withdraw_amount, _) = vd1
+            .withdraw(
+                vault_equity,
+                &mut vault,
+                &mut None,
+                &mut None,
+                now,
+                &UserStats::default(),
+                &None,
+                0,
+            )
+            .unwrap();
+        assert_eq!(withdraw_amount, 75 * QUOTE_PRECISION_U64);
+        assert_eq!(vd1.cost_basis, 37_500_000);
+        assert_eq!(
+            vd1.get_average_entry_price(&vault).unwrap(),
+            NAV_PER_SHARE_PRECISION as u64
+        );
+
+        // a tracked cost basis of 0 is kept while the depositor still holds shares
+        vd1.cost_basis = 0;
+        vd1.net_deposits = 60 * QUOTE_PRECISION_U64 as i64;
+        assert_eq!(vd1.checked_cost_basis(), 0);
+
+        // depositors from before cost basis tracking start from their net deposits until it is updated
+        vd1.cost_basis_migrated = false;
+        assert_eq!(vd1.checked_cost_basis(), 60 * QUOTE_PRECISION_U64);
+        vd1.increase_cost_basis(10 * QUOTE_PRECISION_U64).unwrap();
+        assert!(vd1.cost_basis_migrated);
+        assert_eq!(vd1.checked_cost_basis(), 70 * QUOTE_PRECISION_U64);
+    }
+
     #[test]
     fn test_vault_depositor_request_in_loss_withdraw_in_profit() {
         // test for vault depositor who requests withdraw when in loss
@@ -2032,6 +2376,361 @@ mod vault_v1_tests {
         assert_eq!(vault.cumulative_fuel, 90_000);
     }
 
+    #[test]
+    fn test_vault_depositor_shares_fuel_weighted() {
+        let now = 1000;
+        let mut vault = Vault {
+            total_shares: 1_000_000,
+            ..Vault::default()
+        };
+        let mut fuel_distribution = FuelDistribution::default();
+
+        // not weighted yet, nothing is settled to the manager or protocol
+        vault.cumulative_fuel = 10_000;
+        fuel_distribution.settle(&vault, now).unwrap();
+        assert_eq!(fuel_distribution.manager_fuel, 0);
+        assert_eq!(fuel_distribution.protocol_fuel, 0);
+        assert_eq!(fuel_distribution.last_cumulative_fuel, 10_000);
+        vault.cumulative_fuel = 0;
+        fuel_distribution.last_cumulative_fuel = 0;
+
+        // weights only apply after the timelock
+        fuel_distribution.incoming_update_ts = now + 1;
+        fuel_distribution.incoming_manager_fuel_bps = 2_000;
+        fuel_distribution.incoming_protocol_fuel_bps = 1_000;
+        fuel_distribution.update(&mut vault, now).unwrap();
+        assert_eq!(vault.manager_fuel_bps, 0);
+        assert!(fuel_distribution.is_pending());
+        fuel_distribution.update(&mut vault, now + 1).unwrap();
+        assert_eq!(vault.manager_fuel_bps, 2_000);
+        assert_eq!(vault.protocol_fuel_bps, 1_000);
+        assert_eq!(vault.get_users_fuel_bps().unwrap(), 7_000);
+        assert_eq!(
+            vault.fuel_distribution_mode,
+            FuelDistributionMode::Weighted as u8
+        );
+        assert!(!fuel_distribution.is_pending());
+
+        // can't leave weighted mode without the timelock
+        assert!(vault
+            .update_fuel_distribution_mode(FuelDistributionMode::UsersOnly as u8)
+            .is_err());
+
+        let vd_0 =
+            &mut VaultDepositor::new(Pubkey::default(), Pubkey::default(), Pubkey::default(), now);
+        vd_0.vault_shares = 200_000;
+        vd_0.last_fuel_update_ts = now as u32 - 1;
+        let vd_1 =
+            &mut VaultDepositor::new(Pubkey::default(), Pubkey::default(), Pubkey::default(), now);
+        vd_1.vault_shares = 300_000;
+        vault.user_shares = 500_000;
+        vd_1.last_fuel_update_ts = now as u32 - 1;
+
+        let mut vault_user_stats = UserStats {
+            fuel_insurance: 10_000,
+            fuel_deposits: 10_000,
+            fuel_borrows: 10_000,
+            fuel_positions: 10_000,
+            fuel_taker: 10_000,
+            fuel_maker: 10_000, // total = 60k
+            ..UserStats::default()
+        };
+
+        // 1) first crank, users split 70% of 60k
+        let now = 1000;
+        let vd_0_fuel_amount = vd_0
+            .update_cumulative_fuel_amount(now, &mut vault, &vault_user_stats, &None)
+            .unwrap();
+        assert_eq!(vd_0_fuel_amount, 16_800);
+
+        let vd_1_fuel_amount = vd_1
+            .update_cumulative_fuel_amount(now, &mut vault, &vault_user_stats, &None)
+            .unwrap();
+        assert_eq!(vd_1_fuel_amount, 25_200);
+
+        fuel_distribution.settle(&vault, now).unwrap();
+        assert_eq!(fuel_distribution.manager_fuel, 12_000);
+        assert_eq!(fuel_distribution.protocol_fuel, 6_000);
+        assert_eq!(fuel_distribution.last_cumulative_fuel, 60_000);
+
+        // 2) time advances, 10k more fuel
+        let now = 2000;
+        vault_user_stats.fuel_maker += 10_000; // total = 70k
+        let vd_0_fuel_amount = vd_0
+            .update_cumulative_fuel_amount(now, &mut vault, &vault_user_stats, &None)
+            .unwrap();
+        assert_eq!(vd_0_fuel_amount, 19_600);
+
+        let vd_1_fuel_amount = vd_1
+            .update_cumulative_fuel_amount(now, &mut vault, &vault_user_stats, &None)
+            .unwrap();
+        assert_eq!(vd_1_fuel_amount, 29_400);
+
+        fuel_distribution.settle(&vault, now).unwrap();
+        assert_eq!(fuel_distribution.manager_fuel, 14_000);
+        assert_eq!(fuel_distribution.protocol_fuel, 7_000);
+
+        // 3) 10k more fuel is settled when the vault's fuel is reset for a new season
+        let now = 3000;
+        vault_user_stats.fuel_maker += 10_000; // total = 80k
+        vd_0.update_cumulative_fuel_amount(now, &mut vault, &vault_user_stats, &None)
+            .unwrap();
+        fuel_distribution.settle(&vault, now).unwrap();
+        assert_eq!(fuel_distribution.manager_fuel, 16_000);
+        assert_eq!(fuel_distribution.protocol_fuel, 8_000);
+
+        fuel_distribution.reset_fuel_amount(&vault, now).unwrap();
+        vault.reset_cumulative_fuel_per_share(now);
+        assert_eq!(fuel_distribution.manager_fuel, 0);
+        assert_eq!(fuel_distribution.protocol_fuel, 0);
+        assert_eq!(fuel_distribution.last_cumulative_fuel, 0);
+
+        // 4) new weights are only applied after the fuel under the old ones is settled
+        let now = 4000;
+        vault_user_stats = UserStats {
+            fuel_deposits: 10_000,
+            ..UserStats::default()
+        };
+        vd_0.update_cumulative_fuel_amount(now, &mut vault, &vault_user_stats, &None)
+            .unwrap();
+        fuel_distribution.incoming_update_ts = now;
+        fuel_distribution.incoming_manager_fuel_bps = 5_000;
+        fuel_distribution.incoming_protocol_fuel_bps = 0;
+        fuel_distribution.update(&mut vault, now).unwrap();
+        assert_eq!(fuel_distribution.manager_fuel, 2_000);
+        assert_eq!(fuel_distribution.protocol_fuel, 1_000);
+        assert_eq!(vault.manager_fuel_bps, 5_000);
+        assert_eq!(vault.get_users_fuel_bps().unwrap(), 5_000);
+    }
+
+    #[test]
+    fn test_vault_depositor_shares_fuel_exclude_pending_withdraws() {
+        let now = 1000;
+        let mut vault = Vault {
+            total_shares: 1_000_000,
+            exclude_pending_withdraws_from_fuel: true,
+            ..Vault::default()
+        };
+
+        let vd_0 =
+            &mut VaultDepositor::new(Pubkey::default(), Pubkey::default(), Pubkey::default(), now);
+        vd_0.vault_shares = 200_000;
+        vd_0.last_fuel_update_ts = now as u32 - 1;
+        let vd_1 =
+            &mut VaultDepositor::new(Pubkey::default(), Pubkey::default(), Pubkey::default(), now);
+        vd_1.vault_shares = 300_000;
+        vault.user_shares = 500_000;
+        vd_1.last_fuel_update_ts = now as u32 - 1;
+
+        let mut vault_user_stats = UserStats {
+            fuel_insurance: 10_000,
+            fuel_deposits: 10_000,
+            fuel_borrows: 10_000,
+            fuel_positions: 10_000,
+            fuel_taker: 10_000,
+            fuel_maker: 10_000, // total = 60k
+            ..UserStats::default()
+        };
+
+        // 1) first crank, no pending withdraws
+        let now = 1000;
+        let vd_0_fuel_amount = vd_0
+            .update_cumulative_fuel_amount(now, &mut vault, &vault_user_stats, &None)
+            .unwrap();
+        assert_eq!(vd_0_fuel_amount, 24_000);
+        let vd_1_fuel_amount = vd_1
+            .update_cumulative_fuel_amount(now, &mut vault, &vault_user_stats, &None)
+            .unwrap();
+        assert_eq!(vd_1_fuel_amount, 36_000);
+
+        // 2) vd_1 requests to withdraw 100k shares
+        vd_1.last_withdraw_request.shares = 100_000;
+        vd_1.last_withdraw_request.value = 100_000;
+        vault.increase_withdraw_requested_shares(100_000).unwrap();
+        assert_eq!(vault.get_fuel_eligible_user_shares().unwrap(), 400_000);
+
+        // 3) time advances, 40k more fuel goes to the 400k active shares
+        let now = 2000;
+        vault_user_stats.fuel_maker += 40_000; // total = 100k
+        let vd_0_fuel_amount = vd_0
+            .update_cumulative_fuel_amount(now, &mut vault, &vault_user_stats, &None)
+            .unwrap();
+        assert_eq!(vd_0_fuel_amount, 44_000);
+        let vd_1_fuel_amount = vd_1
+            .update_cumulative_fuel_amount(now, &mut vault, &vault_user_stats, &None)
+            .unwrap();
+        assert_eq!(vd_1_fuel_amount, 56_000);
+
+        // 4) request is cleared, all shares accrue again
+        vault
+            .decrease_withdraw_requested_shares(vd_1.last_withdraw_request.shares)
+            .unwrap();
+        vd_1.last_withdraw_request.reset(now).unwrap();
+        assert_eq!(vault.fuel_shares_adjustment, 0);
+        assert_eq!(vault.get_fuel_eligible_user_shares().unwrap(), 500_000);
+
+        let now = 3000;
+        vault_user_stats.fuel_maker += 50_000; // total = 150k
+        let vd_0_fuel_amount = vd_0
+            .update_cumulative_fuel_amount(now, &mut vault, &vault_user_stats, &None)
+            .unwrap();
+        assert_eq!(vd_0_fuel_amount, 64_000);
+        let vd_1_fuel_amount = vd_1
+            .update_cumulative_fuel_amount(now, &mut vault, &vault_user_stats, &None)
+            .unwrap();
+        assert_eq!(vd_1_fuel_amount, 86_000);
+    }
+
+    #[test]
+    fn test_vault_depositor_fuel_lockup() {
+        let now = 1000;
+        let mut vault = Vault {
+            total_shares: 1_000_000,
+            ..Vault::default()
+        };
+
+        let vd_0 =
+            &mut VaultDepositor::new(Pubkey::default(), Pubkey::default(), Pubkey::default(), now);
+        vd_0.vault_shares = 200_000;
+        vd_0.last_fuel_update_ts = now as u32 - 1;
+        let vd_1 =
+            &mut VaultDepositor::new(Pubkey::default(), Pubkey::default(), Pubkey::default(), now);
+        vd_1.vault_shares = 300_000;
+        vault.user_shares = 500_000;
+        vd_1.last_fuel_update_ts = now as u32 - 1;
+
+        let mut vault_user_stats = UserStats {
+            fuel_insurance: 10_000,
+            fuel_deposits: 10_000,
+            fuel_borrows: 10_000,
+            fuel_positions: 10_000,
+            fuel_taker: 10_000,
+            fuel_maker: 10_000, // total = 60k
+            ..UserStats::default()
+        };
+
+        // 1) first crank, then vd_0 locks for 180 days (1.5x)
+        let vd_0_fuel_amount = vd_0
+            .update_cumulative_fuel_amount(now, &mut vault, &vault_user_stats, &None)
+            .unwrap();
+        assert_eq!(vd_0_fuel_amount, 24_000);
+        let vd_1_fuel_amount = vd_1
+            .update_cumulative_fuel_amount(now, &mut vault, &vault_user_stats, &None)
+            .unwrap();
+        assert_eq!(vd_1_fuel_amount, 36_000);
+
+        vd_0.fuel_lockup(
+            FuelLockupPeriod::OneHundredEightyDays,
+            now,
+            &mut vault,
+            &vault_user_stats,
+            &None,
+        )
+        .unwrap();
+        let lockup_end_ts = now + 180 * ONE_DAY;
+        assert_eq!(vd_0.fuel_lockup_end_ts, lockup_end_ts);
+        assert_eq!(vd_0.fuel_boost_shares, 100_000);
+        assert_eq!(vault.fuel_shares_adjustment, 100_000);
+        assert_eq!(vault.get_fuel_eligible_user_shares().unwrap(), 600_000);
+        assert!(vd_0.is_fuel_locked(now + 1));
+
+        // an active lockup can't be downgraded
+        assert!(vd_0
+            .fuel_lockup(
+                FuelLockupPeriod::ThirtyDays,
+                now + 1,
+                &mut vault,
+                &vault_user_stats,
+                &None,
+            )
+            .is_err());
+
+        // 2) 60k more fuel is split over 600k boosted shares
+        let now = 2000;
+        vault_user_stats.fuel_maker += 60_000; // total = 120k
+        let vd_0_fuel_amount = vd_0
+            .update_cumulative_fuel_amount(now, &mut vault, &vault_user_stats, &None)
+            .unwrap();
+        assert_eq!(vd_0_fuel_amount, 54_000);
+        let vd_1_fuel_amount = vd_1
+            .update_cumulative_fuel_amount(now, &mut vault, &vault_user_stats, &None)
+            .unwrap();
+        assert_eq!(vd_1_fuel_amount, 66_000);
+
+        // the boost can't be removed before the lockup ends
+        assert!(vd_0
+            .remove_expired_fuel_boost(now, &mut vault, &vault_user_stats, &None)
+            .is_err());
+
+        // 3) lockup expires halfway to the next update, the boost only accrues until then
+        let now = lockup_end_ts + (lockup_end_ts - 2000);
+        assert!(!vd_0.is_fuel_locked(now));
+        vault_user_stats.fuel_maker += 60_000; // total = 180k
+        let vd_0_fuel_amount = vd_0
+            .remove_expired_fuel_boost(now, &mut vault, &vault_user_stats, &None)
+            .unwrap();
+        assert_eq!(vd_0_fuel_amount, 54_000 + 20_000 + 5_000);
+        assert_eq!(vd_0.fuel_boost_shares, 0);
+        assert_eq!(vault.fuel_shares_adjustment, 0);
+        let vd_1_fuel_amount = vd_1
+            .update_cumulative_fuel_amount(now, &mut vault, &vault_user_stats, &None)
+            .unwrap();
+        assert_eq!(vd_1_fuel_amount, 96_000);
+
+        // nothing left to remove
+        assert!(vd_0
+            .remove_expired_fuel_boost(now + 1, &mut vault, &vault_user_stats, &None)
+            .is_err());
+
+        // 4) back to a plain pro-rata split
+        let now = now + 1000;
+        vault_user_stats.fuel_maker += 50_000; // total = 230k
+        let vd_0_fuel_amount = vd_0
+            .update_cumulative_fuel_amount(now, &mut vault, &vault_user_stats, &None)
+            .unwrap();
+        assert_eq!(vd_0_fuel_amount, 99_000);
+        let vd_1_fuel_amount = vd_1
+            .update_cumulative_fuel_amount(now, &mut vault, &vault_user_stats, &None)
+            .unwrap();
+        assert_eq!(vd_1_fuel_amount, 126_000);
+    }
+
+    #[test]
+    fn test_vault_depositor_settle_fuel() {
+        let now = 1000;
+        let mut vault = Vault {
+            total_shares: 1_000_000,
+            user_shares: 500_000,
+            ..Vault::default()
+        };
+
+        let vd =
+            &mut VaultDepositor::new(Pubkey::default(), Pubkey::default(), Pubkey::default(), now);
+        vd.vault_shares = 200_000;
+        vd.last_fuel_update_ts = now as u32 - 1;
+
+        let mut vault_user_stats = UserStats {
+            fuel_deposits: 60_000,
+            ..UserStats::default()
+        };
+
+        let fuel_amount = vd
+            .settle_fuel(now, &mut vault, &vault_user_stats, &None)
+            .unwrap();
+        assert_eq!(fuel_amount, 24_000);
+
+        vault_user_stats.fuel_deposits += 10_000;
+        let fuel_amount = vd
+            .settle_fuel(now + 1, &mut vault, &vault_user_stats, &None)
+            .unwrap();
+        assert_eq!(fuel_amount, 28_000);
+        assert_eq!(vd.last_fuel_update_ts, (now + 1) as u32);
+        assert_eq!(
+            vd.cumulative_fuel_per_share_amount,
+            vault.cumulative_fuel_per_share
+        );
+    }
+
     #[test]
     fn test_vault_depositor_shares_fuel_changing_user_shares() {
         let now = 1000;
@@ -2,9 +2,11 @@ use anchor_lang::prelude::*;
 use drift_macros::assert_no_slop;
 use static_assertions::const_assert_eq;
 
+use crate::events::VaultProtocolConfig;
 use crate::state::withdraw_request::WithdrawRequest;
 use crate::state::Size;
 
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq)]
 pub struct VaultFee {
     pub management_fee_payment: i64,
     pub management_fee_shares: i64,
@@ -52,4 +54,13 @@ impl VaultProtocol {
     pub fn get_vault_protocol_seeds<'a>(vault: &'a [u8], bump: &'a u8) -> [&'a [u8]; 3] {
         [b"vault_protocol".as_ref(), vault, bytemuck::bytes_of(bump)]
     }
+
+    /// The settings logged by [`crate::state::events::VaultConfigRecord`]
+    pub fn get_config(&self) -> VaultProtocolConfig {
+        VaultProtocolConfig {
+            protocol: self.protocol,
+            protocol_fee: self.protocol_fee,
+            protocol_profit_share: self.protocol_profit_share,
+        }
+    }
 }
@@ -3914,3 +3914,268 @@ mod full_vault_withdraw_tests {
         }
     }
 }
+
+#[cfg(test)]
+mod preview_tests {
+    use anchor_lang::prelude::Pubkey;
+    use drift::math::constants::{ONE_YEAR, QUOTE_PRECISION_U64};
+    use drift::state::user::UserStats;
+
+    use crate::state::traits::VaultDepositorBase;
+    use crate::state::VaultPreview;
+    use crate::{Vault, VaultDepositor, WithdrawUnit};
+
+    fn setup(now: i64) -> (Vault, VaultDepositor, u64) {
+        let mut vault = Vault {
+            management_fee: 20_000,  // 2%
+            profit_share: 200_000,   // 20%
+            last_fee_update_ts: now, // fees accrue from the first deposit
+            ..Vault::default()
+        };
+        let mut vd = VaultDepositor::new(
+            Pubkey::default(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            now,
+        );
+        let amount = 1_000 * QUOTE_PRECISION_U64;
+        vd.deposit(
+            amount,
+            0,
+            &mut vault,
+            &mut None,
+            &mut None,
+            now,
+            &UserStats::default(),
+            &None,
+            0,
+        )
+        .unwrap();
+        (vault, vd, amount)
+    }
+
+    #[test]
+    fn test_preview_deposit_matches_deposit() {
+        let now = 123456789;
+        let (mut vault, mut vd, amount) = setup(now);
+        let now = now + ONE_YEAR as i64 / 2;
+        let vault_equity = amount * 3 / 2;
+
+        let vault_before = vault;
+        let preview = VaultPreview::new(&vault, &None, &None, vault_equity, now)
+            .unwrap()
+            .preview_deposit(vd, amount)
+            .unwrap();
+        assert_eq!(vault, vault_before);
+
+        vd.deposit(
+            amount,
+            vault_equity,
+            &mut vault,
+            &mut None,
+            &mut None,
+            now,
+            &UserStats::default(),
+            &None,
+            0,
+        )
+        .unwrap();
+
+        assert!(preview.manager_profit_share > 0);
+        assert_eq!(preview.vault_shares_after, vd.get_vault_shares());
+        assert_eq!(preview.total_shares_after, vault.total_shares);
+        assert_eq!(preview.shares_base, vault.shares_base);
+
+        // a depositor with a pending withdraw request can't deposit
+        vd.request_withdraw(
+            100,
+            WithdrawUnit::Token,
+            vault_equity + amount,
+            &mut vault,
+            &mut None,
+            &mut None,
+            now,
+            &UserStats::default(),
+            &None,
+            0,
+        )
+        .unwrap();
+        assert!(
+            VaultPreview::new(&vault, &None, &None, vault_equity + amount, now)
+                .unwrap()
+                .preview_deposit(vd, amount)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_preview_withdraw_matches_request_withdraw() {
+        let now = 123456789;
+        let (mut vault, mut vd, amount) = setup(now);
+        let now = now + ONE_YEAR as i64 / 2;
+        let vault_equity = amount * 2;
+        vault.redeem_period = 3600;
+
+        let equity_preview = VaultPreview::new(&vault, &None, &None, vault_equity, now)
+            .unwrap()
+            .preview_depositor_equity(vd)
+            .unwrap();
+        let fees_preview = VaultPreview::new(&vault, &None, &None, vault_equity, now)
+            .unwrap()
+            .preview_fees()
+            .unwrap();
+        let withdraw_preview = VaultPreview::new(&vault, &None, &None, vault_equity, now)
+            .unwrap()
+            .preview_withdraw(vd, 1_000_000, WithdrawUnit::SharesPercent)
+            .unwrap();
+
+        assert!(fees_preview.management_fee_payment > 0);
+        assert_eq!(equity_preview.vault_shares, withdraw_preview.shares);
+        assert_eq!(equity_preview.equity, withdraw_preview.withdraw_value);
+        assert_eq!(withdraw_preview.redeem_period_end_ts, now + 3600);
+
+        vd.request_withdraw(
+            1_000_000, // 100%
+            WithdrawUnit::SharesPercent,
+            vault_equity,
+            &mut vault,
+            &mut None,
+            &mut None,
+            now,
+            &UserStats::default(),
+            &None,
+            0,
+        )
+        .unwrap();
+
+        assert_eq!(fees_preview.total_shares_after, vault.total_shares);
+        assert_eq!(withdraw_preview.shares, vd.last_withdraw_request.shares);
+        assert_eq!(
+            withdraw_preview.withdraw_value,
+            vd.last_withdraw_request.value
+        );
+        assert_eq!(
+            withdraw_preview.manager_profit_share,
+            vd.profit_share_fee_paid
+        );
+    }
+
+    #[test]
+    fn test_preview_withdraw_payout_matches_withdraw() {
+        let now = 123456789;
+        let (mut vault, mut vd, amount) = setup(now);
+        vault.redeem_period = 3600;
+
+        assert_eq!(
+            VaultPreview::new(&vault, &None, &None, amount, now)
+                .unwrap()
+                .preview_withdraw_payout(vd)
+                .unwrap(),
+            0
+        );
+
+        vd.request_withdraw(
+            500_000, // 50%
+            WithdrawUnit::SharesPercent,
+            amount,
+            &mut vault,
+            &mut None,
+            &mut None,
+            now,
+            &UserStats::default(),
+            &None,
+            0,
+        )
+        .unwrap();
+
+        // the vault lost money during the redeem period, so the request pays less than its value
+        let now = now + 3600;
+        let vault_equity = amount * 4 / 5;
+        let payout = VaultPreview::new(&vault, &None, &None, vault_equity, now)
+            .unwrap()
+            .preview_withdraw_payout(vd)
+            .unwrap();
+        assert!(payout < vd.last_withdraw_request.value);
+
+        let (withdraw_amount, _) = vd
+            .withdraw(
+                vault_equity,
+                &mut vault,
+                &mut None,
+                &mut None,
+                now,
+                &UserStats::default(),
+                &None,
+                0,
+            )
+            .unwrap();
+        assert_eq!(payout, withdraw_amount);
+    }
+}
+
+#[cfg(test)]
+mod update_tokenized_vault_depositor_metadata_tests {
+    use crate::instructions::{
+        UpdateTokenizedVaultDepositorMetadataParams, MAX_TOKEN_NAME_LENGTH,
+        MAX_TOKEN_SYMBOL_LENGTH, MAX_TOKEN_URI_LENGTH,
+    };
+
+    fn params(
+        token_name: Option<&str>,
+        token_symbol: Option<&str>,
+        token_uri: Option<&str>,
+    ) -> UpdateTokenizedVaultDepositorMetadataParams {
+        UpdateTokenizedVaultDepositorMetadataParams {
+            token_name: token_name.map(str::to_string),
+            token_symbol: token_symbol.map(str::to_string),
+            token_uri: token_uri.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn test_validate_metadata_update() {
+        assert!(params(Some("Vault Shares"), None, None).validate().is_ok());
+        assert!(params(None, Some("VS"), None).validate().is_ok());
+        assert!(params(None, None, Some("")).validate().is_ok());
+        assert!(params(
+            Some(&"n".repeat(MAX_TOKEN_NAME_LENGTH)),
+            Some(&"s".repeat(MAX_TOKEN_SYMBOL_LENGTH)),
+            Some(&"u".repeat(MAX_TOKEN_URI_LENGTH)),
+        )
+        .validate()
+        .is_ok());
+
+        // nothing to update
+        assert!(params(None, None, None).validate().is_err());
+    }
+
+    #[test]
+    fn test_validate_metadata_update_lengths() {
+        assert!(params(Some(""), None, None).validate().is_err());
+        assert!(
+            params(Some(&"n".repeat(MAX_TOKEN_NAME_LENGTH + 1)), None, None)
+                .validate()
+                .is_err()
+        );
+        assert!(params(None, Some(""), None).validate().is_err());
+        assert!(
+            params(None, Some(&"s".repeat(MAX_TOKEN_SYMBOL_LENGTH + 1)), None)
+                .validate()
+                .is_err()
+        );
+        assert!(
+            params(None, None, Some(&"u".repeat(MAX_TOKEN_URI_LENGTH + 1)))
+                .validate()
+                .is_err()
+        );
+
+        // one invalid field fails the whole update
+        assert!(params(
+            Some("Vault Shares"),
+            Some(&"s".repeat(MAX_TOKEN_SYMBOL_LENGTH + 1)),
+            None
+        )
+        .validate()
+        .is_err());
+    }
+}
@@ -1,11 +1,20 @@
 use anchor_lang::prelude::*;
 
+/// Share token CPIs are implemented with `anchor_spl::token_interface` so they work with both the
+/// SPL Token and Token-2022 programs.
 pub trait MintTokensCPI {
     fn mint(&self, vault_name: [u8; 32], vault_bump: u8, amount: u64) -> Result<()>;
 }
 
+/// Burns share tokens directly from the holder's token account, signed by the holder.
 pub trait BurnTokensCPI {
-    fn burn(&self, vault_name: [u8; 32], vault_bump: u8, amount: u64) -> Result<()>;
+    fn burn(&self, amount: u64) -> Result<()>;
+}
+
+/// Burns share tokens from the vault's token account, signed by the vault. Used by the legacy
+/// redeem_tokens, which moves the holder's tokens to the vault before burning them.
+pub trait BurnVaultTokensCPI {
+    fn burn_vault_tokens(&self, vault_name: [u8; 32], vault_bump: u8, amount: u64) -> Result<()>;
 }
 
 pub trait TokenTransferCPI {
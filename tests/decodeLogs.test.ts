@@ -0,0 +1,89 @@
+import { AnchorProvider, BN, Program, Wallet } from '@coral-xyz/anchor';
+import { describe, expect, it } from '@jest/globals';
+import { Connection, Keypair } from '@solana/web3.js';
+import { getVariant } from '@drift-labs/sdk';
+import * as fs from 'fs';
+import * as path from 'path';
+import {
+	DriftVaults,
+	IDL,
+	LogParser,
+	VAULT_PROGRAM_ID,
+	VaultDepositorRecord,
+	VaultDepositorV2Record,
+} from '../ts/sdk/lib';
+
+// program logs of a deposit from before the program emitted v2 records, then a deposit, withdraw
+// request and withdraw, one transaction per blank-line separated block
+const fixtureTransactions = fs
+	.readFileSync(
+		path.join(__dirname, 'fixtures', 'vault_depositor_logs.txt'),
+		'utf8'
+	)
+	.trim()
+	.split('\n\n')
+	.map((transaction) => transaction.split('\n'));
+
+describe('decodeLogs', () => {
+	const provider = new AnchorProvider(
+		new Connection('http://localhost:8899'),
+		new Wallet(Keypair.generate()),
+		{}
+	);
+	const program = new Program<DriftVaults>(IDL, VAULT_PROGRAM_ID, provider);
+	const logParser = new LogParser(program as any as Program);
+
+	const events = fixtureTransactions.flatMap((logs, slot) =>
+		logParser.parseEventsFromLogs({ txSig: `tx${slot}`, slot, logs })
+	);
+
+	it('skips records logged by drift cpis', () => {
+		expect(events.map((event) => event.eventType)).toEqual([
+			'VaultDepositorRecord',
+			'VaultDepositorV2Record',
+			'VaultDepositorV2Record',
+			'VaultDepositorV2Record',
+		]);
+	});
+
+	it('decodes a VaultDepositorRecord', () => {
+		const record = events[0] as any as VaultDepositorRecord;
+		expect(getVariant(record.action)).toBe('deposit');
+		expect(record.amount.eq(new BN(1_000_000_000))).toBe(true);
+		expect(record.userVaultSharesAfter.eq(new BN(1_000_000_000))).toBe(true);
+	});
+
+	it('decodes VaultDepositorV2Records', () => {
+		const [deposit, withdrawRequest, withdraw] = events.slice(
+			1
+		) as any as VaultDepositorV2Record[];
+
+		expect(getVariant(deposit.action)).toBe('deposit');
+		expect(deposit.amount.eq(new BN(500_000_000))).toBe(true);
+		expect(deposit.sharesBase).toBe(0);
+		expect(deposit.vaultEquityBefore.eq(new BN(1_250_000_000))).toBe(true);
+		expect(deposit.vaultSharesAfter.eq(new BN(1_400_000_000))).toBe(true);
+		expect(deposit.depositOraclePrice.eq(new BN(1_000_000))).toBe(true);
+		expect(deposit.costBasisAfter!.eq(new BN(1_500_000_000))).toBe(true);
+		expect(deposit.realizedPnl).toBeNull();
+
+		expect(getVariant(withdrawRequest.action)).toBe('withdrawRequest');
+		expect(withdrawRequest.vaultSharesAfter.eq(new BN(1_400_000_000))).toBe(
+			true
+		);
+
+		expect(getVariant(withdraw.action)).toBe('withdraw');
+		expect(withdraw.amount.eq(new BN(875_000_000))).toBe(true);
+		expect(withdraw.vaultSharesAfter.eq(new BN(700_000_000))).toBe(true);
+		expect(withdraw.totalVaultSharesAfter.eq(new BN(700_000_000))).toBe(true);
+		expect(withdraw.costBasisAfter!.eq(new BN(750_000_000))).toBe(true);
+		expect(withdraw.realizedPnl!.eq(new BN(125_000_000))).toBe(true);
+
+		// the vault has no VaultProtocol
+		expect(withdraw.protocolSharesBefore).toBeNull();
+		expect(withdraw.protocolSharesAfter).toBeNull();
+		expect(withdraw.protocolProfitShare).toBeNull();
+		expect(withdraw.protocolFee).toBeNull();
+		expect(withdraw.protocolFeeShares).toBeNull();
+	});
+});
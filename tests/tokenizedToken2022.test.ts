@@ -0,0 +1,576 @@
+import { BN, Program } from '@coral-xyz/anchor';
+import { describe, it } from '@jest/globals';
+import { BankrunContextWrapper } from './common/bankrunConnection';
+import { startAnchor } from 'solana-bankrun';
+import {
+	VaultClient,
+	getVaultAddressSync,
+	getVaultDepositorAddressSync,
+	getTokenizedVaultAddressSync,
+	getTokenizedVaultMintAddressSync,
+	encodeName,
+	DriftVaults,
+	VAULT_PROGRAM_ID,
+	IDL,
+	WithdrawUnit,
+} from '../ts/sdk/lib';
+import {
+	BulkAccountLoader,
+	DRIFT_PROGRAM_ID,
+	DriftClient,
+	OracleSource,
+	PEG_PRECISION,
+	PublicKey,
+	QUOTE_PRECISION,
+	TestClient,
+	ZERO,
+	getUserStatsAccountPublicKey,
+} from '@drift-labs/sdk';
+import { TestBulkAccountLoader } from './common/testBulkAccountLoader';
+import {
+	bootstrapSignerClientAndUserBankrun,
+	initializeQuoteSpotMarket,
+	initializeSolSpotMarket,
+	mockUSDCMintBankrun,
+} from './common/testHelpers';
+import { Keypair, Transaction } from '@solana/web3.js';
+import { mockOracleNoProgram } from './common/bankrunOracle';
+import {
+	TOKEN_2022_PROGRAM_ID,
+	createAssociatedTokenAccountInstruction,
+	createTransferCheckedWithTransferHookInstruction,
+	getAssociatedTokenAddressSync,
+	getTokenMetadata,
+	unpackAccount,
+} from '@solana/spl-token';
+
+const mantissaSqrtScale = new BN(100_000);
+const ammInitialQuoteAssetReserve = new BN(5 * 10 ** 13).mul(mantissaSqrtScale);
+const ammInitialBaseAssetReserve = new BN(5 * 10 ** 13).mul(mantissaSqrtScale);
+
+describe('tokenizedToken2022', () => {
+	let vaultProgram: Program<DriftVaults>;
+	const initialSolPerpPrice = 100;
+	let adminDriftClient: TestClient;
+	let bulkAccountLoader: TestBulkAccountLoader;
+	let bankrunContextWrapper: BankrunContextWrapper;
+	let usdcMint: PublicKey;
+	let solPerpOracle: PublicKey;
+	const vaultName = 'token 2022 vault';
+	const commonVaultKey = getVaultAddressSync(
+		VAULT_PROGRAM_ID,
+		encodeName(vaultName)
+	);
+	const usdcAmount = new BN(1_000_000_000).mul(QUOTE_PRECISION);
+
+	const managerSigner = Keypair.generate();
+	let managerClient: VaultClient;
+	let managerDriftClient: DriftClient;
+
+	const user1Signer = Keypair.generate();
+	let user1Client: VaultClient;
+	let user1DriftClient: DriftClient;
+	let user1UserUSDCAccount: PublicKey;
+	let user1VaultDepositor: PublicKey;
+
+	const user2Signer = Keypair.generate();
+	let user2Client: VaultClient;
+	let user2DriftClient: DriftClient;
+	let user2UserUSDCAccount: PublicKey;
+	let user2VaultDepositor: PublicKey;
+
+	const driftClientConfig = (
+		bulkAccountLoader: TestBulkAccountLoader,
+		solPerpOracle: PublicKey
+	) => ({
+		accountSubscription: {
+			type: 'polling' as const,
+			accountLoader: bulkAccountLoader as BulkAccountLoader,
+		},
+		activeSubAccountId: 0,
+		subAccountIds: [],
+		perpMarketIndexes: [0],
+		spotMarketIndexes: [0, 1],
+		oracleInfos: [{ publicKey: solPerpOracle, source: OracleSource.PYTH }],
+	});
+
+	beforeEach(async () => {
+		const context = await startAnchor(
+			'',
+			[
+				{
+					name: 'drift',
+					programId: new PublicKey(
+						'dRiftyHA39MWEi3m9aunc5MzRF1JYuBsbn6VPcn33UH'
+					),
+				},
+			],
+			[]
+		);
+
+		bankrunContextWrapper = new BankrunContextWrapper(context);
+
+		vaultProgram = new Program<DriftVaults>(
+			IDL,
+			VAULT_PROGRAM_ID,
+			bankrunContextWrapper.provider
+		);
+
+		bulkAccountLoader = new TestBulkAccountLoader(
+			bankrunContextWrapper.connection.toConnection(),
+			'processed',
+			1
+		);
+
+		usdcMint = await mockUSDCMintBankrun(bankrunContextWrapper);
+
+		solPerpOracle = await mockOracleNoProgram(
+			bankrunContextWrapper,
+			initialSolPerpPrice
+		);
+
+		adminDriftClient = new TestClient({
+			connection: bankrunContextWrapper.connection.toConnection(),
+			wallet: bankrunContextWrapper.provider.wallet,
+			programID: new PublicKey(DRIFT_PROGRAM_ID),
+			opts: {
+				commitment: 'confirmed',
+			},
+			activeSubAccountId: 0,
+			perpMarketIndexes: [0],
+			spotMarketIndexes: [0, 1],
+			subAccountIds: [],
+			oracleInfos: [{ publicKey: solPerpOracle, source: OracleSource.PYTH }],
+			accountSubscription: {
+				type: 'polling',
+				accountLoader: bulkAccountLoader as BulkAccountLoader,
+			},
+		});
+
+		await adminDriftClient.initialize(usdcMint, true);
+		await adminDriftClient.subscribe();
+
+		await initializeQuoteSpotMarket(adminDriftClient, usdcMint);
+		await initializeSolSpotMarket(adminDriftClient, solPerpOracle);
+
+		await adminDriftClient.initializePerpMarket(
+			0,
+			solPerpOracle,
+			ammInitialBaseAssetReserve,
+			ammInitialQuoteAssetReserve,
+			new BN(0),
+			new BN(initialSolPerpPrice).mul(PEG_PRECISION)
+		);
+
+		await adminDriftClient.fetchAccounts();
+
+		const managerBootstrap = await bootstrapSignerClientAndUserBankrun({
+			bankrunContext: bankrunContextWrapper,
+			programId: VAULT_PROGRAM_ID,
+			signer: managerSigner,
+			usdcMint: usdcMint,
+			usdcAmount,
+			vaultClientCliMode: true,
+			driftClientConfig: driftClientConfig(bulkAccountLoader, solPerpOracle),
+		});
+		managerClient = managerBootstrap.vaultClient;
+		managerDriftClient = managerBootstrap.driftClient;
+
+		const user1Bootstrap = await bootstrapSignerClientAndUserBankrun({
+			bankrunContext: bankrunContextWrapper,
+			programId: VAULT_PROGRAM_ID,
+			signer: user1Signer,
+			usdcMint: usdcMint,
+			usdcAmount,
+			vaultClientCliMode: true,
+			driftClientConfig: driftClientConfig(bulkAccountLoader, solPerpOracle),
+		});
+		user1Client = user1Bootstrap.vaultClient;
+		user1DriftClient = user1Bootstrap.driftClient;
+		user1UserUSDCAccount = user1Bootstrap.userUSDCAccount.publicKey;
+		user1VaultDepositor = getVaultDepositorAddressSync(
+			VAULT_PROGRAM_ID,
+			commonVaultKey,
+			user1Signer.publicKey
+		);
+
+		const user2Bootstrap = await bootstrapSignerClientAndUserBankrun({
+			bankrunContext: bankrunContextWrapper,
+			programId: VAULT_PROGRAM_ID,
+			signer: user2Signer,
+			usdcMint: usdcMint,
+			usdcAmount,
+			vaultClientCliMode: true,
+			driftClientConfig: driftClientConfig(bulkAccountLoader, solPerpOracle),
+		});
+		user2Client = user2Bootstrap.vaultClient;
+		user2DriftClient = user2Bootstrap.driftClient;
+		user2UserUSDCAccount = user2Bootstrap.userUSDCAccount.publicKey;
+		user2VaultDepositor = getVaultDepositorAddressSync(
+			VAULT_PROGRAM_ID,
+			commonVaultKey,
+			user2Signer.publicKey
+		);
+
+		// initialize vault
+		await managerClient.initializeVault(
+			{
+				name: encodeName(vaultName),
+				spotMarketIndex: 0,
+				redeemPeriod: ZERO,
+				maxTokens: ZERO,
+				managementFee: ZERO,
+				profitShare: 0,
+				hurdleRate: 0,
+				permissioned: false,
+				minDepositAmount: ZERO,
+			},
+			{ noLut: true }
+		);
+
+		// initialize depositors
+		await user1Client.initializeVaultDepositor(
+			commonVaultKey,
+			user1Signer.publicKey,
+			user1Signer.publicKey,
+			{ noLut: true }
+		);
+		await user2Client.initializeVaultDepositor(
+			commonVaultKey,
+			user2Signer.publicKey,
+			user2Signer.publicKey,
+			{ noLut: true }
+		);
+
+		// user1 deposits
+		await user1Client.deposit(
+			user1VaultDepositor,
+			usdcAmount,
+			undefined,
+			{ noLut: true },
+			user1UserUSDCAccount
+		);
+
+		// user2 deposits half
+		await user2Client.deposit(
+			user2VaultDepositor,
+			usdcAmount.divn(2),
+			undefined,
+			{ noLut: true },
+			user2UserUSDCAccount
+		);
+	});
+
+	afterEach(async () => {
+		await adminDriftClient.unsubscribe();
+		await managerClient.unsubscribe();
+		await managerDriftClient.unsubscribe();
+		await user1Client.unsubscribe();
+		await user1DriftClient.unsubscribe();
+		await user2Client.unsubscribe();
+		await user2DriftClient.unsubscribe();
+	});
+
+	const mintAddress = async () => {
+		const vault = await vaultProgram.account.vault.fetch(commonVaultKey);
+		return getTokenizedVaultMintAddressSync(
+			VAULT_PROGRAM_ID,
+			commonVaultKey,
+			vault.sharesBase
+		);
+	};
+
+	const tokenBalance = async (owner: PublicKey, mint: PublicKey) => {
+		const ata = getAssociatedTokenAddressSync(
+			mint,
+			owner,
+			true,
+			TOKEN_2022_PROGRAM_ID
+		);
+		const account = await bankrunContextWrapper.connection.getAccountInfo(ata);
+		return new BN(
+			unpackAccount(ata, account, TOKEN_2022_PROGRAM_ID).amount.toString()
+		);
+	};
+
+	const transferShareTokens = async (
+		from: Keypair,
+		to: PublicKey,
+		mint: PublicKey,
+		amount: BN
+	) => {
+		const source = getAssociatedTokenAddressSync(
+			mint,
+			from.publicKey,
+			true,
+			TOKEN_2022_PROGRAM_ID
+		);
+		const destination = getAssociatedTokenAddressSync(
+			mint,
+			to,
+			true,
+			TOKEN_2022_PROGRAM_ID
+		);
+		const tx = new Transaction();
+		if (
+			(await bankrunContextWrapper.connection.getAccountInfo(destination)) ===
+			null
+		) {
+			tx.add(
+				createAssociatedTokenAccountInstruction(
+					bankrunContextWrapper.context.payer.publicKey,
+					destination,
+					to,
+					mint,
+					TOKEN_2022_PROGRAM_ID
+				)
+			);
+		}
+		tx.add(
+			await createTransferCheckedWithTransferHookInstruction(
+				bankrunContextWrapper.connection.toConnection(),
+				source,
+				mint,
+				destination,
+				from.publicKey,
+				BigInt(amount.toString()),
+				6,
+				[],
+				'confirmed',
+				TOKEN_2022_PROGRAM_ID
+			)
+		);
+		await bankrunContextWrapper.sendTransaction(tx, [from]);
+	};
+
+	it('tokenize shares and redeem through a token 2022 mint', async () => {
+		await managerClient.initializeTokenizedVaultDepositorToken2022(
+			{
+				vault: commonVaultKey,
+				tokenName: 'Token 2022 Vault Shares',
+				tokenSymbol: 'T22VS',
+				tokenUri: '',
+			},
+			{ noLut: true }
+		);
+
+		const mint = await mintAddress();
+		const mintAccount = await bankrunContextWrapper.connection.getAccountInfo(
+			mint
+		);
+		expect(mintAccount.owner.equals(TOKEN_2022_PROGRAM_ID)).toBe(true);
+
+		const vaultBefore = await vaultProgram.account.vault.fetch(commonVaultKey);
+		const vd1Before = await vaultProgram.account.vaultDepositor.fetch(
+			user1VaultDepositor
+		);
+		const sharesToTokenize = vd1Before.vaultShares.divn(2);
+
+		await user1Client.tokenizeShares(
+			user1VaultDepositor,
+			sharesToTokenize,
+			WithdrawUnit.SHARES,
+			mint,
+			{ noLut: true }
+		);
+
+		const tokens = await tokenBalance(user1Signer.publicKey, mint);
+		expect(tokens.gtn(0)).toBe(true);
+
+		const tvd = await vaultProgram.account.tokenizedVaultDepositor.fetch(
+			getTokenizedVaultAddressSync(
+				VAULT_PROGRAM_ID,
+				commonVaultKey,
+				vaultBefore.sharesBase
+			)
+		);
+		expect(tvd.vaultShares.eq(sharesToTokenize)).toBe(true);
+
+		await user1Client.redeemTokens(
+			user1VaultDepositor,
+			tokens,
+			vaultBefore.sharesBase,
+			{ noLut: true }
+		);
+
+		const vd1After = await vaultProgram.account.vaultDepositor.fetch(
+			user1VaultDepositor
+		);
+		expect((await tokenBalance(user1Signer.publicKey, mint)).eqn(0)).toBe(true);
+		// redeeming rounds down by at most a share
+		expect(vd1Before.vaultShares.sub(vd1After.vaultShares).lten(1)).toBe(true);
+
+		const vaultAfter = await vaultProgram.account.vault.fetch(commonVaultKey);
+		expect(vaultAfter.totalShares.eq(vaultBefore.totalShares)).toBe(true);
+	});
+
+	it('transfer hook blocks transfers out of a locked depositor', async () => {
+		await managerClient.initializeTokenizedVaultDepositorToken2022(
+			{
+				vault: commonVaultKey,
+				tokenName: 'Token 2022 Vault Shares',
+				tokenSymbol: 'T22VS',
+				tokenUri: '',
+				enableTransferHook: true,
+			},
+			{ noLut: true }
+		);
+
+		const mint = await mintAddress();
+		const vd1 = await vaultProgram.account.vaultDepositor.fetch(
+			user1VaultDepositor
+		);
+		await user1Client.tokenizeShares(
+			user1VaultDepositor,
+			vd1.vaultShares.divn(2),
+			WithdrawUnit.SHARES,
+			mint,
+			{ noLut: true }
+		);
+		const tokens = await tokenBalance(user1Signer.publicKey, mint);
+
+		// unlocked holders can move their tokens
+		await transferShareTokens(
+			user1Signer,
+			user2Signer.publicKey,
+			mint,
+			tokens.divn(4)
+		);
+		expect(
+			(await tokenBalance(user2Signer.publicKey, mint)).eq(tokens.divn(4))
+		).toBe(true);
+
+		await vaultProgram.methods
+			.lockVaultDepositor({ thirtyDays: {} })
+			.accounts({
+				vault: commonVaultKey,
+				vaultDepositor: user1VaultDepositor,
+				authority: user1Signer.publicKey,
+				driftUserStats: getUserStatsAccountPublicKey(
+					new PublicKey(DRIFT_PROGRAM_ID),
+					commonVaultKey
+				),
+			})
+			.signers([user1Signer])
+			.rpc();
+
+		try {
+			await transferShareTokens(
+				user1Signer,
+				user2Signer.publicKey,
+				mint,
+				tokens.divn(4)
+			);
+			expect(true).toBe(false); // should not reach here
+		} catch (e) {
+			expect(e).toBeDefined();
+		}
+		expect(
+			(await tokenBalance(user2Signer.publicKey, mint)).eq(tokens.divn(4))
+		).toBe(true);
+	});
+
+	it('manager updates token 2022 share metadata', async () => {
+		await managerClient.initializeTokenizedVaultDepositorToken2022(
+			{
+				vault: commonVaultKey,
+				tokenName: 'Token 2022 Vault Shares',
+				tokenSymbol: 'T22VS',
+				tokenUri: '',
+			},
+			{ noLut: true }
+		);
+		const mint = await mintAddress();
+
+		// longer than the metadata it replaces, so the mint is reallocated
+		await managerClient.updateTokenizedVaultDepositorMetadata(
+			{
+				vault: commonVaultKey,
+				tokenName: 'Renamed Token 2022 Vault Shares',
+				tokenUri: 'https://example.com/vault-shares.json',
+			},
+			{ noLut: true }
+		);
+
+		const metadata = await getTokenMetadata(
+			bankrunContextWrapper.connection.toConnection(),
+			mint,
+			'confirmed',
+			TOKEN_2022_PROGRAM_ID
+		);
+		expect(metadata.name).toBe('Renamed Token 2022 Vault Shares');
+		expect(metadata.symbol).toBe('T22VS');
+		expect(metadata.uri).toBe('https://example.com/vault-shares.json');
+	});
+
+	it('metadata update rejects non-managers and invalid fields', async () => {
+		await managerClient.initializeTokenizedVaultDepositorToken2022(
+			{
+				vault: commonVaultKey,
+				tokenName: 'Token 2022 Vault Shares',
+				tokenSymbol: 'T22VS',
+				tokenUri: '',
+			},
+			{ noLut: true }
+		);
+		const mint = await mintAddress();
+		const vault = await vaultProgram.account.vault.fetch(commonVaultKey);
+		const tokenizedVaultDepositor = getTokenizedVaultAddressSync(
+			VAULT_PROGRAM_ID,
+			commonVaultKey,
+			vault.sharesBase
+		);
+
+		// user1 signs as the manager
+		try {
+			await vaultProgram.methods
+				.updateTokenizedVaultDepositorMetadata({
+					tokenName: 'Hijacked',
+					tokenSymbol: null,
+					tokenUri: null,
+				})
+				.accounts({
+					vault: commonVaultKey,
+					manager: user1Signer.publicKey,
+					tokenizedVaultDepositor,
+					mint,
+					metadataAccount: mint,
+					tokenProgram: TOKEN_2022_PROGRAM_ID,
+					tokenMetadataProgram: null,
+				})
+				.signers([user1Signer])
+				.rpc();
+			expect(true).toBe(false); // should not reach here
+		} catch (e) {
+			expect(e).toBeDefined();
+		}
+
+		for (const invalid of [
+			{ tokenSymbol: 'TOOLONGSYMBOL' },
+			{ tokenName: 'n'.repeat(33) },
+			{ tokenName: '' },
+			{ tokenUri: 'u'.repeat(201) },
+			{},
+		]) {
+			try {
+				await managerClient.updateTokenizedVaultDepositorMetadata(
+					{ vault: commonVaultKey, ...invalid },
+					{ noLut: true }
+				);
+				expect(true).toBe(false); // should not reach here
+			} catch (e) {
+				expect(e).toBeDefined();
+			}
+		}
+
+		const metadata = await getTokenMetadata(
+			bankrunContextWrapper.connection.toConnection(),
+			mint,
+			'confirmed',
+			TOKEN_2022_PROGRAM_ID
+		);
+		expect(metadata.name).toBe('Token 2022 Vault Shares');
+		expect(metadata.symbol).toBe('T22VS');
+		expect(metadata.uri).toBe('');
+	});
+});
@@ -3,7 +3,7 @@ import {
     Command
 } from "commander";
 import { getCommandContext } from "../utils";
-import { VaultDepositorRecord } from "../../src";
+import { VaultDepositorRecord, VaultDepositorV2Record } from "../../src";
 import { BN, TEN, convertToNumber, getVariant } from "@drift-labs/sdk";
 
 export const decodeLogs = async (program: Command, cmdOpts: OptionValues) => {
@@ -65,6 +65,50 @@ export const decodeLogs = async (program: Command, cmdOpts: OptionValues) => {
                 // @ts-ignore
                 console.log(` depositOraclePrice:  ${data.depositOraclePrice?.toNumber()}`);
                 break;
+            case "VaultDepositorV2Record":
+                const v2Data: VaultDepositorV2Record = event.data;
+                const v2SpotMarket = driftClient.getSpotMarketAccount(v2Data.spotMarketIndex);
+                const v2SpotPrecision = TEN.pow(new BN(v2SpotMarket!.decimals));
+                const v2Date = new Date(v2Data.ts.toNumber() * 1000);
+
+                console.log(event.name);
+                console.log(` ts: ${v2Date.toISOString()} (${v2Data.ts.toNumber()})`);
+                console.log(` vault:              ${v2Data.vault.toBase58()}`);
+                console.log(` depositorAuthority: ${v2Data.depositorAuthority.toBase58()}`);
+                console.log(` action: ${getVariant(v2Data.action)}`);
+                console.log(` amount: ${convertToNumber(v2Data.amount, v2SpotPrecision)}`);
+                console.log(` sharesBase: ${v2Data.sharesBase}`);
+                console.log(` vaultSharesBefore: ${v2Data.vaultSharesBefore.toString()}`);
+                console.log(` vaultSharesAfter:  ${v2Data.vaultSharesAfter.toString()} (${v2Data.vaultSharesAfter.sub(v2Data.vaultSharesBefore).toString()})`);
+                console.log(` vaultEquityBefore:     ${convertToNumber(v2Data.vaultEquityBefore, v2SpotPrecision)}`);
+                console.log(` managerBorrowedValue:  ${convertToNumber(v2Data.managerBorrowedValue, v2SpotPrecision)}`);
+                console.log(` feeUpdateStatus: ${v2Data.feeUpdateStatus}`);
+                console.log(` userVaultSharesBefore: ${v2Data.userVaultSharesBefore.toString()}`);
+                console.log(` userVaultSharesAfter:  ${v2Data.userVaultSharesAfter.toString()} (${v2Data.userVaultSharesAfter.sub(v2Data.userVaultSharesBefore).toString()})`);
+                console.log(` totalVaultSharesBefore: ${v2Data.totalVaultSharesBefore.toString()}`);
+                console.log(` totalVaultSharesAfter:  ${v2Data.totalVaultSharesAfter.toString()} (${v2Data.totalVaultSharesAfter.sub(v2Data.totalVaultSharesBefore).toString()})`);
+                console.log(` managerProfitShare: ${convertToNumber(v2Data.managerProfitShare, v2SpotPrecision)}`);
+                console.log(` managementFee:  ${convertToNumber(v2Data.managementFee, v2SpotPrecision)}`);
+                console.log(` managementFeeShares:  ${v2Data.managementFeeShares.toString()}`);
+                if (v2Data.protocolSharesBefore && v2Data.protocolSharesAfter) {
+                    console.log(` protocolSharesBefore: ${v2Data.protocolSharesBefore.toString()}`);
+                    console.log(` protocolSharesAfter:  ${v2Data.protocolSharesAfter.toString()}`);
+                }
+                if (v2Data.protocolProfitShare) {
+                    console.log(` protocolProfitShare: ${convertToNumber(v2Data.protocolProfitShare, v2SpotPrecision)}`);
+                }
+                if (v2Data.protocolFee && v2Data.protocolFeeShares) {
+                    console.log(` protocolFee:  ${convertToNumber(v2Data.protocolFee, v2SpotPrecision)}`);
+                    console.log(` protocolFeeShares:  ${v2Data.protocolFeeShares.toString()}`);
+                }
+                console.log(` depositOraclePrice:  ${v2Data.depositOraclePrice.toNumber()}`);
+                if (v2Data.costBasisAfter) {
+                    console.log(` costBasisAfter: ${convertToNumber(v2Data.costBasisAfter, v2SpotPrecision)}`);
+                }
+                if (v2Data.realizedPnl) {
+                    console.log(` realizedPnl: ${convertToNumber(v2Data.realizedPnl, v2SpotPrecision)}`);
+                }
+                break;
             default:
         }
 
@@ -100,6 +100,23 @@ export function getTokenizedVaultMintAddressSync(
 	)[0];
 }
 
+/**
+ * The account holding the extra accounts Token-2022 passes to the vault program's transfer hook,
+ * for share mints created with `enableTransferHook`.
+ */
+export function getTransferHookExtraAccountMetasAddressSync(
+	programId: PublicKey,
+	mint: PublicKey
+): PublicKey {
+	return PublicKey.findProgramAddressSync(
+		[
+			Buffer.from(anchor.utils.bytes.utf8.encode('extra-account-metas')),
+			mint.toBuffer(),
+		],
+		programId
+	)[0];
+}
+
 export function getFeeUpdateAddressSync(
 	programId: PublicKey,
 	vault: PublicKey
@@ -112,3 +129,18 @@ export function getFeeUpdateAddressSync(
 		programId
 	)[0];
 }
+
+export function getFuelSeasonSnapshotAddressSync(
+	programId: PublicKey,
+	vaultDepositor: PublicKey,
+	season: number
+): PublicKey {
+	return PublicKey.findProgramAddressSync(
+		[
+			Buffer.from(anchor.utils.bytes.utf8.encode('fuel_season_snapshot')),
+			vaultDepositor.toBuffer(),
+			Buffer.from([season]),
+		],
+		programId
+	)[0];
+}
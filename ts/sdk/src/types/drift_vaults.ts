@@ -449,11 +449,11 @@ export type DriftVaults = {
 			];
 		},
 		{
-			name: 'tokenizeShares';
+			name: 'initializeTokenizedVaultDepositorToken2022';
 			accounts: [
 				{
 					name: 'vault';
-					isMut: true;
+					isMut: false;
 					isSigner: false;
 				},
 				{
@@ -462,27 +462,67 @@ export type DriftVaults = {
 					isSigner: false;
 				},
 				{
-					name: 'authority';
-					isMut: false;
+					name: 'mintAccount';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'payer';
+					isMut: true;
 					isSigner: true;
 				},
 				{
-					name: 'tokenizedVaultDepositor';
+					name: 'extraAccountMetaList';
 					isMut: true;
 					isSigner: false;
+					isOptional: true;
+					docs: ['enable_transfer_hook is set'];
 				},
 				{
-					name: 'mint';
+					name: 'tokenProgram';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'systemProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [
+				{
+					name: 'params';
+					type: {
+						defined: 'InitializeTokenizedVaultDepositorToken2022Params';
+					};
+				}
+			];
+		},
+		{
+			name: 'updateTokenizedVaultDepositorMetadata';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'manager';
 					isMut: true;
+					isSigner: true;
+				},
+				{
+					name: 'tokenizedVaultDepositor';
+					isMut: false;
 					isSigner: false;
 				},
 				{
-					name: 'userTokenAccount';
+					name: 'mint';
 					isMut: false;
 					isSigner: false;
 				},
 				{
-					name: 'driftUser';
+					name: 'metadataAccount';
 					isMut: true;
 					isSigner: false;
 				},
@@ -490,23 +530,30 @@ export type DriftVaults = {
 					name: 'tokenProgram';
 					isMut: false;
 					isSigner: false;
+				},
+				{
+					name: 'tokenMetadataProgram';
+					isMut: false;
+					isSigner: false;
+					isOptional: true;
+				},
+				{
+					name: 'systemProgram';
+					isMut: false;
+					isSigner: false;
 				}
 			];
 			args: [
 				{
-					name: 'amount';
-					type: 'u64';
-				},
-				{
-					name: 'unit';
+					name: 'params';
 					type: {
-						defined: 'WithdrawUnit';
+						defined: 'UpdateTokenizedVaultDepositorMetadataParams';
 					};
 				}
 			];
 		},
 		{
-			name: 'redeemTokens';
+			name: 'tokenizeShares';
 			accounts: [
 				{
 					name: 'vault';
@@ -535,17 +582,17 @@ export type DriftVaults = {
 				},
 				{
 					name: 'userTokenAccount';
-					isMut: true;
+					isMut: false;
 					isSigner: false;
 				},
 				{
-					name: 'vaultTokenAccount';
+					name: 'driftUser';
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'driftUser';
-					isMut: true;
+					name: 'driftUserStats';
+					isMut: false;
 					isSigner: false;
 				},
 				{
@@ -556,13 +603,19 @@ export type DriftVaults = {
 			];
 			args: [
 				{
-					name: 'tokensToBurn';
+					name: 'amount';
 					type: 'u64';
+				},
+				{
+					name: 'unit';
+					type: {
+						defined: 'WithdrawUnit';
+					};
 				}
 			];
 		},
 		{
-			name: 'deposit';
+			name: 'redeemTokens';
 			accounts: [
 				{
 					name: 'vault';
@@ -580,40 +633,30 @@ export type DriftVaults = {
 					isSigner: true;
 				},
 				{
-					name: 'vaultTokenAccount';
+					name: 'tokenizedVaultDepositor';
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'driftUserStats';
+					name: 'mint';
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'driftUser';
+					name: 'userTokenAccount';
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'driftState';
-					isMut: false;
-					isSigner: false;
-				},
-				{
-					name: 'driftSpotMarketVault';
+					name: 'vaultTokenAccount';
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'userTokenAccount';
+					name: 'driftUser';
 					isMut: true;
 					isSigner: false;
 				},
-				{
-					name: 'driftProgram';
-					isMut: false;
-					isSigner: false;
-				},
 				{
 					name: 'tokenProgram';
 					isMut: false;
@@ -622,13 +665,13 @@ export type DriftVaults = {
 			];
 			args: [
 				{
-					name: 'amount';
+					name: 'tokensToBurn';
 					type: 'u64';
 				}
 			];
 		},
 		{
-			name: 'requestWithdraw';
+			name: 'redeemTokensV2';
 			accounts: [
 				{
 					name: 'vault';
@@ -645,63 +688,97 @@ export type DriftVaults = {
 					isMut: false;
 					isSigner: true;
 				},
+				{
+					name: 'tokenizedVaultDepositor';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'mint';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'userTokenAccount';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftUser';
+					isMut: true;
+					isSigner: false;
+				},
 				{
 					name: 'driftUserStats';
 					isMut: false;
 					isSigner: false;
 				},
 				{
-					name: 'driftUser';
+					name: 'tokenProgram';
 					isMut: false;
 					isSigner: false;
 				}
 			];
 			args: [
 				{
-					name: 'withdrawAmount';
+					name: 'tokensToBurn';
 					type: 'u64';
-				},
-				{
-					name: 'withdrawUnit';
-					type: {
-						defined: 'WithdrawUnit';
-					};
 				}
 			];
 		},
 		{
-			name: 'cancelRequestWithdraw';
+			name: 'transferHook';
 			accounts: [
 				{
-					name: 'vault';
-					isMut: true;
+					name: 'sourceToken';
+					isMut: false;
 					isSigner: false;
 				},
 				{
-					name: 'vaultDepositor';
-					isMut: true;
+					name: 'mint';
+					isMut: false;
 					isSigner: false;
 				},
 				{
-					name: 'authority';
+					name: 'destinationToken';
 					isMut: false;
-					isSigner: true;
+					isSigner: false;
 				},
 				{
-					name: 'driftUserStats';
+					name: 'owner';
 					isMut: false;
 					isSigner: false;
 				},
 				{
-					name: 'driftUser';
+					name: 'extraAccountMetaList';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'vault';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'sourceVaultDepositor';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'destinationVaultDepositor';
 					isMut: false;
 					isSigner: false;
 				}
 			];
-			args: [];
+			args: [
+				{
+					name: 'amount';
+					type: 'u64';
+				}
+			];
 		},
 		{
-			name: 'withdraw';
+			name: 'depositAndMint';
 			accounts: [
 				{
 					name: 'vault';
@@ -709,7 +786,12 @@ export type DriftVaults = {
 					isSigner: false;
 				},
 				{
-					name: 'vaultDepositor';
+					name: 'tokenizedVaultDepositor';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'mint';
 					isMut: true;
 					isSigner: false;
 				},
@@ -744,12 +826,12 @@ export type DriftVaults = {
 					isSigner: false;
 				},
 				{
-					name: 'driftSigner';
-					isMut: false;
+					name: 'userTokenAccount';
+					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'userTokenAccount';
+					name: 'userShareTokenAccount';
 					isMut: true;
 					isSigner: false;
 				},
@@ -762,12 +844,25 @@ export type DriftVaults = {
 					name: 'tokenProgram';
 					isMut: false;
 					isSigner: false;
+				},
+				{
+					name: 'shareTokenProgram';
+					isMut: false;
+					isSigner: false;
+					docs: [
+						'The token program that owns the share token mint, either SPL Token or Token-2022'
+					];
+				}
+			];
+			args: [
+				{
+					name: 'amount';
+					type: 'u64';
 				}
 			];
-			args: [];
 		},
 		{
-			name: 'liquidate';
+			name: 'requestRedeem';
 			accounts: [
 				{
 					name: 'vault';
@@ -775,61 +870,64 @@ export type DriftVaults = {
 					isSigner: false;
 				},
 				{
-					name: 'vaultDepositor';
+					name: 'tokenizedVaultDepositor';
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'authority';
-					isMut: false;
+					name: 'redeemRequest';
+					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'admin';
+					name: 'authority';
 					isMut: true;
 					isSigner: true;
 				},
 				{
-					name: 'driftUserStats';
+					name: 'mint';
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'driftUser';
+					name: 'userTokenAccount';
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'driftProgram';
+					name: 'driftUser';
 					isMut: false;
 					isSigner: false;
-				}
-			];
-			args: [];
-		},
-		{
-			name: 'resetDelegate';
-			accounts: [
+				},
 				{
-					name: 'vault';
-					isMut: true;
+					name: 'driftUserStats';
+					isMut: false;
 					isSigner: false;
 				},
 				{
-					name: 'driftUser';
-					isMut: true;
+					name: 'tokenProgram';
+					isMut: false;
 					isSigner: false;
 				},
 				{
-					name: 'driftProgram';
+					name: 'systemProgram';
 					isMut: false;
 					isSigner: false;
 				}
 			];
-			args: [];
+			args: [
+				{
+					name: 'tokensToBurn';
+					type: 'u64';
+				},
+				{
+					name: 'requestId';
+					type: 'u16';
+				}
+			];
 		},
 		{
-			name: 'resetFuelSeason';
+			name: 'cancelRedeemRequest';
 			accounts: [
 				{
 					name: 'vault';
@@ -837,51 +935,55 @@ export type DriftVaults = {
 					isSigner: false;
 				},
 				{
-					name: 'vaultDepositor';
+					name: 'tokenizedVaultDepositor';
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'admin';
-					isMut: false;
+					name: 'redeemRequest';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'authority';
+					isMut: true;
 					isSigner: true;
 				},
 				{
-					name: 'driftUserStats';
+					name: 'mint';
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'driftState';
-					isMut: false;
+					name: 'userTokenAccount';
+					isMut: true;
 					isSigner: false;
-				}
-			];
-			args: [];
-		},
-		{
-			name: 'resetVaultFuelSeason';
-			accounts: [
+				},
 				{
-					name: 'vault';
-					isMut: true;
+					name: 'driftUser';
+					isMut: false;
 					isSigner: false;
 				},
 				{
-					name: 'admin';
+					name: 'driftUserStats';
 					isMut: false;
-					isSigner: true;
+					isSigner: false;
 				},
 				{
-					name: 'driftState';
+					name: 'tokenProgram';
 					isMut: false;
 					isSigner: false;
 				}
 			];
-			args: [];
+			args: [
+				{
+					name: 'requestId';
+					type: 'u16';
+				}
+			];
 		},
 		{
-			name: 'managerBorrow';
+			name: 'redeemTokensForUnderlying';
 			accounts: [
 				{
 					name: 'vault';
@@ -889,15 +991,25 @@ export type DriftVaults = {
 					isSigner: false;
 				},
 				{
-					name: 'vaultTokenAccount';
+					name: 'tokenizedVaultDepositor';
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'manager';
-					isMut: false;
+					name: 'redeemRequest';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'authority';
+					isMut: true;
 					isSigner: true;
 				},
+				{
+					name: 'vaultTokenAccount';
+					isMut: true;
+					isSigner: false;
+				},
 				{
 					name: 'driftUserStats';
 					isMut: true;
@@ -941,17 +1053,13 @@ export type DriftVaults = {
 			];
 			args: [
 				{
-					name: 'borrowSpotMarketIndex';
+					name: 'requestId';
 					type: 'u16';
-				},
-				{
-					name: 'borrowAmount';
-					type: 'u64';
 				}
 			];
 		},
 		{
-			name: 'managerRepay';
+			name: 'deposit';
 			accounts: [
 				{
 					name: 'vault';
@@ -959,15 +1067,20 @@ export type DriftVaults = {
 					isSigner: false;
 				},
 				{
-					name: 'vaultTokenAccount';
+					name: 'vaultDepositor';
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'manager';
+					name: 'authority';
 					isMut: false;
 					isSigner: true;
 				},
+				{
+					name: 'vaultTokenAccount';
+					isMut: true;
+					isSigner: false;
+				},
 				{
 					name: 'driftUserStats';
 					isMut: true;
@@ -988,11 +1101,6 @@ export type DriftVaults = {
 					isMut: true;
 					isSigner: false;
 				},
-				{
-					name: 'driftSigner';
-					isMut: false;
-					isSigner: false;
-				},
 				{
 					name: 'userTokenAccount';
 					isMut: true;
@@ -1011,23 +1119,13 @@ export type DriftVaults = {
 			];
 			args: [
 				{
-					name: 'repaySpotMarketIndex';
-					type: 'u16';
-				},
-				{
-					name: 'repayAmount';
+					name: 'amount';
 					type: 'u64';
-				},
-				{
-					name: 'repayValue';
-					type: {
-						option: 'u64';
-					};
 				}
 			];
 		},
 		{
-			name: 'managerUpdateBorrow';
+			name: 'requestWithdraw';
 			accounts: [
 				{
 					name: 'vault';
@@ -1035,91 +1133,41 @@ export type DriftVaults = {
 					isSigner: false;
 				},
 				{
-					name: 'manager';
-					isMut: false;
-					isSigner: true;
-				},
-				{
-					name: 'driftUserStats';
-					isMut: true;
-					isSigner: false;
-				},
-				{
-					name: 'driftUser';
-					isMut: true;
-					isSigner: false;
-				}
-			];
-			args: [
-				{
-					name: 'newBorrowValue';
-					type: 'u64';
-				}
-			];
-		},
-		{
-			name: 'managerDeposit';
-			accounts: [
-				{
-					name: 'vault';
+					name: 'vaultDepositor';
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'manager';
+					name: 'authority';
 					isMut: false;
 					isSigner: true;
 				},
-				{
-					name: 'vaultTokenAccount';
-					isMut: true;
-					isSigner: false;
-				},
 				{
 					name: 'driftUserStats';
-					isMut: true;
-					isSigner: false;
-				},
-				{
-					name: 'driftUser';
-					isMut: true;
-					isSigner: false;
-				},
-				{
-					name: 'driftState';
-					isMut: false;
-					isSigner: false;
-				},
-				{
-					name: 'driftSpotMarketVault';
-					isMut: true;
-					isSigner: false;
-				},
-				{
-					name: 'userTokenAccount';
-					isMut: true;
-					isSigner: false;
-				},
-				{
-					name: 'driftProgram';
 					isMut: false;
 					isSigner: false;
 				},
 				{
-					name: 'tokenProgram';
+					name: 'driftUser';
 					isMut: false;
 					isSigner: false;
 				}
 			];
 			args: [
 				{
-					name: 'amount';
+					name: 'withdrawAmount';
 					type: 'u64';
+				},
+				{
+					name: 'withdrawUnit';
+					type: {
+						defined: 'WithdrawUnit';
+					};
 				}
 			];
 		},
 		{
-			name: 'managerRequestWithdraw';
+			name: 'lockVaultDepositor';
 			accounts: [
 				{
 					name: 'vault';
@@ -1127,36 +1175,32 @@ export type DriftVaults = {
 					isSigner: false;
 				},
 				{
-					name: 'manager';
-					isMut: false;
-					isSigner: true;
+					name: 'vaultDepositor';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'driftUserStats';
+					name: 'authority';
 					isMut: false;
-					isSigner: false;
+					isSigner: true;
 				},
 				{
-					name: 'driftUser';
+					name: 'driftUserStats';
 					isMut: false;
 					isSigner: false;
 				}
 			];
 			args: [
 				{
-					name: 'withdrawAmount';
-					type: 'u64';
-				},
-				{
-					name: 'withdrawUnit';
+					name: 'lockupPeriod';
 					type: {
-						defined: 'WithdrawUnit';
+						defined: 'FuelLockupPeriod';
 					};
 				}
 			];
 		},
 		{
-			name: 'mangerCancelWithdrawRequest';
+			name: 'cancelRequestWithdraw';
 			accounts: [
 				{
 					name: 'vault';
@@ -1164,7 +1208,12 @@ export type DriftVaults = {
 					isSigner: false;
 				},
 				{
-					name: 'manager';
+					name: 'vaultDepositor';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'authority';
 					isMut: false;
 					isSigner: true;
 				},
@@ -1182,7 +1231,7 @@ export type DriftVaults = {
 			args: [];
 		},
 		{
-			name: 'managerWithdraw';
+			name: 'withdraw';
 			accounts: [
 				{
 					name: 'vault';
@@ -1190,7 +1239,12 @@ export type DriftVaults = {
 					isSigner: false;
 				},
 				{
-					name: 'manager';
+					name: 'vaultDepositor';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'authority';
 					isMut: false;
 					isSigner: true;
 				},
@@ -1243,7 +1297,7 @@ export type DriftVaults = {
 			args: [];
 		},
 		{
-			name: 'managerUpdateFuelDistributionMode';
+			name: 'liquidate';
 			accounts: [
 				{
 					name: 'vault';
@@ -1251,38 +1305,32 @@ export type DriftVaults = {
 					isSigner: false;
 				},
 				{
-					name: 'manager';
-					isMut: false;
-					isSigner: true;
-				}
-			];
-			args: [
-				{
-					name: 'fuelDistributionMode';
-					type: 'u8';
-				}
-			];
-		},
-		{
-			name: 'adminInitFeeUpdate';
-			accounts: [
-				{
-					name: 'vault';
+					name: 'vaultDepositor';
 					isMut: true;
 					isSigner: false;
 				},
+				{
+					name: 'authority';
+					isMut: false;
+					isSigner: false;
+				},
 				{
 					name: 'admin';
 					isMut: true;
 					isSigner: true;
 				},
 				{
-					name: 'feeUpdate';
+					name: 'driftUserStats';
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'systemProgram';
+					name: 'driftUser';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftProgram';
 					isMut: false;
 					isSigner: false;
 				}
@@ -1290,7 +1338,7 @@ export type DriftVaults = {
 			args: [];
 		},
 		{
-			name: 'adminDeleteFeeUpdate';
+			name: 'resetDelegate';
 			accounts: [
 				{
 					name: 'vault';
@@ -1298,20 +1346,20 @@ export type DriftVaults = {
 					isSigner: false;
 				},
 				{
-					name: 'admin';
+					name: 'driftUser';
 					isMut: true;
-					isSigner: true;
+					isSigner: false;
 				},
 				{
-					name: 'feeUpdate';
-					isMut: true;
+					name: 'driftProgram';
+					isMut: false;
 					isSigner: false;
 				}
 			];
 			args: [];
 		},
 		{
-			name: 'adminUpdateVaultClass';
+			name: 'resetFuelSeason';
 			accounts: [
 				{
 					name: 'vault';
@@ -1319,74 +1367,45 @@ export type DriftVaults = {
 					isSigner: false;
 				},
 				{
-					name: 'admin';
+					name: 'vaultDepositor';
 					isMut: true;
-					isSigner: true;
-				},
-				{
-					name: 'systemProgram';
-					isMut: false;
 					isSigner: false;
-				}
-			];
-			args: [
-				{
-					name: 'newVaultClass';
-					type: 'u8';
-				}
-			];
-		},
-		{
-			name: 'managerUpdateFees';
-			accounts: [
+				},
 				{
-					name: 'vault';
+					name: 'fuelSeasonSnapshot';
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'manager';
+					name: 'admin';
 					isMut: false;
 					isSigner: true;
 				},
 				{
-					name: 'feeUpdate';
+					name: 'payer';
 					isMut: true;
-					isSigner: false;
-				}
-			];
-			args: [
-				{
-					name: 'params';
-					type: {
-						defined: 'ManagerUpdateFeesParams';
-					};
-				}
-			];
-		},
-		{
-			name: 'managerCancelFeeUpdate';
-			accounts: [
+					isSigner: true;
+				},
 				{
-					name: 'vault';
+					name: 'driftUserStats';
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'manager';
+					name: 'driftState';
 					isMut: false;
-					isSigner: true;
+					isSigner: false;
 				},
 				{
-					name: 'feeUpdate';
-					isMut: true;
+					name: 'systemProgram';
+					isMut: false;
 					isSigner: false;
 				}
 			];
 			args: [];
 		},
 		{
-			name: 'applyProfitShare';
+			name: 'resetVaultFuelSeason';
 			accounts: [
 				{
 					name: 'vault';
@@ -1394,45 +1413,20 @@ export type DriftVaults = {
 					isSigner: false;
 				},
 				{
-					name: 'vaultDepositor';
-					isMut: true;
-					isSigner: false;
-				},
-				{
-					name: 'manager';
+					name: 'admin';
 					isMut: false;
 					isSigner: true;
 				},
-				{
-					name: 'driftUserStats';
-					isMut: true;
-					isSigner: false;
-				},
-				{
-					name: 'driftUser';
-					isMut: true;
-					isSigner: false;
-				},
 				{
 					name: 'driftState';
 					isMut: false;
 					isSigner: false;
-				},
-				{
-					name: 'driftSigner';
-					isMut: false;
-					isSigner: false;
-				},
-				{
-					name: 'driftProgram';
-					isMut: false;
-					isSigner: false;
 				}
 			];
 			args: [];
 		},
 		{
-			name: 'applyRebase';
+			name: 'settleFuel';
 			accounts: [
 				{
 					name: 'vault';
@@ -1445,15 +1439,15 @@ export type DriftVaults = {
 					isSigner: false;
 				},
 				{
-					name: 'driftUser';
-					isMut: true;
+					name: 'driftUserStats';
+					isMut: false;
 					isSigner: false;
 				}
 			];
 			args: [];
 		},
 		{
-			name: 'applyRebaseTokenizedDepositor';
+			name: 'removeExpiredFuelBoost';
 			accounts: [
 				{
 					name: 'vault';
@@ -1461,20 +1455,20 @@ export type DriftVaults = {
 					isSigner: false;
 				},
 				{
-					name: 'tokenizedVaultDepositor';
+					name: 'vaultDepositor';
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'driftUser';
-					isMut: true;
+					name: 'driftUserStats';
+					isMut: false;
 					isSigner: false;
 				}
 			];
 			args: [];
 		},
 		{
-			name: 'forceWithdraw';
+			name: 'managerBorrow';
 			accounts: [
 				{
 					name: 'vault';
@@ -1482,19 +1476,14 @@ export type DriftVaults = {
 					isSigner: false;
 				},
 				{
-					name: 'manager';
-					isMut: false;
-					isSigner: true;
-				},
-				{
-					name: 'vaultDepositor';
+					name: 'vaultTokenAccount';
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'vaultTokenAccount';
-					isMut: true;
-					isSigner: false;
+					name: 'manager';
+					isMut: false;
+					isSigner: true;
 				},
 				{
 					name: 'driftUserStats';
@@ -1537,61 +1526,65 @@ export type DriftVaults = {
 					isSigner: false;
 				}
 			];
-			args: [];
+			args: [
+				{
+					name: 'borrowSpotMarketIndex';
+					type: 'u16';
+				},
+				{
+					name: 'borrowAmount';
+					type: 'u64';
+				}
+			];
 		},
 		{
-			name: 'initializeInsuranceFundStake';
+			name: 'managerRepay';
 			accounts: [
 				{
 					name: 'vault';
 					isMut: true;
 					isSigner: false;
 				},
+				{
+					name: 'vaultTokenAccount';
+					isMut: true;
+					isSigner: false;
+				},
 				{
 					name: 'manager';
 					isMut: false;
 					isSigner: true;
 				},
 				{
-					name: 'payer';
+					name: 'driftUserStats';
 					isMut: true;
-					isSigner: true;
-				},
-				{
-					name: 'rent';
-					isMut: false;
 					isSigner: false;
 				},
 				{
-					name: 'driftSpotMarket';
+					name: 'driftUser';
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'driftSpotMarketMint';
+					name: 'driftState';
 					isMut: false;
 					isSigner: false;
 				},
 				{
-					name: 'vaultTokenAccount';
+					name: 'driftSpotMarketVault';
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'insuranceFundStake';
-					isMut: true;
+					name: 'driftSigner';
+					isMut: false;
 					isSigner: false;
 				},
 				{
-					name: 'driftUserStats';
+					name: 'userTokenAccount';
 					isMut: true;
 					isSigner: false;
 				},
-				{
-					name: 'driftState';
-					isMut: false;
-					isSigner: false;
-				},
 				{
 					name: 'driftProgram';
 					isMut: false;
@@ -1601,22 +1594,27 @@ export type DriftVaults = {
 					name: 'tokenProgram';
 					isMut: false;
 					isSigner: false;
-				},
-				{
-					name: 'systemProgram';
-					isMut: false;
-					isSigner: false;
 				}
 			];
 			args: [
 				{
-					name: 'marketIndex';
+					name: 'repaySpotMarketIndex';
 					type: 'u16';
+				},
+				{
+					name: 'repayAmount';
+					type: 'u64';
+				},
+				{
+					name: 'repayValue';
+					type: {
+						option: 'u64';
+					};
 				}
 			];
 		},
 		{
-			name: 'addInsuranceFundStake';
+			name: 'managerUpdateBorrow';
 			accounts: [
 				{
 					name: 'vault';
@@ -1625,41 +1623,52 @@ export type DriftVaults = {
 				},
 				{
 					name: 'manager';
-					isMut: true;
+					isMut: false;
 					isSigner: true;
 				},
 				{
-					name: 'driftSpotMarket';
+					name: 'driftUserStats';
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'driftSpotMarketVault';
+					name: 'driftUser';
 					isMut: true;
 					isSigner: false;
-				},
+				}
+			];
+			args: [
 				{
-					name: 'insuranceFundStake';
+					name: 'newBorrowValue';
+					type: 'u64';
+				}
+			];
+		},
+		{
+			name: 'managerDeposit';
+			accounts: [
+				{
+					name: 'vault';
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'insuranceFundVault';
-					isMut: true;
-					isSigner: false;
+					name: 'manager';
+					isMut: false;
+					isSigner: true;
 				},
 				{
-					name: 'managerTokenAccount';
+					name: 'vaultTokenAccount';
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'vaultIfTokenAccount';
+					name: 'driftUserStats';
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'driftUserStats';
+					name: 'driftUser';
 					isMut: true;
 					isSigner: false;
 				},
@@ -1669,8 +1678,13 @@ export type DriftVaults = {
 					isSigner: false;
 				},
 				{
-					name: 'driftSigner';
-					isMut: false;
+					name: 'driftSpotMarketVault';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'userTokenAccount';
+					isMut: true;
 					isSigner: false;
 				},
 				{
@@ -1685,10 +1699,6 @@ export type DriftVaults = {
 				}
 			];
 			args: [
-				{
-					name: 'marketIndex';
-					type: 'u16';
-				},
 				{
 					name: 'amount';
 					type: 'u64';
@@ -1696,7 +1706,7 @@ export type DriftVaults = {
 			];
 		},
 		{
-			name: 'requestRemoveInsuranceFundStake';
+			name: 'managerRequestWithdraw';
 			accounts: [
 				{
 					name: 'vault';
@@ -1708,45 +1718,32 @@ export type DriftVaults = {
 					isMut: false;
 					isSigner: true;
 				},
-				{
-					name: 'driftSpotMarket';
-					isMut: true;
-					isSigner: false;
-				},
-				{
-					name: 'insuranceFundStake';
-					isMut: true;
-					isSigner: false;
-				},
-				{
-					name: 'insuranceFundVault';
-					isMut: true;
-					isSigner: false;
-				},
 				{
 					name: 'driftUserStats';
-					isMut: true;
+					isMut: false;
 					isSigner: false;
 				},
 				{
-					name: 'driftProgram';
+					name: 'driftUser';
 					isMut: false;
 					isSigner: false;
 				}
 			];
 			args: [
 				{
-					name: 'marketIndex';
-					type: 'u16';
+					name: 'withdrawAmount';
+					type: 'u64';
 				},
 				{
-					name: 'amount';
-					type: 'u64';
+					name: 'withdrawUnit';
+					type: {
+						defined: 'WithdrawUnit';
+					};
 				}
 			];
 		},
 		{
-			name: 'removeInsuranceFundStake';
+			name: 'mangerCancelWithdrawRequest';
 			accounts: [
 				{
 					name: 'vault';
@@ -1759,32 +1756,43 @@ export type DriftVaults = {
 					isSigner: true;
 				},
 				{
-					name: 'driftSpotMarket';
-					isMut: true;
+					name: 'driftUserStats';
+					isMut: false;
 					isSigner: false;
 				},
 				{
-					name: 'insuranceFundStake';
-					isMut: true;
+					name: 'driftUser';
+					isMut: false;
 					isSigner: false;
-				},
+				}
+			];
+			args: [];
+		},
+		{
+			name: 'managerWithdraw';
+			accounts: [
 				{
-					name: 'insuranceFundVault';
+					name: 'vault';
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'managerTokenAccount';
+					name: 'manager';
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: 'vaultTokenAccount';
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'vaultIfTokenAccount';
+					name: 'driftUserStats';
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'driftUserStats';
+					name: 'driftUser';
 					isMut: true;
 					isSigner: false;
 				},
@@ -1794,30 +1802,35 @@ export type DriftVaults = {
 					isSigner: false;
 				},
 				{
-					name: 'driftSigner';
-					isMut: false;
+					name: 'driftSpotMarketVault';
+					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'driftProgram';
+					name: 'driftSigner';
 					isMut: false;
 					isSigner: false;
 				},
 				{
-					name: 'tokenProgram';
+					name: 'userTokenAccount';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftProgram';
 					isMut: false;
 					isSigner: false;
-				}
-			];
-			args: [
+				},
 				{
-					name: 'marketIndex';
-					type: 'u16';
+					name: 'tokenProgram';
+					isMut: false;
+					isSigner: false;
 				}
 			];
+			args: [];
 		},
 		{
-			name: 'cancelRequestRemoveInsuranceFundStake';
+			name: 'managerUpdateFuelDistributionMode';
 			accounts: [
 				{
 					name: 'vault';
@@ -1828,42 +1841,71 @@ export type DriftVaults = {
 					name: 'manager';
 					isMut: false;
 					isSigner: true;
-				},
+				}
+			];
+			args: [
 				{
-					name: 'driftSpotMarket';
-					isMut: true;
+					name: 'fuelDistributionMode';
+					type: 'u8';
+				}
+			];
+		},
+		{
+			name: 'initializeFuelDistribution';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: false;
 					isSigner: false;
 				},
 				{
-					name: 'insuranceFundStake';
+					name: 'manager';
 					isMut: true;
-					isSigner: false;
+					isSigner: true;
 				},
 				{
-					name: 'insuranceFundVault';
+					name: 'fuelDistribution';
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'driftUserStats';
-					isMut: true;
+					name: 'systemProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [];
+		},
+		{
+			name: 'managerUpdateFuelDistribution';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: false;
 					isSigner: false;
 				},
 				{
-					name: 'driftProgram';
+					name: 'manager';
 					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: 'fuelDistribution';
+					isMut: true;
 					isSigner: false;
 				}
 			];
 			args: [
 				{
-					name: 'marketIndex';
-					type: 'u16';
+					name: 'params';
+					type: {
+						defined: 'ManagerUpdateFuelDistributionParams';
+					};
 				}
 			];
 		},
 		{
-			name: 'transferVaultDepositorShares';
+			name: 'updateFuelDistribution';
 			accounts: [
 				{
 					name: 'vault';
@@ -1871,83 +1913,149 @@ export type DriftVaults = {
 					isSigner: false;
 				},
 				{
-					name: 'vaultDepositor';
+					name: 'fuelDistribution';
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'authority';
+					name: 'driftUserStats';
 					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [];
+		},
+		{
+			name: 'initializeNavPolicy';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'manager';
+					isMut: true;
 					isSigner: true;
 				},
 				{
-					name: 'toVaultDepositor';
+					name: 'navPolicy';
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'driftUser';
-					isMut: true;
+					name: 'systemProgram';
+					isMut: false;
 					isSigner: false;
 				}
 			];
 			args: [
 				{
-					name: 'amount';
-					type: 'u64';
-				},
-				{
-					name: 'withdrawUnit';
+					name: 'params';
 					type: {
-						defined: 'WithdrawUnit';
+						defined: 'NavPolicyParams';
 					};
 				}
 			];
 		},
 		{
-			name: 'protocolRequestWithdraw';
+			name: 'managerUpdateNavPolicy';
 			accounts: [
 				{
 					name: 'vault';
-					isMut: true;
+					isMut: false;
 					isSigner: false;
 				},
 				{
-					name: 'vaultProtocol';
+					name: 'manager';
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: 'navPolicy';
 					isMut: true;
 					isSigner: false;
+				}
+			];
+			args: [
+				{
+					name: 'params';
+					type: {
+						defined: 'NavPolicyParams';
+					};
+				}
+			];
+		},
+		{
+			name: 'resetNavCircuitBreaker';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: false;
+					isSigner: false;
 				},
 				{
-					name: 'protocol';
+					name: 'authority';
 					isMut: false;
 					isSigner: true;
 				},
 				{
-					name: 'driftUserStats';
+					name: 'navPolicy';
+					isMut: true;
+					isSigner: false;
+				}
+			];
+			args: [];
+		},
+		{
+			name: 'tripNavBreaker';
+			accounts: [
+				{
+					name: 'vault';
 					isMut: false;
 					isSigner: false;
 				},
+				{
+					name: 'navPolicy';
+					isMut: true;
+					isSigner: false;
+				},
 				{
 					name: 'driftUser';
 					isMut: false;
 					isSigner: false;
 				}
 			];
-			args: [
+			args: [];
+		},
+		{
+			name: 'checkMaxDrawdown';
+			accounts: [
 				{
-					name: 'withdrawAmount';
-					type: 'u64';
+					name: 'vault';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'withdrawUnit';
-					type: {
-						defined: 'WithdrawUnit';
-					};
+					name: 'navPolicy';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftUser';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftProgram';
+					isMut: false;
+					isSigner: false;
 				}
 			];
+			args: [];
 		},
 		{
-			name: 'protocolCancelWithdrawRequest';
+			name: 'managerReenableTrading';
 			accounts: [
 				{
 					name: 'vault';
@@ -1955,22 +2063,22 @@ export type DriftVaults = {
 					isSigner: false;
 				},
 				{
-					name: 'vaultProtocol';
-					isMut: true;
-					isSigner: false;
-				},
-				{
-					name: 'protocol';
+					name: 'manager';
 					isMut: false;
 					isSigner: true;
 				},
 				{
-					name: 'driftUserStats';
-					isMut: false;
+					name: 'navPolicy';
+					isMut: true;
 					isSigner: false;
 				},
 				{
 					name: 'driftUser';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftProgram';
 					isMut: false;
 					isSigner: false;
 				}
@@ -1978,7 +2086,7 @@ export type DriftVaults = {
 			args: [];
 		},
 		{
-			name: 'protocolWithdraw';
+			name: 'depositorReenableTrading';
 			accounts: [
 				{
 					name: 'vault';
@@ -1986,3222 +2094,8539 @@ export type DriftVaults = {
 					isSigner: false;
 				},
 				{
-					name: 'vaultProtocol';
-					isMut: true;
+					name: 'vaultDepositor';
+					isMut: false;
 					isSigner: false;
 				},
 				{
-					name: 'protocol';
+					name: 'authority';
 					isMut: false;
 					isSigner: true;
 				},
 				{
-					name: 'vaultTokenAccount';
+					name: 'navPolicy';
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'driftUserStats';
+					name: 'driftUser';
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'driftUser';
-					isMut: true;
+					name: 'driftProgram';
+					isMut: false;
 					isSigner: false;
-				},
+				}
+			];
+			args: [];
+		},
+		{
+			name: 'initializeNavHistory';
+			accounts: [
 				{
-					name: 'driftState';
+					name: 'vault';
 					isMut: false;
 					isSigner: false;
 				},
 				{
-					name: 'driftSpotMarketVault';
+					name: 'payer';
+					isMut: true;
+					isSigner: true;
+				},
+				{
+					name: 'navHistory';
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'driftSigner';
+					name: 'systemProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [];
+		},
+		{
+			name: 'snapshotVault';
+			accounts: [
+				{
+					name: 'vault';
 					isMut: false;
 					isSigner: false;
 				},
 				{
-					name: 'userTokenAccount';
+					name: 'navHistory';
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'driftProgram';
+					name: 'driftUser';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [];
+		},
+		{
+			name: 'initializeManagerStats';
+			accounts: [
+				{
+					name: 'vault';
 					isMut: false;
 					isSigner: false;
 				},
 				{
-					name: 'tokenProgram';
+					name: 'payer';
+					isMut: true;
+					isSigner: true;
+				},
+				{
+					name: 'managerStats';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'systemProgram';
 					isMut: false;
 					isSigner: false;
 				}
 			];
 			args: [];
-		}
-	];
-	accounts: [
+		},
 		{
-			name: 'feeUpdate';
-			type: {
-				kind: 'struct';
-				fields: [
-					{
-						name: 'incomingUpdateTs';
-						type: 'i64';
-					},
-					{
-						name: 'incomingManagementFee';
-						type: 'i64';
-					},
-					{
-						name: 'incomingProfitShare';
-						type: 'u32';
-					},
-					{
-						name: 'incomingHurdleRate';
-						type: 'u32';
-					},
-					{
-						name: 'padding';
-						type: {
-							array: ['u128', 10];
-						};
-					},
-					{
-						name: 'padding2';
-						type: {
-							array: ['u8', 8];
-						};
-					}
-				];
+			name: 'updateManagerStats';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'managerStats';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftUser';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [];
+		},
+		{
+			name: 'previewDeposit';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'vaultDepositor';
+					isMut: false;
+					isSigner: false;
+					isOptional: true;
+				},
+				{
+					name: 'driftUser';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [
+				{
+					name: 'amount';
+					type: 'u64';
+				}
+			];
+			returns: {
+				defined: 'DepositPreview';
 			};
 		},
 		{
-			name: 'tokenizedVaultDepositor';
-			type: {
-				kind: 'struct';
-				fields: [
-					{
-						name: 'vault';
-						docs: ['The vault deposited into'];
-						type: 'publicKey';
-					},
-					{
-						name: 'pubkey';
-						docs: [
-							"The vault depositor account's pubkey. It is a pda of vault"
-						];
-						type: 'publicKey';
-					},
-					{
-						name: 'mint';
-						docs: [
-							'The token mint for tokenized shares owned by this VaultDepositor'
-						];
-						type: 'publicKey';
-					},
-					{
-						name: 'vaultShares';
-						docs: [
-							"share of vault owned by this depositor. vault_shares / vault.total_shares is depositor's ownership of vault_equity"
-						];
-						type: 'u128';
-					},
-					{
-						name: 'lastVaultShares';
-						docs: [
-							'stores the vault_shares from the most recent liquidity event (redeem or issuance) before a spl token',
-							'CPI is done, used to track invariants'
-						];
-						type: 'u128';
-					},
-					{
-						name: 'lastValidTs';
-						docs: ['creation ts of vault depositor'];
-						type: 'i64';
-					},
-					{
-						name: 'netDeposits';
-						docs: ['lifetime net deposits of vault depositor for the vault'];
-						type: 'i64';
-					},
-					{
-						name: 'totalDeposits';
-						docs: ['lifetime total deposits'];
-						type: 'u64';
-					},
-					{
-						name: 'totalWithdraws';
-						docs: ['lifetime total withdraws'];
-						type: 'u64';
-					},
-					{
-						name: 'cumulativeProfitShareAmount';
-						docs: [
-							'the token amount of gains the vault depositor has paid performance fees on'
-						];
-						type: 'i64';
-					},
-					{
-						name: 'profitShareFeePaid';
-						type: 'u64';
-					},
-					{
-						name: 'vaultSharesBase';
-						docs: [
-							'The exponent for vault_shares decimal places at the time the tokenized vault depositor was initialized.',
-							'If the vault undergoes a rebase, this TokenizedVaultDepositor can no longer issue new tokens, only redeem',
-							'is possible.'
-						];
-						type: 'u32';
-					},
-					{
-						name: 'bump';
-						docs: ['The bump for the vault pda'];
-						type: 'u8';
-					},
-					{
-						name: 'padding1';
-						type: {
-							array: ['u8', 3];
-						};
-					},
-					{
-						name: 'padding';
-						type: {
-							array: ['u64', 10];
-						};
-					}
-				];
+			name: 'previewWithdraw';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'vaultDepositor';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'driftUser';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [
+				{
+					name: 'withdrawAmount';
+					type: 'u64';
+				},
+				{
+					name: 'withdrawUnit';
+					type: {
+						defined: 'WithdrawUnit';
+					};
+				}
+			];
+			returns: {
+				defined: 'WithdrawPreview';
 			};
 		},
 		{
-			name: 'vaultDepositor';
-			type: {
-				kind: 'struct';
-				fields: [
-					{
-						name: 'vault';
-						docs: ['The vault deposited into'];
-						type: 'publicKey';
-					},
-					{
-						name: 'pubkey';
-						docs: [
-							"The vault depositor account's pubkey. It is a pda of vault and authority"
-						];
-						type: 'publicKey';
-					},
-					{
-						name: 'authority';
-						docs: [
-							'The authority is the address w permission to deposit/withdraw'
-						];
-						type: 'publicKey';
-					},
-					{
-						name: 'vaultShares';
-						docs: [
-							"share of vault owned by this depositor. vault_shares / vault.total_shares is depositor's ownership of vault_equity"
-						];
-						type: 'u128';
-					},
-					{
-						name: 'lastWithdrawRequest';
-						docs: ['last withdraw request'];
-						type: {
-							defined: 'WithdrawRequest';
-						};
-					},
-					{
-						name: 'lastValidTs';
-						docs: ['creation ts of vault depositor'];
-						type: 'i64';
-					},
-					{
-						name: 'netDeposits';
-						docs: ['lifetime net deposits of vault depositor for the vault'];
-						type: 'i64';
-					},
-					{
-						name: 'totalDeposits';
-						docs: ['lifetime total deposits'];
-						type: 'u64';
-					},
-					{
-						name: 'totalWithdraws';
-						docs: ['lifetime total withdraws'];
-						type: 'u64';
-					},
-					{
-						name: 'cumulativeProfitShareAmount';
-						docs: [
-							'the token amount of gains the vault depositor has paid performance fees on'
-						];
-						type: 'i64';
-					},
-					{
-						name: 'profitShareFeePaid';
-						type: 'u64';
-					},
-					{
-						name: 'vaultSharesBase';
-						docs: ['the exponent for vault_shares decimal places'];
-						type: 'u32';
-					},
-					{
-						name: 'lastFuelUpdateTs';
-						type: 'u32';
-					},
-					{
-						name: 'cumulativeFuelPerShareAmount';
-						docs: ['precision: FUEL_SHARE_PRECISION'];
-						type: 'u128';
-					},
-					{
-						name: 'fuelAmount';
-						docs: ['precision: none'];
-						type: 'u128';
-					},
-					{
-						name: 'padding';
-						type: {
-							array: ['u64', 4];
-						};
-					}
-				];
+			name: 'previewDepositorEquity';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'vaultDepositor';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'driftUser';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [];
+			returns: {
+				defined: 'DepositorEquityPreview';
 			};
 		},
 		{
-			name: 'vaultProtocol';
-			type: {
-				kind: 'struct';
-				fields: [
-					{
-						name: 'protocol';
-						docs: [
-							'The protocol, company, or entity that services the product using this vault.',
-							'The protocol is not allowed to deposit into the vault but can profit share and collect annual fees just like the manager.'
-						];
-						type: 'publicKey';
-					},
-					{
-						name: 'protocolProfitAndFeeShares';
-						docs: [
-							'The shares from profit share and annual fee unclaimed by the protocol.'
-						];
-						type: 'u128';
-					},
-					{
-						name: 'protocolFee';
-						docs: [
-							'The annual fee charged on deposits by the protocol (traditional hedge funds typically charge 2% per year on assets under management).',
-							"Unlike the management fee this can't be negative."
-						];
-						type: 'u64';
-					},
-					{
-						name: 'protocolTotalWithdraws';
-						docs: ['Total withdraws for the protocol'];
-						type: 'u64';
-					},
-					{
-						name: 'protocolTotalFee';
-						docs: [
-							'Total fee charged by the protocol (annual management fee + profit share).',
-							"Unlike the management fee this can't be negative."
-						];
-						type: 'u64';
-					},
-					{
-						name: 'protocolTotalProfitShare';
-						docs: ['Total profit share charged by the protocol'];
-						type: 'u64';
-					},
-					{
-						name: 'lastProtocolWithdrawRequest';
-						type: {
-							defined: 'WithdrawRequest';
-						};
-					},
-					{
-						name: 'protocolProfitShare';
-						docs: [
-							'Percentage the protocol charges on all profits realized by depositors: PERCENTAGE_PRECISION'
-						];
-						type: 'u32';
-					},
-					{
-						name: 'bump';
-						type: 'u8';
-					},
-					{
-						name: 'version';
-						type: 'u8';
-					},
-					{
-						name: 'padding';
-						type: {
-							array: ['u8', 2];
-						};
-					}
-				];
+			name: 'previewFees';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'driftUser';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [];
+			returns: {
+				defined: 'FeesPreview';
 			};
 		},
 		{
-			name: 'vault';
-			type: {
-				kind: 'struct';
-				fields: [
-					{
-						name: 'name';
-						docs: [
-							'The name of the vault. Vault pubkey is derived from this name.'
-						];
-						type: {
-							array: ['u8', 32];
-						};
-					},
-					{
-						name: 'pubkey';
-						docs: [
-							"The vault's pubkey. It is a pda of name and also used as the authority for drift user"
-						];
-						type: 'publicKey';
-					},
-					{
-						name: 'manager';
-						docs: [
-							'The manager of the vault who has ability to update vault params'
-						];
-						type: 'publicKey';
-					},
-					{
-						name: 'tokenAccount';
-						docs: [
-							'The vaults token account. Used to receive tokens between deposits and withdrawals'
-						];
-						type: 'publicKey';
-					},
-					{
-						name: 'userStats';
-						docs: ['The drift user stats account for the vault'];
-						type: 'publicKey';
-					},
-					{
-						name: 'user';
-						docs: ['The drift user account for the vault'];
-						type: 'publicKey';
-					},
-					{
-						name: 'delegate';
-						docs: [
-							'The vaults designated delegate for drift user account',
-							'can differ from actual user delegate if vault is in liquidation'
-						];
-						type: 'publicKey';
-					},
-					{
-						name: 'liquidationDelegate';
-						docs: ['The delegate handling liquidation for depositor'];
-						type: 'publicKey';
-					},
-					{
-						name: 'userShares';
-						docs: [
-							'The sum of all shares held by the users (vault depositors)'
-						];
-						type: 'u128';
-					},
-					{
-						name: 'totalShares';
-						docs: [
-							'The sum of all shares: deposits from users, manager deposits, manager profit/fee, and protocol profit/fee.',
-							'The manager deposits are total_shares - user_shares - protocol_profit_and_fee_shares.'
-						];
-						type: 'u128';
-					},
-					{
-						name: 'lastFeeUpdateTs';
-						docs: ['Last fee update unix timestamp'];
-						type: 'i64';
-					},
-					{
-						name: 'liquidationStartTs';
-						docs: ['When the liquidation starts'];
-						type: 'i64';
-					},
-					{
-						name: 'redeemPeriod';
-						docs: [
-							'The period (in seconds) that a vault depositor must wait after requesting a withdrawal to finalize withdrawal.',
-							'Currently, the maximum is 90 days.'
-						];
-						type: 'i64';
-					},
-					{
-						name: 'totalWithdrawRequested';
-						docs: ['The sum of all outstanding withdraw requests'];
-						type: 'u64';
-					},
-					{
-						name: 'maxTokens';
-						docs: [
-							'Max token capacity, once hit/passed vault will reject new deposits (updatable)'
-						];
-						type: 'u64';
-					},
-					{
-						name: 'managementFee';
-						docs: [
-							'The annual fee charged on deposits by the manager.',
-							'Traditional funds typically charge 2% per year on assets under management.'
-						];
-						type: 'i64';
-					},
-					{
-						name: 'initTs';
-						docs: ['Timestamp vault initialized'];
-						type: 'i64';
-					},
-					{
-						name: 'netDeposits';
-						docs: ['The net deposits for the vault'];
-						type: 'i64';
-					},
-					{
-						name: 'managerNetDeposits';
-						docs: ['The net deposits for the manager'];
-						type: 'i64';
-					},
-					{
-						name: 'totalDeposits';
-						docs: ['Total deposits'];
-						type: 'u64';
-					},
-					{
-						name: 'totalWithdraws';
-						docs: ['Total withdraws'];
-						type: 'u64';
-					},
-					{
-						name: 'managerTotalDeposits';
-						docs: ['Total deposits for the manager'];
-						type: 'u64';
-					},
-					{
-						name: 'managerTotalWithdraws';
-						docs: ['Total withdraws for the manager'];
-						type: 'u64';
-					},
-					{
-						name: 'managerTotalFee';
-						docs: ['Total management fee accrued by the manager'];
-						type: 'i64';
-					},
-					{
-						name: 'managerTotalProfitShare';
-						docs: ['Total profit share accrued by the manager'];
-						type: 'u64';
-					},
-					{
-						name: 'minDepositAmount';
-						docs: ['The minimum deposit amount'];
-						type: 'u64';
-					},
-					{
-						name: 'lastManagerWithdrawRequest';
-						type: {
-							defined: 'WithdrawRequest';
-						};
-					},
-					{
-						name: 'sharesBase';
-						docs: [
-							'The base 10 exponent of the shares (given massive share inflation can occur at near zero vault equity)'
-						];
-						type: 'u32';
-					},
-					{
-						name: 'profitShare';
-						docs: [
-							'Percentage the manager charges on all profits realized by depositors: PERCENTAGE_PRECISION'
-						];
-						type: 'u32';
-					},
-					{
-						name: 'hurdleRate';
-						docs: [
-							'Vault manager only collect incentive fees during periods when returns are higher than this amount: PERCENTAGE_PRECISION'
-						];
-						type: 'u32';
-					},
-					{
-						name: 'spotMarketIndex';
-						docs: [
-							'The spot market index the vault deposits into/withdraws from'
-						];
-						type: 'u16';
-					},
-					{
-						name: 'bump';
-						docs: ['The bump for the vault pda'];
-						type: 'u8';
-					},
-					{
-						name: 'permissioned';
-						docs: ['Whether anybody can be a depositor'];
-						type: 'bool';
-					},
-					{
-						name: 'vaultProtocol';
-						docs: ['The optional [`VaultProtocol`] account.'];
-						type: 'bool';
-					},
-					{
-						name: 'fuelDistributionMode';
-						docs: [
-							'How fuel distribution should be treated [`FuelDistributionMode`]. Default is `UsersOnly`'
-						];
-						type: 'u8';
-					},
-					{
-						name: 'feeUpdateStatus';
-						docs: [
-							'Whether the vault has a FeeUpdate account [`FeeUpdateStatus`]. Default is `FeeUpdateStatus::None`',
-							'After a `FeeUpdate` account is created and the manager has staged a fee update, the status is set to `PendingFeeUpdate`.',
-							'And instructsions that may finalize the fee update must include the `FeeUpdate` account with `remaining_accounts`.'
-						];
-						type: 'u8';
-					},
-					{
-						name: 'vaultClass';
-						docs: [
-							'The class of the vault [`VaultClass`]. Default is `VaultClass::Normal`'
-						];
-						type: 'u8';
-					},
-					{
-						name: 'lastCumulativeFuelPerShareTs';
-						docs: ['The timestamp cumulative_fuel_per_share was last updated'];
-						type: 'u32';
-					},
-					{
-						name: 'cumulativeFuelPerShare';
-						docs: [
-							'The cumulative fuel per share (scaled up by 1e6 to avoid losing precision)'
-						];
-						type: 'u128';
-					},
-					{
-						name: 'cumulativeFuel';
-						docs: ['The total fuel accumulated'];
-						type: 'u128';
-					},
-					{
-						name: 'managerBorrowedValue';
-						docs: [
-							'The total value (in deposit asset) of borrows the manager has outstanding.',
-							'Purely for informational purposes for assets that have left the vault that the manager',
-							'is expected to return.'
-						];
-						type: 'u64';
-					},
-					{
-						name: 'padding';
-						type: {
-							array: ['u64', 2];
-						};
-					}
-				];
-			};
-		}
-	];
-	types: [
+			name: 'initializeRewardStream';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'manager';
+					isMut: true;
+					isSigner: true;
+				},
+				{
+					name: 'rewardStream';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'mint';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'tokenAccount';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'tokenProgram';
+					isMut: false;
+					isSigner: false;
+					docs: [
+						'The token program that owns the reward mint, either SPL Token or Token-2022'
+					];
+				},
+				{
+					name: 'systemProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [
+				{
+					name: 'params';
+					type: {
+						defined: 'InitializeRewardStreamParams';
+					};
+				}
+			];
+		},
 		{
-			name: 'InitializeTokenizedVaultDepositorParams';
-			type: {
-				kind: 'struct';
-				fields: [
-					{
-						name: 'tokenName';
-						type: 'string';
-					},
-					{
-						name: 'tokenSymbol';
-						type: 'string';
-					},
-					{
-						name: 'tokenUri';
-						type: 'string';
-					},
-					{
-						name: 'decimals';
-						type: 'u8';
-					}
-				];
-			};
+			name: 'fundRewardStream';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'manager';
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: 'rewardStream';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'mint';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'tokenAccount';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'managerTokenAccount';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'tokenProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [
+				{
+					name: 'amount';
+					type: 'u64';
+				}
+			];
 		},
 		{
-			name: 'VaultWithProtocolParams';
-			type: {
-				kind: 'struct';
-				fields: [
-					{
-						name: 'name';
-						type: {
-							array: ['u8', 32];
-						};
-					},
-					{
-						name: 'redeemPeriod';
-						type: 'i64';
-					},
-					{
-						name: 'maxTokens';
-						type: 'u64';
-					},
-					{
-						name: 'managementFee';
-						type: 'i64';
-					},
-					{
-						name: 'minDepositAmount';
-						type: 'u64';
-					},
-					{
-						name: 'profitShare';
-						type: 'u32';
-					},
-					{
-						name: 'hurdleRate';
-						type: 'u32';
-					},
-					{
-						name: 'spotMarketIndex';
-						type: 'u16';
-					},
-					{
-						name: 'permissioned';
-						type: 'bool';
-					},
-					{
-						name: 'vaultProtocol';
-						type: {
-							defined: 'VaultProtocolParams';
-						};
-					}
-				];
-			};
+			name: 'closeRewardStream';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'manager';
+					isMut: true;
+					isSigner: true;
+				},
+				{
+					name: 'rewardStream';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'mint';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'tokenAccount';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'managerTokenAccount';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'tokenProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [];
 		},
 		{
-			name: 'VaultProtocolParams';
-			type: {
-				kind: 'struct';
-				fields: [
-					{
-						name: 'protocol';
-						type: 'publicKey';
-					},
-					{
-						name: 'protocolFee';
-						type: 'u64';
-					},
-					{
-						name: 'protocolProfitShare';
-						type: 'u32';
-					}
-				];
-			};
+			name: 'initializeRewardStreamDepositor';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'rewardStream';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'vaultDepositor';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'authority';
+					isMut: false;
+					isSigner: true;
+					docs: [
+						'The depositor has to pass the reward stream depositor whenever its shares change, so only it can opt in'
+					];
+				},
+				{
+					name: 'rewardStreamDepositor';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'payer';
+					isMut: true;
+					isSigner: true;
+				},
+				{
+					name: 'systemProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [];
 		},
 		{
-			name: 'VaultParams';
-			type: {
-				kind: 'struct';
-				fields: [
-					{
-						name: 'name';
-						type: {
-							array: ['u8', 32];
-						};
-					},
-					{
-						name: 'redeemPeriod';
-						type: 'i64';
-					},
-					{
-						name: 'maxTokens';
-						type: 'u64';
-					},
-					{
-						name: 'managementFee';
-						type: 'i64';
-					},
-					{
-						name: 'minDepositAmount';
-						type: 'u64';
-					},
-					{
-						name: 'profitShare';
-						type: 'u32';
-					},
-					{
-						name: 'hurdleRate';
-						type: 'u32';
-					},
-					{
-						name: 'spotMarketIndex';
-						type: 'u16';
-					},
-					{
-						name: 'permissioned';
-						type: 'bool';
-					}
-				];
-			};
+			name: 'closeRewardStreamDepositor';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'rewardStream';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'vaultDepositor';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'rewardStreamDepositor';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'signer';
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: 'authority';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'mint';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'tokenAccount';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'userTokenAccount';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'tokenProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [];
 		},
 		{
-			name: 'ManagerUpdateFeesParams';
-			type: {
-				kind: 'struct';
-				fields: [
-					{
-						name: 'timelockDuration';
-						type: 'i64';
-					},
-					{
-						name: 'newManagementFee';
-						type: {
-							option: 'i64';
-						};
-					},
-					{
-						name: 'newProfitShare';
-						type: {
-							option: 'u32';
-						};
-					},
-					{
-						name: 'newHurdleRate';
-						type: {
-							option: 'u32';
-						};
-					}
-				];
-			};
-		},
-		{
-			name: 'UpdateVaultProtocolParams';
-			type: {
-				kind: 'struct';
-				fields: [
-					{
-						name: 'protocolFee';
-						type: {
-							option: 'u64';
-						};
-					},
-					{
-						name: 'protocolProfitShare';
-						type: {
-							option: 'u32';
-						};
-					}
-				];
-			};
-		},
-		{
-			name: 'UpdateVaultParams';
-			type: {
-				kind: 'struct';
-				fields: [
-					{
-						name: 'redeemPeriod';
-						type: {
-							option: 'i64';
-						};
-					},
-					{
-						name: 'maxTokens';
-						type: {
-							option: 'u64';
-						};
-					},
-					{
-						name: 'managementFee';
-						type: {
-							option: 'i64';
-						};
-					},
-					{
-						name: 'minDepositAmount';
-						type: {
-							option: 'u64';
-						};
-					},
-					{
-						name: 'profitShare';
-						type: {
-							option: 'u32';
-						};
-					},
-					{
-						name: 'hurdleRate';
-						type: {
-							option: 'u32';
-						};
-					},
-					{
-						name: 'permissioned';
-						type: {
-							option: 'bool';
-						};
-					}
-				];
-			};
-		},
-		{
-			name: 'WithdrawRequest';
-			type: {
-				kind: 'struct';
-				fields: [
-					{
-						name: 'shares';
-						docs: ['request shares of vault withdraw'];
-						type: 'u128';
-					},
-					{
-						name: 'value';
-						docs: [
-							'requested value (in vault spot_market_index) of shares for withdraw'
-						];
-						type: 'u64';
-					},
-					{
-						name: 'ts';
-						docs: ['request ts of vault withdraw'];
-						type: 'i64';
-					}
-				];
-			};
-		},
-		{
-			name: 'VaultDepositorAction';
-			type: {
-				kind: 'enum';
-				variants: [
-					{
-						name: 'Deposit';
-					},
-					{
-						name: 'WithdrawRequest';
-					},
-					{
-						name: 'CancelWithdrawRequest';
-					},
-					{
-						name: 'Withdraw';
-					},
-					{
-						name: 'FeePayment';
-					},
-					{
-						name: 'TokenizeShares';
-					},
-					{
-						name: 'RedeemTokens';
-					}
-				];
-			};
-		},
-		{
-			name: 'FeeUpdateAction';
-			type: {
-				kind: 'enum';
-				variants: [
-					{
-						name: 'Pending';
-					},
-					{
-						name: 'Applied';
-					},
-					{
-						name: 'Cancelled';
-					}
-				];
-			};
-		},
-		{
-			name: 'FuelDistributionMode';
-			type: {
-				kind: 'enum';
-				variants: [
-					{
-						name: 'UsersOnly';
-					},
-					{
-						name: 'UsersAndManager';
-					}
-				];
-			};
-		},
-		{
-			name: 'FeeUpdateStatus';
-			type: {
-				kind: 'enum';
-				variants: [
-					{
-						name: 'None';
-					},
-					{
-						name: 'PendingFeeUpdate';
-					}
-				];
-			};
-		},
-		{
-			name: 'VaultClass';
-			type: {
-				kind: 'enum';
-				variants: [
-					{
-						name: 'Normal';
-					},
-					{
-						name: 'Trusted';
-					}
-				];
-			};
-		},
-		{
-			name: 'WithdrawUnit';
-			type: {
-				kind: 'enum';
-				variants: [
-					{
-						name: 'Shares';
-					},
-					{
-						name: 'Token';
-					},
-					{
-						name: 'SharesPercent';
-					}
-				];
-			};
-		}
-	];
-	events: [
-		{
-			name: 'VaultRecord';
-			fields: [
+			name: 'claimRewardStream';
+			accounts: [
 				{
-					name: 'ts';
-					type: 'i64';
-					index: false;
+					name: 'vault';
+					isMut: false;
+					isSigner: false;
 				},
 				{
-					name: 'spotMarketIndex';
-					type: 'u16';
-					index: false;
+					name: 'rewardStream';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'vaultEquityBefore';
-					type: 'u64';
-					index: false;
-				}
-			];
-		},
-		{
-			name: 'VaultDepositorRecord';
-			fields: [
+					name: 'vaultDepositor';
+					isMut: false;
+					isSigner: false;
+				},
 				{
-					name: 'ts';
-					type: 'i64';
-					index: false;
+					name: 'rewardStreamDepositor';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'vault';
-					type: 'publicKey';
-					index: false;
+					name: 'authority';
+					isMut: false;
+					isSigner: true;
 				},
 				{
-					name: 'depositorAuthority';
-					type: 'publicKey';
-					index: false;
+					name: 'mint';
+					isMut: false;
+					isSigner: false;
 				},
 				{
-					name: 'action';
-					type: {
-						defined: 'VaultDepositorAction';
-					};
-					index: false;
+					name: 'tokenAccount';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'amount';
-					type: 'u64';
-					index: false;
+					name: 'userTokenAccount';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'spotMarketIndex';
-					type: 'u16';
-					index: false;
-				},
+					name: 'tokenProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [];
+		},
+		{
+			name: 'adminInitFeeUpdate';
+			accounts: [
 				{
-					name: 'vaultSharesBefore';
-					type: 'u128';
-					index: false;
+					name: 'vault';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'vaultSharesAfter';
-					type: 'u128';
-					index: false;
+					name: 'admin';
+					isMut: true;
+					isSigner: true;
 				},
 				{
-					name: 'vaultEquityBefore';
-					type: 'u64';
-					index: false;
+					name: 'feeUpdate';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'userVaultSharesBefore';
-					type: 'u128';
-					index: false;
-				},
+					name: 'systemProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [];
+		},
+		{
+			name: 'adminDeleteFeeUpdate';
+			accounts: [
 				{
-					name: 'totalVaultSharesBefore';
-					type: 'u128';
-					index: false;
+					name: 'vault';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'userVaultSharesAfter';
-					type: 'u128';
-					index: false;
+					name: 'admin';
+					isMut: true;
+					isSigner: true;
 				},
 				{
-					name: 'totalVaultSharesAfter';
-					type: 'u128';
-					index: false;
-				},
+					name: 'feeUpdate';
+					isMut: true;
+					isSigner: false;
+				}
+			];
+			args: [];
+		},
+		{
+			name: 'adminUpdateVaultClass';
+			accounts: [
 				{
-					name: 'profitShare';
-					type: 'u64';
-					index: false;
+					name: 'vault';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'managementFee';
-					type: 'i64';
-					index: false;
+					name: 'admin';
+					isMut: true;
+					isSigner: true;
 				},
 				{
-					name: 'managementFeeShares';
-					type: 'i64';
-					index: false;
-				},
+					name: 'systemProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [
 				{
-					name: 'depositOraclePrice';
-					type: 'i64';
-					index: false;
+					name: 'newVaultClass';
+					type: 'u8';
 				}
 			];
 		},
 		{
-			name: 'VaultDepositorV1Record';
-			fields: [
-				{
-					name: 'ts';
-					type: 'i64';
-					index: false;
-				},
+			name: 'managerUpdateFees';
+			accounts: [
 				{
 					name: 'vault';
-					type: 'publicKey';
-					index: false;
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'depositorAuthority';
-					type: 'publicKey';
-					index: false;
+					name: 'manager';
+					isMut: false;
+					isSigner: true;
 				},
 				{
-					name: 'action';
+					name: 'feeUpdate';
+					isMut: true;
+					isSigner: false;
+				}
+			];
+			args: [
+				{
+					name: 'params';
 					type: {
-						defined: 'VaultDepositorAction';
+						defined: 'ManagerUpdateFeesParams';
 					};
-					index: false;
-				},
+				}
+			];
+		},
+		{
+			name: 'managerCancelFeeUpdate';
+			accounts: [
 				{
-					name: 'amount';
-					type: 'u64';
-					index: false;
+					name: 'vault';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'spotMarketIndex';
-					type: 'u16';
-					index: false;
+					name: 'manager';
+					isMut: false;
+					isSigner: true;
 				},
 				{
-					name: 'vaultSharesBefore';
-					type: 'u128';
-					index: false;
-				},
+					name: 'feeUpdate';
+					isMut: true;
+					isSigner: false;
+				}
+			];
+			args: [];
+		},
+		{
+			name: 'applyProfitShare';
+			accounts: [
 				{
-					name: 'vaultSharesAfter';
-					type: 'u128';
-					index: false;
+					name: 'vault';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'vaultEquityBefore';
-					type: 'u64';
-					index: false;
+					name: 'vaultDepositor';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'userVaultSharesBefore';
-					type: 'u128';
-					index: false;
+					name: 'manager';
+					isMut: false;
+					isSigner: true;
 				},
 				{
-					name: 'totalVaultSharesBefore';
-					type: 'u128';
-					index: false;
+					name: 'driftUserStats';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'userVaultSharesAfter';
-					type: 'u128';
-					index: false;
+					name: 'driftUser';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'totalVaultSharesAfter';
-					type: 'u128';
-					index: false;
+					name: 'driftState';
+					isMut: false;
+					isSigner: false;
 				},
 				{
-					name: 'protocolSharesBefore';
-					type: 'u128';
-					index: false;
+					name: 'driftSigner';
+					isMut: false;
+					isSigner: false;
 				},
 				{
-					name: 'protocolSharesAfter';
-					type: 'u128';
-					index: false;
-				},
+					name: 'driftProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [];
+		},
+		{
+			name: 'applyRebase';
+			accounts: [
 				{
-					name: 'protocolProfitShare';
-					type: 'u64';
-					index: false;
+					name: 'vault';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'protocolFee';
-					type: 'i64';
-					index: false;
+					name: 'vaultDepositor';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'protocolFeeShares';
-					type: 'i64';
-					index: false;
-				},
+					name: 'driftUser';
+					isMut: true;
+					isSigner: false;
+				}
+			];
+			args: [];
+		},
+		{
+			name: 'applyRebaseTokenizedDepositor';
+			accounts: [
 				{
-					name: 'managerProfitShare';
-					type: 'u64';
-					index: false;
-				},
-				{
-					name: 'managementFee';
-					type: 'i64';
-					index: false;
+					name: 'vault';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'managementFeeShares';
-					type: 'i64';
-					index: false;
+					name: 'tokenizedVaultDepositor';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'depositOraclePrice';
-					type: 'i64';
-					index: false;
+					name: 'driftUser';
+					isMut: true;
+					isSigner: false;
 				}
 			];
+			args: [];
 		},
 		{
-			name: 'ShareTransferRecord';
-			fields: [
-				{
-					name: 'ts';
-					type: 'i64';
-					index: false;
-				},
+			name: 'forceWithdraw';
+			accounts: [
 				{
 					name: 'vault';
-					type: 'publicKey';
-					index: false;
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'fromVaultDepositor';
-					type: 'publicKey';
-					index: false;
+					name: 'manager';
+					isMut: false;
+					isSigner: true;
 				},
 				{
-					name: 'toVaultDepositor';
-					type: 'publicKey';
-					index: false;
+					name: 'vaultDepositor';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'shares';
-					type: 'u128';
-					index: false;
+					name: 'vaultTokenAccount';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'value';
-					type: 'u64';
-					index: false;
+					name: 'driftUserStats';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'fromDepositorSharesBefore';
-					type: 'u128';
-					index: false;
+					name: 'driftUser';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'fromDepositorSharesAfter';
-					type: 'u128';
-					index: false;
+					name: 'driftState';
+					isMut: false;
+					isSigner: false;
 				},
 				{
-					name: 'toDepositorSharesBefore';
-					type: 'u128';
-					index: false;
+					name: 'driftSpotMarketVault';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'toDepositorSharesAfter';
-					type: 'u128';
-					index: false;
-				}
-			];
-		},
-		{
-			name: 'FuelSeasonRecord';
-			fields: [
-				{
-					name: 'ts';
-					type: 'i64';
-					index: false;
+					name: 'driftSigner';
+					isMut: false;
+					isSigner: false;
 				},
 				{
-					name: 'authority';
-					type: 'publicKey';
-					index: false;
+					name: 'userTokenAccount';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'fuelInsurance';
-					type: 'u128';
-					index: false;
+					name: 'driftProgram';
+					isMut: false;
+					isSigner: false;
 				},
 				{
-					name: 'fuelDeposits';
-					type: 'u128';
-					index: false;
-				},
+					name: 'tokenProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [];
+		},
+		{
+			name: 'initializeInsuranceFundStake';
+			accounts: [
 				{
-					name: 'fuelBorrows';
-					type: 'u128';
-					index: false;
+					name: 'vault';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'fuelPositions';
-					type: 'u128';
-					index: false;
+					name: 'manager';
+					isMut: false;
+					isSigner: true;
 				},
 				{
-					name: 'fuelTaker';
-					type: 'u128';
-					index: false;
+					name: 'payer';
+					isMut: true;
+					isSigner: true;
 				},
 				{
-					name: 'fuelMaker';
-					type: 'u128';
-					index: false;
+					name: 'rent';
+					isMut: false;
+					isSigner: false;
 				},
 				{
-					name: 'fuelTotal';
-					type: 'u128';
-					index: false;
-				}
-			];
-		},
-		{
-			name: 'FeeUpdateRecord';
-			fields: [
-				{
-					name: 'ts';
-					type: 'i64';
-					index: false;
+					name: 'driftSpotMarket';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'action';
-					type: {
-						defined: 'FeeUpdateAction';
-					};
-					index: false;
+					name: 'driftSpotMarketMint';
+					isMut: false;
+					isSigner: false;
 				},
 				{
-					name: 'timelockEndTs';
-					type: 'i64';
-					index: false;
+					name: 'vaultTokenAccount';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'vault';
-					type: 'publicKey';
-					index: false;
+					name: 'insuranceFundStake';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'oldManagementFee';
-					type: 'i64';
-					index: false;
+					name: 'driftUserStats';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'oldProfitShare';
-					type: 'u32';
-					index: false;
+					name: 'driftState';
+					isMut: false;
+					isSigner: false;
 				},
 				{
-					name: 'oldHurdleRate';
-					type: 'u32';
-					index: false;
+					name: 'driftProgram';
+					isMut: false;
+					isSigner: false;
 				},
 				{
-					name: 'newManagementFee';
-					type: 'i64';
-					index: false;
+					name: 'tokenProgram';
+					isMut: false;
+					isSigner: false;
 				},
 				{
-					name: 'newProfitShare';
-					type: 'u32';
-					index: false;
-				},
+					name: 'systemProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [
 				{
-					name: 'newHurdleRate';
-					type: 'u32';
-					index: false;
+					name: 'marketIndex';
+					type: 'u16';
 				}
 			];
 		},
 		{
-			name: 'ManagerBorrowRecord';
-			fields: [
-				{
-					name: 'ts';
-					type: 'i64';
-					index: false;
-				},
+			name: 'addInsuranceFundStake';
+			accounts: [
 				{
 					name: 'vault';
-					type: 'publicKey';
-					index: false;
+					isMut: true;
+					isSigner: false;
 				},
 				{
 					name: 'manager';
-					type: 'publicKey';
-					index: false;
+					isMut: true;
+					isSigner: true;
 				},
 				{
-					name: 'borrowAmount';
-					type: 'u64';
-					index: false;
+					name: 'driftSpotMarket';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'borrowValue';
-					type: 'u64';
-					index: false;
+					name: 'driftSpotMarketVault';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'borrowSpotMarketIndex';
-					type: 'u16';
-					index: false;
+					name: 'insuranceFundStake';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'borrowOraclePrice';
-					type: 'i64';
-					index: false;
+					name: 'insuranceFundVault';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'depositSpotMarketIndex';
-					type: 'u16';
-					index: false;
+					name: 'managerTokenAccount';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'depositOraclePrice';
-					type: 'i64';
-					index: false;
+					name: 'vaultIfTokenAccount';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'vaultEquity';
+					name: 'driftUserStats';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftState';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'driftSigner';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'driftProgram';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'tokenProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [
+				{
+					name: 'marketIndex';
+					type: 'u16';
+				},
+				{
+					name: 'amount';
 					type: 'u64';
-					index: false;
 				}
 			];
 		},
 		{
-			name: 'ManagerRepayRecord';
-			fields: [
+			name: 'requestRemoveInsuranceFundStake';
+			accounts: [
 				{
-					name: 'ts';
-					type: 'i64';
-					index: false;
+					name: 'vault';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'manager';
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: 'driftSpotMarket';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'insuranceFundStake';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'insuranceFundVault';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftUserStats';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [
+				{
+					name: 'marketIndex';
+					type: 'u16';
 				},
+				{
+					name: 'amount';
+					type: 'u64';
+				}
+			];
+		},
+		{
+			name: 'removeInsuranceFundStake';
+			accounts: [
 				{
 					name: 'vault';
-					type: 'publicKey';
-					index: false;
+					isMut: true;
+					isSigner: false;
 				},
 				{
 					name: 'manager';
-					type: 'publicKey';
-					index: false;
+					isMut: false;
+					isSigner: true;
 				},
 				{
-					name: 'repayAmount';
-					type: 'u64';
-					index: false;
+					name: 'driftSpotMarket';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'repayValue';
-					type: 'u64';
-					index: false;
+					name: 'insuranceFundStake';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'repaySpotMarketIndex';
-					type: 'u16';
-					index: false;
+					name: 'insuranceFundVault';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'repayOraclePrice';
-					type: 'i64';
-					index: false;
+					name: 'managerTokenAccount';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'depositSpotMarketIndex';
-					type: 'u16';
-					index: false;
+					name: 'vaultIfTokenAccount';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'depositOraclePrice';
-					type: 'i64';
-					index: false;
+					name: 'driftUserStats';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'vaultEquityBefore';
-					type: 'u64';
-					index: false;
+					name: 'driftState';
+					isMut: false;
+					isSigner: false;
 				},
 				{
-					name: 'vaultEquityAfter';
-					type: 'u64';
-					index: false;
+					name: 'driftSigner';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'driftProgram';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'tokenProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [
+				{
+					name: 'marketIndex';
+					type: 'u16';
 				}
 			];
 		},
 		{
-			name: 'ManagerUpdateBorrowRecord';
-			fields: [
-				{
-					name: 'ts';
-					type: 'i64';
-					index: false;
-				},
+			name: 'cancelRequestRemoveInsuranceFundStake';
+			accounts: [
 				{
 					name: 'vault';
-					type: 'publicKey';
-					index: false;
+					isMut: true;
+					isSigner: false;
 				},
 				{
 					name: 'manager';
-					type: 'publicKey';
-					index: false;
+					isMut: false;
+					isSigner: true;
 				},
 				{
-					name: 'previousBorrowValue';
-					type: 'u64';
-					index: false;
+					name: 'driftSpotMarket';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'newBorrowValue';
-					type: 'u64';
-					index: false;
+					name: 'insuranceFundStake';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'vaultEquityBefore';
-					type: 'u64';
-					index: false;
+					name: 'insuranceFundVault';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'vaultEquityAfter';
-					type: 'u64';
-					index: false;
+					name: 'driftUserStats';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [
+				{
+					name: 'marketIndex';
+					type: 'u16';
 				}
 			];
-		}
-	];
-	errors: [
-		{
-			code: 6000;
-			name: 'Default';
-			msg: 'Default';
-		},
-		{
-			code: 6001;
-			name: 'InvalidVaultRebase';
-			msg: 'InvalidVaultRebase';
-		},
-		{
-			code: 6002;
-			name: 'InvalidVaultSharesDetected';
-			msg: 'InvalidVaultSharesDetected';
-		},
-		{
-			code: 6003;
-			name: 'CannotWithdrawBeforeRedeemPeriodEnd';
-			msg: 'CannotWithdrawBeforeRedeemPeriodEnd';
 		},
 		{
-			code: 6004;
-			name: 'InvalidVaultWithdraw';
-			msg: 'InvalidVaultWithdraw';
-		},
-		{
-			code: 6005;
-			name: 'InsufficientVaultShares';
-			msg: 'InsufficientVaultShares';
-		},
-		{
-			code: 6006;
-			name: 'InvalidVaultWithdrawSize';
-			msg: 'InvalidVaultWithdrawSize';
-		},
-		{
-			code: 6007;
-			name: 'InvalidVaultForNewDepositors';
-			msg: 'InvalidVaultForNewDepositors';
-		},
-		{
-			code: 6008;
-			name: 'VaultWithdrawRequestInProgress';
-			msg: 'VaultWithdrawRequestInProgress';
-		},
-		{
-			code: 6009;
-			name: 'VaultIsAtCapacity';
-			msg: 'VaultIsAtCapacity';
-		},
-		{
-			code: 6010;
-			name: 'InvalidVaultDepositorInitialization';
-			msg: 'InvalidVaultDepositorInitialization';
-		},
-		{
-			code: 6011;
-			name: 'DelegateNotAvailableForLiquidation';
-			msg: 'DelegateNotAvailableForLiquidation';
-		},
-		{
-			code: 6012;
-			name: 'InvalidEquityValue';
-			msg: 'InvalidEquityValue';
-		},
-		{
-			code: 6013;
-			name: 'VaultInLiquidation';
-			msg: 'VaultInLiquidation';
-		},
-		{
-			code: 6014;
-			name: 'DriftError';
-			msg: 'DriftError';
-		},
-		{
-			code: 6015;
-			name: 'InvalidVaultInitialization';
-			msg: 'InvalidVaultInitialization';
-		},
-		{
-			code: 6016;
-			name: 'InvalidVaultUpdate';
-			msg: 'InvalidVaultUpdate';
-		},
-		{
-			code: 6017;
-			name: 'PermissionedVault';
-			msg: 'PermissionedVault';
-		},
-		{
-			code: 6018;
-			name: 'WithdrawInProgress';
-			msg: 'WithdrawInProgress';
-		},
-		{
-			code: 6019;
-			name: 'SharesPercentTooLarge';
-			msg: 'SharesPercentTooLarge';
-		},
-		{
-			code: 6020;
-			name: 'InvalidVaultDeposit';
-			msg: 'InvalidVaultDeposit';
-		},
-		{
-			code: 6021;
-			name: 'OngoingLiquidation';
-			msg: 'OngoingLiquidation';
-		},
-		{
-			code: 6022;
-			name: 'VaultProtocolMissing';
-			msg: 'VaultProtocolMissing';
-		},
-		{
-			code: 6023;
-			name: 'InvalidTokenization';
-			msg: 'InvalidTokenization';
-		},
-		{
-			code: 6024;
-			name: 'InvalidFuelDistributionMode';
-			msg: 'InvalidFuelDistributionMode';
-		},
-		{
-			code: 6025;
-			name: 'FeeUpdateMissing';
-			msg: 'FeeUpdateMissing';
-		},
-		{
-			code: 6026;
-			name: 'InvalidFeeUpdateStatus';
-			msg: 'InvalidFeeUpdateStatus';
-		},
-		{
-			code: 6027;
-			name: 'InvalidVaultClass';
-			msg: 'InvalidVaultClass';
-		},
-		{
-			code: 6028;
-			name: 'InvalidBorrowAmount';
-			msg: 'InvalidBorrowAmount';
-		},
-		{
-			code: 6029;
-			name: 'InvalidRepayAmount';
-			msg: 'InvalidRepayAmount';
-		}
-	];
-};
-
-export const IDL: DriftVaults = {
-	version: '0.9.0',
-	name: 'drift_vaults',
-	instructions: [
-		{
-			name: 'initializeVault',
+			name: 'transferVaultDepositorShares';
 			accounts: [
 				{
-					name: 'vault',
-					isMut: true,
-					isSigner: false,
+					name: 'vault';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'tokenAccount',
-					isMut: true,
-					isSigner: false,
+					name: 'vaultDepositor';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'driftUserStats',
-					isMut: true,
-					isSigner: false,
+					name: 'authority';
+					isMut: false;
+					isSigner: true;
 				},
 				{
-					name: 'driftUser',
-					isMut: true,
-					isSigner: false,
+					name: 'toVaultDepositor';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'driftState',
-					isMut: true,
-					isSigner: false,
+					name: 'driftUser';
+					isMut: false;
+					isSigner: false;
 				},
 				{
-					name: 'driftSpotMarket',
-					isMut: false,
-					isSigner: false,
-				},
+					name: 'driftUserStats';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [
 				{
-					name: 'driftSpotMarketMint',
-					isMut: false,
-					isSigner: false,
+					name: 'amount';
+					type: 'u64';
 				},
 				{
-					name: 'manager',
-					isMut: false,
-					isSigner: true,
-				},
+					name: 'withdrawUnit';
+					type: {
+						defined: 'WithdrawUnit';
+					};
+				}
+			];
+		},
+		{
+			name: 'protocolRequestWithdraw';
+			accounts: [
 				{
-					name: 'payer',
-					isMut: true,
-					isSigner: true,
+					name: 'vault';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'rent',
-					isMut: false,
-					isSigner: false,
+					name: 'vaultProtocol';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'systemProgram',
-					isMut: false,
-					isSigner: false,
+					name: 'protocol';
+					isMut: false;
+					isSigner: true;
 				},
 				{
-					name: 'driftProgram',
-					isMut: false,
-					isSigner: false,
+					name: 'driftUserStats';
+					isMut: false;
+					isSigner: false;
 				},
 				{
-					name: 'tokenProgram',
-					isMut: false,
-					isSigner: false,
-				},
-			],
+					name: 'driftUser';
+					isMut: false;
+					isSigner: false;
+				}
+			];
 			args: [
 				{
-					name: 'params',
-					type: {
-						defined: 'VaultParams',
-					},
+					name: 'withdrawAmount';
+					type: 'u64';
 				},
-			],
+				{
+					name: 'withdrawUnit';
+					type: {
+						defined: 'WithdrawUnit';
+					};
+				}
+			];
 		},
 		{
-			name: 'initializeVaultWithProtocol',
+			name: 'protocolCancelWithdrawRequest';
 			accounts: [
 				{
-					name: 'vault',
-					isMut: true,
-					isSigner: false,
+					name: 'vault';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'vaultProtocol',
-					isMut: true,
-					isSigner: false,
+					name: 'vaultProtocol';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'tokenAccount',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftUserStats',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftUser',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftState',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftSpotMarket',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'driftSpotMarketMint',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'manager',
-					isMut: false,
-					isSigner: true,
-				},
-				{
-					name: 'payer',
-					isMut: true,
-					isSigner: true,
-				},
-				{
-					name: 'rent',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'systemProgram',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'driftProgram',
-					isMut: false,
-					isSigner: false,
+					name: 'protocol';
+					isMut: false;
+					isSigner: true;
 				},
 				{
-					name: 'tokenProgram',
-					isMut: false,
-					isSigner: false,
+					name: 'driftUserStats';
+					isMut: false;
+					isSigner: false;
 				},
-			],
-			args: [
 				{
-					name: 'params',
-					type: {
-						defined: 'VaultWithProtocolParams',
-					},
-				},
-			],
+					name: 'driftUser';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [];
 		},
 		{
-			name: 'updateDelegate',
+			name: 'protocolWithdraw';
 			accounts: [
 				{
-					name: 'vault',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'manager',
-					isMut: false,
-					isSigner: true,
-				},
-				{
-					name: 'driftUser',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftProgram',
-					isMut: false,
-					isSigner: false,
+					name: 'vault';
+					isMut: true;
+					isSigner: false;
 				},
-			],
-			args: [
 				{
-					name: 'delegate',
-					type: 'publicKey',
+					name: 'vaultProtocol';
+					isMut: true;
+					isSigner: false;
 				},
-			],
-		},
-		{
-			name: 'updateMarginTradingEnabled',
-			accounts: [
 				{
-					name: 'vault',
-					isMut: true,
-					isSigner: false,
+					name: 'protocol';
+					isMut: false;
+					isSigner: true;
 				},
 				{
-					name: 'manager',
-					isMut: false,
-					isSigner: true,
+					name: 'vaultTokenAccount';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'driftUser',
-					isMut: true,
-					isSigner: false,
+					name: 'driftUserStats';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'driftProgram',
-					isMut: false,
-					isSigner: false,
+					name: 'driftUser';
+					isMut: true;
+					isSigner: false;
 				},
-			],
-			args: [
 				{
-					name: 'enabled',
-					type: 'bool',
+					name: 'driftState';
+					isMut: false;
+					isSigner: false;
 				},
-			],
-		},
-		{
-			name: 'updateUserPoolId',
-			accounts: [
 				{
-					name: 'vault',
-					isMut: true,
-					isSigner: false,
+					name: 'driftSpotMarketVault';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'manager',
-					isMut: false,
-					isSigner: true,
+					name: 'driftSigner';
+					isMut: false;
+					isSigner: false;
 				},
 				{
-					name: 'driftUser',
-					isMut: true,
-					isSigner: false,
+					name: 'userTokenAccount';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'driftProgram',
-					isMut: false,
-					isSigner: false,
+					name: 'driftProgram';
+					isMut: false;
+					isSigner: false;
 				},
-			],
-			args: [
 				{
-					name: 'poolId',
-					type: 'u8',
-				},
-			],
-		},
+					name: 'tokenProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [];
+		}
+	];
+	accounts: [
 		{
-			name: 'updateVaultProtocol',
-			accounts: [
-				{
-					name: 'vault',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'protocol',
-					isMut: false,
-					isSigner: true,
-				},
-				{
-					name: 'vaultProtocol',
-					isMut: true,
-					isSigner: false,
-				},
-			],
-			args: [
-				{
-					name: 'params',
-					type: {
-						defined: 'UpdateVaultProtocolParams',
+			name: 'feeUpdate';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'incomingUpdateTs';
+						type: 'i64';
 					},
-				},
-			],
+					{
+						name: 'incomingManagementFee';
+						type: 'i64';
+					},
+					{
+						name: 'incomingProfitShare';
+						type: 'u32';
+					},
+					{
+						name: 'incomingHurdleRate';
+						type: 'u32';
+					},
+					{
+						name: 'padding';
+						type: {
+							array: ['u128', 10];
+						};
+					},
+					{
+						name: 'bump';
+						docs: [
+							'The bump for the fee update pda, 0 for fee updates created before it was stored'
+						];
+						type: 'u8';
+					},
+					{
+						name: 'padding2';
+						type: {
+							array: ['u8', 7];
+						};
+					}
+				];
+			};
 		},
 		{
-			name: 'updateVault',
-			accounts: [
-				{
-					name: 'vault',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'manager',
-					isMut: false,
-					isSigner: true,
-				},
-			],
-			args: [
-				{
-					name: 'params',
-					type: {
-						defined: 'UpdateVaultParams',
+			name: 'fuelDistribution';
+			docs: [
+				"Holds the fuel accrued by the manager and the protocol when the vault's fuel_distribution_mode is",
+				'`Weighted`, and any timelocked change to the weights. Users accrue their share through',
+				'vault.cumulative_fuel_per_share, the rest of vault.cumulative_fuel is settled here. `Vault` and',
+				'`VaultProtocol` have no room left for the accrued fuel, so it lives in its own account.',
+				'',
+				"Settling accrues the weighted share of the vault's fuel since the last settle, so it is exact however",
+				"rarely it runs as long as it runs before the weights change and before the vault's fuel season is reset.",
+				'`update` and `reset_fuel_amount` settle first for that reason.'
+			];
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'vault';
+						docs: ['The vault this account distributes fuel for'];
+						type: 'publicKey';
 					},
-				},
-			],
+					{
+						name: 'lastCumulativeFuel';
+						docs: ['The vault.cumulative_fuel at the last settle'];
+						type: 'u128';
+					},
+					{
+						name: 'managerFuel';
+						docs: ['Total fuel accrued by the manager'];
+						type: 'u128';
+					},
+					{
+						name: 'protocolFuel';
+						docs: ['Total fuel accrued by the protocol'];
+						type: 'u128';
+					},
+					{
+						name: 'incomingUpdateTs';
+						docs: [
+							'The ts the incoming weights can be applied at, 0 if there is no pending update'
+						];
+						type: 'i64';
+					},
+					{
+						name: 'incomingManagerFuelBps';
+						type: 'u16';
+					},
+					{
+						name: 'incomingProtocolFuelBps';
+						type: 'u16';
+					},
+					{
+						name: 'lastSettleTs';
+						docs: ['Last ts fuel was settled'];
+						type: 'u32';
+					},
+					{
+						name: 'bump';
+						type: 'u8';
+					},
+					{
+						name: 'padding1';
+						type: {
+							array: ['u8', 7];
+						};
+					},
+					{
+						name: 'padding';
+						type: {
+							array: ['u64', 5];
+						};
+					}
+				];
+			};
 		},
 		{
-			name: 'updateVaultManager',
-			accounts: [
-				{
-					name: 'vault',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'manager',
-					isMut: false,
-					isSigner: true,
-				},
-			],
-			args: [
-				{
-					name: 'manager',
-					type: 'publicKey',
-				},
-			],
+			name: 'fuelSeasonSnapshot';
+			docs: [
+				"A depositor's fuel recorded by reset_fuel_season at the end of a fuel season, so external programs can read",
+				"it without parsing logs. There is one snapshot per depositor and season and it can't be overwritten."
+			];
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'vault';
+						docs: ['The vault the depositor is in'];
+						type: 'publicKey';
+					},
+					{
+						name: 'vaultDepositor';
+						docs: ['The vault depositor whose fuel was recorded'];
+						type: 'publicKey';
+					},
+					{
+						name: 'authority';
+						docs: ["The vault depositor's authority"];
+						type: 'publicKey';
+					},
+					{
+						name: 'fuelAmount';
+						docs: ["The depositor's fuel_amount at the snapshot"];
+						type: 'u128';
+					},
+					{
+						name: 'ts';
+						docs: ['The ts the snapshot was taken'];
+						type: 'i64';
+					},
+					{
+						name: 'season';
+						docs: [
+							"The vault's fuel_season the fuel was accrued in, part of the snapshot's pda seeds"
+						];
+						type: 'u8';
+					},
+					{
+						name: 'bump';
+						type: 'u8';
+					},
+					{
+						name: 'padding';
+						type: {
+							array: ['u8', 6];
+						};
+					}
+				];
+			};
 		},
 		{
-			name: 'updateCumulativeFuelAmount',
-			accounts: [
-				{
-					name: 'vault',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'vaultDepositor',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'signer',
-					isMut: false,
-					isSigner: true,
-				},
-				{
-					name: 'driftUserStats',
-					isMut: true,
-					isSigner: false,
-				},
-			],
-			args: [],
+			name: 'managerStats';
+			docs: [
+				"The vault's track record, built from its share price each time its equity is observed: by the",
+				'update_manager_stats crank, the snapshot_vault crank, and deposits and withdrawals that pass it in',
+				'remaining_accounts.',
+				'',
+				"Share prices are the vault's equity over its total_shares, in the vault's current shares_base. They are net",
+				'of management and protocol fees, which mint shares, and gross of profit share, which only moves shares.'
+			];
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'vault';
+						docs: ['The vault these stats are for'];
+						type: 'publicKey';
+					},
+					{
+						name: 'hwmSharePrice';
+						docs: [
+							'The highest share price observed. precision: NAV_PER_SHARE_PRECISION'
+						];
+						type: 'u128';
+					},
+					{
+						name: 'lastSharePrice';
+						docs: [
+							'The share price at the last observation. precision: NAV_PER_SHARE_PRECISION'
+						];
+						type: 'u128';
+					},
+					{
+						name: 'periodStartSharePrice';
+						docs: [
+							'The share price the current return period started at. precision: NAV_PER_SHARE_PRECISION'
+						];
+						type: 'u128';
+					},
+					{
+						name: 'sumReturns';
+						docs: [
+							'Sum of the period returns. precision: PERCENTAGE_PRECISION'
+						];
+						type: 'i128';
+					},
+					{
+						name: 'sumSquaredReturns';
+						docs: [
+							'Sum of the squared period returns. precision: PERCENTAGE_PRECISION^2'
+						];
+						type: 'u128';
+					},
+					{
+						name: 'inceptionTs';
+						docs: ["The vault's init_ts"];
+						type: 'i64';
+					},
+					{
+						name: 'firstObservationTs';
+						docs: ['The ts of the first observation'];
+						type: 'i64';
+					},
+					{
+						name: 'lastObservationTs';
+						type: 'i64';
+					},
+					{
+						name: 'hwmTs';
+						docs: ['The ts the high-water mark was set'];
+						type: 'i64';
+					},
+					{
+						name: 'periodStartTs';
+						type: 'i64';
+					},
+					{
+						name: 'maxDrawdown';
+						docs: [
+							'The largest fall of the share price from its high-water mark. precision: PERCENTAGE_PRECISION'
+						];
+						type: 'u64';
+					},
+					{
+						name: 'maxDrawdownTs';
+						docs: ['The ts the max drawdown was observed'];
+						type: 'i64';
+					},
+					{
+						name: 'returnCount';
+						docs: ['The number of period returns recorded'];
+						type: 'u32';
+					},
+					{
+						name: 'sharesBase';
+						docs: ['The shares_base the share prices are in'];
+						type: 'u32';
+					},
+					{
+						name: 'bump';
+						type: 'u8';
+					},
+					{
+						name: 'padding1';
+						type: {
+							array: ['u8', 3];
+						};
+					},
+					{
+						name: 'missedPeriodCount';
+						docs: [
+							"The number of periods without a return because the stats weren't observed in the period after them"
+						];
+						type: 'u32';
+					},
+					{
+						name: 'padding';
+						type: {
+							array: ['u64', 8];
+						};
+					}
+				];
+			};
 		},
 		{
-			name: 'initializeVaultDepositor',
-			accounts: [
-				{
-					name: 'vault',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'vaultDepositor',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'authority',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'payer',
-					isMut: true,
-					isSigner: true,
-				},
-				{
-					name: 'rent',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'systemProgram',
-					isMut: false,
-					isSigner: false,
-				},
-			],
-			args: [],
+			name: 'navHistory';
+			docs: [
+				"Fixed size ring buffer of the vault's equity and shares, so share price history can be read without",
+				'replaying logs. Appended by the snapshot_vault crank and by deposits and withdrawals that pass it in',
+				'remaining_accounts, at most once every NAV_HISTORY_MIN_SNAPSHOT_INTERVAL.'
+			];
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'vault';
+						docs: ['The vault this history is for'];
+						type: 'publicKey';
+					},
+					{
+						name: 'head';
+						docs: ['The index the next snapshot is written to'];
+						type: 'u32';
+					},
+					{
+						name: 'len';
+						docs: [
+							'The number of snapshots recorded, up to NAV_HISTORY_LENGTH'
+						];
+						type: 'u32';
+					},
+					{
+						name: 'bump';
+						type: 'u8';
+					},
+					{
+						name: 'padding';
+						type: {
+							array: ['u8', 7];
+						};
+					},
+					{
+						name: 'snapshots';
+						type: {
+							array: [
+								{
+									defined: 'NavSnapshot';
+								},
+								128
+							];
+						};
+					}
+				];
+			};
 		},
 		{
-			name: 'initializeTokenizedVaultDepositor',
-			accounts: [
-				{
-					name: 'vault',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'vaultDepositor',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'mintAccount',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'metadataAccount',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'payer',
-					isMut: true,
-					isSigner: true,
-				},
-				{
-					name: 'tokenProgram',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'tokenMetadataProgram',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'rent',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'systemProgram',
-					isMut: false,
-					isSigner: false,
-				},
-			],
-			args: [
-				{
-					name: 'params',
-					type: {
-						defined: 'InitializeTokenizedVaultDepositorParams',
+			name: 'navPolicy';
+			docs: [
+				"Optional policy on how the vault's equity is priced for deposits and withdrawals. Once a vault has",
+				'one, it must be passed in remaining_accounts to every instruction that mints or burns shares.',
+				'It also holds the nav circuit breaker state, so it must be writable for deposits and withdraw requests,',
+				'and the max drawdown protection state.'
+			];
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'vault';
+						docs: ['The vault this policy applies to'];
+						type: 'publicKey';
 					},
-				},
-			],
+					{
+						name: 'swingPricingMode';
+						docs: [
+							'How deposits and withdrawals are swing priced [`SwingPricingMode`]. Default is `Disabled`'
+						];
+						type: 'u8';
+					},
+					{
+						name: 'bump';
+						type: 'u8';
+					},
+					{
+						name: 'depositSpreadBps';
+						docs: [
+							'Estimated cost to deploy a deposit in bps of equity, added to the equity deposits are priced at'
+						];
+						type: 'u16';
+					},
+					{
+						name: 'withdrawSpreadBps';
+						docs: [
+							'Estimated cost to unwind a withdrawal in bps of equity, removed from the equity withdrawals are priced at'
+						];
+						type: 'u16';
+					},
+					{
+						name: 'maxSwingBps';
+						docs: [
+							'Caps the total swing on either side in bps of equity, including the perp market spread estimate'
+						];
+						type: 'u16';
+					},
+					{
+						name: 'oracleValidityMode';
+						docs: [
+							'How positions with an invalid oracle are valued [`OracleValidityMode`]. Default is `Strict`'
+						];
+						type: 'u8';
+					},
+					{
+						name: 'padding1';
+						type: {
+							array: ['u8', 7];
+						};
+					},
+					{
+						name: 'lastEquityPerShare';
+						docs: [
+							"The vault's equity per share last observed by the circuit breaker",
+							'precision: NAV_PER_SHARE_PRECISION'
+						];
+						type: 'u128';
+					},
+					{
+						name: 'lastEquityTs';
+						docs: ['The ts last_equity_per_share was observed at'];
+						type: 'i64';
+					},
+					{
+						name: 'navBreakerWindow';
+						docs: [
+							'Equity per share moves are only compared against observations younger than this, in seconds'
+						];
+						type: 'i64';
+					},
+					{
+						name: 'navBreakerThresholdBps';
+						docs: [
+							'Max move in equity per share within nav_breaker_window before the breaker trips, in bps. 0 disables it'
+						];
+						type: 'u16';
+					},
+					{
+						name: 'navBreakerTripped';
+						docs: [
+							'Deposits and withdraw requests are rejected until the breaker is reset'
+						];
+						type: 'bool';
+					},
+					{
+						name: 'padding2';
+						type: {
+							array: ['u8', 1];
+						};
+					},
+					{
+						name: 'lastSharesBase';
+						docs: [
+							"The vault's shares_base at last_equity_per_share, a rebase invalidates the observation"
+						];
+						type: 'u32';
+					},
+					{
+						name: 'drawdownTriggeredTs';
+						docs: [
+							"The ts max drawdown protection was triggered, 0 if it isn't"
+						];
+						type: 'i64';
+					},
+					{
+						name: 'hwmEquityPerShare';
+						docs: [
+							"The vault's highest equity per share seen by the max drawdown crank",
+							'precision: NAV_PER_SHARE_PRECISION'
+						];
+						type: 'u128';
+					},
+					{
+						name: 'reenableTradingTs';
+						docs: [
+							'The ts trading can be re-enabled after a triggered drawdown, 0 if not requested'
+						];
+						type: 'i64';
+					},
+					{
+						name: 'redeemPeriodBeforeDrawdown';
+						docs: [
+							"The vault's redeem_period before the drawdown waived it, restored when trading is re-enabled"
+						];
+						type: 'i64';
+					},
+					{
+						name: 'maxDrawdownBps';
+						docs: [
+							'Max drop in equity per share from hwm_equity_per_share before the vault is set reduce-only, in bps.',
+							'0 disables it. Once enabled it can only be lowered.'
+						];
+						type: 'u16';
+					},
+					{
+						name: 'padding3';
+						type: {
+							array: ['u8', 2];
+						};
+					},
+					{
+						name: 'hwmSharesBase';
+						docs: ["The vault's shares_base at hwm_equity_per_share"];
+						type: 'u32';
+					},
+					{
+						name: 'incomingUpdateTs';
+						docs: [
+							'The ts the queued increase to the withdraw swing can be applied at, 0 if none is queued'
+						];
+						type: 'i64';
+					},
+					{
+						name: 'incomingWithdrawSpreadBps';
+						docs: ['The withdraw_spread_bps applied at incoming_update_ts'];
+						type: 'u16';
+					},
+					{
+						name: 'incomingMaxSwingBps';
+						docs: ['The max_swing_bps applied at incoming_update_ts'];
+						type: 'u16';
+					},
+					{
+						name: 'incomingSwingPricingMode';
+						docs: ['The swing_pricing_mode applied at incoming_update_ts'];
+						type: 'u8';
+					},
+					{
+						name: 'padding4';
+						type: {
+							array: ['u8', 3];
+						};
+					},
+					{
+						name: 'padding';
+						type: {
+							array: ['u64', 3];
+						};
+					}
+				];
+			};
 		},
 		{
-			name: 'tokenizeShares',
-			accounts: [
-				{
-					name: 'vault',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'vaultDepositor',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'authority',
-					isMut: false,
-					isSigner: true,
-				},
-				{
-					name: 'tokenizedVaultDepositor',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'mint',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'userTokenAccount',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'driftUser',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'tokenProgram',
-					isMut: false,
-					isSigner: false,
-				},
-			],
-			args: [
-				{
-					name: 'amount',
-					type: 'u64',
-				},
-				{
-					name: 'unit',
-					type: {
-						defined: 'WithdrawUnit',
+			name: 'rewardStreamDepositor';
+			docs: [
+				"Tracks the rewards a [`VaultDepositor`] has earned from a [`RewardStream`]. The depositor's shares are",
+				"registered in the stream and settled whenever they change, so every instruction changing the depositor's",
+				'shares must pass all of its reward stream depositors, see vault_depositor.reward_stream_count.',
+				'',
+				'[`VaultDepositor`]: crate::state::VaultDepositor'
+			];
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'rewardStream';
+						docs: ['The reward stream paying the depositor'];
+						type: 'publicKey';
 					},
-				},
-			],
+					{
+						name: 'vaultDepositor';
+						docs: ['The vault depositor earning rewards'];
+						type: 'publicKey';
+					},
+					{
+						name: 'authority';
+						docs: [
+							"The vault depositor's authority, rewards are claimed to its associated token account"
+						];
+						type: 'publicKey';
+					},
+					{
+						name: 'lastCumulativeRewardPerShare';
+						docs: [
+							'The reward_stream.cumulative_reward_per_share at the last update'
+						];
+						type: 'u128';
+					},
+					{
+						name: 'vaultShares';
+						docs: [
+							"The depositor's shares registered in the reward stream, in the reward stream's shares base"
+						];
+						type: 'u128';
+					},
+					{
+						name: 'unclaimedRewards';
+						docs: ['Rewards earned but not claimed yet'];
+						type: 'u64';
+					},
+					{
+						name: 'totalClaimed';
+						docs: ['Lifetime rewards claimed'];
+						type: 'u64';
+					},
+					{
+						name: 'lastUpdateTs';
+						docs: ['Last ts rewards were accrued to the depositor'];
+						type: 'i64';
+					},
+					{
+						name: 'bump';
+						type: 'u8';
+					},
+					{
+						name: 'padding1';
+						type: {
+							array: ['u8', 7];
+						};
+					},
+					{
+						name: 'padding';
+						type: {
+							array: ['u64', 4];
+						};
+					}
+				];
+			};
 		},
 		{
-			name: 'redeemTokens',
-			accounts: [
-				{
-					name: 'vault',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'vaultDepositor',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'authority',
-					isMut: false,
-					isSigner: true,
-				},
-				{
-					name: 'tokenizedVaultDepositor',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'mint',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'userTokenAccount',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'vaultTokenAccount',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftUser',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'tokenProgram',
-					isMut: false,
-					isSigner: false,
-				},
-			],
-			args: [
-				{
-					name: 'tokensToBurn',
-					type: 'u64',
-				},
-			],
+			name: 'rewardStream';
+			docs: [
+				"Streams an SPL token to the vault's depositors at a fixed rate. Depositors opt in with a",
+				'[`RewardStreamDepositor`], which registers their shares in `total_shares`, and emitted rewards are spread',
+				'over the registered shares through `cumulative_reward_per_share`. The stream only emits while it has',
+				"funds that haven't been distributed.",
+				'',
+				'[`RewardStreamDepositor`]: crate::state::RewardStreamDepositor'
+			];
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'vault';
+						docs: ['The vault the stream pays depositors of'];
+						type: 'publicKey';
+					},
+					{
+						name: 'mint';
+						docs: ['The reward token mint'];
+						type: 'publicKey';
+					},
+					{
+						name: 'tokenAccount';
+						docs: ['The token account holding the rewards, owned by the vault'];
+						type: 'publicKey';
+					},
+					{
+						name: 'cumulativeRewardPerShare';
+						docs: [
+							'precision: REWARD_SHARE_PRECISION, per share in `shares_base`'
+						];
+						type: 'u128';
+					},
+					{
+						name: 'rewardRate';
+						docs: ['Reward tokens emitted per second'];
+						type: 'u64';
+					},
+					{
+						name: 'totalFunded';
+						docs: [
+							'Lifetime reward tokens funded, net of the remainder returned on close'
+						];
+						type: 'u64';
+					},
+					{
+						name: 'totalDistributed';
+						docs: ['Lifetime reward tokens emitted to depositors'];
+						type: 'u64';
+					},
+					{
+						name: 'totalClaimed';
+						docs: ['Lifetime reward tokens claimed by depositors'];
+						type: 'u64';
+					},
+					{
+						name: 'startTs';
+						docs: ['The ts the stream starts emitting'];
+						type: 'i64';
+					},
+					{
+						name: 'endTs';
+						docs: ['The ts the stream stops emitting'];
+						type: 'i64';
+					},
+					{
+						name: 'lastUpdateTs';
+						docs: ['Last ts rewards were emitted'];
+						type: 'i64';
+					},
+					{
+						name: 'sharesBase';
+						docs: [
+							'The vault.shares_base when the stream was created, shares from later bases are scaled to it'
+						];
+						type: 'u32';
+					},
+					{
+						name: 'rewardStreamId';
+						docs: [
+							"Distinguishes the vault's streams, part of the stream's pda seeds"
+						];
+						type: 'u16';
+					},
+					{
+						name: 'bump';
+						type: 'u8';
+					},
+					{
+						name: 'padding1';
+						type: {
+							array: ['u8', 1];
+						};
+					},
+					{
+						name: 'totalShares';
+						docs: [
+							"The shares registered by the stream's depositors, in `shares_base`"
+						];
+						type: 'u128';
+					},
+					{
+						name: 'depositorCount';
+						docs: [
+							'The number of open [`RewardStreamDepositor`]s, the stream can only be closed once there are none',
+							'',
+							'[`RewardStreamDepositor`]: crate::state::RewardStreamDepositor'
+						];
+						type: 'u32';
+					},
+					{
+						name: 'padding2';
+						type: {
+							array: ['u8', 4];
+						};
+					},
+					{
+						name: 'padding';
+						type: {
+							array: ['u64', 1];
+						};
+					}
+				];
+			};
 		},
 		{
-			name: 'deposit',
-			accounts: [
-				{
-					name: 'vault',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'vaultDepositor',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'authority',
-					isMut: false,
-					isSigner: true,
-				},
-				{
-					name: 'vaultTokenAccount',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftUserStats',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftUser',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftState',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'driftSpotMarketVault',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'userTokenAccount',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftProgram',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'tokenProgram',
-					isMut: false,
-					isSigner: false,
-				},
-			],
-			args: [
-				{
-					name: 'amount',
-					type: 'u64',
-				},
-			],
+			name: 'tokenizedRedeemRequest';
+			docs: [
+				'A pending redemption of tokenized vault shares. Created by `request_redeem` when a holder burns',
+				"share tokens, and closed by `redeem_tokens_for_underlying` once the vault's redeem period has passed,",
+				'or by `cancel_redeem_request` which mints the shares back to the holder as tokens.',
+				'The shares queued here have already left the [`TokenizedVaultDepositor`] but are still counted in',
+				'`vault.user_shares` until the redemption is finalized.',
+				'',
+				'A holder may have several requests outstanding, each keyed by its `request_id`.',
+				'',
+				'[`TokenizedVaultDepositor`]: crate::state::TokenizedVaultDepositor'
+			];
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'vault';
+						docs: ['The vault being redeemed from'];
+						type: 'publicKey';
+					},
+					{
+						name: 'tokenizedVaultDepositor';
+						docs: ['The tokenized vault depositor whose tokens were burned'];
+						type: 'publicKey';
+					},
+					{
+						name: 'authority';
+						docs: ['The token holder that will receive the underlying tokens'];
+						type: 'publicKey';
+					},
+					{
+						name: 'withdrawRequest';
+						docs: ['The queued withdraw'];
+						type: {
+							defined: 'WithdrawRequest';
+						};
+					},
+					{
+						name: 'vaultSharesBase';
+						docs: [
+							'The vault shares_base at the time withdraw_request.shares was last updated'
+						];
+						type: 'u32';
+					},
+					{
+						name: 'bump';
+						type: 'u8';
+					},
+					{
+						name: 'padding1';
+						type: 'u8';
+					},
+					{
+						name: 'requestId';
+						docs: [
+							'Chosen by the holder, distinguishes their outstanding requests'
+						];
+						type: 'u16';
+					},
+					{
+						name: 'padding';
+						type: {
+							array: ['u64', 5];
+						};
+					}
+				];
+			};
 		},
 		{
-			name: 'requestWithdraw',
-			accounts: [
-				{
-					name: 'vault',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'vaultDepositor',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'authority',
-					isMut: false,
-					isSigner: true,
-				},
-				{
-					name: 'driftUserStats',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'driftUser',
-					isMut: false,
-					isSigner: false,
-				},
-			],
-			args: [
-				{
-					name: 'withdrawAmount',
-					type: 'u64',
-				},
-				{
-					name: 'withdrawUnit',
-					type: {
-						defined: 'WithdrawUnit',
+			name: 'tokenizedVaultDepositor';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'vault';
+						docs: ['The vault deposited into'];
+						type: 'publicKey';
 					},
-				},
-			],
+					{
+						name: 'pubkey';
+						docs: [
+							"The vault depositor account's pubkey. It is a pda of vault"
+						];
+						type: 'publicKey';
+					},
+					{
+						name: 'mint';
+						docs: [
+							'The token mint for tokenized shares owned by this VaultDepositor'
+						];
+						type: 'publicKey';
+					},
+					{
+						name: 'vaultShares';
+						docs: [
+							"share of vault owned by this depositor. vault_shares / vault.total_shares is depositor's ownership of vault_equity"
+						];
+						type: 'u128';
+					},
+					{
+						name: 'lastVaultShares';
+						docs: [
+							'stores the vault_shares from the most recent liquidity event (redeem or issuance) before a spl token',
+							'CPI is done, used to track invariants'
+						];
+						type: 'u128';
+					},
+					{
+						name: 'lastValidTs';
+						docs: ['creation ts of vault depositor'];
+						type: 'i64';
+					},
+					{
+						name: 'netDeposits';
+						docs: ['lifetime net deposits of vault depositor for the vault'];
+						type: 'i64';
+					},
+					{
+						name: 'totalDeposits';
+						docs: ['lifetime total deposits'];
+						type: 'u64';
+					},
+					{
+						name: 'totalWithdraws';
+						docs: ['lifetime total withdraws'];
+						type: 'u64';
+					},
+					{
+						name: 'cumulativeProfitShareAmount';
+						docs: [
+							'the token amount of gains the vault depositor has paid performance fees on'
+						];
+						type: 'i64';
+					},
+					{
+						name: 'profitShareFeePaid';
+						type: 'u64';
+					},
+					{
+						name: 'vaultSharesBase';
+						docs: [
+							'The exponent for vault_shares decimal places at the time the tokenized vault depositor was initialized.',
+							'If the vault undergoes a rebase, this TokenizedVaultDepositor can no longer issue new tokens, only redeem',
+							'is possible.'
+						];
+						type: 'u32';
+					},
+					{
+						name: 'bump';
+						docs: ['The bump for the vault pda'];
+						type: 'u8';
+					},
+					{
+						name: 'costBasisMigrated';
+						docs: ['See [`crate::state::VaultDepositor::cost_basis_migrated`]'];
+						type: 'bool';
+					},
+					{
+						name: 'padding1';
+						type: {
+							array: ['u8', 2];
+						};
+					},
+					{
+						name: 'lastFuelUpdateTs';
+						docs: ['Last ts fuel was accrued to this depositor'];
+						type: 'u32';
+					},
+					{
+						name: 'padding2';
+						type: {
+							array: ['u8', 4];
+						};
+					},
+					{
+						name: 'cumulativeFuelPerShareAmount';
+						docs: [
+							"The vault's cumulative_fuel_per_share at the last fuel accrual"
+						];
+						type: 'u128';
+					},
+					{
+						name: 'fuelAmount';
+						docs: [
+							'Total fuel accrued by the tokenized shares. Fuel is attributed to the tokenized vault depositor as a',
+							"whole, token holders don't accrue fuel individually"
+						];
+						type: 'u128';
+					},
+					{
+						name: 'costBasis';
+						docs: [
+							'The deposit asset paid for the tokenized shares, see [`crate::state::VaultDepositor::cost_basis`]'
+						];
+						type: 'u64';
+					},
+					{
+						name: 'padding';
+						type: {
+							array: ['u64', 4];
+						};
+					}
+				];
+			};
 		},
 		{
-			name: 'cancelRequestWithdraw',
-			accounts: [
-				{
-					name: 'vault',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'vaultDepositor',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'authority',
-					isMut: false,
-					isSigner: true,
-				},
-				{
-					name: 'driftUserStats',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'driftUser',
-					isMut: false,
-					isSigner: false,
-				},
-			],
-			args: [],
-		},
-		{
-			name: 'withdraw',
-			accounts: [
-				{
-					name: 'vault',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'vaultDepositor',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'authority',
-					isMut: false,
-					isSigner: true,
-				},
-				{
-					name: 'vaultTokenAccount',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftUserStats',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftUser',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftState',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'driftSpotMarketVault',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftSigner',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'userTokenAccount',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftProgram',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'tokenProgram',
-					isMut: false,
-					isSigner: false,
-				},
-			],
-			args: [],
-		},
-		{
-			name: 'liquidate',
-			accounts: [
-				{
-					name: 'vault',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'vaultDepositor',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'authority',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'admin',
-					isMut: true,
-					isSigner: true,
-				},
-				{
-					name: 'driftUserStats',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftUser',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftProgram',
-					isMut: false,
-					isSigner: false,
-				},
-			],
-			args: [],
+			name: 'vaultDepositor';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'vault';
+						docs: ['The vault deposited into'];
+						type: 'publicKey';
+					},
+					{
+						name: 'pubkey';
+						docs: [
+							"The vault depositor account's pubkey. It is a pda of vault and authority"
+						];
+						type: 'publicKey';
+					},
+					{
+						name: 'authority';
+						docs: [
+							'The authority is the address w permission to deposit/withdraw'
+						];
+						type: 'publicKey';
+					},
+					{
+						name: 'vaultShares';
+						docs: [
+							"share of vault owned by this depositor. vault_shares / vault.total_shares is depositor's ownership of vault_equity"
+						];
+						type: 'u128';
+					},
+					{
+						name: 'lastWithdrawRequest';
+						docs: ['last withdraw request'];
+						type: {
+							defined: 'WithdrawRequest';
+						};
+					},
+					{
+						name: 'lastValidTs';
+						docs: ['creation ts of vault depositor'];
+						type: 'i64';
+					},
+					{
+						name: 'netDeposits';
+						docs: ['lifetime net deposits of vault depositor for the vault'];
+						type: 'i64';
+					},
+					{
+						name: 'totalDeposits';
+						docs: ['lifetime total deposits'];
+						type: 'u64';
+					},
+					{
+						name: 'totalWithdraws';
+						docs: ['lifetime total withdraws'];
+						type: 'u64';
+					},
+					{
+						name: 'cumulativeProfitShareAmount';
+						docs: [
+							'the token amount of gains the vault depositor has paid performance fees on'
+						];
+						type: 'i64';
+					},
+					{
+						name: 'profitShareFeePaid';
+						type: 'u64';
+					},
+					{
+						name: 'vaultSharesBase';
+						docs: ['the exponent for vault_shares decimal places'];
+						type: 'u32';
+					},
+					{
+						name: 'lastFuelUpdateTs';
+						type: 'u32';
+					},
+					{
+						name: 'cumulativeFuelPerShareAmount';
+						docs: ['precision: FUEL_SHARE_PRECISION'];
+						type: 'u128';
+					},
+					{
+						name: 'fuelAmount';
+						docs: ['precision: none'];
+						type: 'u128';
+					},
+					{
+						name: 'fuelLockupEndTs';
+						docs: [
+							"The ts the depositor's fuel lockup ends, request_withdraw is blocked until then"
+						];
+						type: 'i64';
+					},
+					{
+						name: 'fuelBoostShares';
+						docs: [
+							'The extra shares from the fuel lockup counted in vault.fuel_shares_adjustment'
+						];
+						type: 'u64';
+					},
+					{
+						name: 'fuelLockupMultiplier';
+						docs: ['precision: FUEL_LOCKUP_MULTIPLIER_PRECISION'];
+						type: 'u32';
+					},
+					{
+						name: 'rewardStreamCount';
+						docs: [
+							'The number of [`RewardStreamDepositor`]s of the depositor. They must all be passed in remaining_accounts',
+							"to instructions that change the depositor's shares",
+							'',
+							'[`RewardStreamDepositor`]: crate::state::RewardStreamDepositor'
+						];
+						type: 'u8';
+					},
+					{
+						name: 'costBasisMigrated';
+						docs: [
+							'Set once cost_basis is tracked. Depositors from before it was tracked value their shares at their net',
+							'deposits until then'
+						];
+						type: 'bool';
+					},
+					{
+						name: 'padding1';
+						type: {
+							array: ['u8', 2];
+						};
+					},
+					{
+						name: 'costBasis';
+						docs: [
+							"The deposit asset paid for the depositor's shares: deposits and the value of shares transferred in, less",
+							'the cost of the shares withdrawn or transferred out. Unchanged by rebases and profit share'
+						];
+						type: 'u64';
+					}
+				];
+			};
 		},
 		{
-			name: 'resetDelegate',
-			accounts: [
-				{
-					name: 'vault',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftUser',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftProgram',
-					isMut: false,
-					isSigner: false,
-				},
-			],
-			args: [],
-		},
-		{
-			name: 'resetFuelSeason',
-			accounts: [
-				{
-					name: 'vault',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'vaultDepositor',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'admin',
-					isMut: false,
-					isSigner: true,
-				},
-				{
-					name: 'driftUserStats',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftState',
-					isMut: false,
-					isSigner: false,
-				},
-			],
-			args: [],
-		},
-		{
-			name: 'resetVaultFuelSeason',
-			accounts: [
-				{
-					name: 'vault',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'admin',
-					isMut: false,
-					isSigner: true,
-				},
-				{
-					name: 'driftState',
-					isMut: false,
-					isSigner: false,
-				},
-			],
-			args: [],
-		},
-		{
-			name: 'managerBorrow',
-			accounts: [
-				{
-					name: 'vault',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'vaultTokenAccount',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'manager',
-					isMut: false,
-					isSigner: true,
-				},
-				{
-					name: 'driftUserStats',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftUser',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftState',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'driftSpotMarketVault',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftSigner',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'userTokenAccount',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftProgram',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'tokenProgram',
-					isMut: false,
-					isSigner: false,
-				},
-			],
-			args: [
-				{
-					name: 'borrowSpotMarketIndex',
-					type: 'u16',
-				},
-				{
-					name: 'borrowAmount',
-					type: 'u64',
-				},
-			],
+			name: 'vaultProtocol';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'protocol';
+						docs: [
+							'The protocol, company, or entity that services the product using this vault.',
+							'The protocol is not allowed to deposit into the vault but can profit share and collect annual fees just like the manager.'
+						];
+						type: 'publicKey';
+					},
+					{
+						name: 'protocolProfitAndFeeShares';
+						docs: [
+							'The shares from profit share and annual fee unclaimed by the protocol.'
+						];
+						type: 'u128';
+					},
+					{
+						name: 'protocolFee';
+						docs: [
+							'The annual fee charged on deposits by the protocol (traditional hedge funds typically charge 2% per year on assets under management).',
+							"Unlike the management fee this can't be negative."
+						];
+						type: 'u64';
+					},
+					{
+						name: 'protocolTotalWithdraws';
+						docs: ['Total withdraws for the protocol'];
+						type: 'u64';
+					},
+					{
+						name: 'protocolTotalFee';
+						docs: [
+							'Total fee charged by the protocol (annual management fee + profit share).',
+							"Unlike the management fee this can't be negative."
+						];
+						type: 'u64';
+					},
+					{
+						name: 'protocolTotalProfitShare';
+						docs: ['Total profit share charged by the protocol'];
+						type: 'u64';
+					},
+					{
+						name: 'lastProtocolWithdrawRequest';
+						type: {
+							defined: 'WithdrawRequest';
+						};
+					},
+					{
+						name: 'protocolProfitShare';
+						docs: [
+							'Percentage the protocol charges on all profits realized by depositors: PERCENTAGE_PRECISION'
+						];
+						type: 'u32';
+					},
+					{
+						name: 'bump';
+						type: 'u8';
+					},
+					{
+						name: 'version';
+						type: 'u8';
+					},
+					{
+						name: 'padding';
+						type: {
+							array: ['u8', 2];
+						};
+					}
+				];
+			};
 		},
 		{
-			name: 'managerRepay',
-			accounts: [
-				{
-					name: 'vault',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'vaultTokenAccount',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'manager',
-					isMut: false,
-					isSigner: true,
-				},
-				{
-					name: 'driftUserStats',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftUser',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftState',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'driftSpotMarketVault',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftSigner',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'userTokenAccount',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftProgram',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'tokenProgram',
-					isMut: false,
-					isSigner: false,
-				},
-			],
-			args: [
-				{
-					name: 'repaySpotMarketIndex',
-					type: 'u16',
-				},
-				{
-					name: 'repayAmount',
-					type: 'u64',
-				},
-				{
-					name: 'repayValue',
-					type: {
-						option: 'u64',
+			name: 'vault';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'name';
+						docs: [
+							'The name of the vault. Vault pubkey is derived from this name.'
+						];
+						type: {
+							array: ['u8', 32];
+						};
 					},
-				},
-			],
-		},
-		{
-			name: 'managerUpdateBorrow',
-			accounts: [
-				{
-					name: 'vault',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'manager',
-					isMut: false,
-					isSigner: true,
-				},
-				{
-					name: 'driftUserStats',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftUser',
-					isMut: true,
-					isSigner: false,
-				},
-			],
-			args: [
-				{
-					name: 'newBorrowValue',
-					type: 'u64',
-				},
-			],
-		},
-		{
-			name: 'managerDeposit',
-			accounts: [
-				{
-					name: 'vault',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'manager',
-					isMut: false,
-					isSigner: true,
-				},
-				{
-					name: 'vaultTokenAccount',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftUserStats',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftUser',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftState',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'driftSpotMarketVault',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'userTokenAccount',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftProgram',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'tokenProgram',
-					isMut: false,
-					isSigner: false,
-				},
-			],
-			args: [
-				{
-					name: 'amount',
-					type: 'u64',
-				},
-			],
-		},
-		{
-			name: 'managerRequestWithdraw',
-			accounts: [
-				{
-					name: 'vault',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'manager',
-					isMut: false,
-					isSigner: true,
-				},
-				{
-					name: 'driftUserStats',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'driftUser',
-					isMut: false,
-					isSigner: false,
-				},
-			],
-			args: [
-				{
-					name: 'withdrawAmount',
-					type: 'u64',
-				},
-				{
-					name: 'withdrawUnit',
-					type: {
-						defined: 'WithdrawUnit',
+					{
+						name: 'pubkey';
+						docs: [
+							"The vault's pubkey. It is a pda of name and also used as the authority for drift user"
+						];
+						type: 'publicKey';
 					},
-				},
-			],
-		},
-		{
-			name: 'mangerCancelWithdrawRequest',
-			accounts: [
-				{
-					name: 'vault',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'manager',
-					isMut: false,
-					isSigner: true,
-				},
-				{
-					name: 'driftUserStats',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'driftUser',
-					isMut: false,
-					isSigner: false,
-				},
-			],
-			args: [],
-		},
-		{
-			name: 'managerWithdraw',
-			accounts: [
-				{
-					name: 'vault',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'manager',
-					isMut: false,
-					isSigner: true,
-				},
-				{
-					name: 'vaultTokenAccount',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftUserStats',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftUser',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftState',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'driftSpotMarketVault',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftSigner',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'userTokenAccount',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftProgram',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'tokenProgram',
-					isMut: false,
-					isSigner: false,
-				},
-			],
-			args: [],
-		},
-		{
-			name: 'managerUpdateFuelDistributionMode',
-			accounts: [
-				{
-					name: 'vault',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'manager',
-					isMut: false,
-					isSigner: true,
-				},
-			],
-			args: [
-				{
-					name: 'fuelDistributionMode',
-					type: 'u8',
-				},
-			],
-		},
-		{
-			name: 'adminInitFeeUpdate',
-			accounts: [
-				{
-					name: 'vault',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'admin',
-					isMut: true,
-					isSigner: true,
-				},
-				{
-					name: 'feeUpdate',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'systemProgram',
-					isMut: false,
-					isSigner: false,
-				},
-			],
-			args: [],
-		},
-		{
-			name: 'adminDeleteFeeUpdate',
-			accounts: [
-				{
-					name: 'vault',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'admin',
-					isMut: true,
-					isSigner: true,
-				},
-				{
-					name: 'feeUpdate',
-					isMut: true,
-					isSigner: false,
-				},
-			],
-			args: [],
-		},
-		{
-			name: 'adminUpdateVaultClass',
-			accounts: [
-				{
-					name: 'vault',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'admin',
-					isMut: true,
-					isSigner: true,
-				},
-				{
-					name: 'systemProgram',
-					isMut: false,
-					isSigner: false,
-				},
-			],
-			args: [
-				{
-					name: 'newVaultClass',
-					type: 'u8',
-				},
-			],
-		},
-		{
-			name: 'managerUpdateFees',
-			accounts: [
-				{
-					name: 'vault',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'manager',
-					isMut: false,
-					isSigner: true,
-				},
-				{
-					name: 'feeUpdate',
-					isMut: true,
-					isSigner: false,
-				},
-			],
-			args: [
-				{
-					name: 'params',
-					type: {
-						defined: 'ManagerUpdateFeesParams',
+					{
+						name: 'manager';
+						docs: [
+							'The manager of the vault who has ability to update vault params'
+						];
+						type: 'publicKey';
 					},
-				},
-			],
-		},
-		{
-			name: 'managerCancelFeeUpdate',
-			accounts: [
-				{
-					name: 'vault',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'manager',
-					isMut: false,
-					isSigner: true,
-				},
-				{
-					name: 'feeUpdate',
-					isMut: true,
-					isSigner: false,
-				},
-			],
-			args: [],
-		},
-		{
-			name: 'applyProfitShare',
-			accounts: [
-				{
-					name: 'vault',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'vaultDepositor',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'manager',
-					isMut: false,
-					isSigner: true,
-				},
-				{
-					name: 'driftUserStats',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftUser',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftState',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'driftSigner',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'driftProgram',
-					isMut: false,
-					isSigner: false,
-				},
-			],
-			args: [],
-		},
-		{
-			name: 'applyRebase',
-			accounts: [
-				{
-					name: 'vault',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'vaultDepositor',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftUser',
-					isMut: true,
-					isSigner: false,
-				},
-			],
-			args: [],
-		},
-		{
-			name: 'applyRebaseTokenizedDepositor',
-			accounts: [
-				{
-					name: 'vault',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'tokenizedVaultDepositor',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftUser',
-					isMut: true,
-					isSigner: false,
-				},
-			],
-			args: [],
-		},
-		{
-			name: 'forceWithdraw',
-			accounts: [
-				{
-					name: 'vault',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'manager',
-					isMut: false,
-					isSigner: true,
-				},
-				{
-					name: 'vaultDepositor',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'vaultTokenAccount',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftUserStats',
-					isMut: true,
-					isSigner: false,
-				},
-				{
+					{
+						name: 'tokenAccount';
+						docs: [
+							'The vaults token account. Used to receive tokens between deposits and withdrawals'
+						];
+						type: 'publicKey';
+					},
+					{
+						name: 'userStats';
+						docs: ['The drift user stats account for the vault'];
+						type: 'publicKey';
+					},
+					{
+						name: 'user';
+						docs: ['The drift user account for the vault'];
+						type: 'publicKey';
+					},
+					{
+						name: 'delegate';
+						docs: [
+							'The vaults designated delegate for drift user account',
+							'can differ from actual user delegate if vault is in liquidation'
+						];
+						type: 'publicKey';
+					},
+					{
+						name: 'liquidationDelegate';
+						docs: ['The delegate handling liquidation for depositor'];
+						type: 'publicKey';
+					},
+					{
+						name: 'userShares';
+						docs: [
+							'The sum of all shares held by the users (vault depositors)'
+						];
+						type: 'u128';
+					},
+					{
+						name: 'totalShares';
+						docs: [
+							'The sum of all shares: deposits from users, manager deposits, manager profit/fee, and protocol profit/fee.',
+							'The manager deposits are total_shares - user_shares - protocol_profit_and_fee_shares.'
+						];
+						type: 'u128';
+					},
+					{
+						name: 'lastFeeUpdateTs';
+						docs: ['Last fee update unix timestamp'];
+						type: 'i64';
+					},
+					{
+						name: 'liquidationStartTs';
+						docs: ['When the liquidation starts'];
+						type: 'i64';
+					},
+					{
+						name: 'redeemPeriod';
+						docs: [
+							'The period (in seconds) that a vault depositor must wait after requesting a withdrawal to finalize withdrawal.',
+							'Currently, the maximum is 90 days.'
+						];
+						type: 'i64';
+					},
+					{
+						name: 'totalWithdrawRequested';
+						docs: ['The sum of all outstanding withdraw requests'];
+						type: 'u64';
+					},
+					{
+						name: 'maxTokens';
+						docs: [
+							'Max token capacity, once hit/passed vault will reject new deposits (updatable)'
+						];
+						type: 'u64';
+					},
+					{
+						name: 'managementFee';
+						docs: [
+							'The annual fee charged on deposits by the manager.',
+							'Traditional funds typically charge 2% per year on assets under management.'
+						];
+						type: 'i64';
+					},
+					{
+						name: 'initTs';
+						docs: ['Timestamp vault initialized'];
+						type: 'i64';
+					},
+					{
+						name: 'netDeposits';
+						docs: ['The net deposits for the vault'];
+						type: 'i64';
+					},
+					{
+						name: 'managerNetDeposits';
+						docs: ['The net deposits for the manager'];
+						type: 'i64';
+					},
+					{
+						name: 'totalDeposits';
+						docs: ['Total deposits'];
+						type: 'u64';
+					},
+					{
+						name: 'totalWithdraws';
+						docs: ['Total withdraws'];
+						type: 'u64';
+					},
+					{
+						name: 'managerTotalDeposits';
+						docs: ['Total deposits for the manager'];
+						type: 'u64';
+					},
+					{
+						name: 'managerTotalWithdraws';
+						docs: ['Total withdraws for the manager'];
+						type: 'u64';
+					},
+					{
+						name: 'managerTotalFee';
+						docs: ['Total management fee accrued by the manager'];
+						type: 'i64';
+					},
+					{
+						name: 'managerTotalProfitShare';
+						docs: ['Total profit share accrued by the manager'];
+						type: 'u64';
+					},
+					{
+						name: 'minDepositAmount';
+						docs: ['The minimum deposit amount'];
+						type: 'u64';
+					},
+					{
+						name: 'lastManagerWithdrawRequest';
+						type: {
+							defined: 'WithdrawRequest';
+						};
+					},
+					{
+						name: 'sharesBase';
+						docs: [
+							'The base 10 exponent of the shares (given massive share inflation can occur at near zero vault equity)'
+						];
+						type: 'u32';
+					},
+					{
+						name: 'profitShare';
+						docs: [
+							'Percentage the manager charges on all profits realized by depositors: PERCENTAGE_PRECISION'
+						];
+						type: 'u32';
+					},
+					{
+						name: 'hurdleRate';
+						docs: [
+							'Vault manager only collect incentive fees during periods when returns are higher than this amount: PERCENTAGE_PRECISION'
+						];
+						type: 'u32';
+					},
+					{
+						name: 'spotMarketIndex';
+						docs: [
+							'The spot market index the vault deposits into/withdraws from'
+						];
+						type: 'u16';
+					},
+					{
+						name: 'bump';
+						docs: ['The bump for the vault pda'];
+						type: 'u8';
+					},
+					{
+						name: 'permissioned';
+						docs: ['Whether anybody can be a depositor'];
+						type: 'bool';
+					},
+					{
+						name: 'vaultProtocol';
+						docs: ['The optional [`VaultProtocol`] account.'];
+						type: 'bool';
+					},
+					{
+						name: 'fuelDistributionMode';
+						docs: [
+							'How fuel distribution should be treated [`FuelDistributionMode`]. Default is `UsersOnly`'
+						];
+						type: 'u8';
+					},
+					{
+						name: 'feeUpdateStatus';
+						docs: [
+							'Whether the vault has a FeeUpdate account [`FeeUpdateStatus`]. Default is `FeeUpdateStatus::None`',
+							'After a `FeeUpdate` account is created and the manager has staged a fee update, the status is set to `PendingFeeUpdate`.',
+							'And instructsions that may finalize the fee update must include the `FeeUpdate` account with `remaining_accounts`.'
+						];
+						type: 'u8';
+					},
+					{
+						name: 'vaultClass';
+						docs: [
+							'The class of the vault [`VaultClass`]. Default is `VaultClass::Normal`'
+						];
+						type: 'u8';
+					},
+					{
+						name: 'lastCumulativeFuelPerShareTs';
+						docs: ['The timestamp cumulative_fuel_per_share was last updated'];
+						type: 'u32';
+					},
+					{
+						name: 'cumulativeFuelPerShare';
+						docs: [
+							'The cumulative fuel per share (scaled up by 1e6 to avoid losing precision)'
+						];
+						type: 'u128';
+					},
+					{
+						name: 'cumulativeFuel';
+						docs: ['The total fuel accumulated'];
+						type: 'u128';
+					},
+					{
+						name: 'managerBorrowedValue';
+						docs: [
+							'The total value (in deposit asset) of borrows the manager has outstanding.',
+							'Purely for informational purposes for assets that have left the vault that the manager',
+							'is expected to return.'
+						];
+						type: 'u64';
+					},
+					{
+						name: 'managerFuelBps';
+						docs: [
+							'The share of fuel allocated to the manager in bps when fuel_distribution_mode is `Weighted`'
+						];
+						type: 'u16';
+					},
+					{
+						name: 'protocolFuelBps';
+						docs: [
+							'The share of fuel allocated to the [`VaultProtocol`] in bps when fuel_distribution_mode is `Weighted`'
+						];
+						type: 'u16';
+					},
+					{
+						name: 'excludePendingWithdrawsFromFuel';
+						docs: [
+							'Whether shares in pending depositor withdraw requests stop accruing fuel.',
+							'Can only be changed while there are no pending withdraw requests.'
+						];
+						type: 'bool';
+					},
+					{
+						name: 'navPolicy';
+						docs: [
+							'Whether the vault has a [`NavPolicy`] account, which must then be passed in remaining_accounts to',
+							'instructions that price deposits and withdrawals.'
+						];
+						type: 'bool';
+					},
+					{
+						name: 'drawdownTriggered';
+						docs: [
+							'Whether the vault breached its [`NavPolicy`] max drawdown. The drift user is kept reduce-only and',
+							'the redeem period is waived until the manager re-enables trading.'
+						];
+						type: 'bool';
+					},
+					{
+						name: 'fuelSeason';
+						docs: [
+							"The number of fuel seasons the vault has been reset for, the current season's [`FuelSeasonSnapshot`]",
+							'pda seed. Wraps after 255 seasons',
+							'',
+							'[`FuelSeasonSnapshot`]: crate::state::FuelSeasonSnapshot'
+						];
+						type: 'u8';
+					},
+					{
+						name: 'fuelSharesAdjustment';
+						docs: [
+							'Net shares added to user_shares when distributing fuel: the extra shares from depositor fuel',
+							'lockups, less the shares in pending tokenized redeem requests and, if exclude_pending_withdraws_from_fuel',
+							'is set, the shares in pending depositor withdraw requests'
+						];
+						type: 'i64';
+					}
+				];
+			};
+		}
+	];
+	types: [
+		{
+			name: 'InitializeRewardStreamParams';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'rewardStreamId';
+						type: 'u16';
+					},
+					{
+						name: 'rewardRate';
+						type: 'u64';
+					},
+					{
+						name: 'startTs';
+						type: 'i64';
+					},
+					{
+						name: 'endTs';
+						type: 'i64';
+					}
+				];
+			};
+		},
+		{
+			name: 'InitializeTokenizedVaultDepositorToken2022Params';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'tokenName';
+						type: 'string';
+					},
+					{
+						name: 'tokenSymbol';
+						type: 'string';
+					},
+					{
+						name: 'tokenUri';
+						type: 'string';
+					},
+					{
+						name: 'decimals';
+						type: 'u8';
+					},
+					{
+						name: 'enableTransferHook';
+						docs: [
+							'Create the mint with this program as its transfer hook, enforcing the vault allowlist and',
+							'depositor lockups on secondary transfers'
+						];
+						type: 'bool';
+					}
+				];
+			};
+		},
+		{
+			name: 'InitializeTokenizedVaultDepositorParams';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'tokenName';
+						type: 'string';
+					},
+					{
+						name: 'tokenSymbol';
+						type: 'string';
+					},
+					{
+						name: 'tokenUri';
+						type: 'string';
+					},
+					{
+						name: 'decimals';
+						type: 'u8';
+					}
+				];
+			};
+		},
+		{
+			name: 'VaultWithProtocolParams';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'name';
+						type: {
+							array: ['u8', 32];
+						};
+					},
+					{
+						name: 'redeemPeriod';
+						type: 'i64';
+					},
+					{
+						name: 'maxTokens';
+						type: 'u64';
+					},
+					{
+						name: 'managementFee';
+						type: 'i64';
+					},
+					{
+						name: 'minDepositAmount';
+						type: 'u64';
+					},
+					{
+						name: 'profitShare';
+						type: 'u32';
+					},
+					{
+						name: 'hurdleRate';
+						type: 'u32';
+					},
+					{
+						name: 'spotMarketIndex';
+						type: 'u16';
+					},
+					{
+						name: 'permissioned';
+						type: 'bool';
+					},
+					{
+						name: 'vaultProtocol';
+						type: {
+							defined: 'VaultProtocolParams';
+						};
+					}
+				];
+			};
+		},
+		{
+			name: 'VaultProtocolParams';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'protocol';
+						type: 'publicKey';
+					},
+					{
+						name: 'protocolFee';
+						type: 'u64';
+					},
+					{
+						name: 'protocolProfitShare';
+						type: 'u32';
+					}
+				];
+			};
+		},
+		{
+			name: 'VaultParams';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'name';
+						type: {
+							array: ['u8', 32];
+						};
+					},
+					{
+						name: 'redeemPeriod';
+						type: 'i64';
+					},
+					{
+						name: 'maxTokens';
+						type: 'u64';
+					},
+					{
+						name: 'managementFee';
+						type: 'i64';
+					},
+					{
+						name: 'minDepositAmount';
+						type: 'u64';
+					},
+					{
+						name: 'profitShare';
+						type: 'u32';
+					},
+					{
+						name: 'hurdleRate';
+						type: 'u32';
+					},
+					{
+						name: 'spotMarketIndex';
+						type: 'u16';
+					},
+					{
+						name: 'permissioned';
+						type: 'bool';
+					}
+				];
+			};
+		},
+		{
+			name: 'ManagerUpdateFeesParams';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'timelockDuration';
+						type: 'i64';
+					},
+					{
+						name: 'newManagementFee';
+						type: {
+							option: 'i64';
+						};
+					},
+					{
+						name: 'newProfitShare';
+						type: {
+							option: 'u32';
+						};
+					},
+					{
+						name: 'newHurdleRate';
+						type: {
+							option: 'u32';
+						};
+					}
+				];
+			};
+		},
+		{
+			name: 'ManagerUpdateFuelDistributionParams';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'timelockDuration';
+						type: 'i64';
+					},
+					{
+						name: 'managerFuelBps';
+						type: 'u16';
+					},
+					{
+						name: 'protocolFuelBps';
+						type: 'u16';
+					}
+				];
+			};
+		},
+		{
+			name: 'UpdateTokenizedVaultDepositorMetadataParams';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'tokenName';
+						type: {
+							option: 'string';
+						};
+					},
+					{
+						name: 'tokenSymbol';
+						type: {
+							option: 'string';
+						};
+					},
+					{
+						name: 'tokenUri';
+						type: {
+							option: 'string';
+						};
+					}
+				];
+			};
+		},
+		{
+			name: 'UpdateVaultProtocolParams';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'protocolFee';
+						type: {
+							option: 'u64';
+						};
+					},
+					{
+						name: 'protocolProfitShare';
+						type: {
+							option: 'u32';
+						};
+					}
+				];
+			};
+		},
+		{
+			name: 'UpdateVaultParams';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'redeemPeriod';
+						type: {
+							option: 'i64';
+						};
+					},
+					{
+						name: 'maxTokens';
+						type: {
+							option: 'u64';
+						};
+					},
+					{
+						name: 'managementFee';
+						type: {
+							option: 'i64';
+						};
+					},
+					{
+						name: 'minDepositAmount';
+						type: {
+							option: 'u64';
+						};
+					},
+					{
+						name: 'profitShare';
+						type: {
+							option: 'u32';
+						};
+					},
+					{
+						name: 'hurdleRate';
+						type: {
+							option: 'u32';
+						};
+					},
+					{
+						name: 'permissioned';
+						type: {
+							option: 'bool';
+						};
+					},
+					{
+						name: 'excludePendingWithdrawsFromFuel';
+						type: {
+							option: 'bool';
+						};
+					}
+				];
+			};
+		},
+		{
+			name: 'VaultConfig';
+			docs: [
+				"The vault's configurable settings, see [`crate::state::Vault::get_config`]"
+			];
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'manager';
+						type: 'publicKey';
+					},
+					{
+						name: 'delegate';
+						type: 'publicKey';
+					},
+					{
+						name: 'liquidationDelegate';
+						type: 'publicKey';
+					},
+					{
+						name: 'redeemPeriod';
+						type: 'i64';
+					},
+					{
+						name: 'maxTokens';
+						type: 'u64';
+					},
+					{
+						name: 'minDepositAmount';
+						type: 'u64';
+					},
+					{
+						name: 'managementFee';
+						type: 'i64';
+					},
+					{
+						name: 'profitShare';
+						type: 'u32';
+					},
+					{
+						name: 'hurdleRate';
+						type: 'u32';
+					},
+					{
+						name: 'permissioned';
+						type: 'bool';
+					},
+					{
+						name: 'excludePendingWithdrawsFromFuel';
+						type: 'bool';
+					},
+					{
+						name: 'vaultClass';
+						type: 'u8';
+					},
+					{
+						name: 'fuelDistributionMode';
+						type: 'u8';
+					},
+					{
+						name: 'managerFuelBps';
+						type: 'u16';
+					},
+					{
+						name: 'protocolFuelBps';
+						type: 'u16';
+					},
+					{
+						name: 'feeUpdateStatus';
+						type: 'u8';
+					},
+					{
+						name: 'managerBorrowedValue';
+						type: 'u64';
+					},
+					{
+						name: 'marginTradingEnabled';
+						docs: [
+							"Settings of the vault's drift user, only set by the instructions that update the drift user"
+						];
+						type: {
+							option: 'bool';
+						};
+					},
+					{
+						name: 'poolId';
+						type: {
+							option: 'u8';
+						};
+					},
+					{
+						name: 'navPolicy';
+						docs: [
+							"Settings of the vault's optional accounts, only set by the instructions that update them"
+						];
+						type: {
+							option: {
+								defined: 'NavPolicyConfig';
+							};
+						};
+					},
+					{
+						name: 'vaultProtocol';
+						type: {
+							option: {
+								defined: 'VaultProtocolConfig';
+							};
+						};
+					},
+					{
+						name: 'fuelDistribution';
+						type: {
+							option: {
+								defined: 'FuelDistributionConfig';
+							};
+						};
+					},
+					{
+						name: 'feeUpdate';
+						type: {
+							option: {
+								defined: 'FeeUpdateConfig';
+							};
+						};
+					}
+				];
+			};
+		},
+		{
+			name: 'NavPolicyConfig';
+			docs: [
+				'The [`crate::state::NavPolicy`] settings, see [`crate::state::NavPolicy::get_config`]'
+			];
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'swingPricingMode';
+						type: 'u8';
+					},
+					{
+						name: 'depositSpreadBps';
+						type: 'u16';
+					},
+					{
+						name: 'withdrawSpreadBps';
+						type: 'u16';
+					},
+					{
+						name: 'maxSwingBps';
+						type: 'u16';
+					},
+					{
+						name: 'oracleValidityMode';
+						type: 'u8';
+					},
+					{
+						name: 'navBreakerWindow';
+						type: 'i64';
+					},
+					{
+						name: 'navBreakerThresholdBps';
+						type: 'u16';
+					},
+					{
+						name: 'navBreakerTripped';
+						type: 'bool';
+					},
+					{
+						name: 'maxDrawdownBps';
+						type: 'u16';
+					},
+					{
+						name: 'drawdownTriggeredTs';
+						type: 'i64';
+					},
+					{
+						name: 'reenableTradingTs';
+						type: 'i64';
+					},
+					{
+						name: 'incomingUpdateTs';
+						docs: [
+							'The queued increase to the withdraw swing, 0 if none is queued'
+						];
+						type: 'i64';
+					},
+					{
+						name: 'incomingWithdrawSpreadBps';
+						type: 'u16';
+					},
+					{
+						name: 'incomingMaxSwingBps';
+						type: 'u16';
+					},
+					{
+						name: 'incomingSwingPricingMode';
+						type: 'u8';
+					}
+				];
+			};
+		},
+		{
+			name: 'VaultProtocolConfig';
+			docs: [
+				'The [`crate::state::VaultProtocol`] settings, see [`crate::state::VaultProtocol::get_config`]'
+			];
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'protocol';
+						type: 'publicKey';
+					},
+					{
+						name: 'protocolFee';
+						type: 'u64';
+					},
+					{
+						name: 'protocolProfitShare';
+						type: 'u32';
+					}
+				];
+			};
+		},
+		{
+			name: 'FuelDistributionConfig';
+			docs: [
+				'The [`crate::state::FuelDistribution`] settings, see [`crate::state::FuelDistribution::get_config`]'
+			];
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'incomingUpdateTs';
+						docs: ['The queued fuel weights, 0 if none are queued'];
+						type: 'i64';
+					},
+					{
+						name: 'incomingManagerFuelBps';
+						type: 'u16';
+					},
+					{
+						name: 'incomingProtocolFuelBps';
+						type: 'u16';
+					}
+				];
+			};
+		},
+		{
+			name: 'FeeUpdateConfig';
+			docs: [
+				'The [`crate::state::FeeUpdate`] settings, see [`crate::state::FeeUpdate::get_config`]'
+			];
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'incomingUpdateTs';
+						docs: ['The queued fees, 0 if none are queued'];
+						type: 'i64';
+					},
+					{
+						name: 'incomingManagementFee';
+						type: 'i64';
+					},
+					{
+						name: 'incomingProfitShare';
+						type: 'u32';
+					},
+					{
+						name: 'incomingHurdleRate';
+						type: 'u32';
+					}
+				];
+			};
+		},
+		{
+			name: 'NavSnapshot';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'ts';
+						docs: ['The ts the snapshot was taken'];
+						type: 'i64';
+					},
+					{
+						name: 'equity';
+						docs: [
+							"The vault's equity (in vault spot_market_index) at the snapshot"
+						];
+						type: 'u64';
+					},
+					{
+						name: 'totalShares';
+						docs: ["The vault's total_shares at the snapshot"];
+						type: 'u128';
+					},
+					{
+						name: 'sharesBase';
+						docs: [
+							"The vault's shares_base at the snapshot, total_shares are only comparable across equal shares_base"
+						];
+						type: 'u32';
+					},
+					{
+						name: 'padding';
+						type: {
+							array: ['u8', 12];
+						};
+					}
+				];
+			};
+		},
+		{
+			name: 'NavPolicyParams';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'swingPricingMode';
+						type: 'u8';
+					},
+					{
+						name: 'depositSpreadBps';
+						type: 'u16';
+					},
+					{
+						name: 'withdrawSpreadBps';
+						type: 'u16';
+					},
+					{
+						name: 'maxSwingBps';
+						type: 'u16';
+					},
+					{
+						name: 'oracleValidityMode';
+						type: 'u8';
+					},
+					{
+						name: 'navBreakerWindow';
+						type: 'i64';
+					},
+					{
+						name: 'navBreakerThresholdBps';
+						type: 'u16';
+					},
+					{
+						name: 'maxDrawdownBps';
+						type: 'u16';
+					}
+				];
+			};
+		},
+		{
+			name: 'DepositPreview';
+			docs: ['Returned by preview_deposit'];
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'vaultEquity';
+						docs: ['The vault equity the deposit is priced at'];
+						type: 'u64';
+					},
+					{
+						name: 'amount';
+						docs: [
+							"The amount deposited, capped to stay within the vault's max_tokens"
+						];
+						type: 'u64';
+					},
+					{
+						name: 'shares';
+						docs: ['The shares minted for the deposit'];
+						type: 'u128';
+					},
+					{
+						name: 'vaultSharesAfter';
+						docs: ["The depositor's shares after the deposit"];
+						type: 'u128';
+					},
+					{
+						name: 'totalSharesAfter';
+						docs: ["The vault's total_shares after the deposit"];
+						type: 'u128';
+					},
+					{
+						name: 'sharesBase';
+						type: 'u32';
+					},
+					{
+						name: 'managerProfitShare';
+						docs: [
+							"Profit share paid by the depositor's existing shares before the deposit"
+						];
+						type: 'u64';
+					},
+					{
+						name: 'protocolProfitShare';
+						type: 'u64';
+					}
+				];
+			};
+		},
+		{
+			name: 'WithdrawPreview';
+			docs: ['Returned by preview_withdraw'];
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'vaultEquity';
+						docs: ['The vault equity the withdraw request is priced at'];
+						type: 'u64';
+					},
+					{
+						name: 'withdrawValue';
+						docs: [
+							"The value of the withdraw request. The withdraw pays the lesser of this and the shares' value once",
+							'the redeem period is over'
+						];
+						type: 'u64';
+					},
+					{
+						name: 'shares';
+						docs: ['The shares in the withdraw request'];
+						type: 'u128';
+					},
+					{
+						name: 'vaultShares';
+						docs: [
+							"The depositor's shares, after profit share, that the request is taken from"
+						];
+						type: 'u128';
+					},
+					{
+						name: 'sharesBase';
+						type: 'u32';
+					},
+					{
+						name: 'redeemPeriodEndTs';
+						docs: ['The ts the withdraw can be completed at'];
+						type: 'i64';
+					},
+					{
+						name: 'managerProfitShare';
+						docs: ['Profit share paid by the depositor before the request'];
+						type: 'u64';
+					},
+					{
+						name: 'protocolProfitShare';
+						type: 'u64';
+					}
+				];
+			};
+		},
+		{
+			name: 'DepositorEquityPreview';
+			docs: ['Returned by preview_depositor_equity'];
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'vaultEquity';
+						docs: ["The vault equity the depositor's shares are valued at"];
+						type: 'u64';
+					},
+					{
+						name: 'vaultShares';
+						docs: ["The depositor's shares after fees and profit share"];
+						type: 'u128';
+					},
+					{
+						name: 'sharesBase';
+						type: 'u32';
+					},
+					{
+						name: 'equity';
+						docs: [
+							"The value of the depositor's shares after fees and profit share"
+						];
+						type: 'u64';
+					},
+					{
+						name: 'managerProfitShare';
+						docs: [
+							'Profit share the depositor would pay now. Not charged while a withdraw request is pending'
+						];
+						type: 'u64';
+					},
+					{
+						name: 'protocolProfitShare';
+						type: 'u64';
+					},
+					{
+						name: 'costBasis';
+						docs: [
+							"The deposit asset paid for the depositor's shares, equity less cost_basis is its unrealized pnl"
+						];
+						type: 'u64';
+					},
+					{
+						name: 'averageEntryPrice';
+						docs: [
+							'cost_basis per share in shares_base. precision: NAV_PER_SHARE_PRECISION'
+						];
+						type: 'u64';
+					}
+				];
+			};
+		},
+		{
+			name: 'FeesPreview';
+			docs: ['Returned by preview_fees'];
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'vaultEquity';
+						type: 'u64';
+					},
+					{
+						name: 'managementFee';
+						docs: [
+							"The vault's fees, including a pending fee update whose timelock has passed"
+						];
+						type: 'i64';
+					},
+					{
+						name: 'profitShare';
+						type: 'u32';
+					},
+					{
+						name: 'hurdleRate';
+						type: 'u32';
+					},
+					{
+						name: 'managementFeePayment';
+						docs: ["Fees accrued since the vault's last_fee_update_ts"];
+						type: 'i64';
+					},
+					{
+						name: 'managementFeeShares';
+						type: 'i64';
+					},
+					{
+						name: 'protocolFeePayment';
+						type: 'i64';
+					},
+					{
+						name: 'protocolFeeShares';
+						type: 'i64';
+					},
+					{
+						name: 'totalSharesAfter';
+						docs: ["The vault's total_shares after fees"];
+						type: 'u128';
+					},
+					{
+						name: 'sharesBase';
+						type: 'u32';
+					}
+				];
+			};
+		},
+		{
+			name: 'WithdrawRequest';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'shares';
+						docs: ['request shares of vault withdraw'];
+						type: 'u128';
+					},
+					{
+						name: 'value';
+						docs: [
+							'requested value (in vault spot_market_index) of shares for withdraw'
+						];
+						type: 'u64';
+					},
+					{
+						name: 'ts';
+						docs: ['request ts of vault withdraw'];
+						type: 'i64';
+					}
+				];
+			};
+		},
+		{
+			name: 'VaultDepositorAction';
+			type: {
+				kind: 'enum';
+				variants: [
+					{
+						name: 'Deposit';
+					},
+					{
+						name: 'WithdrawRequest';
+					},
+					{
+						name: 'CancelWithdrawRequest';
+					},
+					{
+						name: 'Withdraw';
+					},
+					{
+						name: 'FeePayment';
+					},
+					{
+						name: 'TokenizeShares';
+					},
+					{
+						name: 'RedeemTokens';
+					}
+				];
+			};
+		},
+		{
+			name: 'FeeUpdateAction';
+			type: {
+				kind: 'enum';
+				variants: [
+					{
+						name: 'Pending';
+					},
+					{
+						name: 'Applied';
+					},
+					{
+						name: 'Cancelled';
+					}
+				];
+			};
+		},
+		{
+			name: 'FuelDistributionUpdateAction';
+			type: {
+				kind: 'enum';
+				variants: [
+					{
+						name: 'Pending';
+					},
+					{
+						name: 'Applied';
+					}
+				];
+			};
+		},
+		{
+			name: 'NavCircuitBreakerAction';
+			type: {
+				kind: 'enum';
+				variants: [
+					{
+						name: 'Tripped';
+					},
+					{
+						name: 'Reset';
+					}
+				];
+			};
+		},
+		{
+			name: 'DrawdownProtectionAction';
+			type: {
+				kind: 'enum';
+				variants: [
+					{
+						name: 'Triggered';
+					},
+					{
+						name: 'ReenablePending';
+					},
+					{
+						name: 'Reenabled';
+					}
+				];
+			};
+		},
+		{
+			name: 'VaultConfigAction';
+			type: {
+				kind: 'enum';
+				variants: [
+					{
+						name: 'UpdateVault';
+					},
+					{
+						name: 'UpdateDelegate';
+					},
+					{
+						name: 'ResetDelegate';
+					},
+					{
+						name: 'UpdateMarginTradingEnabled';
+					},
+					{
+						name: 'UpdatePoolId';
+					},
+					{
+						name: 'UpdateVaultManager';
+					},
+					{
+						name: 'UpdateVaultClass';
+					},
+					{
+						name: 'UpdateFuelDistributionMode';
+					},
+					{
+						name: 'InitializeNavPolicy';
+					},
+					{
+						name: 'UpdateNavPolicy';
+					},
+					{
+						name: 'UpdateVaultProtocol';
+					},
+					{
+						name: 'UpdateFuelDistribution';
+					},
+					{
+						name: 'ReenableTrading';
+					},
+					{
+						name: 'UpdateFees';
+					},
+					{
+						name: 'ApplyFeeUpdate';
+					},
+					{
+						name: 'InitializeFeeUpdate';
+					},
+					{
+						name: 'DeleteFeeUpdate';
+					},
+					{
+						name: 'UpdateBorrow';
+					},
+					{
+						name: 'ApplyFuelDistribution';
+					}
+				];
+			};
+		},
+		{
+			name: 'FuelLockupPeriod';
+			docs: [
+				'Voluntary lockups a depositor can opt into. While locked, `request_withdraw` is blocked and the',
+				"depositor's shares accrue fuel with the lockup's multiplier."
+			];
+			type: {
+				kind: 'enum';
+				variants: [
+					{
+						name: 'ThirtyDays';
+					},
+					{
+						name: 'NinetyDays';
+					},
+					{
+						name: 'OneHundredEightyDays';
+					}
+				];
+			};
+		},
+		{
+			name: 'NavSide';
+			type: {
+				kind: 'enum';
+				variants: [
+					{
+						name: 'Deposit';
+					},
+					{
+						name: 'Withdraw';
+					}
+				];
+			};
+		},
+		{
+			name: 'SwingPricingMode';
+			type: {
+				kind: 'enum';
+				variants: [
+					{
+						name: 'Disabled';
+					},
+					{
+						name: 'Fixed';
+					},
+					{
+						name: 'PerpMarketSpread';
+					}
+				];
+			};
+		},
+		{
+			name: 'OracleValidityMode';
+			type: {
+				kind: 'enum';
+				variants: [
+					{
+						name: 'Strict';
+					},
+					{
+						name: 'ConservativeWithdraws';
+					}
+				];
+			};
+		},
+		{
+			name: 'FuelDistributionMode';
+			type: {
+				kind: 'enum';
+				variants: [
+					{
+						name: 'UsersOnly';
+					},
+					{
+						name: 'UsersAndManager';
+					},
+					{
+						name: 'Weighted';
+					}
+				];
+			};
+		},
+		{
+			name: 'FeeUpdateStatus';
+			type: {
+				kind: 'enum';
+				variants: [
+					{
+						name: 'None';
+					},
+					{
+						name: 'PendingFeeUpdate';
+					}
+				];
+			};
+		},
+		{
+			name: 'VaultClass';
+			type: {
+				kind: 'enum';
+				variants: [
+					{
+						name: 'Normal';
+					},
+					{
+						name: 'Trusted';
+					}
+				];
+			};
+		},
+		{
+			name: 'WithdrawUnit';
+			type: {
+				kind: 'enum';
+				variants: [
+					{
+						name: 'Shares';
+					},
+					{
+						name: 'Token';
+					},
+					{
+						name: 'SharesPercent';
+					}
+				];
+			};
+		}
+	];
+	events: [
+		{
+			name: 'VaultRecord';
+			fields: [
+				{
+					name: 'ts';
+					type: 'i64';
+					index: false;
+				},
+				{
+					name: 'vault';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'spotMarketIndex';
+					type: 'u16';
+					index: false;
+				},
+				{
+					name: 'vaultEquityBefore';
+					type: 'u64';
+					index: false;
+				},
+				{
+					name: 'totalShares';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'sharesBase';
+					type: 'u32';
+					index: false;
+				}
+			];
+		},
+		{
+			name: 'VaultDepositorRecord';
+			fields: [
+				{
+					name: 'ts';
+					type: 'i64';
+					index: false;
+				},
+				{
+					name: 'vault';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'depositorAuthority';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'action';
+					type: {
+						defined: 'VaultDepositorAction';
+					};
+					index: false;
+				},
+				{
+					name: 'amount';
+					type: 'u64';
+					index: false;
+				},
+				{
+					name: 'spotMarketIndex';
+					type: 'u16';
+					index: false;
+				},
+				{
+					name: 'vaultSharesBefore';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'vaultSharesAfter';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'vaultEquityBefore';
+					type: 'u64';
+					index: false;
+				},
+				{
+					name: 'userVaultSharesBefore';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'totalVaultSharesBefore';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'userVaultSharesAfter';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'totalVaultSharesAfter';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'profitShare';
+					type: 'u64';
+					index: false;
+				},
+				{
+					name: 'managementFee';
+					type: 'i64';
+					index: false;
+				},
+				{
+					name: 'managementFeeShares';
+					type: 'i64';
+					index: false;
+				},
+				{
+					name: 'depositOraclePrice';
+					type: 'i64';
+					index: false;
+				}
+			];
+		},
+		{
+			name: 'VaultDepositorV1Record';
+			fields: [
+				{
+					name: 'ts';
+					type: 'i64';
+					index: false;
+				},
+				{
+					name: 'vault';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'depositorAuthority';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'action';
+					type: {
+						defined: 'VaultDepositorAction';
+					};
+					index: false;
+				},
+				{
+					name: 'amount';
+					type: 'u64';
+					index: false;
+				},
+				{
+					name: 'spotMarketIndex';
+					type: 'u16';
+					index: false;
+				},
+				{
+					name: 'vaultSharesBefore';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'vaultSharesAfter';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'vaultEquityBefore';
+					type: 'u64';
+					index: false;
+				},
+				{
+					name: 'userVaultSharesBefore';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'totalVaultSharesBefore';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'userVaultSharesAfter';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'totalVaultSharesAfter';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'protocolSharesBefore';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'protocolSharesAfter';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'protocolProfitShare';
+					type: 'u64';
+					index: false;
+				},
+				{
+					name: 'protocolFee';
+					type: 'i64';
+					index: false;
+				},
+				{
+					name: 'protocolFeeShares';
+					type: 'i64';
+					index: false;
+				},
+				{
+					name: 'managerProfitShare';
+					type: 'u64';
+					index: false;
+				},
+				{
+					name: 'managementFee';
+					type: 'i64';
+					index: false;
+				},
+				{
+					name: 'managementFeeShares';
+					type: 'i64';
+					index: false;
+				},
+				{
+					name: 'depositOraclePrice';
+					type: 'i64';
+					index: false;
+				}
+			];
+		},
+		{
+			name: 'VaultDepositorV2Record';
+			fields: [
+				{
+					name: 'ts';
+					type: 'i64';
+					index: false;
+				},
+				{
+					name: 'vault';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'depositorAuthority';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'action';
+					type: {
+						defined: 'VaultDepositorAction';
+					};
+					index: false;
+				},
+				{
+					name: 'amount';
+					type: 'u64';
+					index: false;
+				},
+				{
+					name: 'spotMarketIndex';
+					type: 'u16';
+					index: false;
+				},
+				{
+					name: 'sharesBase';
+					type: 'u32';
+					index: false;
+				},
+				{
+					name: 'vaultSharesBefore';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'vaultSharesAfter';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'vaultEquityBefore';
+					type: 'u64';
+					index: false;
+				},
+				{
+					name: 'managerBorrowedValue';
+					type: 'u64';
+					index: false;
+				},
+				{
+					name: 'feeUpdateStatus';
+					type: 'u8';
+					index: false;
+				},
+				{
+					name: 'userVaultSharesBefore';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'totalVaultSharesBefore';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'userVaultSharesAfter';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'totalVaultSharesAfter';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'managerProfitShare';
+					type: 'u64';
+					index: false;
+				},
+				{
+					name: 'managementFee';
+					type: 'i64';
+					index: false;
+				},
+				{
+					name: 'managementFeeShares';
+					type: 'i64';
+					index: false;
+				},
+				{
+					name: 'protocolSharesBefore';
+					type: {
+						option: 'u128';
+					};
+					index: false;
+				},
+				{
+					name: 'protocolSharesAfter';
+					type: {
+						option: 'u128';
+					};
+					index: false;
+				},
+				{
+					name: 'protocolProfitShare';
+					type: {
+						option: 'u64';
+					};
+					index: false;
+				},
+				{
+					name: 'protocolFee';
+					type: {
+						option: 'i64';
+					};
+					index: false;
+				},
+				{
+					name: 'protocolFeeShares';
+					type: {
+						option: 'i64';
+					};
+					index: false;
+				},
+				{
+					name: 'depositOraclePrice';
+					type: 'i64';
+					index: false;
+				},
+				{
+					name: 'costBasisAfter';
+					type: {
+						option: 'u64';
+					};
+					index: false;
+				},
+				{
+					name: 'realizedPnl';
+					type: {
+						option: 'i64';
+					};
+					index: false;
+				}
+			];
+		},
+		{
+			name: 'ShareTransferRecord';
+			fields: [
+				{
+					name: 'ts';
+					type: 'i64';
+					index: false;
+				},
+				{
+					name: 'vault';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'fromVaultDepositor';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'toVaultDepositor';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'shares';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'value';
+					type: 'u64';
+					index: false;
+				},
+				{
+					name: 'fromDepositorSharesBefore';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'fromDepositorSharesAfter';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'toDepositorSharesBefore';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'toDepositorSharesAfter';
+					type: 'u128';
+					index: false;
+				}
+			];
+		},
+		{
+			name: 'FuelSeasonRecord';
+			fields: [
+				{
+					name: 'ts';
+					type: 'i64';
+					index: false;
+				},
+				{
+					name: 'authority';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'fuelInsurance';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'fuelDeposits';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'fuelBorrows';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'fuelPositions';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'fuelTaker';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'fuelMaker';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'fuelTotal';
+					type: 'u128';
+					index: false;
+				}
+			];
+		},
+		{
+			name: 'VaultDepositorFuelRecord';
+			fields: [
+				{
+					name: 'ts';
+					type: 'i64';
+					index: false;
+				},
+				{
+					name: 'vault';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'vaultDepositor';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'depositorAuthority';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'fuelAmountBefore';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'fuelAmountAfter';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'fuelShares';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'cumulativeFuelPerShare';
+					type: 'u128';
+					index: false;
+				}
+			];
+		},
+		{
+			name: 'FeeUpdateRecord';
+			fields: [
+				{
+					name: 'ts';
+					type: 'i64';
+					index: false;
+				},
+				{
+					name: 'action';
+					type: {
+						defined: 'FeeUpdateAction';
+					};
+					index: false;
+				},
+				{
+					name: 'timelockEndTs';
+					type: 'i64';
+					index: false;
+				},
+				{
+					name: 'vault';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'oldManagementFee';
+					type: 'i64';
+					index: false;
+				},
+				{
+					name: 'oldProfitShare';
+					type: 'u32';
+					index: false;
+				},
+				{
+					name: 'oldHurdleRate';
+					type: 'u32';
+					index: false;
+				},
+				{
+					name: 'newManagementFee';
+					type: 'i64';
+					index: false;
+				},
+				{
+					name: 'newProfitShare';
+					type: 'u32';
+					index: false;
+				},
+				{
+					name: 'newHurdleRate';
+					type: 'u32';
+					index: false;
+				}
+			];
+		},
+		{
+			name: 'FuelDistributionUpdateRecord';
+			fields: [
+				{
+					name: 'ts';
+					type: 'i64';
+					index: false;
+				},
+				{
+					name: 'action';
+					type: {
+						defined: 'FuelDistributionUpdateAction';
+					};
+					index: false;
+				},
+				{
+					name: 'timelockEndTs';
+					type: 'i64';
+					index: false;
+				},
+				{
+					name: 'vault';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'oldManagerFuelBps';
+					type: 'u16';
+					index: false;
+				},
+				{
+					name: 'oldProtocolFuelBps';
+					type: 'u16';
+					index: false;
+				},
+				{
+					name: 'newManagerFuelBps';
+					type: 'u16';
+					index: false;
+				},
+				{
+					name: 'newProtocolFuelBps';
+					type: 'u16';
+					index: false;
+				}
+			];
+		},
+		{
+			name: 'ManagerBorrowRecord';
+			fields: [
+				{
+					name: 'ts';
+					type: 'i64';
+					index: false;
+				},
+				{
+					name: 'vault';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'manager';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'borrowAmount';
+					type: 'u64';
+					index: false;
+				},
+				{
+					name: 'borrowValue';
+					type: 'u64';
+					index: false;
+				},
+				{
+					name: 'borrowSpotMarketIndex';
+					type: 'u16';
+					index: false;
+				},
+				{
+					name: 'borrowOraclePrice';
+					type: 'i64';
+					index: false;
+				},
+				{
+					name: 'depositSpotMarketIndex';
+					type: 'u16';
+					index: false;
+				},
+				{
+					name: 'depositOraclePrice';
+					type: 'i64';
+					index: false;
+				},
+				{
+					name: 'vaultEquity';
+					type: 'u64';
+					index: false;
+				}
+			];
+		},
+		{
+			name: 'ManagerRepayRecord';
+			fields: [
+				{
+					name: 'ts';
+					type: 'i64';
+					index: false;
+				},
+				{
+					name: 'vault';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'manager';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'repayAmount';
+					type: 'u64';
+					index: false;
+				},
+				{
+					name: 'repayValue';
+					type: 'u64';
+					index: false;
+				},
+				{
+					name: 'repaySpotMarketIndex';
+					type: 'u16';
+					index: false;
+				},
+				{
+					name: 'repayOraclePrice';
+					type: 'i64';
+					index: false;
+				},
+				{
+					name: 'depositSpotMarketIndex';
+					type: 'u16';
+					index: false;
+				},
+				{
+					name: 'depositOraclePrice';
+					type: 'i64';
+					index: false;
+				},
+				{
+					name: 'vaultEquityBefore';
+					type: 'u64';
+					index: false;
+				},
+				{
+					name: 'vaultEquityAfter';
+					type: 'u64';
+					index: false;
+				}
+			];
+		},
+		{
+			name: 'ManagerUpdateBorrowRecord';
+			fields: [
+				{
+					name: 'ts';
+					type: 'i64';
+					index: false;
+				},
+				{
+					name: 'vault';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'manager';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'previousBorrowValue';
+					type: 'u64';
+					index: false;
+				},
+				{
+					name: 'newBorrowValue';
+					type: 'u64';
+					index: false;
+				},
+				{
+					name: 'vaultEquityBefore';
+					type: 'u64';
+					index: false;
+				},
+				{
+					name: 'vaultEquityAfter';
+					type: 'u64';
+					index: false;
+				}
+			];
+		},
+		{
+			name: 'NavCircuitBreakerRecord';
+			fields: [
+				{
+					name: 'ts';
+					type: 'i64';
+					index: false;
+				},
+				{
+					name: 'action';
+					type: {
+						defined: 'NavCircuitBreakerAction';
+					};
+					index: false;
+				},
+				{
+					name: 'vault';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'authority';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'lastEquityPerShare';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'lastEquityTs';
+					type: 'i64';
+					index: false;
+				},
+				{
+					name: 'equityPerShare';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'thresholdBps';
+					type: 'u16';
+					index: false;
+				}
+			];
+		},
+		{
+			name: 'DrawdownProtectionRecord';
+			fields: [
+				{
+					name: 'ts';
+					type: 'i64';
+					index: false;
+				},
+				{
+					name: 'action';
+					type: {
+						defined: 'DrawdownProtectionAction';
+					};
+					index: false;
+				},
+				{
+					name: 'vault';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'hwmEquityPerShare';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'equityPerShare';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'maxDrawdownBps';
+					type: 'u16';
+					index: false;
+				},
+				{
+					name: 'reenableTradingTs';
+					type: 'i64';
+					index: false;
+				}
+			];
+		},
+		{
+			name: 'VaultConfigRecord';
+			fields: [
+				{
+					name: 'ts';
+					type: 'i64';
+					index: false;
+				},
+				{
+					name: 'action';
+					type: {
+						defined: 'VaultConfigAction';
+					};
+					index: false;
+				},
+				{
+					name: 'vault';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'authority';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'oldConfig';
+					type: {
+						defined: 'VaultConfig';
+					};
+					index: false;
+				},
+				{
+					name: 'newConfig';
+					type: {
+						defined: 'VaultConfig';
+					};
+					index: false;
+				}
+			];
+		}
+	];
+	errors: [
+		{
+			code: 6000;
+			name: 'Default';
+			msg: 'Default';
+		},
+		{
+			code: 6001;
+			name: 'InvalidVaultRebase';
+			msg: 'InvalidVaultRebase';
+		},
+		{
+			code: 6002;
+			name: 'InvalidVaultSharesDetected';
+			msg: 'InvalidVaultSharesDetected';
+		},
+		{
+			code: 6003;
+			name: 'CannotWithdrawBeforeRedeemPeriodEnd';
+			msg: 'CannotWithdrawBeforeRedeemPeriodEnd';
+		},
+		{
+			code: 6004;
+			name: 'InvalidVaultWithdraw';
+			msg: 'InvalidVaultWithdraw';
+		},
+		{
+			code: 6005;
+			name: 'InsufficientVaultShares';
+			msg: 'InsufficientVaultShares';
+		},
+		{
+			code: 6006;
+			name: 'InvalidVaultWithdrawSize';
+			msg: 'InvalidVaultWithdrawSize';
+		},
+		{
+			code: 6007;
+			name: 'InvalidVaultForNewDepositors';
+			msg: 'InvalidVaultForNewDepositors';
+		},
+		{
+			code: 6008;
+			name: 'VaultWithdrawRequestInProgress';
+			msg: 'VaultWithdrawRequestInProgress';
+		},
+		{
+			code: 6009;
+			name: 'VaultIsAtCapacity';
+			msg: 'VaultIsAtCapacity';
+		},
+		{
+			code: 6010;
+			name: 'InvalidVaultDepositorInitialization';
+			msg: 'InvalidVaultDepositorInitialization';
+		},
+		{
+			code: 6011;
+			name: 'DelegateNotAvailableForLiquidation';
+			msg: 'DelegateNotAvailableForLiquidation';
+		},
+		{
+			code: 6012;
+			name: 'InvalidEquityValue';
+			msg: 'InvalidEquityValue';
+		},
+		{
+			code: 6013;
+			name: 'VaultInLiquidation';
+			msg: 'VaultInLiquidation';
+		},
+		{
+			code: 6014;
+			name: 'DriftError';
+			msg: 'DriftError';
+		},
+		{
+			code: 6015;
+			name: 'InvalidVaultInitialization';
+			msg: 'InvalidVaultInitialization';
+		},
+		{
+			code: 6016;
+			name: 'InvalidVaultUpdate';
+			msg: 'InvalidVaultUpdate';
+		},
+		{
+			code: 6017;
+			name: 'PermissionedVault';
+			msg: 'PermissionedVault';
+		},
+		{
+			code: 6018;
+			name: 'WithdrawInProgress';
+			msg: 'WithdrawInProgress';
+		},
+		{
+			code: 6019;
+			name: 'SharesPercentTooLarge';
+			msg: 'SharesPercentTooLarge';
+		},
+		{
+			code: 6020;
+			name: 'InvalidVaultDeposit';
+			msg: 'InvalidVaultDeposit';
+		},
+		{
+			code: 6021;
+			name: 'OngoingLiquidation';
+			msg: 'OngoingLiquidation';
+		},
+		{
+			code: 6022;
+			name: 'VaultProtocolMissing';
+			msg: 'VaultProtocolMissing';
+		},
+		{
+			code: 6023;
+			name: 'InvalidTokenization';
+			msg: 'InvalidTokenization';
+		},
+		{
+			code: 6024;
+			name: 'InvalidFuelDistributionMode';
+			msg: 'InvalidFuelDistributionMode';
+		},
+		{
+			code: 6025;
+			name: 'FeeUpdateMissing';
+			msg: 'FeeUpdateMissing';
+		},
+		{
+			code: 6026;
+			name: 'InvalidFeeUpdateStatus';
+			msg: 'InvalidFeeUpdateStatus';
+		},
+		{
+			code: 6027;
+			name: 'InvalidVaultClass';
+			msg: 'InvalidVaultClass';
+		},
+		{
+			code: 6028;
+			name: 'InvalidBorrowAmount';
+			msg: 'InvalidBorrowAmount';
+		},
+		{
+			code: 6029;
+			name: 'InvalidRepayAmount';
+			msg: 'InvalidRepayAmount';
+		},
+		{
+			code: 6030;
+			name: 'VaultDepositorLocked';
+			msg: 'VaultDepositorLocked';
+		},
+		{
+			code: 6031;
+			name: 'InvalidRewardStream';
+			msg: 'InvalidRewardStream';
+		},
+		{
+			code: 6032;
+			name: 'InvalidNavPolicy';
+			msg: 'InvalidNavPolicy';
+		},
+		{
+			code: 6033;
+			name: 'NavPolicyMissing';
+			msg: 'NavPolicyMissing';
+		},
+		{
+			code: 6034;
+			name: 'NavCircuitBreakerTripped';
+			msg: 'NavCircuitBreakerTripped';
+		},
+		{
+			code: 6035;
+			name: 'NavSnapshotTooSoon';
+			msg: 'NavSnapshotTooSoon';
+		},
+		{
+			code: 6036;
+			name: 'FuelDistributionMissing';
+			msg: 'FuelDistributionMissing';
+		},
+		{
+			code: 6037;
+			name: 'FuelBoostNotExpired';
+			msg: 'FuelBoostNotExpired';
+		},
+		{
+			code: 6038;
+			name: 'RewardStreamDepositorMissing';
+			msg: 'RewardStreamDepositorMissing';
+		},
+		{
+			code: 6039;
+			name: 'InvalidFuelSeason';
+			msg: 'InvalidFuelSeason';
+		},
+		{
+			code: 6040;
+			name: 'NavCircuitBreakerNotTripped';
+			msg: 'NavCircuitBreakerNotTripped';
+		}
+	];
+};
+
+export const IDL: DriftVaults = {
+	version: '0.9.0',
+	name: 'drift_vaults',
+	instructions: [
+		{
+			name: 'initializeVault',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'tokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftState',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftSpotMarket',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftSpotMarketMint',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'payer',
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: 'rent',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'systemProgram',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftProgram',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'tokenProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'params',
+					type: {
+						defined: 'VaultParams',
+					},
+				},
+			],
+		},
+		{
+			name: 'initializeVaultWithProtocol',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultProtocol',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'tokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftState',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftSpotMarket',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftSpotMarketMint',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'payer',
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: 'rent',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'systemProgram',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftProgram',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'tokenProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'params',
+					type: {
+						defined: 'VaultWithProtocolParams',
+					},
+				},
+			],
+		},
+		{
+			name: 'updateDelegate',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'driftUser',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'delegate',
+					type: 'publicKey',
+				},
+			],
+		},
+		{
+			name: 'updateMarginTradingEnabled',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'driftUser',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'enabled',
+					type: 'bool',
+				},
+			],
+		},
+		{
+			name: 'updateUserPoolId',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'driftUser',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'poolId',
+					type: 'u8',
+				},
+			],
+		},
+		{
+			name: 'updateVaultProtocol',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'protocol',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'vaultProtocol',
+					isMut: true,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'params',
+					type: {
+						defined: 'UpdateVaultProtocolParams',
+					},
+				},
+			],
+		},
+		{
+			name: 'updateVault',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+			],
+			args: [
+				{
+					name: 'params',
+					type: {
+						defined: 'UpdateVaultParams',
+					},
+				},
+			],
+		},
+		{
+			name: 'updateVaultManager',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+			],
+			args: [
+				{
+					name: 'manager',
+					type: 'publicKey',
+				},
+			],
+		},
+		{
+			name: 'updateCumulativeFuelAmount',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositor',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'signer',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: true,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'initializeVaultDepositor',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositor',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'authority',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'payer',
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: 'rent',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'systemProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'initializeTokenizedVaultDepositor',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositor',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'mintAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'metadataAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'payer',
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: 'tokenProgram',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'tokenMetadataProgram',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'rent',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'systemProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'params',
+					type: {
+						defined: 'InitializeTokenizedVaultDepositorParams',
+					},
+				},
+			],
+		},
+		{
+			name: 'initializeTokenizedVaultDepositorToken2022',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositor',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'mintAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'payer',
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: 'extraAccountMetaList',
+					isMut: true,
+					isSigner: false,
+					isOptional: true,
+					docs: ['enable_transfer_hook is set'],
+				},
+				{
+					name: 'tokenProgram',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'systemProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'params',
+					type: {
+						defined: 'InitializeTokenizedVaultDepositorToken2022Params',
+					},
+				},
+			],
+		},
+		{
+			name: 'updateTokenizedVaultDepositorMetadata',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: 'tokenizedVaultDepositor',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'mint',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'metadataAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'tokenProgram',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'tokenMetadataProgram',
+					isMut: false,
+					isSigner: false,
+					isOptional: true,
+				},
+				{
+					name: 'systemProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'params',
+					type: {
+						defined: 'UpdateTokenizedVaultDepositorMetadataParams',
+					},
+				},
+			],
+		},
+		{
+			name: 'tokenizeShares',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositor',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'authority',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'tokenizedVaultDepositor',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'mint',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'userTokenAccount',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'tokenProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'amount',
+					type: 'u64',
+				},
+				{
+					name: 'unit',
+					type: {
+						defined: 'WithdrawUnit',
+					},
+				},
+			],
+		},
+		{
+			name: 'redeemTokens',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositor',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'authority',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'tokenizedVaultDepositor',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'mint',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'userTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'tokenProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'tokensToBurn',
+					type: 'u64',
+				},
+			],
+		},
+		{
+			name: 'redeemTokensV2',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositor',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'authority',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'tokenizedVaultDepositor',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'mint',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'userTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'tokenProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'tokensToBurn',
+					type: 'u64',
+				},
+			],
+		},
+		{
+			name: 'transferHook',
+			accounts: [
+				{
+					name: 'sourceToken',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'mint',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'destinationToken',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'owner',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'extraAccountMetaList',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'sourceVaultDepositor',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'destinationVaultDepositor',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'amount',
+					type: 'u64',
+				},
+			],
+		},
+		{
+			name: 'depositAndMint',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'tokenizedVaultDepositor',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'mint',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'authority',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'vaultTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftState',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftSpotMarketVault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'userTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'userShareTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftProgram',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'tokenProgram',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'shareTokenProgram',
+					isMut: false,
+					isSigner: false,
+					docs: [
+						'The token program that owns the share token mint, either SPL Token or Token-2022',
+					],
+				},
+			],
+			args: [
+				{
+					name: 'amount',
+					type: 'u64',
+				},
+			],
+		},
+		{
+			name: 'requestRedeem',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'tokenizedVaultDepositor',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'redeemRequest',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'authority',
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: 'mint',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'userTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'tokenProgram',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'systemProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'tokensToBurn',
+					type: 'u64',
+				},
+				{
+					name: 'requestId',
+					type: 'u16',
+				},
+			],
+		},
+		{
+			name: 'cancelRedeemRequest',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'tokenizedVaultDepositor',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'redeemRequest',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'authority',
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: 'mint',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'userTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'tokenProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'requestId',
+					type: 'u16',
+				},
+			],
+		},
+		{
+			name: 'redeemTokensForUnderlying',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'tokenizedVaultDepositor',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'redeemRequest',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'authority',
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: 'vaultTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftState',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftSpotMarketVault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftSigner',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'userTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftProgram',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'tokenProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'requestId',
+					type: 'u16',
+				},
+			],
+		},
+		{
+			name: 'deposit',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositor',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'authority',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'vaultTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftState',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftSpotMarketVault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'userTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftProgram',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'tokenProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'amount',
+					type: 'u64',
+				},
+			],
+		},
+		{
+			name: 'requestWithdraw',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositor',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'authority',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'withdrawAmount',
+					type: 'u64',
+				},
+				{
+					name: 'withdrawUnit',
+					type: {
+						defined: 'WithdrawUnit',
+					},
+				},
+			],
+		},
+		{
+			name: 'lockVaultDepositor',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositor',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'authority',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'lockupPeriod',
+					type: {
+						defined: 'FuelLockupPeriod',
+					},
+				},
+			],
+		},
+		{
+			name: 'cancelRequestWithdraw',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositor',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'authority',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'withdraw',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositor',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'authority',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'vaultTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftState',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftSpotMarketVault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftSigner',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'userTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftProgram',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'tokenProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'liquidate',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositor',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'authority',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'admin',
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'resetDelegate',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'resetFuelSeason',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositor',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'fuelSeasonSnapshot',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'admin',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'payer',
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftState',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'systemProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'resetVaultFuelSeason',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'admin',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'driftState',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'settleFuel',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositor',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'removeExpiredFuelBoost',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositor',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'managerBorrow',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftState',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftSpotMarketVault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftSigner',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'userTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftProgram',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'tokenProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'borrowSpotMarketIndex',
+					type: 'u16',
+				},
+				{
+					name: 'borrowAmount',
+					type: 'u64',
+				},
+			],
+		},
+		{
+			name: 'managerRepay',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftState',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftSpotMarketVault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftSigner',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'userTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftProgram',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'tokenProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'repaySpotMarketIndex',
+					type: 'u16',
+				},
+				{
+					name: 'repayAmount',
+					type: 'u64',
+				},
+				{
+					name: 'repayValue',
+					type: {
+						option: 'u64',
+					},
+				},
+			],
+		},
+		{
+			name: 'managerUpdateBorrow',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: true,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'newBorrowValue',
+					type: 'u64',
+				},
+			],
+		},
+		{
+			name: 'managerDeposit',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'vaultTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftState',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftSpotMarketVault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'userTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftProgram',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'tokenProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'amount',
+					type: 'u64',
+				},
+			],
+		},
+		{
+			name: 'managerRequestWithdraw',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'withdrawAmount',
+					type: 'u64',
+				},
+				{
+					name: 'withdrawUnit',
+					type: {
+						defined: 'WithdrawUnit',
+					},
+				},
+			],
+		},
+		{
+			name: 'mangerCancelWithdrawRequest',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'managerWithdraw',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'vaultTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftState',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftSpotMarketVault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftSigner',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'userTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftProgram',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'tokenProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'managerUpdateFuelDistributionMode',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+			],
+			args: [
+				{
+					name: 'fuelDistributionMode',
+					type: 'u8',
+				},
+			],
+		},
+		{
+			name: 'initializeFuelDistribution',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: 'fuelDistribution',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'systemProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'managerUpdateFuelDistribution',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'fuelDistribution',
+					isMut: true,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'params',
+					type: {
+						defined: 'ManagerUpdateFuelDistributionParams',
+					},
+				},
+			],
+		},
+		{
+			name: 'updateFuelDistribution',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'fuelDistribution',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'initializeNavPolicy',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: 'navPolicy',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'systemProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'params',
+					type: {
+						defined: 'NavPolicyParams',
+					},
+				},
+			],
+		},
+		{
+			name: 'managerUpdateNavPolicy',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'navPolicy',
+					isMut: true,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'params',
+					type: {
+						defined: 'NavPolicyParams',
+					},
+				},
+			],
+		},
+		{
+			name: 'resetNavCircuitBreaker',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'authority',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'navPolicy',
+					isMut: true,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'tripNavBreaker',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'navPolicy',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'checkMaxDrawdown',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'navPolicy',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'managerReenableTrading',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'navPolicy',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'depositorReenableTrading',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositor',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'authority',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'navPolicy',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'initializeNavHistory',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'payer',
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: 'navHistory',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'systemProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'snapshotVault',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'navHistory',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'initializeManagerStats',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'payer',
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: 'managerStats',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'systemProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'updateManagerStats',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'managerStats',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'previewDeposit',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositor',
+					isMut: false,
+					isSigner: false,
+					isOptional: true,
+				},
+				{
+					name: 'driftUser',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'amount',
+					type: 'u64',
+				},
+			],
+			returns: {
+				defined: 'DepositPreview',
+			},
+		},
+		{
+			name: 'previewWithdraw',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositor',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'withdrawAmount',
+					type: 'u64',
+				},
+				{
+					name: 'withdrawUnit',
+					type: {
+						defined: 'WithdrawUnit',
+					},
+				},
+			],
+			returns: {
+				defined: 'WithdrawPreview',
+			},
+		},
+		{
+			name: 'previewDepositorEquity',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositor',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+			returns: {
+				defined: 'DepositorEquityPreview',
+			},
+		},
+		{
+			name: 'previewFees',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+			returns: {
+				defined: 'FeesPreview',
+			},
+		},
+		{
+			name: 'initializeRewardStream',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: 'rewardStream',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'mint',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'tokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'tokenProgram',
+					isMut: false,
+					isSigner: false,
+					docs: [
+						'The token program that owns the reward mint, either SPL Token or Token-2022',
+					],
+				},
+				{
+					name: 'systemProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'params',
+					type: {
+						defined: 'InitializeRewardStreamParams',
+					},
+				},
+			],
+		},
+		{
+			name: 'fundRewardStream',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'rewardStream',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'mint',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'tokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'managerTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'tokenProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'amount',
+					type: 'u64',
+				},
+			],
+		},
+		{
+			name: 'closeRewardStream',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: 'rewardStream',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'mint',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'tokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'managerTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'tokenProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'initializeRewardStreamDepositor',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'rewardStream',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositor',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'authority',
+					isMut: false,
+					isSigner: true,
+					docs: [
+						'The depositor has to pass the reward stream depositor whenever its shares change, so only it can opt in',
+					],
+				},
+				{
+					name: 'rewardStreamDepositor',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'payer',
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: 'systemProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'closeRewardStreamDepositor',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'rewardStream',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositor',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'rewardStreamDepositor',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'signer',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'authority',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'mint',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'tokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'userTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'tokenProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'claimRewardStream',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'rewardStream',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositor',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'rewardStreamDepositor',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'authority',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'mint',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'tokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'userTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'tokenProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'adminInitFeeUpdate',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'admin',
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: 'feeUpdate',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'systemProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'adminDeleteFeeUpdate',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'admin',
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: 'feeUpdate',
+					isMut: true,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'adminUpdateVaultClass',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'admin',
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: 'systemProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'newVaultClass',
+					type: 'u8',
+				},
+			],
+		},
+		{
+			name: 'managerUpdateFees',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'feeUpdate',
+					isMut: true,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'params',
+					type: {
+						defined: 'ManagerUpdateFeesParams',
+					},
+				},
+			],
+		},
+		{
+			name: 'managerCancelFeeUpdate',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'feeUpdate',
+					isMut: true,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'applyProfitShare',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositor',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftState',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftSigner',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'applyRebase',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositor',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: true,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'applyRebaseTokenizedDepositor',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'tokenizedVaultDepositor',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: true,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'forceWithdraw',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'vaultDepositor',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftState',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftSpotMarketVault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftSigner',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'userTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftProgram',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'tokenProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'initializeInsuranceFundStake',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'payer',
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: 'rent',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftSpotMarket',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftSpotMarketMint',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'insuranceFundStake',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftState',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftProgram',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'tokenProgram',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'systemProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'marketIndex',
+					type: 'u16',
+				},
+			],
+		},
+		{
+			name: 'addInsuranceFundStake',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: 'driftSpotMarket',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftSpotMarketVault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'insuranceFundStake',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'insuranceFundVault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'managerTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultIfTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftState',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftSigner',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftProgram',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'tokenProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'marketIndex',
+					type: 'u16',
+				},
+				{
+					name: 'amount',
+					type: 'u64',
+				},
+			],
+		},
+		{
+			name: 'requestRemoveInsuranceFundStake',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'driftSpotMarket',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'insuranceFundStake',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'insuranceFundVault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'marketIndex',
+					type: 'u16',
+				},
+				{
+					name: 'amount',
+					type: 'u64',
+				},
+			],
+		},
+		{
+			name: 'removeInsuranceFundStake',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'driftSpotMarket',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'insuranceFundStake',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'insuranceFundVault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'managerTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultIfTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftState',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftSigner',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftProgram',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'tokenProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'marketIndex',
+					type: 'u16',
+				},
+			],
+		},
+		{
+			name: 'cancelRequestRemoveInsuranceFundStake',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'driftSpotMarket',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'insuranceFundStake',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'insuranceFundVault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'marketIndex',
+					type: 'u16',
+				},
+			],
+		},
+		{
+			name: 'transferVaultDepositorShares',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositor',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'authority',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'toVaultDepositor',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'amount',
+					type: 'u64',
+				},
+				{
+					name: 'withdrawUnit',
+					type: {
+						defined: 'WithdrawUnit',
+					},
+				},
+			],
+		},
+		{
+			name: 'protocolRequestWithdraw',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultProtocol',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'protocol',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'withdrawAmount',
+					type: 'u64',
+				},
+				{
+					name: 'withdrawUnit',
+					type: {
+						defined: 'WithdrawUnit',
+					},
+				},
+			],
+		},
+		{
+			name: 'protocolCancelWithdrawRequest',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultProtocol',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'protocol',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'protocolWithdraw',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultProtocol',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'protocol',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'vaultTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: true,
+					isSigner: false,
+				},
+				{
 					name: 'driftUser',
 					isMut: true,
 					isSigner: false,
@@ -5237,1401 +10662,2659 @@ export const IDL: DriftVaults = {
 					isSigner: false,
 				},
 			],
-			args: [],
+			args: [],
+		},
+	],
+	accounts: [
+		{
+			name: 'feeUpdate',
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'incomingUpdateTs',
+						type: 'i64',
+					},
+					{
+						name: 'incomingManagementFee',
+						type: 'i64',
+					},
+					{
+						name: 'incomingProfitShare',
+						type: 'u32',
+					},
+					{
+						name: 'incomingHurdleRate',
+						type: 'u32',
+					},
+					{
+						name: 'padding',
+						type: {
+							array: ['u128', 10],
+						},
+					},
+					{
+						name: 'bump',
+						docs: [
+							'The bump for the fee update pda, 0 for fee updates created before it was stored',
+						],
+						type: 'u8',
+					},
+					{
+						name: 'padding2',
+						type: {
+							array: ['u8', 7],
+						},
+					},
+				],
+			},
+		},
+		{
+			name: 'fuelDistribution',
+			docs: [
+				"Holds the fuel accrued by the manager and the protocol when the vault's fuel_distribution_mode is",
+				'`Weighted`, and any timelocked change to the weights. Users accrue their share through',
+				'vault.cumulative_fuel_per_share, the rest of vault.cumulative_fuel is settled here. `Vault` and',
+				'`VaultProtocol` have no room left for the accrued fuel, so it lives in its own account.',
+				'',
+				"Settling accrues the weighted share of the vault's fuel since the last settle, so it is exact however",
+				"rarely it runs as long as it runs before the weights change and before the vault's fuel season is reset.",
+				'`update` and `reset_fuel_amount` settle first for that reason.',
+			],
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'vault',
+						docs: ['The vault this account distributes fuel for'],
+						type: 'publicKey',
+					},
+					{
+						name: 'lastCumulativeFuel',
+						docs: ['The vault.cumulative_fuel at the last settle'],
+						type: 'u128',
+					},
+					{
+						name: 'managerFuel',
+						docs: ['Total fuel accrued by the manager'],
+						type: 'u128',
+					},
+					{
+						name: 'protocolFuel',
+						docs: ['Total fuel accrued by the protocol'],
+						type: 'u128',
+					},
+					{
+						name: 'incomingUpdateTs',
+						docs: [
+							'The ts the incoming weights can be applied at, 0 if there is no pending update',
+						],
+						type: 'i64',
+					},
+					{
+						name: 'incomingManagerFuelBps',
+						type: 'u16',
+					},
+					{
+						name: 'incomingProtocolFuelBps',
+						type: 'u16',
+					},
+					{
+						name: 'lastSettleTs',
+						docs: ['Last ts fuel was settled'],
+						type: 'u32',
+					},
+					{
+						name: 'bump',
+						type: 'u8',
+					},
+					{
+						name: 'padding1',
+						type: {
+							array: ['u8', 7],
+						},
+					},
+					{
+						name: 'padding',
+						type: {
+							array: ['u64', 5],
+						},
+					},
+				],
+			},
+		},
+		{
+			name: 'fuelSeasonSnapshot',
+			docs: [
+				"A depositor's fuel recorded by reset_fuel_season at the end of a fuel season, so external programs can read",
+				"it without parsing logs. There is one snapshot per depositor and season and it can't be overwritten.",
+			],
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'vault',
+						docs: ['The vault the depositor is in'],
+						type: 'publicKey',
+					},
+					{
+						name: 'vaultDepositor',
+						docs: ['The vault depositor whose fuel was recorded'],
+						type: 'publicKey',
+					},
+					{
+						name: 'authority',
+						docs: ["The vault depositor's authority"],
+						type: 'publicKey',
+					},
+					{
+						name: 'fuelAmount',
+						docs: ["The depositor's fuel_amount at the snapshot"],
+						type: 'u128',
+					},
+					{
+						name: 'ts',
+						docs: ['The ts the snapshot was taken'],
+						type: 'i64',
+					},
+					{
+						name: 'season',
+						docs: [
+							"The vault's fuel_season the fuel was accrued in, part of the snapshot's pda seeds",
+						],
+						type: 'u8',
+					},
+					{
+						name: 'bump',
+						type: 'u8',
+					},
+					{
+						name: 'padding',
+						type: {
+							array: ['u8', 6],
+						},
+					},
+				],
+			},
+		},
+		{
+			name: 'managerStats',
+			docs: [
+				"The vault's track record, built from its share price each time its equity is observed: by the",
+				'update_manager_stats crank, the snapshot_vault crank, and deposits and withdrawals that pass it in',
+				'remaining_accounts.',
+				'',
+				"Share prices are the vault's equity over its total_shares, in the vault's current shares_base. They are net",
+				'of management and protocol fees, which mint shares, and gross of profit share, which only moves shares.',
+			],
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'vault',
+						docs: ['The vault these stats are for'],
+						type: 'publicKey',
+					},
+					{
+						name: 'hwmSharePrice',
+						docs: [
+							'The highest share price observed. precision: NAV_PER_SHARE_PRECISION',
+						],
+						type: 'u128',
+					},
+					{
+						name: 'lastSharePrice',
+						docs: [
+							'The share price at the last observation. precision: NAV_PER_SHARE_PRECISION',
+						],
+						type: 'u128',
+					},
+					{
+						name: 'periodStartSharePrice',
+						docs: [
+							'The share price the current return period started at. precision: NAV_PER_SHARE_PRECISION',
+						],
+						type: 'u128',
+					},
+					{
+						name: 'sumReturns',
+						docs: [
+							'Sum of the period returns. precision: PERCENTAGE_PRECISION',
+						],
+						type: 'i128',
+					},
+					{
+						name: 'sumSquaredReturns',
+						docs: [
+							'Sum of the squared period returns. precision: PERCENTAGE_PRECISION^2',
+						],
+						type: 'u128',
+					},
+					{
+						name: 'inceptionTs',
+						docs: ["The vault's init_ts"],
+						type: 'i64',
+					},
+					{
+						name: 'firstObservationTs',
+						docs: ['The ts of the first observation'],
+						type: 'i64',
+					},
+					{
+						name: 'lastObservationTs',
+						type: 'i64',
+					},
+					{
+						name: 'hwmTs',
+						docs: ['The ts the high-water mark was set'],
+						type: 'i64',
+					},
+					{
+						name: 'periodStartTs',
+						type: 'i64',
+					},
+					{
+						name: 'maxDrawdown',
+						docs: [
+							'The largest fall of the share price from its high-water mark. precision: PERCENTAGE_PRECISION',
+						],
+						type: 'u64',
+					},
+					{
+						name: 'maxDrawdownTs',
+						docs: ['The ts the max drawdown was observed'],
+						type: 'i64',
+					},
+					{
+						name: 'returnCount',
+						docs: ['The number of period returns recorded'],
+						type: 'u32',
+					},
+					{
+						name: 'sharesBase',
+						docs: ['The shares_base the share prices are in'],
+						type: 'u32',
+					},
+					{
+						name: 'bump',
+						type: 'u8',
+					},
+					{
+						name: 'padding1',
+						type: {
+							array: ['u8', 3],
+						},
+					},
+					{
+						name: 'missedPeriodCount',
+						docs: [
+							"The number of periods without a return because the stats weren't observed in the period after them",
+						],
+						type: 'u32',
+					},
+					{
+						name: 'padding',
+						type: {
+							array: ['u64', 8],
+						},
+					},
+				],
+			},
+		},
+		{
+			name: 'navHistory',
+			docs: [
+				"Fixed size ring buffer of the vault's equity and shares, so share price history can be read without",
+				'replaying logs. Appended by the snapshot_vault crank and by deposits and withdrawals that pass it in',
+				'remaining_accounts, at most once every NAV_HISTORY_MIN_SNAPSHOT_INTERVAL.',
+			],
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'vault',
+						docs: ['The vault this history is for'],
+						type: 'publicKey',
+					},
+					{
+						name: 'head',
+						docs: ['The index the next snapshot is written to'],
+						type: 'u32',
+					},
+					{
+						name: 'len',
+						docs: [
+							'The number of snapshots recorded, up to NAV_HISTORY_LENGTH',
+						],
+						type: 'u32',
+					},
+					{
+						name: 'bump',
+						type: 'u8',
+					},
+					{
+						name: 'padding',
+						type: {
+							array: ['u8', 7],
+						},
+					},
+					{
+						name: 'snapshots',
+						type: {
+							array: [
+								{
+									defined: 'NavSnapshot',
+								},
+								128,
+							],
+						},
+					},
+				],
+			},
+		},
+		{
+			name: 'navPolicy',
+			docs: [
+				"Optional policy on how the vault's equity is priced for deposits and withdrawals. Once a vault has",
+				'one, it must be passed in remaining_accounts to every instruction that mints or burns shares.',
+				'It also holds the nav circuit breaker state, so it must be writable for deposits and withdraw requests,',
+				'and the max drawdown protection state.',
+			],
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'vault',
+						docs: ['The vault this policy applies to'],
+						type: 'publicKey',
+					},
+					{
+						name: 'swingPricingMode',
+						docs: [
+							'How deposits and withdrawals are swing priced [`SwingPricingMode`]. Default is `Disabled`',
+						],
+						type: 'u8',
+					},
+					{
+						name: 'bump',
+						type: 'u8',
+					},
+					{
+						name: 'depositSpreadBps',
+						docs: [
+							'Estimated cost to deploy a deposit in bps of equity, added to the equity deposits are priced at',
+						],
+						type: 'u16',
+					},
+					{
+						name: 'withdrawSpreadBps',
+						docs: [
+							'Estimated cost to unwind a withdrawal in bps of equity, removed from the equity withdrawals are priced at',
+						],
+						type: 'u16',
+					},
+					{
+						name: 'maxSwingBps',
+						docs: [
+							'Caps the total swing on either side in bps of equity, including the perp market spread estimate',
+						],
+						type: 'u16',
+					},
+					{
+						name: 'oracleValidityMode',
+						docs: [
+							'How positions with an invalid oracle are valued [`OracleValidityMode`]. Default is `Strict`',
+						],
+						type: 'u8',
+					},
+					{
+						name: 'padding1',
+						type: {
+							array: ['u8', 7],
+						},
+					},
+					{
+						name: 'lastEquityPerShare',
+						docs: [
+							"The vault's equity per share last observed by the circuit breaker",
+							'precision: NAV_PER_SHARE_PRECISION',
+						],
+						type: 'u128',
+					},
+					{
+						name: 'lastEquityTs',
+						docs: ['The ts last_equity_per_share was observed at'],
+						type: 'i64',
+					},
+					{
+						name: 'navBreakerWindow',
+						docs: [
+							'Equity per share moves are only compared against observations younger than this, in seconds',
+						],
+						type: 'i64',
+					},
+					{
+						name: 'navBreakerThresholdBps',
+						docs: [
+							'Max move in equity per share within nav_breaker_window before the breaker trips, in bps. 0 disables it',
+						],
+						type: 'u16',
+					},
+					{
+						name: 'navBreakerTripped',
+						docs: [
+							'Deposits and withdraw requests are rejected until the breaker is reset',
+						],
+						type: 'bool',
+					},
+					{
+						name: 'padding2',
+						type: {
+							array: ['u8', 1],
+						},
+					},
+					{
+						name: 'lastSharesBase',
+						docs: [
+							"The vault's shares_base at last_equity_per_share, a rebase invalidates the observation",
+						],
+						type: 'u32',
+					},
+					{
+						name: 'drawdownTriggeredTs',
+						docs: [
+							"The ts max drawdown protection was triggered, 0 if it isn't",
+						],
+						type: 'i64',
+					},
+					{
+						name: 'hwmEquityPerShare',
+						docs: [
+							"The vault's highest equity per share seen by the max drawdown crank",
+							'precision: NAV_PER_SHARE_PRECISION',
+						],
+						type: 'u128',
+					},
+					{
+						name: 'reenableTradingTs',
+						docs: [
+							'The ts trading can be re-enabled after a triggered drawdown, 0 if not requested',
+						],
+						type: 'i64',
+					},
+					{
+						name: 'redeemPeriodBeforeDrawdown',
+						docs: [
+							"The vault's redeem_period before the drawdown waived it, restored when trading is re-enabled",
+						],
+						type: 'i64',
+					},
+					{
+						name: 'maxDrawdownBps',
+						docs: [
+							'Max drop in equity per share from hwm_equity_per_share before the vault is set reduce-only, in bps.',
+							'0 disables it. Once enabled it can only be lowered.',
+						],
+						type: 'u16',
+					},
+					{
+						name: 'padding3',
+						type: {
+							array: ['u8', 2],
+						},
+					},
+					{
+						name: 'hwmSharesBase',
+						docs: ["The vault's shares_base at hwm_equity_per_share"],
+						type: 'u32',
+					},
+					{
+						name: 'incomingUpdateTs',
+						docs: [
+							'The ts the queued increase to the withdraw swing can be applied at, 0 if none is queued',
+						],
+						type: 'i64',
+					},
+					{
+						name: 'incomingWithdrawSpreadBps',
+						docs: ['The withdraw_spread_bps applied at incoming_update_ts'],
+						type: 'u16',
+					},
+					{
+						name: 'incomingMaxSwingBps',
+						docs: ['The max_swing_bps applied at incoming_update_ts'],
+						type: 'u16',
+					},
+					{
+						name: 'incomingSwingPricingMode',
+						docs: ['The swing_pricing_mode applied at incoming_update_ts'],
+						type: 'u8',
+					},
+					{
+						name: 'padding4',
+						type: {
+							array: ['u8', 3],
+						},
+					},
+					{
+						name: 'padding',
+						type: {
+							array: ['u64', 3],
+						},
+					},
+				],
+			},
+		},
+		{
+			name: 'rewardStreamDepositor',
+			docs: [
+				"Tracks the rewards a [`VaultDepositor`] has earned from a [`RewardStream`]. The depositor's shares are",
+				"registered in the stream and settled whenever they change, so every instruction changing the depositor's",
+				'shares must pass all of its reward stream depositors, see vault_depositor.reward_stream_count.',
+				'',
+				'[`VaultDepositor`]: crate::state::VaultDepositor',
+			],
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'rewardStream',
+						docs: ['The reward stream paying the depositor'],
+						type: 'publicKey',
+					},
+					{
+						name: 'vaultDepositor',
+						docs: ['The vault depositor earning rewards'],
+						type: 'publicKey',
+					},
+					{
+						name: 'authority',
+						docs: [
+							"The vault depositor's authority, rewards are claimed to its associated token account",
+						],
+						type: 'publicKey',
+					},
+					{
+						name: 'lastCumulativeRewardPerShare',
+						docs: [
+							'The reward_stream.cumulative_reward_per_share at the last update',
+						],
+						type: 'u128',
+					},
+					{
+						name: 'vaultShares',
+						docs: [
+							"The depositor's shares registered in the reward stream, in the reward stream's shares base",
+						],
+						type: 'u128',
+					},
+					{
+						name: 'unclaimedRewards',
+						docs: ['Rewards earned but not claimed yet'],
+						type: 'u64',
+					},
+					{
+						name: 'totalClaimed',
+						docs: ['Lifetime rewards claimed'],
+						type: 'u64',
+					},
+					{
+						name: 'lastUpdateTs',
+						docs: ['Last ts rewards were accrued to the depositor'],
+						type: 'i64',
+					},
+					{
+						name: 'bump',
+						type: 'u8',
+					},
+					{
+						name: 'padding1',
+						type: {
+							array: ['u8', 7],
+						},
+					},
+					{
+						name: 'padding',
+						type: {
+							array: ['u64', 4],
+						},
+					},
+				],
+			},
+		},
+		{
+			name: 'rewardStream',
+			docs: [
+				"Streams an SPL token to the vault's depositors at a fixed rate. Depositors opt in with a",
+				'[`RewardStreamDepositor`], which registers their shares in `total_shares`, and emitted rewards are spread',
+				'over the registered shares through `cumulative_reward_per_share`. The stream only emits while it has',
+				"funds that haven't been distributed.",
+				'',
+				'[`RewardStreamDepositor`]: crate::state::RewardStreamDepositor',
+			],
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'vault',
+						docs: ['The vault the stream pays depositors of'],
+						type: 'publicKey',
+					},
+					{
+						name: 'mint',
+						docs: ['The reward token mint'],
+						type: 'publicKey',
+					},
+					{
+						name: 'tokenAccount',
+						docs: ['The token account holding the rewards, owned by the vault'],
+						type: 'publicKey',
+					},
+					{
+						name: 'cumulativeRewardPerShare',
+						docs: [
+							'precision: REWARD_SHARE_PRECISION, per share in `shares_base`',
+						],
+						type: 'u128',
+					},
+					{
+						name: 'rewardRate',
+						docs: ['Reward tokens emitted per second'],
+						type: 'u64',
+					},
+					{
+						name: 'totalFunded',
+						docs: [
+							'Lifetime reward tokens funded, net of the remainder returned on close',
+						],
+						type: 'u64',
+					},
+					{
+						name: 'totalDistributed',
+						docs: ['Lifetime reward tokens emitted to depositors'],
+						type: 'u64',
+					},
+					{
+						name: 'totalClaimed',
+						docs: ['Lifetime reward tokens claimed by depositors'],
+						type: 'u64',
+					},
+					{
+						name: 'startTs',
+						docs: ['The ts the stream starts emitting'],
+						type: 'i64',
+					},
+					{
+						name: 'endTs',
+						docs: ['The ts the stream stops emitting'],
+						type: 'i64',
+					},
+					{
+						name: 'lastUpdateTs',
+						docs: ['Last ts rewards were emitted'],
+						type: 'i64',
+					},
+					{
+						name: 'sharesBase',
+						docs: [
+							'The vault.shares_base when the stream was created, shares from later bases are scaled to it',
+						],
+						type: 'u32',
+					},
+					{
+						name: 'rewardStreamId',
+						docs: [
+							"Distinguishes the vault's streams, part of the stream's pda seeds",
+						],
+						type: 'u16',
+					},
+					{
+						name: 'bump',
+						type: 'u8',
+					},
+					{
+						name: 'padding1',
+						type: {
+							array: ['u8', 1],
+						},
+					},
+					{
+						name: 'totalShares',
+						docs: [
+							"The shares registered by the stream's depositors, in `shares_base`",
+						],
+						type: 'u128',
+					},
+					{
+						name: 'depositorCount',
+						docs: [
+							'The number of open [`RewardStreamDepositor`]s, the stream can only be closed once there are none',
+							'',
+							'[`RewardStreamDepositor`]: crate::state::RewardStreamDepositor',
+						],
+						type: 'u32',
+					},
+					{
+						name: 'padding2',
+						type: {
+							array: ['u8', 4],
+						},
+					},
+					{
+						name: 'padding',
+						type: {
+							array: ['u64', 1],
+						},
+					},
+				],
+			},
+		},
+		{
+			name: 'tokenizedRedeemRequest',
+			docs: [
+				'A pending redemption of tokenized vault shares. Created by `request_redeem` when a holder burns',
+				"share tokens, and closed by `redeem_tokens_for_underlying` once the vault's redeem period has passed,",
+				'or by `cancel_redeem_request` which mints the shares back to the holder as tokens.',
+				'The shares queued here have already left the [`TokenizedVaultDepositor`] but are still counted in',
+				'`vault.user_shares` until the redemption is finalized.',
+				'',
+				'A holder may have several requests outstanding, each keyed by its `request_id`.',
+				'',
+				'[`TokenizedVaultDepositor`]: crate::state::TokenizedVaultDepositor',
+			],
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'vault',
+						docs: ['The vault being redeemed from'],
+						type: 'publicKey',
+					},
+					{
+						name: 'tokenizedVaultDepositor',
+						docs: ['The tokenized vault depositor whose tokens were burned'],
+						type: 'publicKey',
+					},
+					{
+						name: 'authority',
+						docs: ['The token holder that will receive the underlying tokens'],
+						type: 'publicKey',
+					},
+					{
+						name: 'withdrawRequest',
+						docs: ['The queued withdraw'],
+						type: {
+							defined: 'WithdrawRequest',
+						},
+					},
+					{
+						name: 'vaultSharesBase',
+						docs: [
+							'The vault shares_base at the time withdraw_request.shares was last updated',
+						],
+						type: 'u32',
+					},
+					{
+						name: 'bump',
+						type: 'u8',
+					},
+					{
+						name: 'padding1',
+						type: 'u8',
+					},
+					{
+						name: 'requestId',
+						docs: [
+							'Chosen by the holder, distinguishes their outstanding requests',
+						],
+						type: 'u16',
+					},
+					{
+						name: 'padding',
+						type: {
+							array: ['u64', 5],
+						},
+					},
+				],
+			},
+		},
+		{
+			name: 'tokenizedVaultDepositor',
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'vault',
+						docs: ['The vault deposited into'],
+						type: 'publicKey',
+					},
+					{
+						name: 'pubkey',
+						docs: [
+							"The vault depositor account's pubkey. It is a pda of vault",
+						],
+						type: 'publicKey',
+					},
+					{
+						name: 'mint',
+						docs: [
+							'The token mint for tokenized shares owned by this VaultDepositor',
+						],
+						type: 'publicKey',
+					},
+					{
+						name: 'vaultShares',
+						docs: [
+							"share of vault owned by this depositor. vault_shares / vault.total_shares is depositor's ownership of vault_equity",
+						],
+						type: 'u128',
+					},
+					{
+						name: 'lastVaultShares',
+						docs: [
+							'stores the vault_shares from the most recent liquidity event (redeem or issuance) before a spl token',
+							'CPI is done, used to track invariants',
+						],
+						type: 'u128',
+					},
+					{
+						name: 'lastValidTs',
+						docs: ['creation ts of vault depositor'],
+						type: 'i64',
+					},
+					{
+						name: 'netDeposits',
+						docs: ['lifetime net deposits of vault depositor for the vault'],
+						type: 'i64',
+					},
+					{
+						name: 'totalDeposits',
+						docs: ['lifetime total deposits'],
+						type: 'u64',
+					},
+					{
+						name: 'totalWithdraws',
+						docs: ['lifetime total withdraws'],
+						type: 'u64',
+					},
+					{
+						name: 'cumulativeProfitShareAmount',
+						docs: [
+							'the token amount of gains the vault depositor has paid performance fees on',
+						],
+						type: 'i64',
+					},
+					{
+						name: 'profitShareFeePaid',
+						type: 'u64',
+					},
+					{
+						name: 'vaultSharesBase',
+						docs: [
+							'The exponent for vault_shares decimal places at the time the tokenized vault depositor was initialized.',
+							'If the vault undergoes a rebase, this TokenizedVaultDepositor can no longer issue new tokens, only redeem',
+							'is possible.',
+						],
+						type: 'u32',
+					},
+					{
+						name: 'bump',
+						docs: ['The bump for the vault pda'],
+						type: 'u8',
+					},
+					{
+						name: 'costBasisMigrated',
+						docs: ['See [`crate::state::VaultDepositor::cost_basis_migrated`]'],
+						type: 'bool',
+					},
+					{
+						name: 'padding1',
+						type: {
+							array: ['u8', 2],
+						},
+					},
+					{
+						name: 'lastFuelUpdateTs',
+						docs: ['Last ts fuel was accrued to this depositor'],
+						type: 'u32',
+					},
+					{
+						name: 'padding2',
+						type: {
+							array: ['u8', 4],
+						},
+					},
+					{
+						name: 'cumulativeFuelPerShareAmount',
+						docs: [
+							"The vault's cumulative_fuel_per_share at the last fuel accrual",
+						],
+						type: 'u128',
+					},
+					{
+						name: 'fuelAmount',
+						docs: [
+							'Total fuel accrued by the tokenized shares. Fuel is attributed to the tokenized vault depositor as a',
+							"whole, token holders don't accrue fuel individually",
+						],
+						type: 'u128',
+					},
+					{
+						name: 'costBasis',
+						docs: [
+							'The deposit asset paid for the tokenized shares, see [`crate::state::VaultDepositor::cost_basis`]',
+						],
+						type: 'u64',
+					},
+					{
+						name: 'padding',
+						type: {
+							array: ['u64', 4],
+						},
+					},
+				],
+			},
 		},
 		{
-			name: 'initializeInsuranceFundStake',
-			accounts: [
-				{
-					name: 'vault',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'manager',
-					isMut: false,
-					isSigner: true,
-				},
-				{
-					name: 'payer',
-					isMut: true,
-					isSigner: true,
-				},
-				{
-					name: 'rent',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'driftSpotMarket',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftSpotMarketMint',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'vaultTokenAccount',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'insuranceFundStake',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftUserStats',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftState',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'driftProgram',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'tokenProgram',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'systemProgram',
-					isMut: false,
-					isSigner: false,
-				},
-			],
-			args: [
-				{
-					name: 'marketIndex',
-					type: 'u16',
-				},
-			],
+			name: 'vaultDepositor',
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'vault',
+						docs: ['The vault deposited into'],
+						type: 'publicKey',
+					},
+					{
+						name: 'pubkey',
+						docs: [
+							"The vault depositor account's pubkey. It is a pda of vault and authority",
+						],
+						type: 'publicKey',
+					},
+					{
+						name: 'authority',
+						docs: [
+							'The authority is the address w permission to deposit/withdraw',
+						],
+						type: 'publicKey',
+					},
+					{
+						name: 'vaultShares',
+						docs: [
+							"share of vault owned by this depositor. vault_shares / vault.total_shares is depositor's ownership of vault_equity",
+						],
+						type: 'u128',
+					},
+					{
+						name: 'lastWithdrawRequest',
+						docs: ['last withdraw request'],
+						type: {
+							defined: 'WithdrawRequest',
+						},
+					},
+					{
+						name: 'lastValidTs',
+						docs: ['creation ts of vault depositor'],
+						type: 'i64',
+					},
+					{
+						name: 'netDeposits',
+						docs: ['lifetime net deposits of vault depositor for the vault'],
+						type: 'i64',
+					},
+					{
+						name: 'totalDeposits',
+						docs: ['lifetime total deposits'],
+						type: 'u64',
+					},
+					{
+						name: 'totalWithdraws',
+						docs: ['lifetime total withdraws'],
+						type: 'u64',
+					},
+					{
+						name: 'cumulativeProfitShareAmount',
+						docs: [
+							'the token amount of gains the vault depositor has paid performance fees on',
+						],
+						type: 'i64',
+					},
+					{
+						name: 'profitShareFeePaid',
+						type: 'u64',
+					},
+					{
+						name: 'vaultSharesBase',
+						docs: ['the exponent for vault_shares decimal places'],
+						type: 'u32',
+					},
+					{
+						name: 'lastFuelUpdateTs',
+						type: 'u32',
+					},
+					{
+						name: 'cumulativeFuelPerShareAmount',
+						docs: ['precision: FUEL_SHARE_PRECISION'],
+						type: 'u128',
+					},
+					{
+						name: 'fuelAmount',
+						docs: ['precision: none'],
+						type: 'u128',
+					},
+					{
+						name: 'fuelLockupEndTs',
+						docs: [
+							"The ts the depositor's fuel lockup ends, request_withdraw is blocked until then",
+						],
+						type: 'i64',
+					},
+					{
+						name: 'fuelBoostShares',
+						docs: [
+							'The extra shares from the fuel lockup counted in vault.fuel_shares_adjustment',
+						],
+						type: 'u64',
+					},
+					{
+						name: 'fuelLockupMultiplier',
+						docs: ['precision: FUEL_LOCKUP_MULTIPLIER_PRECISION'],
+						type: 'u32',
+					},
+					{
+						name: 'rewardStreamCount',
+						docs: [
+							'The number of [`RewardStreamDepositor`]s of the depositor. They must all be passed in remaining_accounts',
+							"to instructions that change the depositor's shares",
+							'',
+							'[`RewardStreamDepositor`]: crate::state::RewardStreamDepositor',
+						],
+						type: 'u8',
+					},
+					{
+						name: 'costBasisMigrated',
+						docs: [
+							'Set once cost_basis is tracked. Depositors from before it was tracked value their shares at their net',
+							'deposits until then',
+						],
+						type: 'bool',
+					},
+					{
+						name: 'padding1',
+						type: {
+							array: ['u8', 2],
+						},
+					},
+					{
+						name: 'costBasis',
+						docs: [
+							"The deposit asset paid for the depositor's shares: deposits and the value of shares transferred in, less",
+							'the cost of the shares withdrawn or transferred out. Unchanged by rebases and profit share',
+						],
+						type: 'u64',
+					},
+				],
+			},
 		},
 		{
-			name: 'addInsuranceFundStake',
-			accounts: [
-				{
-					name: 'vault',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'manager',
-					isMut: true,
-					isSigner: true,
-				},
-				{
-					name: 'driftSpotMarket',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftSpotMarketVault',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'insuranceFundStake',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'insuranceFundVault',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'managerTokenAccount',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'vaultIfTokenAccount',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftUserStats',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftState',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'driftSigner',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'driftProgram',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'tokenProgram',
-					isMut: false,
-					isSigner: false,
-				},
-			],
-			args: [
-				{
-					name: 'marketIndex',
-					type: 'u16',
-				},
-				{
-					name: 'amount',
-					type: 'u64',
-				},
-			],
+			name: 'vaultProtocol',
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'protocol',
+						docs: [
+							'The protocol, company, or entity that services the product using this vault.',
+							'The protocol is not allowed to deposit into the vault but can profit share and collect annual fees just like the manager.',
+						],
+						type: 'publicKey',
+					},
+					{
+						name: 'protocolProfitAndFeeShares',
+						docs: [
+							'The shares from profit share and annual fee unclaimed by the protocol.',
+						],
+						type: 'u128',
+					},
+					{
+						name: 'protocolFee',
+						docs: [
+							'The annual fee charged on deposits by the protocol (traditional hedge funds typically charge 2% per year on assets under management).',
+							"Unlike the management fee this can't be negative.",
+						],
+						type: 'u64',
+					},
+					{
+						name: 'protocolTotalWithdraws',
+						docs: ['Total withdraws for the protocol'],
+						type: 'u64',
+					},
+					{
+						name: 'protocolTotalFee',
+						docs: [
+							'Total fee charged by the protocol (annual management fee + profit share).',
+							"Unlike the management fee this can't be negative.",
+						],
+						type: 'u64',
+					},
+					{
+						name: 'protocolTotalProfitShare',
+						docs: ['Total profit share charged by the protocol'],
+						type: 'u64',
+					},
+					{
+						name: 'lastProtocolWithdrawRequest',
+						type: {
+							defined: 'WithdrawRequest',
+						},
+					},
+					{
+						name: 'protocolProfitShare',
+						docs: [
+							'Percentage the protocol charges on all profits realized by depositors: PERCENTAGE_PRECISION',
+						],
+						type: 'u32',
+					},
+					{
+						name: 'bump',
+						type: 'u8',
+					},
+					{
+						name: 'version',
+						type: 'u8',
+					},
+					{
+						name: 'padding',
+						type: {
+							array: ['u8', 2],
+						},
+					},
+				],
+			},
 		},
 		{
-			name: 'requestRemoveInsuranceFundStake',
-			accounts: [
-				{
-					name: 'vault',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'manager',
-					isMut: false,
-					isSigner: true,
-				},
-				{
-					name: 'driftSpotMarket',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'insuranceFundStake',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'insuranceFundVault',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftUserStats',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftProgram',
-					isMut: false,
-					isSigner: false,
-				},
-			],
-			args: [
-				{
-					name: 'marketIndex',
-					type: 'u16',
-				},
-				{
-					name: 'amount',
-					type: 'u64',
-				},
-			],
+			name: 'vault',
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'name',
+						docs: [
+							'The name of the vault. Vault pubkey is derived from this name.',
+						],
+						type: {
+							array: ['u8', 32],
+						},
+					},
+					{
+						name: 'pubkey',
+						docs: [
+							"The vault's pubkey. It is a pda of name and also used as the authority for drift user",
+						],
+						type: 'publicKey',
+					},
+					{
+						name: 'manager',
+						docs: [
+							'The manager of the vault who has ability to update vault params',
+						],
+						type: 'publicKey',
+					},
+					{
+						name: 'tokenAccount',
+						docs: [
+							'The vaults token account. Used to receive tokens between deposits and withdrawals',
+						],
+						type: 'publicKey',
+					},
+					{
+						name: 'userStats',
+						docs: ['The drift user stats account for the vault'],
+						type: 'publicKey',
+					},
+					{
+						name: 'user',
+						docs: ['The drift user account for the vault'],
+						type: 'publicKey',
+					},
+					{
+						name: 'delegate',
+						docs: [
+							'The vaults designated delegate for drift user account',
+							'can differ from actual user delegate if vault is in liquidation',
+						],
+						type: 'publicKey',
+					},
+					{
+						name: 'liquidationDelegate',
+						docs: ['The delegate handling liquidation for depositor'],
+						type: 'publicKey',
+					},
+					{
+						name: 'userShares',
+						docs: [
+							'The sum of all shares held by the users (vault depositors)',
+						],
+						type: 'u128',
+					},
+					{
+						name: 'totalShares',
+						docs: [
+							'The sum of all shares: deposits from users, manager deposits, manager profit/fee, and protocol profit/fee.',
+							'The manager deposits are total_shares - user_shares - protocol_profit_and_fee_shares.',
+						],
+						type: 'u128',
+					},
+					{
+						name: 'lastFeeUpdateTs',
+						docs: ['Last fee update unix timestamp'],
+						type: 'i64',
+					},
+					{
+						name: 'liquidationStartTs',
+						docs: ['When the liquidation starts'],
+						type: 'i64',
+					},
+					{
+						name: 'redeemPeriod',
+						docs: [
+							'The period (in seconds) that a vault depositor must wait after requesting a withdrawal to finalize withdrawal.',
+							'Currently, the maximum is 90 days.',
+						],
+						type: 'i64',
+					},
+					{
+						name: 'totalWithdrawRequested',
+						docs: ['The sum of all outstanding withdraw requests'],
+						type: 'u64',
+					},
+					{
+						name: 'maxTokens',
+						docs: [
+							'Max token capacity, once hit/passed vault will reject new deposits (updatable)',
+						],
+						type: 'u64',
+					},
+					{
+						name: 'managementFee',
+						docs: [
+							'The annual fee charged on deposits by the manager.',
+							'Traditional funds typically charge 2% per year on assets under management.',
+						],
+						type: 'i64',
+					},
+					{
+						name: 'initTs',
+						docs: ['Timestamp vault initialized'],
+						type: 'i64',
+					},
+					{
+						name: 'netDeposits',
+						docs: ['The net deposits for the vault'],
+						type: 'i64',
+					},
+					{
+						name: 'managerNetDeposits',
+						docs: ['The net deposits for the manager'],
+						type: 'i64',
+					},
+					{
+						name: 'totalDeposits',
+						docs: ['Total deposits'],
+						type: 'u64',
+					},
+					{
+						name: 'totalWithdraws',
+						docs: ['Total withdraws'],
+						type: 'u64',
+					},
+					{
+						name: 'managerTotalDeposits',
+						docs: ['Total deposits for the manager'],
+						type: 'u64',
+					},
+					{
+						name: 'managerTotalWithdraws',
+						docs: ['Total withdraws for the manager'],
+						type: 'u64',
+					},
+					{
+						name: 'managerTotalFee',
+						docs: ['Total management fee accrued by the manager'],
+						type: 'i64',
+					},
+					{
+						name: 'managerTotalProfitShare',
+						docs: ['Total profit share accrued by the manager'],
+						type: 'u64',
+					},
+					{
+						name: 'minDepositAmount',
+						docs: ['The minimum deposit amount'],
+						type: 'u64',
+					},
+					{
+						name: 'lastManagerWithdrawRequest',
+						type: {
+							defined: 'WithdrawRequest',
+						},
+					},
+					{
+						name: 'sharesBase',
+						docs: [
+							'The base 10 exponent of the shares (given massive share inflation can occur at near zero vault equity)',
+						],
+						type: 'u32',
+					},
+					{
+						name: 'profitShare',
+						docs: [
+							'Percentage the manager charges on all profits realized by depositors: PERCENTAGE_PRECISION',
+						],
+						type: 'u32',
+					},
+					{
+						name: 'hurdleRate',
+						docs: [
+							'Vault manager only collect incentive fees during periods when returns are higher than this amount: PERCENTAGE_PRECISION',
+						],
+						type: 'u32',
+					},
+					{
+						name: 'spotMarketIndex',
+						docs: [
+							'The spot market index the vault deposits into/withdraws from',
+						],
+						type: 'u16',
+					},
+					{
+						name: 'bump',
+						docs: ['The bump for the vault pda'],
+						type: 'u8',
+					},
+					{
+						name: 'permissioned',
+						docs: ['Whether anybody can be a depositor'],
+						type: 'bool',
+					},
+					{
+						name: 'vaultProtocol',
+						docs: ['The optional [`VaultProtocol`] account.'],
+						type: 'bool',
+					},
+					{
+						name: 'fuelDistributionMode',
+						docs: [
+							'How fuel distribution should be treated [`FuelDistributionMode`]. Default is `UsersOnly`',
+						],
+						type: 'u8',
+					},
+					{
+						name: 'feeUpdateStatus',
+						docs: [
+							'Whether the vault has a FeeUpdate account [`FeeUpdateStatus`]. Default is `FeeUpdateStatus::None`',
+							'After a `FeeUpdate` account is created and the manager has staged a fee update, the status is set to `PendingFeeUpdate`.',
+							'And instructsions that may finalize the fee update must include the `FeeUpdate` account with `remaining_accounts`.',
+						],
+						type: 'u8',
+					},
+					{
+						name: 'vaultClass',
+						docs: [
+							'The class of the vault [`VaultClass`]. Default is `VaultClass::Normal`',
+						],
+						type: 'u8',
+					},
+					{
+						name: 'lastCumulativeFuelPerShareTs',
+						docs: ['The timestamp cumulative_fuel_per_share was last updated'],
+						type: 'u32',
+					},
+					{
+						name: 'cumulativeFuelPerShare',
+						docs: [
+							'The cumulative fuel per share (scaled up by 1e6 to avoid losing precision)',
+						],
+						type: 'u128',
+					},
+					{
+						name: 'cumulativeFuel',
+						docs: ['The total fuel accumulated'],
+						type: 'u128',
+					},
+					{
+						name: 'managerBorrowedValue',
+						docs: [
+							'The total value (in deposit asset) of borrows the manager has outstanding.',
+							'Purely for informational purposes for assets that have left the vault that the manager',
+							'is expected to return.',
+						],
+						type: 'u64',
+					},
+					{
+						name: 'managerFuelBps',
+						docs: [
+							'The share of fuel allocated to the manager in bps when fuel_distribution_mode is `Weighted`',
+						],
+						type: 'u16',
+					},
+					{
+						name: 'protocolFuelBps',
+						docs: [
+							'The share of fuel allocated to the [`VaultProtocol`] in bps when fuel_distribution_mode is `Weighted`',
+						],
+						type: 'u16',
+					},
+					{
+						name: 'excludePendingWithdrawsFromFuel',
+						docs: [
+							'Whether shares in pending depositor withdraw requests stop accruing fuel.',
+							'Can only be changed while there are no pending withdraw requests.',
+						],
+						type: 'bool',
+					},
+					{
+						name: 'navPolicy',
+						docs: [
+							'Whether the vault has a [`NavPolicy`] account, which must then be passed in remaining_accounts to',
+							'instructions that price deposits and withdrawals.',
+						],
+						type: 'bool',
+					},
+					{
+						name: 'drawdownTriggered',
+						docs: [
+							'Whether the vault breached its [`NavPolicy`] max drawdown. The drift user is kept reduce-only and',
+							'the redeem period is waived until the manager re-enables trading.',
+						],
+						type: 'bool',
+					},
+					{
+						name: 'fuelSeason',
+						docs: [
+							"The number of fuel seasons the vault has been reset for, the current season's [`FuelSeasonSnapshot`]",
+							'pda seed. Wraps after 255 seasons',
+							'',
+							'[`FuelSeasonSnapshot`]: crate::state::FuelSeasonSnapshot',
+						],
+						type: 'u8',
+					},
+					{
+						name: 'fuelSharesAdjustment',
+						docs: [
+							'Net shares added to user_shares when distributing fuel: the extra shares from depositor fuel',
+							'lockups, less the shares in pending tokenized redeem requests and, if exclude_pending_withdraws_from_fuel',
+							'is set, the shares in pending depositor withdraw requests',
+						],
+						type: 'i64',
+					},
+				],
+			},
 		},
+	],
+	types: [
 		{
-			name: 'removeInsuranceFundStake',
-			accounts: [
-				{
-					name: 'vault',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'manager',
-					isMut: false,
-					isSigner: true,
-				},
-				{
-					name: 'driftSpotMarket',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'insuranceFundStake',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'insuranceFundVault',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'managerTokenAccount',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'vaultIfTokenAccount',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftUserStats',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftState',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'driftSigner',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'driftProgram',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'tokenProgram',
-					isMut: false,
-					isSigner: false,
-				},
-			],
-			args: [
-				{
-					name: 'marketIndex',
-					type: 'u16',
-				},
-			],
+			name: 'InitializeRewardStreamParams',
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'rewardStreamId',
+						type: 'u16',
+					},
+					{
+						name: 'rewardRate',
+						type: 'u64',
+					},
+					{
+						name: 'startTs',
+						type: 'i64',
+					},
+					{
+						name: 'endTs',
+						type: 'i64',
+					},
+				],
+			},
 		},
 		{
-			name: 'cancelRequestRemoveInsuranceFundStake',
-			accounts: [
-				{
-					name: 'vault',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'manager',
-					isMut: false,
-					isSigner: true,
-				},
-				{
-					name: 'driftSpotMarket',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'insuranceFundStake',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'insuranceFundVault',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftUserStats',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftProgram',
-					isMut: false,
-					isSigner: false,
-				},
-			],
-			args: [
-				{
-					name: 'marketIndex',
-					type: 'u16',
-				},
-			],
+			name: 'InitializeTokenizedVaultDepositorToken2022Params',
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'tokenName',
+						type: 'string',
+					},
+					{
+						name: 'tokenSymbol',
+						type: 'string',
+					},
+					{
+						name: 'tokenUri',
+						type: 'string',
+					},
+					{
+						name: 'decimals',
+						type: 'u8',
+					},
+					{
+						name: 'enableTransferHook',
+						docs: [
+							'Create the mint with this program as its transfer hook, enforcing the vault allowlist and',
+							'depositor lockups on secondary transfers',
+						],
+						type: 'bool',
+					},
+				],
+			},
 		},
 		{
-			name: 'transferVaultDepositorShares',
-			accounts: [
-				{
-					name: 'vault',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'vaultDepositor',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'authority',
-					isMut: false,
-					isSigner: true,
-				},
-				{
-					name: 'toVaultDepositor',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftUser',
-					isMut: true,
-					isSigner: false,
-				},
-			],
-			args: [
-				{
-					name: 'amount',
-					type: 'u64',
-				},
-				{
-					name: 'withdrawUnit',
-					type: {
-						defined: 'WithdrawUnit',
+			name: 'InitializeTokenizedVaultDepositorParams',
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'tokenName',
+						type: 'string',
+					},
+					{
+						name: 'tokenSymbol',
+						type: 'string',
+					},
+					{
+						name: 'tokenUri',
+						type: 'string',
+					},
+					{
+						name: 'decimals',
+						type: 'u8',
+					},
+				],
+			},
+		},
+		{
+			name: 'VaultWithProtocolParams',
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'name',
+						type: {
+							array: ['u8', 32],
+						},
+					},
+					{
+						name: 'redeemPeriod',
+						type: 'i64',
+					},
+					{
+						name: 'maxTokens',
+						type: 'u64',
 					},
-				},
-			],
+					{
+						name: 'managementFee',
+						type: 'i64',
+					},
+					{
+						name: 'minDepositAmount',
+						type: 'u64',
+					},
+					{
+						name: 'profitShare',
+						type: 'u32',
+					},
+					{
+						name: 'hurdleRate',
+						type: 'u32',
+					},
+					{
+						name: 'spotMarketIndex',
+						type: 'u16',
+					},
+					{
+						name: 'permissioned',
+						type: 'bool',
+					},
+					{
+						name: 'vaultProtocol',
+						type: {
+							defined: 'VaultProtocolParams',
+						},
+					},
+				],
+			},
 		},
 		{
-			name: 'protocolRequestWithdraw',
-			accounts: [
-				{
-					name: 'vault',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'vaultProtocol',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'protocol',
-					isMut: false,
-					isSigner: true,
-				},
-				{
-					name: 'driftUserStats',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'driftUser',
-					isMut: false,
-					isSigner: false,
-				},
-			],
-			args: [
-				{
-					name: 'withdrawAmount',
-					type: 'u64',
-				},
-				{
-					name: 'withdrawUnit',
-					type: {
-						defined: 'WithdrawUnit',
+			name: 'VaultProtocolParams',
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'protocol',
+						type: 'publicKey',
 					},
-				},
-			],
+					{
+						name: 'protocolFee',
+						type: 'u64',
+					},
+					{
+						name: 'protocolProfitShare',
+						type: 'u32',
+					},
+				],
+			},
 		},
 		{
-			name: 'protocolCancelWithdrawRequest',
-			accounts: [
-				{
-					name: 'vault',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'vaultProtocol',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'protocol',
-					isMut: false,
-					isSigner: true,
-				},
-				{
-					name: 'driftUserStats',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'driftUser',
-					isMut: false,
-					isSigner: false,
-				},
-			],
-			args: [],
+			name: 'VaultParams',
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'name',
+						type: {
+							array: ['u8', 32],
+						},
+					},
+					{
+						name: 'redeemPeriod',
+						type: 'i64',
+					},
+					{
+						name: 'maxTokens',
+						type: 'u64',
+					},
+					{
+						name: 'managementFee',
+						type: 'i64',
+					},
+					{
+						name: 'minDepositAmount',
+						type: 'u64',
+					},
+					{
+						name: 'profitShare',
+						type: 'u32',
+					},
+					{
+						name: 'hurdleRate',
+						type: 'u32',
+					},
+					{
+						name: 'spotMarketIndex',
+						type: 'u16',
+					},
+					{
+						name: 'permissioned',
+						type: 'bool',
+					},
+				],
+			},
 		},
 		{
-			name: 'protocolWithdraw',
-			accounts: [
-				{
-					name: 'vault',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'vaultProtocol',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'protocol',
-					isMut: false,
-					isSigner: true,
-				},
-				{
-					name: 'vaultTokenAccount',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftUserStats',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftUser',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftState',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'driftSpotMarketVault',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftSigner',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'userTokenAccount',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftProgram',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'tokenProgram',
-					isMut: false,
-					isSigner: false,
-				},
-			],
-			args: [],
+			name: 'ManagerUpdateFeesParams',
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'timelockDuration',
+						type: 'i64',
+					},
+					{
+						name: 'newManagementFee',
+						type: {
+							option: 'i64',
+						},
+					},
+					{
+						name: 'newProfitShare',
+						type: {
+							option: 'u32',
+						},
+					},
+					{
+						name: 'newHurdleRate',
+						type: {
+							option: 'u32',
+						},
+					},
+				],
+			},
 		},
-	],
-	accounts: [
 		{
-			name: 'feeUpdate',
+			name: 'ManagerUpdateFuelDistributionParams',
 			type: {
 				kind: 'struct',
 				fields: [
 					{
-						name: 'incomingUpdateTs',
+						name: 'timelockDuration',
 						type: 'i64',
 					},
 					{
-						name: 'incomingManagementFee',
-						type: 'i64',
+						name: 'managerFuelBps',
+						type: 'u16',
 					},
 					{
-						name: 'incomingProfitShare',
-						type: 'u32',
+						name: 'protocolFuelBps',
+						type: 'u16',
 					},
+				],
+			},
+		},
+		{
+			name: 'UpdateTokenizedVaultDepositorMetadataParams',
+			type: {
+				kind: 'struct',
+				fields: [
 					{
-						name: 'incomingHurdleRate',
-						type: 'u32',
+						name: 'tokenName',
+						type: {
+							option: 'string',
+						},
 					},
 					{
-						name: 'padding',
+						name: 'tokenSymbol',
 						type: {
-							array: ['u128', 10],
+							option: 'string',
 						},
 					},
 					{
-						name: 'padding2',
+						name: 'tokenUri',
 						type: {
-							array: ['u8', 8],
+							option: 'string',
 						},
 					},
 				],
 			},
 		},
 		{
-			name: 'tokenizedVaultDepositor',
+			name: 'UpdateVaultProtocolParams',
 			type: {
 				kind: 'struct',
 				fields: [
 					{
-						name: 'vault',
-						docs: ['The vault deposited into'],
+						name: 'protocolFee',
+						type: {
+							option: 'u64',
+						},
+					},
+					{
+						name: 'protocolProfitShare',
+						type: {
+							option: 'u32',
+						},
+					},
+				],
+			},
+		},
+		{
+			name: 'UpdateVaultParams',
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'redeemPeriod',
+						type: {
+							option: 'i64',
+						},
+					},
+					{
+						name: 'maxTokens',
+						type: {
+							option: 'u64',
+						},
+					},
+					{
+						name: 'managementFee',
+						type: {
+							option: 'i64',
+						},
+					},
+					{
+						name: 'minDepositAmount',
+						type: {
+							option: 'u64',
+						},
+					},
+					{
+						name: 'profitShare',
+						type: {
+							option: 'u32',
+						},
+					},
+					{
+						name: 'hurdleRate',
+						type: {
+							option: 'u32',
+						},
+					},
+					{
+						name: 'permissioned',
+						type: {
+							option: 'bool',
+						},
+					},
+					{
+						name: 'excludePendingWithdrawsFromFuel',
+						type: {
+							option: 'bool',
+						},
+					},
+				],
+			},
+		},
+		{
+			name: 'VaultConfig',
+			docs: [
+				"The vault's configurable settings, see [`crate::state::Vault::get_config`]",
+			],
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'manager',
 						type: 'publicKey',
 					},
 					{
-						name: 'pubkey',
-						docs: [
-							"The vault depositor account's pubkey. It is a pda of vault",
-						],
+						name: 'delegate',
 						type: 'publicKey',
 					},
 					{
-						name: 'mint',
-						docs: [
-							'The token mint for tokenized shares owned by this VaultDepositor',
-						],
+						name: 'liquidationDelegate',
 						type: 'publicKey',
 					},
 					{
-						name: 'vaultShares',
-						docs: [
-							"share of vault owned by this depositor. vault_shares / vault.total_shares is depositor's ownership of vault_equity",
-						],
-						type: 'u128',
+						name: 'redeemPeriod',
+						type: 'i64',
 					},
 					{
-						name: 'lastVaultShares',
-						docs: [
-							'stores the vault_shares from the most recent liquidity event (redeem or issuance) before a spl token',
-							'CPI is done, used to track invariants',
-						],
-						type: 'u128',
+						name: 'maxTokens',
+						type: 'u64',
 					},
 					{
-						name: 'lastValidTs',
-						docs: ['creation ts of vault depositor'],
-						type: 'i64',
+						name: 'minDepositAmount',
+						type: 'u64',
 					},
 					{
-						name: 'netDeposits',
-						docs: ['lifetime net deposits of vault depositor for the vault'],
+						name: 'managementFee',
 						type: 'i64',
 					},
 					{
-						name: 'totalDeposits',
-						docs: ['lifetime total deposits'],
-						type: 'u64',
+						name: 'profitShare',
+						type: 'u32',
 					},
 					{
-						name: 'totalWithdraws',
-						docs: ['lifetime total withdraws'],
+						name: 'hurdleRate',
+						type: 'u32',
+					},
+					{
+						name: 'permissioned',
+						type: 'bool',
+					},
+					{
+						name: 'excludePendingWithdrawsFromFuel',
+						type: 'bool',
+					},
+					{
+						name: 'vaultClass',
+						type: 'u8',
+					},
+					{
+						name: 'fuelDistributionMode',
+						type: 'u8',
+					},
+					{
+						name: 'managerFuelBps',
+						type: 'u16',
+					},
+					{
+						name: 'protocolFuelBps',
+						type: 'u16',
+					},
+					{
+						name: 'feeUpdateStatus',
+						type: 'u8',
+					},
+					{
+						name: 'managerBorrowedValue',
 						type: 'u64',
 					},
 					{
-						name: 'cumulativeProfitShareAmount',
+						name: 'marginTradingEnabled',
 						docs: [
-							'the token amount of gains the vault depositor has paid performance fees on',
+							"Settings of the vault's drift user, only set by the instructions that update the drift user",
 						],
-						type: 'i64',
+						type: {
+							option: 'bool',
+						},
 					},
 					{
-						name: 'profitShareFeePaid',
-						type: 'u64',
+						name: 'poolId',
+						type: {
+							option: 'u8',
+						},
 					},
 					{
-						name: 'vaultSharesBase',
+						name: 'navPolicy',
 						docs: [
-							'The exponent for vault_shares decimal places at the time the tokenized vault depositor was initialized.',
-							'If the vault undergoes a rebase, this TokenizedVaultDepositor can no longer issue new tokens, only redeem',
-							'is possible.',
+							"Settings of the vault's optional accounts, only set by the instructions that update them",
 						],
-						type: 'u32',
+						type: {
+							option: {
+								defined: 'NavPolicyConfig',
+							},
+						},
 					},
 					{
-						name: 'bump',
-						docs: ['The bump for the vault pda'],
-						type: 'u8',
+						name: 'vaultProtocol',
+						type: {
+							option: {
+								defined: 'VaultProtocolConfig',
+							},
+						},
 					},
 					{
-						name: 'padding1',
+						name: 'fuelDistribution',
 						type: {
-							array: ['u8', 3],
+							option: {
+								defined: 'FuelDistributionConfig',
+							},
 						},
 					},
 					{
-						name: 'padding',
+						name: 'feeUpdate',
 						type: {
-							array: ['u64', 10],
+							option: {
+								defined: 'FeeUpdateConfig',
+							},
 						},
 					},
 				],
 			},
 		},
 		{
-			name: 'vaultDepositor',
+			name: 'NavPolicyConfig',
+			docs: [
+				'The [`crate::state::NavPolicy`] settings, see [`crate::state::NavPolicy::get_config`]',
+			],
 			type: {
 				kind: 'struct',
 				fields: [
 					{
-						name: 'vault',
-						docs: ['The vault deposited into'],
-						type: 'publicKey',
-					},
-					{
-						name: 'pubkey',
-						docs: [
-							"The vault depositor account's pubkey. It is a pda of vault and authority",
-						],
-						type: 'publicKey',
+						name: 'swingPricingMode',
+						type: 'u8',
 					},
 					{
-						name: 'authority',
-						docs: [
-							'The authority is the address w permission to deposit/withdraw',
-						],
-						type: 'publicKey',
+						name: 'depositSpreadBps',
+						type: 'u16',
 					},
 					{
-						name: 'vaultShares',
-						docs: [
-							"share of vault owned by this depositor. vault_shares / vault.total_shares is depositor's ownership of vault_equity",
-						],
-						type: 'u128',
+						name: 'withdrawSpreadBps',
+						type: 'u16',
 					},
 					{
-						name: 'lastWithdrawRequest',
-						docs: ['last withdraw request'],
-						type: {
-							defined: 'WithdrawRequest',
-						},
+						name: 'maxSwingBps',
+						type: 'u16',
 					},
 					{
-						name: 'lastValidTs',
-						docs: ['creation ts of vault depositor'],
-						type: 'i64',
+						name: 'oracleValidityMode',
+						type: 'u8',
 					},
 					{
-						name: 'netDeposits',
-						docs: ['lifetime net deposits of vault depositor for the vault'],
+						name: 'navBreakerWindow',
 						type: 'i64',
 					},
 					{
-						name: 'totalDeposits',
-						docs: ['lifetime total deposits'],
-						type: 'u64',
-					},
-					{
-						name: 'totalWithdraws',
-						docs: ['lifetime total withdraws'],
-						type: 'u64',
+						name: 'navBreakerThresholdBps',
+						type: 'u16',
 					},
 					{
-						name: 'cumulativeProfitShareAmount',
-						docs: [
-							'the token amount of gains the vault depositor has paid performance fees on',
-						],
-						type: 'i64',
+						name: 'navBreakerTripped',
+						type: 'bool',
 					},
 					{
-						name: 'profitShareFeePaid',
-						type: 'u64',
+						name: 'maxDrawdownBps',
+						type: 'u16',
 					},
 					{
-						name: 'vaultSharesBase',
-						docs: ['the exponent for vault_shares decimal places'],
-						type: 'u32',
+						name: 'drawdownTriggeredTs',
+						type: 'i64',
 					},
 					{
-						name: 'lastFuelUpdateTs',
-						type: 'u32',
+						name: 'reenableTradingTs',
+						type: 'i64',
 					},
 					{
-						name: 'cumulativeFuelPerShareAmount',
-						docs: ['precision: FUEL_SHARE_PRECISION'],
-						type: 'u128',
+						name: 'incomingUpdateTs',
+						docs: [
+							'The queued increase to the withdraw swing, 0 if none is queued',
+						],
+						type: 'i64',
 					},
 					{
-						name: 'fuelAmount',
-						docs: ['precision: none'],
-						type: 'u128',
+						name: 'incomingWithdrawSpreadBps',
+						type: 'u16',
 					},
 					{
-						name: 'padding',
-						type: {
-							array: ['u64', 4],
-						},
+						name: 'incomingMaxSwingBps',
+						type: 'u16',
+					},
+					{
+						name: 'incomingSwingPricingMode',
+						type: 'u8',
 					},
 				],
 			},
 		},
 		{
-			name: 'vaultProtocol',
+			name: 'VaultProtocolConfig',
+			docs: [
+				'The [`crate::state::VaultProtocol`] settings, see [`crate::state::VaultProtocol::get_config`]',
+			],
 			type: {
 				kind: 'struct',
 				fields: [
 					{
 						name: 'protocol',
-						docs: [
-							'The protocol, company, or entity that services the product using this vault.',
-							'The protocol is not allowed to deposit into the vault but can profit share and collect annual fees just like the manager.',
-						],
 						type: 'publicKey',
 					},
-					{
-						name: 'protocolProfitAndFeeShares',
-						docs: [
-							'The shares from profit share and annual fee unclaimed by the protocol.',
-						],
-						type: 'u128',
-					},
 					{
 						name: 'protocolFee',
-						docs: [
-							'The annual fee charged on deposits by the protocol (traditional hedge funds typically charge 2% per year on assets under management).',
-							"Unlike the management fee this can't be negative.",
-						],
 						type: 'u64',
 					},
 					{
-						name: 'protocolTotalWithdraws',
-						docs: ['Total withdraws for the protocol'],
-						type: 'u64',
+						name: 'protocolProfitShare',
+						type: 'u32',
 					},
+				],
+			},
+		},
+		{
+			name: 'FuelDistributionConfig',
+			docs: [
+				'The [`crate::state::FuelDistribution`] settings, see [`crate::state::FuelDistribution::get_config`]',
+			],
+			type: {
+				kind: 'struct',
+				fields: [
 					{
-						name: 'protocolTotalFee',
-						docs: [
-							'Total fee charged by the protocol (annual management fee + profit share).',
-							"Unlike the management fee this can't be negative.",
-						],
-						type: 'u64',
+						name: 'incomingUpdateTs',
+						docs: ['The queued fuel weights, 0 if none are queued'],
+						type: 'i64',
 					},
 					{
-						name: 'protocolTotalProfitShare',
-						docs: ['Total profit share charged by the protocol'],
-						type: 'u64',
+						name: 'incomingManagerFuelBps',
+						type: 'u16',
 					},
 					{
-						name: 'lastProtocolWithdrawRequest',
-						type: {
-							defined: 'WithdrawRequest',
-						},
+						name: 'incomingProtocolFuelBps',
+						type: 'u16',
 					},
+				],
+			},
+		},
+		{
+			name: 'FeeUpdateConfig',
+			docs: [
+				'The [`crate::state::FeeUpdate`] settings, see [`crate::state::FeeUpdate::get_config`]',
+			],
+			type: {
+				kind: 'struct',
+				fields: [
 					{
-						name: 'protocolProfitShare',
-						docs: [
-							'Percentage the protocol charges on all profits realized by depositors: PERCENTAGE_PRECISION',
-						],
-						type: 'u32',
+						name: 'incomingUpdateTs',
+						docs: ['The queued fees, 0 if none are queued'],
+						type: 'i64',
 					},
 					{
-						name: 'bump',
-						type: 'u8',
+						name: 'incomingManagementFee',
+						type: 'i64',
 					},
 					{
-						name: 'version',
-						type: 'u8',
+						name: 'incomingProfitShare',
+						type: 'u32',
 					},
 					{
-						name: 'padding',
-						type: {
-							array: ['u8', 2],
-						},
+						name: 'incomingHurdleRate',
+						type: 'u32',
 					},
 				],
 			},
 		},
 		{
-			name: 'vault',
+			name: 'NavSnapshot',
 			type: {
 				kind: 'struct',
 				fields: [
 					{
-						name: 'name',
-						docs: [
-							'The name of the vault. Vault pubkey is derived from this name.',
-						],
-						type: {
-							array: ['u8', 32],
-						},
+						name: 'ts',
+						docs: ['The ts the snapshot was taken'],
+						type: 'i64',
 					},
 					{
-						name: 'pubkey',
+						name: 'equity',
 						docs: [
-							"The vault's pubkey. It is a pda of name and also used as the authority for drift user",
+							"The vault's equity (in vault spot_market_index) at the snapshot",
 						],
-						type: 'publicKey',
+						type: 'u64',
 					},
 					{
-						name: 'manager',
-						docs: [
-							'The manager of the vault who has ability to update vault params',
-						],
-						type: 'publicKey',
+						name: 'totalShares',
+						docs: ["The vault's total_shares at the snapshot"],
+						type: 'u128',
 					},
 					{
-						name: 'tokenAccount',
+						name: 'sharesBase',
 						docs: [
-							'The vaults token account. Used to receive tokens between deposits and withdrawals',
+							"The vault's shares_base at the snapshot, total_shares are only comparable across equal shares_base",
 						],
-						type: 'publicKey',
+						type: 'u32',
 					},
 					{
-						name: 'userStats',
-						docs: ['The drift user stats account for the vault'],
-						type: 'publicKey',
+						name: 'padding',
+						type: {
+							array: ['u8', 12],
+						},
 					},
+				],
+			},
+		},
+		{
+			name: 'NavPolicyParams',
+			type: {
+				kind: 'struct',
+				fields: [
 					{
-						name: 'user',
-						docs: ['The drift user account for the vault'],
-						type: 'publicKey',
+						name: 'swingPricingMode',
+						type: 'u8',
 					},
 					{
-						name: 'delegate',
-						docs: [
-							'The vaults designated delegate for drift user account',
-							'can differ from actual user delegate if vault is in liquidation',
-						],
-						type: 'publicKey',
+						name: 'depositSpreadBps',
+						type: 'u16',
 					},
 					{
-						name: 'liquidationDelegate',
-						docs: ['The delegate handling liquidation for depositor'],
-						type: 'publicKey',
+						name: 'withdrawSpreadBps',
+						type: 'u16',
 					},
 					{
-						name: 'userShares',
-						docs: [
-							'The sum of all shares held by the users (vault depositors)',
-						],
-						type: 'u128',
+						name: 'maxSwingBps',
+						type: 'u16',
 					},
 					{
-						name: 'totalShares',
-						docs: [
-							'The sum of all shares: deposits from users, manager deposits, manager profit/fee, and protocol profit/fee.',
-							'The manager deposits are total_shares - user_shares - protocol_profit_and_fee_shares.',
-						],
-						type: 'u128',
+						name: 'oracleValidityMode',
+						type: 'u8',
 					},
 					{
-						name: 'lastFeeUpdateTs',
-						docs: ['Last fee update unix timestamp'],
+						name: 'navBreakerWindow',
 						type: 'i64',
 					},
 					{
-						name: 'liquidationStartTs',
-						docs: ['When the liquidation starts'],
-						type: 'i64',
+						name: 'navBreakerThresholdBps',
+						type: 'u16',
 					},
 					{
-						name: 'redeemPeriod',
-						docs: [
-							'The period (in seconds) that a vault depositor must wait after requesting a withdrawal to finalize withdrawal.',
-							'Currently, the maximum is 90 days.',
-						],
-						type: 'i64',
+						name: 'maxDrawdownBps',
+						type: 'u16',
 					},
+				],
+			},
+		},
+		{
+			name: 'DepositPreview',
+			docs: ['Returned by preview_deposit'],
+			type: {
+				kind: 'struct',
+				fields: [
 					{
-						name: 'totalWithdrawRequested',
-						docs: ['The sum of all outstanding withdraw requests'],
+						name: 'vaultEquity',
+						docs: ['The vault equity the deposit is priced at'],
 						type: 'u64',
 					},
 					{
-						name: 'maxTokens',
+						name: 'amount',
 						docs: [
-							'Max token capacity, once hit/passed vault will reject new deposits (updatable)',
+							"The amount deposited, capped to stay within the vault's max_tokens",
 						],
 						type: 'u64',
 					},
 					{
-						name: 'managementFee',
-						docs: [
-							'The annual fee charged on deposits by the manager.',
-							'Traditional funds typically charge 2% per year on assets under management.',
-						],
-						type: 'i64',
+						name: 'shares',
+						docs: ['The shares minted for the deposit'],
+						type: 'u128',
 					},
 					{
-						name: 'initTs',
-						docs: ['Timestamp vault initialized'],
-						type: 'i64',
+						name: 'vaultSharesAfter',
+						docs: ["The depositor's shares after the deposit"],
+						type: 'u128',
 					},
 					{
-						name: 'netDeposits',
-						docs: ['The net deposits for the vault'],
-						type: 'i64',
+						name: 'totalSharesAfter',
+						docs: ["The vault's total_shares after the deposit"],
+						type: 'u128',
 					},
 					{
-						name: 'managerNetDeposits',
-						docs: ['The net deposits for the manager'],
-						type: 'i64',
+						name: 'sharesBase',
+						type: 'u32',
 					},
 					{
-						name: 'totalDeposits',
-						docs: ['Total deposits'],
+						name: 'managerProfitShare',
+						docs: [
+							"Profit share paid by the depositor's existing shares before the deposit",
+						],
 						type: 'u64',
 					},
 					{
-						name: 'totalWithdraws',
-						docs: ['Total withdraws'],
+						name: 'protocolProfitShare',
 						type: 'u64',
 					},
+				],
+			},
+		},
+		{
+			name: 'WithdrawPreview',
+			docs: ['Returned by preview_withdraw'],
+			type: {
+				kind: 'struct',
+				fields: [
 					{
-						name: 'managerTotalDeposits',
-						docs: ['Total deposits for the manager'],
+						name: 'vaultEquity',
+						docs: ['The vault equity the withdraw request is priced at'],
 						type: 'u64',
 					},
 					{
-						name: 'managerTotalWithdraws',
-						docs: ['Total withdraws for the manager'],
+						name: 'withdrawValue',
+						docs: [
+							"The value of the withdraw request. The withdraw pays the lesser of this and the shares' value once",
+							'the redeem period is over',
+						],
 						type: 'u64',
 					},
 					{
-						name: 'managerTotalFee',
-						docs: ['Total management fee accrued by the manager'],
+						name: 'shares',
+						docs: ['The shares in the withdraw request'],
+						type: 'u128',
+					},
+					{
+						name: 'vaultShares',
+						docs: [
+							"The depositor's shares, after profit share, that the request is taken from",
+						],
+						type: 'u128',
+					},
+					{
+						name: 'sharesBase',
+						type: 'u32',
+					},
+					{
+						name: 'redeemPeriodEndTs',
+						docs: ['The ts the withdraw can be completed at'],
 						type: 'i64',
 					},
 					{
-						name: 'managerTotalProfitShare',
-						docs: ['Total profit share accrued by the manager'],
+						name: 'managerProfitShare',
+						docs: ['Profit share paid by the depositor before the request'],
+						type: 'u64',
+					},
+					{
+						name: 'protocolProfitShare',
 						type: 'u64',
 					},
+				],
+			},
+		},
+		{
+			name: 'DepositorEquityPreview',
+			docs: ['Returned by preview_depositor_equity'],
+			type: {
+				kind: 'struct',
+				fields: [
 					{
-						name: 'minDepositAmount',
-						docs: ['The minimum deposit amount'],
+						name: 'vaultEquity',
+						docs: ["The vault equity the depositor's shares are valued at"],
 						type: 'u64',
 					},
 					{
-						name: 'lastManagerWithdrawRequest',
-						type: {
-							defined: 'WithdrawRequest',
-						},
+						name: 'vaultShares',
+						docs: ["The depositor's shares after fees and profit share"],
+						type: 'u128',
 					},
 					{
 						name: 'sharesBase',
-						docs: [
-							'The base 10 exponent of the shares (given massive share inflation can occur at near zero vault equity)',
-						],
 						type: 'u32',
 					},
 					{
-						name: 'profitShare',
+						name: 'equity',
 						docs: [
-							'Percentage the manager charges on all profits realized by depositors: PERCENTAGE_PRECISION',
+							"The value of the depositor's shares after fees and profit share",
 						],
-						type: 'u32',
+						type: 'u64',
 					},
 					{
-						name: 'hurdleRate',
+						name: 'managerProfitShare',
 						docs: [
-							'Vault manager only collect incentive fees during periods when returns are higher than this amount: PERCENTAGE_PRECISION',
+							'Profit share the depositor would pay now. Not charged while a withdraw request is pending',
 						],
-						type: 'u32',
+						type: 'u64',
 					},
 					{
-						name: 'spotMarketIndex',
-						docs: [
-							'The spot market index the vault deposits into/withdraws from',
-						],
-						type: 'u16',
+						name: 'protocolProfitShare',
+						type: 'u64',
 					},
 					{
-						name: 'bump',
-						docs: ['The bump for the vault pda'],
-						type: 'u8',
+						name: 'costBasis',
+						docs: [
+							"The deposit asset paid for the depositor's shares, equity less cost_basis is its unrealized pnl",
+						],
+						type: 'u64',
 					},
 					{
-						name: 'permissioned',
-						docs: ['Whether anybody can be a depositor'],
-						type: 'bool',
+						name: 'averageEntryPrice',
+						docs: [
+							'cost_basis per share in shares_base. precision: NAV_PER_SHARE_PRECISION',
+						],
+						type: 'u64',
 					},
+				],
+			},
+		},
+		{
+			name: 'FeesPreview',
+			docs: ['Returned by preview_fees'],
+			type: {
+				kind: 'struct',
+				fields: [
 					{
-						name: 'vaultProtocol',
-						docs: ['The optional [`VaultProtocol`] account.'],
-						type: 'bool',
+						name: 'vaultEquity',
+						type: 'u64',
 					},
 					{
-						name: 'fuelDistributionMode',
+						name: 'managementFee',
 						docs: [
-							'How fuel distribution should be treated [`FuelDistributionMode`]. Default is `UsersOnly`',
+							"The vault's fees, including a pending fee update whose timelock has passed",
 						],
-						type: 'u8',
+						type: 'i64',
 					},
 					{
-						name: 'feeUpdateStatus',
-						docs: [
-							'Whether the vault has a FeeUpdate account [`FeeUpdateStatus`]. Default is `FeeUpdateStatus::None`',
-							'After a `FeeUpdate` account is created and the manager has staged a fee update, the status is set to `PendingFeeUpdate`.',
-							'And instructsions that may finalize the fee update must include the `FeeUpdate` account with `remaining_accounts`.',
-						],
-						type: 'u8',
+						name: 'profitShare',
+						type: 'u32',
 					},
 					{
-						name: 'vaultClass',
-						docs: [
-							'The class of the vault [`VaultClass`]. Default is `VaultClass::Normal`',
-						],
-						type: 'u8',
+						name: 'hurdleRate',
+						type: 'u32',
 					},
 					{
-						name: 'lastCumulativeFuelPerShareTs',
-						docs: ['The timestamp cumulative_fuel_per_share was last updated'],
-						type: 'u32',
+						name: 'managementFeePayment',
+						docs: ["Fees accrued since the vault's last_fee_update_ts"],
+						type: 'i64',
 					},
 					{
-						name: 'cumulativeFuelPerShare',
-						docs: [
-							'The cumulative fuel per share (scaled up by 1e6 to avoid losing precision)',
-						],
-						type: 'u128',
+						name: 'managementFeeShares',
+						type: 'i64',
 					},
 					{
-						name: 'cumulativeFuel',
-						docs: ['The total fuel accumulated'],
-						type: 'u128',
+						name: 'protocolFeePayment',
+						type: 'i64',
 					},
 					{
-						name: 'managerBorrowedValue',
-						docs: [
-							'The total value (in deposit asset) of borrows the manager has outstanding.',
-							'Purely for informational purposes for assets that have left the vault that the manager',
-							'is expected to return.',
-						],
-						type: 'u64',
+						name: 'protocolFeeShares',
+						type: 'i64',
 					},
 					{
-						name: 'padding',
-						type: {
-							array: ['u64', 2],
-						},
+						name: 'totalSharesAfter',
+						docs: ["The vault's total_shares after fees"],
+						type: 'u128',
+					},
+					{
+						name: 'sharesBase',
+						type: 'u32',
 					},
 				],
 			},
 		},
-	],
-	types: [
 		{
-			name: 'InitializeTokenizedVaultDepositorParams',
+			name: 'WithdrawRequest',
 			type: {
 				kind: 'struct',
 				fields: [
 					{
-						name: 'tokenName',
-						type: 'string',
-					},
-					{
-						name: 'tokenSymbol',
-						type: 'string',
+						name: 'shares',
+						docs: ['request shares of vault withdraw'],
+						type: 'u128',
 					},
 					{
-						name: 'tokenUri',
-						type: 'string',
+						name: 'value',
+						docs: [
+							'requested value (in vault spot_market_index) of shares for withdraw',
+						],
+						type: 'u64',
 					},
 					{
-						name: 'decimals',
-						type: 'u8',
+						name: 'ts',
+						docs: ['request ts of vault withdraw'],
+						type: 'i64',
 					},
 				],
 			},
 		},
 		{
-			name: 'VaultWithProtocolParams',
+			name: 'VaultDepositorAction',
 			type: {
-				kind: 'struct',
-				fields: [
+				kind: 'enum',
+				variants: [
 					{
-						name: 'name',
-						type: {
-							array: ['u8', 32],
-						},
+						name: 'Deposit',
 					},
 					{
-						name: 'redeemPeriod',
-						type: 'i64',
+						name: 'WithdrawRequest',
 					},
 					{
-						name: 'maxTokens',
-						type: 'u64',
+						name: 'CancelWithdrawRequest',
 					},
 					{
-						name: 'managementFee',
-						type: 'i64',
+						name: 'Withdraw',
 					},
 					{
-						name: 'minDepositAmount',
-						type: 'u64',
+						name: 'FeePayment',
 					},
 					{
-						name: 'profitShare',
-						type: 'u32',
+						name: 'TokenizeShares',
 					},
 					{
-						name: 'hurdleRate',
-						type: 'u32',
+						name: 'RedeemTokens',
 					},
+				],
+			},
+		},
+		{
+			name: 'FeeUpdateAction',
+			type: {
+				kind: 'enum',
+				variants: [
 					{
-						name: 'spotMarketIndex',
-						type: 'u16',
+						name: 'Pending',
 					},
 					{
-						name: 'permissioned',
-						type: 'bool',
+						name: 'Applied',
 					},
 					{
-						name: 'vaultProtocol',
-						type: {
-							defined: 'VaultProtocolParams',
-						},
+						name: 'Cancelled',
 					},
 				],
 			},
 		},
 		{
-			name: 'VaultProtocolParams',
+			name: 'FuelDistributionUpdateAction',
 			type: {
-				kind: 'struct',
-				fields: [
-					{
-						name: 'protocol',
-						type: 'publicKey',
-					},
+				kind: 'enum',
+				variants: [
 					{
-						name: 'protocolFee',
-						type: 'u64',
+						name: 'Pending',
 					},
 					{
-						name: 'protocolProfitShare',
-						type: 'u32',
+						name: 'Applied',
 					},
 				],
 			},
 		},
 		{
-			name: 'VaultParams',
+			name: 'NavCircuitBreakerAction',
 			type: {
-				kind: 'struct',
-				fields: [
+				kind: 'enum',
+				variants: [
 					{
-						name: 'name',
-						type: {
-							array: ['u8', 32],
-						},
+						name: 'Tripped',
 					},
 					{
-						name: 'redeemPeriod',
-						type: 'i64',
+						name: 'Reset',
 					},
+				],
+			},
+		},
+		{
+			name: 'DrawdownProtectionAction',
+			type: {
+				kind: 'enum',
+				variants: [
 					{
-						name: 'maxTokens',
-						type: 'u64',
+						name: 'Triggered',
 					},
 					{
-						name: 'managementFee',
-						type: 'i64',
+						name: 'ReenablePending',
 					},
 					{
-						name: 'minDepositAmount',
-						type: 'u64',
+						name: 'Reenabled',
 					},
+				],
+			},
+		},
+		{
+			name: 'VaultConfigAction',
+			type: {
+				kind: 'enum',
+				variants: [
 					{
-						name: 'profitShare',
-						type: 'u32',
+						name: 'UpdateVault',
 					},
 					{
-						name: 'hurdleRate',
-						type: 'u32',
+						name: 'UpdateDelegate',
 					},
 					{
-						name: 'spotMarketIndex',
-						type: 'u16',
+						name: 'ResetDelegate',
 					},
 					{
-						name: 'permissioned',
-						type: 'bool',
+						name: 'UpdateMarginTradingEnabled',
 					},
-				],
-			},
-		},
-		{
-			name: 'ManagerUpdateFeesParams',
-			type: {
-				kind: 'struct',
-				fields: [
 					{
-						name: 'timelockDuration',
-						type: 'i64',
+						name: 'UpdatePoolId',
 					},
 					{
-						name: 'newManagementFee',
-						type: {
-							option: 'i64',
-						},
+						name: 'UpdateVaultManager',
 					},
 					{
-						name: 'newProfitShare',
-						type: {
-							option: 'u32',
-						},
+						name: 'UpdateVaultClass',
+					},
+					{
+						name: 'UpdateFuelDistributionMode',
 					},
 					{
-						name: 'newHurdleRate',
-						type: {
-							option: 'u32',
-						},
+						name: 'InitializeNavPolicy',
 					},
-				],
-			},
-		},
-		{
-			name: 'UpdateVaultProtocolParams',
-			type: {
-				kind: 'struct',
-				fields: [
 					{
-						name: 'protocolFee',
-						type: {
-							option: 'u64',
-						},
+						name: 'UpdateNavPolicy',
 					},
 					{
-						name: 'protocolProfitShare',
-						type: {
-							option: 'u32',
-						},
+						name: 'UpdateVaultProtocol',
 					},
-				],
-			},
-		},
-		{
-			name: 'UpdateVaultParams',
-			type: {
-				kind: 'struct',
-				fields: [
 					{
-						name: 'redeemPeriod',
-						type: {
-							option: 'i64',
-						},
+						name: 'UpdateFuelDistribution',
 					},
 					{
-						name: 'maxTokens',
-						type: {
-							option: 'u64',
-						},
+						name: 'ReenableTrading',
 					},
 					{
-						name: 'managementFee',
-						type: {
-							option: 'i64',
-						},
+						name: 'UpdateFees',
 					},
 					{
-						name: 'minDepositAmount',
-						type: {
-							option: 'u64',
-						},
+						name: 'ApplyFeeUpdate',
 					},
 					{
-						name: 'profitShare',
-						type: {
-							option: 'u32',
-						},
+						name: 'InitializeFeeUpdate',
 					},
 					{
-						name: 'hurdleRate',
-						type: {
-							option: 'u32',
-						},
+						name: 'DeleteFeeUpdate',
 					},
 					{
-						name: 'permissioned',
-						type: {
-							option: 'bool',
-						},
+						name: 'UpdateBorrow',
+					},
+					{
+						name: 'ApplyFuelDistribution',
 					},
 				],
 			},
 		},
 		{
-			name: 'WithdrawRequest',
+			name: 'FuelLockupPeriod',
+			docs: [
+				'Voluntary lockups a depositor can opt into. While locked, `request_withdraw` is blocked and the',
+				"depositor's shares accrue fuel with the lockup's multiplier.",
+			],
 			type: {
-				kind: 'struct',
-				fields: [
+				kind: 'enum',
+				variants: [
 					{
-						name: 'shares',
-						docs: ['request shares of vault withdraw'],
-						type: 'u128',
+						name: 'ThirtyDays',
 					},
 					{
-						name: 'value',
-						docs: [
-							'requested value (in vault spot_market_index) of shares for withdraw',
-						],
-						type: 'u64',
+						name: 'NinetyDays',
 					},
 					{
-						name: 'ts',
-						docs: ['request ts of vault withdraw'],
-						type: 'i64',
+						name: 'OneHundredEightyDays',
 					},
 				],
 			},
 		},
 		{
-			name: 'VaultDepositorAction',
+			name: 'NavSide',
 			type: {
 				kind: 'enum',
 				variants: [
 					{
 						name: 'Deposit',
 					},
-					{
-						name: 'WithdrawRequest',
-					},
-					{
-						name: 'CancelWithdrawRequest',
-					},
 					{
 						name: 'Withdraw',
 					},
+				],
+			},
+		},
+		{
+			name: 'SwingPricingMode',
+			type: {
+				kind: 'enum',
+				variants: [
 					{
-						name: 'FeePayment',
+						name: 'Disabled',
 					},
 					{
-						name: 'TokenizeShares',
+						name: 'Fixed',
 					},
 					{
-						name: 'RedeemTokens',
+						name: 'PerpMarketSpread',
 					},
 				],
 			},
 		},
 		{
-			name: 'FeeUpdateAction',
+			name: 'OracleValidityMode',
 			type: {
 				kind: 'enum',
 				variants: [
 					{
-						name: 'Pending',
-					},
-					{
-						name: 'Applied',
+						name: 'Strict',
 					},
 					{
-						name: 'Cancelled',
+						name: 'ConservativeWithdraws',
 					},
 				],
 			},
@@ -6647,6 +13330,9 @@ export const IDL: DriftVaults = {
 					{
 						name: 'UsersAndManager',
 					},
+					{
+						name: 'Weighted',
+					},
 				],
 			},
 		},
@@ -6705,6 +13391,11 @@ export const IDL: DriftVaults = {
 					type: 'i64',
 					index: false,
 				},
+				{
+					name: 'vault',
+					type: 'publicKey',
+					index: false,
+				},
 				{
 					name: 'spotMarketIndex',
 					type: 'u16',
@@ -6715,6 +13406,16 @@ export const IDL: DriftVaults = {
 					type: 'u64',
 					index: false,
 				},
+				{
+					name: 'totalShares',
+					type: 'u128',
+					index: false,
+				},
+				{
+					name: 'sharesBase',
+					type: 'u32',
+					index: false,
+				},
 			],
 		},
 		{
@@ -6870,58 +13571,214 @@ export const IDL: DriftVaults = {
 					index: false,
 				},
 				{
-					name: 'userVaultSharesAfter',
-					type: 'u128',
+					name: 'userVaultSharesAfter',
+					type: 'u128',
+					index: false,
+				},
+				{
+					name: 'totalVaultSharesAfter',
+					type: 'u128',
+					index: false,
+				},
+				{
+					name: 'protocolSharesBefore',
+					type: 'u128',
+					index: false,
+				},
+				{
+					name: 'protocolSharesAfter',
+					type: 'u128',
+					index: false,
+				},
+				{
+					name: 'protocolProfitShare',
+					type: 'u64',
+					index: false,
+				},
+				{
+					name: 'protocolFee',
+					type: 'i64',
+					index: false,
+				},
+				{
+					name: 'protocolFeeShares',
+					type: 'i64',
+					index: false,
+				},
+				{
+					name: 'managerProfitShare',
+					type: 'u64',
+					index: false,
+				},
+				{
+					name: 'managementFee',
+					type: 'i64',
+					index: false,
+				},
+				{
+					name: 'managementFeeShares',
+					type: 'i64',
+					index: false,
+				},
+				{
+					name: 'depositOraclePrice',
+					type: 'i64',
+					index: false,
+				},
+			],
+		},
+		{
+			name: 'VaultDepositorV2Record',
+			fields: [
+				{
+					name: 'ts',
+					type: 'i64',
+					index: false,
+				},
+				{
+					name: 'vault',
+					type: 'publicKey',
+					index: false,
+				},
+				{
+					name: 'depositorAuthority',
+					type: 'publicKey',
+					index: false,
+				},
+				{
+					name: 'action',
+					type: {
+						defined: 'VaultDepositorAction',
+					},
+					index: false,
+				},
+				{
+					name: 'amount',
+					type: 'u64',
+					index: false,
+				},
+				{
+					name: 'spotMarketIndex',
+					type: 'u16',
+					index: false,
+				},
+				{
+					name: 'sharesBase',
+					type: 'u32',
+					index: false,
+				},
+				{
+					name: 'vaultSharesBefore',
+					type: 'u128',
+					index: false,
+				},
+				{
+					name: 'vaultSharesAfter',
+					type: 'u128',
+					index: false,
+				},
+				{
+					name: 'vaultEquityBefore',
+					type: 'u64',
+					index: false,
+				},
+				{
+					name: 'managerBorrowedValue',
+					type: 'u64',
+					index: false,
+				},
+				{
+					name: 'feeUpdateStatus',
+					type: 'u8',
+					index: false,
+				},
+				{
+					name: 'userVaultSharesBefore',
+					type: 'u128',
+					index: false,
+				},
+				{
+					name: 'totalVaultSharesBefore',
+					type: 'u128',
+					index: false,
+				},
+				{
+					name: 'userVaultSharesAfter',
+					type: 'u128',
+					index: false,
+				},
+				{
+					name: 'totalVaultSharesAfter',
+					type: 'u128',
+					index: false,
+				},
+				{
+					name: 'managerProfitShare',
+					type: 'u64',
+					index: false,
+				},
+				{
+					name: 'managementFee',
+					type: 'i64',
 					index: false,
 				},
 				{
-					name: 'totalVaultSharesAfter',
-					type: 'u128',
+					name: 'managementFeeShares',
+					type: 'i64',
 					index: false,
 				},
 				{
 					name: 'protocolSharesBefore',
-					type: 'u128',
+					type: {
+						option: 'u128',
+					},
 					index: false,
 				},
 				{
 					name: 'protocolSharesAfter',
-					type: 'u128',
+					type: {
+						option: 'u128',
+					},
 					index: false,
 				},
 				{
 					name: 'protocolProfitShare',
-					type: 'u64',
+					type: {
+						option: 'u64',
+					},
 					index: false,
 				},
 				{
 					name: 'protocolFee',
-					type: 'i64',
+					type: {
+						option: 'i64',
+					},
 					index: false,
 				},
 				{
 					name: 'protocolFeeShares',
-					type: 'i64',
-					index: false,
-				},
-				{
-					name: 'managerProfitShare',
-					type: 'u64',
+					type: {
+						option: 'i64',
+					},
 					index: false,
 				},
 				{
-					name: 'managementFee',
+					name: 'depositOraclePrice',
 					type: 'i64',
 					index: false,
 				},
 				{
-					name: 'managementFeeShares',
-					type: 'i64',
+					name: 'costBasisAfter',
+					type: {
+						option: 'u64',
+					},
 					index: false,
 				},
 				{
-					name: 'depositOraclePrice',
-					type: 'i64',
+					name: 'realizedPnl',
+					type: {
+						option: 'i64',
+					},
 					index: false,
 				},
 			],
@@ -7031,6 +13888,51 @@ export const IDL: DriftVaults = {
 				},
 			],
 		},
+		{
+			name: 'VaultDepositorFuelRecord',
+			fields: [
+				{
+					name: 'ts',
+					type: 'i64',
+					index: false,
+				},
+				{
+					name: 'vault',
+					type: 'publicKey',
+					index: false,
+				},
+				{
+					name: 'vaultDepositor',
+					type: 'publicKey',
+					index: false,
+				},
+				{
+					name: 'depositorAuthority',
+					type: 'publicKey',
+					index: false,
+				},
+				{
+					name: 'fuelAmountBefore',
+					type: 'u128',
+					index: false,
+				},
+				{
+					name: 'fuelAmountAfter',
+					type: 'u128',
+					index: false,
+				},
+				{
+					name: 'fuelShares',
+					type: 'u128',
+					index: false,
+				},
+				{
+					name: 'cumulativeFuelPerShare',
+					type: 'u128',
+					index: false,
+				},
+			],
+		},
 		{
 			name: 'FeeUpdateRecord',
 			fields: [
@@ -7088,6 +13990,53 @@ export const IDL: DriftVaults = {
 				},
 			],
 		},
+		{
+			name: 'FuelDistributionUpdateRecord',
+			fields: [
+				{
+					name: 'ts',
+					type: 'i64',
+					index: false,
+				},
+				{
+					name: 'action',
+					type: {
+						defined: 'FuelDistributionUpdateAction',
+					},
+					index: false,
+				},
+				{
+					name: 'timelockEndTs',
+					type: 'i64',
+					index: false,
+				},
+				{
+					name: 'vault',
+					type: 'publicKey',
+					index: false,
+				},
+				{
+					name: 'oldManagerFuelBps',
+					type: 'u16',
+					index: false,
+				},
+				{
+					name: 'oldProtocolFuelBps',
+					type: 'u16',
+					index: false,
+				},
+				{
+					name: 'newManagerFuelBps',
+					type: 'u16',
+					index: false,
+				},
+				{
+					name: 'newProtocolFuelBps',
+					type: 'u16',
+					index: false,
+				},
+			],
+		},
 		{
 			name: 'ManagerBorrowRecord',
 			fields: [
@@ -7243,6 +14192,136 @@ export const IDL: DriftVaults = {
 				},
 			],
 		},
+		{
+			name: 'NavCircuitBreakerRecord',
+			fields: [
+				{
+					name: 'ts',
+					type: 'i64',
+					index: false,
+				},
+				{
+					name: 'action',
+					type: {
+						defined: 'NavCircuitBreakerAction',
+					},
+					index: false,
+				},
+				{
+					name: 'vault',
+					type: 'publicKey',
+					index: false,
+				},
+				{
+					name: 'authority',
+					type: 'publicKey',
+					index: false,
+				},
+				{
+					name: 'lastEquityPerShare',
+					type: 'u128',
+					index: false,
+				},
+				{
+					name: 'lastEquityTs',
+					type: 'i64',
+					index: false,
+				},
+				{
+					name: 'equityPerShare',
+					type: 'u128',
+					index: false,
+				},
+				{
+					name: 'thresholdBps',
+					type: 'u16',
+					index: false,
+				},
+			],
+		},
+		{
+			name: 'DrawdownProtectionRecord',
+			fields: [
+				{
+					name: 'ts',
+					type: 'i64',
+					index: false,
+				},
+				{
+					name: 'action',
+					type: {
+						defined: 'DrawdownProtectionAction',
+					},
+					index: false,
+				},
+				{
+					name: 'vault',
+					type: 'publicKey',
+					index: false,
+				},
+				{
+					name: 'hwmEquityPerShare',
+					type: 'u128',
+					index: false,
+				},
+				{
+					name: 'equityPerShare',
+					type: 'u128',
+					index: false,
+				},
+				{
+					name: 'maxDrawdownBps',
+					type: 'u16',
+					index: false,
+				},
+				{
+					name: 'reenableTradingTs',
+					type: 'i64',
+					index: false,
+				},
+			],
+		},
+		{
+			name: 'VaultConfigRecord',
+			fields: [
+				{
+					name: 'ts',
+					type: 'i64',
+					index: false,
+				},
+				{
+					name: 'action',
+					type: {
+						defined: 'VaultConfigAction',
+					},
+					index: false,
+				},
+				{
+					name: 'vault',
+					type: 'publicKey',
+					index: false,
+				},
+				{
+					name: 'authority',
+					type: 'publicKey',
+					index: false,
+				},
+				{
+					name: 'oldConfig',
+					type: {
+						defined: 'VaultConfig',
+					},
+					index: false,
+				},
+				{
+					name: 'newConfig',
+					type: {
+						defined: 'VaultConfig',
+					},
+					index: false,
+				},
+			],
+		},
 	],
 	errors: [
 		{
@@ -7395,5 +14474,60 @@ export const IDL: DriftVaults = {
 			name: 'InvalidRepayAmount',
 			msg: 'InvalidRepayAmount',
 		},
+		{
+			code: 6030,
+			name: 'VaultDepositorLocked',
+			msg: 'VaultDepositorLocked',
+		},
+		{
+			code: 6031,
+			name: 'InvalidRewardStream',
+			msg: 'InvalidRewardStream',
+		},
+		{
+			code: 6032,
+			name: 'InvalidNavPolicy',
+			msg: 'InvalidNavPolicy',
+		},
+		{
+			code: 6033,
+			name: 'NavPolicyMissing',
+			msg: 'NavPolicyMissing',
+		},
+		{
+			code: 6034,
+			name: 'NavCircuitBreakerTripped',
+			msg: 'NavCircuitBreakerTripped',
+		},
+		{
+			code: 6035,
+			name: 'NavSnapshotTooSoon',
+			msg: 'NavSnapshotTooSoon',
+		},
+		{
+			code: 6036,
+			name: 'FuelDistributionMissing',
+			msg: 'FuelDistributionMissing',
+		},
+		{
+			code: 6037,
+			name: 'FuelBoostNotExpired',
+			msg: 'FuelBoostNotExpired',
+		},
+		{
+			code: 6038,
+			name: 'RewardStreamDepositorMissing',
+			msg: 'RewardStreamDepositorMissing',
+		},
+		{
+			code: 6039,
+			name: 'InvalidFuelSeason',
+			msg: 'InvalidFuelSeason',
+		},
+		{
+			code: 6040,
+			name: 'NavCircuitBreakerNotTripped',
+			msg: 'NavCircuitBreakerNotTripped',
+		},
 	],
 };
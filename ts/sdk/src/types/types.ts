@@ -232,6 +232,8 @@ export class VaultDepositorAction {
 	static readonly WITHDRAW_REQUEST = { withdrawRequest: {} };
 	static readonly CANCEL_WITHDRAW_REQUEST = { cancelWithdrawRequest: {} };
 	static readonly FEE_PAYMENT = { feePayment: {} };
+	static readonly TOKENIZE_SHARES = { tokenizeShares: {} };
+	static readonly REDEEM_TOKENS = { redeemTokens: {} };
 }
 
 export type VaultDepositorRecord = {
@@ -290,6 +292,48 @@ export type VaultDepositorV1Record = {
 	depositOraclePrice: BN;
 };
 
+/**
+ * Emitted by every depositor state change. The protocol fields are null for vaults without a VaultProtocol,
+ * and costBasisAfter and realizedPnl are null where the record doesn't change them.
+ */
+export type VaultDepositorV2Record = {
+	ts: BN;
+
+	vault: PublicKey;
+	depositorAuthority: PublicKey;
+	action: VaultDepositorAction;
+	amount: BN;
+
+	spotMarketIndex: number;
+	sharesBase: number;
+	vaultSharesBefore: BN;
+	vaultSharesAfter: BN;
+	vaultEquityBefore: BN;
+	managerBorrowedValue: BN;
+	feeUpdateStatus: number;
+
+	userVaultSharesBefore: BN;
+	totalVaultSharesBefore: BN;
+
+	userVaultSharesAfter: BN;
+	totalVaultSharesAfter: BN;
+
+	managerProfitShare: BN;
+	managementFee: BN;
+	managementFeeShares: BN;
+
+	protocolSharesBefore: BN | null;
+	protocolSharesAfter: BN | null;
+	protocolProfitShare: BN | null;
+	protocolFee: BN | null;
+	protocolFeeShares: BN | null;
+
+	depositOraclePrice: BN;
+
+	costBasisAfter: BN | null;
+	realizedPnl: BN | null;
+};
+
 export type FuelSeasonRecord = {
 	ts: BN;
 	authority: PublicKey;
@@ -305,6 +349,7 @@ export type FuelSeasonRecord = {
 export type VaultsEventMap = {
 	VaultDepositorRecord: Event<VaultDepositorRecord>;
 	VaultDepositorV1Record: Event<VaultDepositorV1Record>;
+	VaultDepositorV2Record: Event<VaultDepositorV2Record>;
 };
 
 export type EventType = keyof VaultsEventMap;
@@ -32,6 +32,8 @@ import {
 	getVaultDepositorAddressSync,
 	getVaultProtocolAddressSync,
 	getFeeUpdateAddressSync,
+	getFuelSeasonSnapshotAddressSync,
+	getTransferHookExtraAccountMetasAddressSync,
 } from './addresses';
 import {
 	AccountMeta,
@@ -49,6 +51,7 @@ import {
 	createCloseAccountInstruction,
 	createSyncNativeInstruction,
 	getAssociatedTokenAddressSync,
+	TOKEN_2022_PROGRAM_ID,
 	TOKEN_PROGRAM_ID,
 } from '@solana/spl-token';
 import {
@@ -1923,6 +1926,143 @@ export class VaultClient {
 		);
 	}
 
+	/**
+	 * Initializes a tokenized vault depositor whose share mint is a Token-2022 mint with native metadata.
+	 * @param params.enableTransferHook create the mint with the vault program as its transfer hook, enforcing
+	 * the vault allowlist and depositor lockups on transfers of the share token
+	 */
+	public async initializeTokenizedVaultDepositorToken2022(
+		params: {
+			vault: PublicKey;
+			tokenName: string;
+			tokenSymbol: string;
+			tokenUri: string;
+			decimals?: number;
+			enableTransferHook?: boolean;
+		},
+		uiTxParams?: TxParams
+	): Promise<TransactionSignature> {
+		const vaultAccount = await this.program.account.vault.fetch(params.vault);
+
+		let decimals = params.decimals;
+		if (decimals === undefined) {
+			const spotMarketAccount = this.driftClient.getSpotMarketAccount(
+				vaultAccount.spotMarketIndex
+			);
+			if (!spotMarketAccount) {
+				throw new Error(
+					`DriftClient failed to load vault's spot market (marketIndex: ${vaultAccount.spotMarketIndex})`
+				);
+			}
+			decimals = spotMarketAccount.decimals;
+		}
+
+		const mintAddress = getTokenizedVaultMintAddressSync(
+			this.program.programId,
+			params.vault,
+			vaultAccount.sharesBase
+		);
+		const enableTransferHook = params.enableTransferHook ?? false;
+
+		const ix = await this.program.methods
+			.initializeTokenizedVaultDepositorToken2022({
+				tokenName: params.tokenName,
+				tokenSymbol: params.tokenSymbol,
+				tokenUri: params.tokenUri,
+				decimals,
+				enableTransferHook,
+			})
+			.accounts({
+				vault: params.vault,
+				vaultDepositor: getTokenizedVaultAddressSync(
+					this.program.programId,
+					params.vault,
+					vaultAccount.sharesBase
+				),
+				mintAccount: mintAddress,
+				payer: vaultAccount.manager,
+				extraAccountMetaList: enableTransferHook
+					? getTransferHookExtraAccountMetasAddressSync(
+							this.program.programId,
+							mintAddress
+					  )
+					: null,
+				tokenProgram: TOKEN_2022_PROGRAM_ID,
+			})
+			.instruction();
+
+		return await this.createAndSendTxn([ix], uiTxParams);
+	}
+
+	/**
+	 * Updates the name, symbol or uri of a tokenized vault depositor's share mint. Only the vault manager can update them.
+	 * @param params.sharesBase the shares base of the tokenized vault depositor, defaults to the vault's current one
+	 */
+	public async updateTokenizedVaultDepositorMetadata(
+		params: {
+			vault: PublicKey;
+			tokenName?: string;
+			tokenSymbol?: string;
+			tokenUri?: string;
+			sharesBase?: number;
+		},
+		uiTxParams?: TxParams
+	): Promise<TransactionSignature> {
+		const vaultAccount = await this.program.account.vault.fetch(params.vault);
+		const sharesBase = params.sharesBase ?? vaultAccount.sharesBase;
+
+		const mint = getTokenizedVaultMintAddressSync(
+			this.program.programId,
+			params.vault,
+			sharesBase
+		);
+		const tokenProgram = await this.getMintTokenProgram(mint);
+		const isToken2022 = tokenProgram.equals(TOKEN_2022_PROGRAM_ID);
+		if (!isToken2022 && !this.metaplex) {
+			throw new Error(
+				'Metaplex instance is required when constructing VaultClient to update SPL Token share metadata'
+			);
+		}
+
+		const ix = await this.program.methods
+			.updateTokenizedVaultDepositorMetadata({
+				tokenName: params.tokenName ?? null,
+				tokenSymbol: params.tokenSymbol ?? null,
+				tokenUri: params.tokenUri ?? null,
+			})
+			.accounts({
+				vault: params.vault,
+				manager: vaultAccount.manager,
+				tokenizedVaultDepositor: getTokenizedVaultAddressSync(
+					this.program.programId,
+					params.vault,
+					sharesBase
+				),
+				mint,
+				metadataAccount: isToken2022
+					? mint
+					: this.metaplex.nfts().pdas().metadata({ mint }),
+				tokenProgram,
+				tokenMetadataProgram: isToken2022
+					? null
+					: this.metaplex.programs().getTokenMetadata().address,
+			})
+			.instruction();
+
+		return await this.createAndSendTxn([ix], uiTxParams);
+	}
+
+	/**
+	 * The token program owning a share mint, SPL Token or Token-2022
+	 */
+	public async getMintTokenProgram(mint: PublicKey): Promise<PublicKey> {
+		const mintAccount = await this.driftClient.connection.getAccountInfo(mint);
+		if (!mintAccount) {
+			throw new Error(`Mint ${mint.toBase58()} not found`);
+		}
+		return mintAccount.owner;
+	}
+
 	public async createTokenizeSharesIx(
 		vaultDepositor: PublicKey,
 		amount: BN,
@@ -1943,10 +2083,12 @@ export class VaultClient {
 				vaultAccount.sharesBase
 			);
 
+		const tokenProgram = await this.getMintTokenProgram(mint);
 		const userAta = getAssociatedTokenAddressSync(
 			mint,
 			this.driftClient.wallet.publicKey,
-			true
+			true,
+			tokenProgram
 		);
 
 		const ixs: TransactionInstruction[] = [];
@@ -1960,7 +2102,8 @@ export class VaultClient {
 					this.driftClient.wallet.publicKey,
 					userAta,
 					this.driftClient.wallet.publicKey,
-					mint
+					mint,
+					tokenProgram
 				)
 			);
 		}
@@ -2000,7 +2143,8 @@ export class VaultClient {
 					mint,
 					userTokenAccount: userAta,
 					driftUser: vaultAccount.user,
-					tokenProgram: TOKEN_PROGRAM_ID,
+					driftUserStats: userStatsKey,
+					tokenProgram,
 				})
 				.remainingAccounts(remainingAccounts)
 				.instruction()
@@ -2067,6 +2211,7 @@ export class VaultClient {
 					authority: this.driftClient.wallet.publicKey,
 					toVaultDepositor,
 					driftUser: vaultAccount.user,
+					driftUserStats: userStatsKey,
 				})
 				.remainingAccounts(remainingAccounts)
 				.instruction()
@@ -2108,16 +2253,12 @@ export class VaultClient {
 			sharesBase ?? vaultAccount.sharesBase
 		);
 
+		const tokenProgram = await this.getMintTokenProgram(mint);
 		const userAta = getAssociatedTokenAddressSync(
 			mint,
 			this.driftClient.wallet.publicKey,
-			true
-		);
-
-		const vaultTokenAta = getAssociatedTokenAddressSync(
-			mint,
-			vaultDepositorAccount.vault,
-			true
+			true,
+			tokenProgram
 		);
 
 		const user = await this.getSubscribedVaultUser(vaultAccount.user);
@@ -2139,7 +2280,7 @@ export class VaultClient {
 		);
 
 		return await this.program.methods
-			.redeemTokens(tokensToBurn)
+			.redeemTokensV2(tokensToBurn)
 			.accounts({
 				authority: this.driftClient.wallet.publicKey,
 				vault: vaultDepositorAccount.vault,
@@ -2151,9 +2292,9 @@ export class VaultClient {
 				),
 				mint,
 				userTokenAccount: userAta,
-				vaultTokenAccount: vaultTokenAta,
 				driftUser: vaultAccount.user,
-				tokenProgram: TOKEN_PROGRAM_ID,
+				driftUserStats: userStatsKey,
+				tokenProgram,
 			})
 			.remainingAccounts(remainingAccounts)
 			.instruction();
@@ -3749,9 +3890,16 @@ export class VaultClient {
 			.accounts({
 				vault: vaultDepositorAccount.vault,
 				vaultDepositor,
+				fuelSeasonSnapshot: getFuelSeasonSnapshotAddressSync(
+					this.program.programId,
+					vaultDepositor,
+					vaultAccount.fuelSeason
+				),
 				admin: this.driftClient.wallet.publicKey,
+				payer: this.driftClient.wallet.publicKey,
 				driftUserStats: userStatsKey,
 				driftState: await this.driftClient.getStatePublicKey(),
+				systemProgram: SystemProgram.programId,
 				// @ts-ignore
 				logAccount: FUEL_RESET_LOG_ACCOUNT,
 			})